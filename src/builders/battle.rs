@@ -8,6 +8,7 @@ use crate::core::battle_format::BattleFormat;
 use crate::core::battle_state::BattleState;
 use crate::data::GameDataRepository;
 use crate::data::RandomPokemonSet;
+use crate::engine::coverage::{check_battle_coverage, CoverageGap};
 use crate::simulator::Player;
 
 /// Battle builder with standardized interface
@@ -41,6 +42,8 @@ pub struct BattleConfig {
     pub detailed_logging: bool,
     /// Timeout per turn in milliseconds
     pub turn_timeout_ms: Option<u32>,
+    /// Whether teams using unimplemented effects are rejected
+    pub strict_format_validation: bool,
 }
 
 impl Default for BattleConfig {
@@ -51,6 +54,7 @@ impl Default for BattleConfig {
             measure_time: false,
             detailed_logging: false,
             turn_timeout_ms: None,
+            strict_format_validation: crate::config::Config::default().battle.strict_format_validation,
         }
     }
 }
@@ -65,6 +69,8 @@ pub struct Battle {
     pub player2: Box<dyn Player>,
     /// Battle configuration
     pub config: BattleConfig,
+    /// Unimplemented effects the teams use, when not rejected
+    pub coverage_gaps: Vec<CoverageGap>,
 }
 
 impl<'a> BattleBuilder<'a> {
@@ -192,6 +198,12 @@ impl<'a> BattleBuilder<'a> {
         self
     }
 
+    /// Reject teams using unimplemented effects instead of only reporting them
+    pub fn strict_format_validation(mut self, strict: bool) -> Self {
+        self.config.strict_format_validation = strict;
+        self
+    }
+
     /// Set validation context
     pub fn validation_context(mut self, context: ValidationContext) -> Self {
         self.validation_context = context;
@@ -366,24 +378,27 @@ impl<'a> Builder<Battle> for BattleBuilder<'a> {
                 value: "failed to load".to_string(),
                 reason: format!("Failed to load game data repository: {}", e),
             })?;
-        let mut battle_state = BattleState::new(format, self.generation_repo.clone(), game_data_repo);
+        // Convert RandomPokemonSet to battle Pokemon at the format's levels and add to sides
+        let team1: Vec<_> = team1.iter().map(|pokemon_set| pokemon_set.to_battle_pokemon_for_format(self.data, &format)).collect();
+        let team2: Vec<_> = team2.iter().map(|pokemon_set| pokemon_set.to_battle_pokemon_for_format(self.data, &format)).collect();
 
-        // Convert RandomPokemonSet to battle Pokemon and add to sides
-        for pokemon_set in team1 {
-            let battle_pokemon = pokemon_set.to_battle_pokemon(self.data);
+        let mut battle_state = BattleState::new(format, self.generation_repo.clone(), game_data_repo);
+        for battle_pokemon in team1 {
             battle_state.sides[0].add_pokemon(battle_pokemon);
         }
-
-        for pokemon_set in team2 {
-            let battle_pokemon = pokemon_set.to_battle_pokemon(self.data);
+        for battle_pokemon in team2 {
             battle_state.sides[1].add_pokemon(battle_pokemon);
         }
 
+        let coverage_gaps = check_battle_coverage(&battle_state, self.config.strict_format_validation)
+            .map_err(|e| BuilderError::ValidationFailed { reason: e.to_string() })?;
+
         Ok(Battle {
             state: battle_state,
             player1,
             player2,
             config: self.config,
+            coverage_gaps,
         })
     }
 
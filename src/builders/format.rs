@@ -1,4 +1,4 @@
-use crate::core::battle_format::{BanList, BattleFormat, FormatClause, FormatType};
+use crate::core::battle_format::{BanList, BattleFormat, FormatClause, FormatType, LevelRule, TypeChartRule};
 use crate::generation::Generation;
 use crate::types::errors::{FormatError, FormatResult};
 use crate::types::{Abilities, Items, Moves, PokemonName};
@@ -16,6 +16,9 @@ pub struct FormatBuilder {
     banned_moves: Vec<Moves>,
     banned_items: Vec<Items>,
     banned_abilities: Vec<Abilities>,
+    type_chart: TypeChartRule,
+    custom_type_chart_json: Option<String>,
+    level_rule: Option<LevelRule>,
 }
 
 impl FormatBuilder {
@@ -32,6 +35,9 @@ impl FormatBuilder {
             banned_moves: Vec::new(),
             banned_items: Vec::new(),
             banned_abilities: Vec::new(),
+            type_chart: TypeChartRule::Standard,
+            custom_type_chart_json: None,
+            level_rule: None,
         }
     }
 
@@ -261,6 +267,42 @@ impl FormatBuilder {
         self
     }
 
+    /// Set the type chart rule
+    pub fn type_chart(mut self, type_chart: TypeChartRule) -> Self {
+        self.type_chart = type_chart;
+        self.custom_type_chart_json = None;
+        self
+    }
+
+    /// Play as an Inverse Battle
+    pub fn inverse(self) -> Self {
+        self.type_chart(TypeChartRule::Inverse)
+    }
+
+    /// Use a custom type chart loaded from JSON (see `TypeChartRule::custom_from_json`)
+    ///
+    /// The JSON is parsed when the format is built.
+    pub fn custom_type_chart(mut self, json: impl Into<String>) -> Self {
+        self.custom_type_chart_json = Some(json.into());
+        self
+    }
+
+    /// Set the level rule (defaults to level 50 for VGC, levels as given otherwise)
+    pub fn level_rule(mut self, level_rule: LevelRule) -> Self {
+        self.level_rule = Some(level_rule);
+        self
+    }
+
+    /// Battle every Pokemon at this level
+    pub fn level(self, level: u8) -> Self {
+        self.level_rule(LevelRule::Fixed(level))
+    }
+
+    /// Scale Pokemon above this level down to it
+    pub fn level_cap(self, level: u8) -> Self {
+        self.level_rule(LevelRule::Cap(level))
+    }
+
     /// Add standard competitive clauses
     pub fn standard_clauses(mut self) -> Self {
         self = self
@@ -296,6 +338,23 @@ impl FormatBuilder {
             });
         }
 
+        let level_rule = self
+            .level_rule
+            .unwrap_or(LevelRule::for_format_type(format_type));
+        if let LevelRule::Fixed(level) | LevelRule::Cap(level) = level_rule {
+            if level == 0 || level > 100 {
+                return Err(FormatError::RuleViolation {
+                    rule: "Levels must be between 1 and 100".to_string(),
+                });
+            }
+        }
+
+        let type_chart = match self.custom_type_chart_json {
+            Some(json) => TypeChartRule::custom_from_json(&json)
+                .map_err(|reason| FormatError::InvalidTypeChart { reason })?,
+            None => self.type_chart,
+        };
+
         // Create ban list
         let ban_list = BanList::new(
             self.banned_species
@@ -324,7 +383,9 @@ impl FormatBuilder {
             active_per_side,
         )
         .with_clauses(self.clauses)
-        .with_bans(ban_list);
+        .with_bans(ban_list)
+        .with_type_chart(type_chart)
+        .with_level_rule(level_rule);
 
         Ok(format)
     }
@@ -356,6 +417,7 @@ impl FormatBuilder {
             .species_clause()
             .team_size(6)
             .active_count(2)
+            .level(50)
     }
 
     /// Gen 8 OU format
@@ -386,6 +448,17 @@ impl FormatBuilder {
             .freeze_clause() // Gen 4 had freeze clause
     }
 
+    /// Little Cup format: every Pokemon battles at level 5
+    pub fn little_cup(generation: Generation) -> Self {
+        let name = format!("Gen {} LC", generation as u8);
+        Self::new()
+            .name(name)
+            .generation(generation)
+            .singles()
+            .standard_clauses()
+            .level(5)
+    }
+
     /// Random Battle format
     pub fn random_battle(generation: Generation) -> Self {
         let name = format!("Gen {} Random Battle", generation as u8);
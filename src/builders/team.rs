@@ -162,7 +162,8 @@ impl<'a> Builder<Vec<RandomPokemonSet>> for TeamBuilder<'a> {
         // Validate first
         self.validate()?;
 
-        // Convert PokemonBuilders to RandomPokemonSets
+        // Convert PokemonBuilders to RandomPokemonSets. The format's level rule is
+        // applied when the sets become battle Pokemon.
         let mut team = Vec::new();
         for pokemon_builder in self.pokemon {
             let pokemon_set = pokemon_builder.build(self.data)?;
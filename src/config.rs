@@ -52,7 +52,7 @@ pub struct PerformanceConfig {
 pub struct BattleConfig {
     /// Default maximum turns per battle
     pub default_max_turns: u32,
-    /// Enable strict format validation
+    /// Reject teams using unimplemented effects instead of warning about them
     pub strict_format_validation: bool,
     /// Enable damage range randomization
     pub damage_randomization: bool,
@@ -102,7 +102,7 @@ impl Default for BattleConfig {
     fn default() -> Self {
         Self {
             default_max_turns: 1000,
-            strict_format_validation: true,
+            strict_format_validation: false,
             damage_randomization: true,
             random_seed: None,
             enable_undo: true,
@@ -154,6 +154,8 @@ impl DamageMaximizer {
                 }
             }
             MoveChoice::Switch(_) => -10.0, // Slight penalty for switching
+            MoveChoice::Revive(_) => 0.0,
+            MoveChoice::Struggle { .. } => 50.0,
             MoveChoice::None => 0.0,
         }
     }
@@ -205,6 +207,8 @@ pub struct TurnInfo {
     pub side_one_choice: MoveChoice,
     pub side_two_choice: MoveChoice,
     pub instructions_generated: Vec<BattleInstructions>,
+    /// Index of the instruction sequence that was applied, if any were generated
+    pub chosen_sequence: Option<usize>,
     pub state_after: BattleState,
 }
 
@@ -215,6 +219,8 @@ pub struct BattleEnvironment {
     pub max_turns: usize,
     pub verbose: bool,
     pub log_file: Option<String>,
+    /// Whether players only see what the opponent has revealed
+    pub partial_observability: bool,
 }
 
 impl BattleEnvironment {
@@ -231,6 +237,7 @@ impl BattleEnvironment {
             max_turns,
             verbose,
             log_file: None,
+            partial_observability: false,
         }
     }
 
@@ -240,14 +247,45 @@ impl BattleEnvironment {
         self
     }
 
+    /// Give players only what the opponent has revealed instead of the full state
+    pub fn with_partial_observability(mut self) -> Self {
+        self.partial_observability = true;
+        self
+    }
+
+    /// The state a player on one side gets to choose from
+    fn player_view(&self, state: &BattleState, side_ref: SideReference) -> BattleState {
+        if self.partial_observability {
+            state.view_for(side_ref).state
+        } else {
+            state.view_for_side(side_ref)
+        }
+    }
+
     /// Generate initial switch-in instructions
     fn generate_initial_instructions(state: &mut BattleState) -> Vec<BattleInstructions> {
         // Generate initial instructions for start-of-battle effects like abilities
         let no_move_s1 = MoveChoice::None;
         let no_move_s2 = MoveChoice::None;
 
-        turn::generate_instructions(state, (&no_move_s1, &no_move_s2), false)
-            .unwrap_or_else(|_| vec![BattleInstructions::new(100.0, vec![])])
+        let mut instructions = turn::generate_move_pair_instructions(state, (&no_move_s1, &no_move_s2), false)
+            .unwrap_or_else(|_| vec![BattleInstructions::new(100.0, vec![])]);
+
+        // A lead with Neutralizing Gas switches off the other leads' abilities, then
+        // leads with Illusion take on their disguise before the first turn
+        let mut lead_instructions = crate::engine::combat::core::sync_ability_suppression(state);
+        let mut lead_state = state.clone();
+        lead_state.apply_instructions(&lead_instructions);
+        lead_instructions.extend(
+            lead_state
+                .get_all_active_positions()
+                .into_iter()
+                .filter_map(|position| crate::engine::combat::core::illusion_on_switch_in(&lead_state, position)),
+        );
+        for instruction_set in &mut instructions {
+            instruction_set.instruction_list.splice(0..0, lead_instructions.iter().cloned());
+        }
+        instructions
     }
 
     /// Run a complete battle - exact parity with poke-engine logic
@@ -333,6 +371,7 @@ impl BattleEnvironment {
                     chosen_index
                 );
             }
+            state.record_revealed((&MoveChoice::None, &MoveChoice::None), &initial_instructions[chosen_index].instruction_list);
             state.apply_instructions(&initial_instructions[chosen_index].instruction_list);
         } else if self.verbose {
             println!("DEBUG: No initial instructions generated");
@@ -380,12 +419,17 @@ impl BattleEnvironment {
             }
 
             // Players choose their moves
-            let side_one_choice =
-                self.player_one
-                    .choose_move(&state, SideReference::SideOne, &side_one_options);
-            let side_two_choice =
-                self.player_two
-                    .choose_move(&state, SideReference::SideTwo, &side_two_options);
+            // Each player only sees the state from its own side
+            let side_one_choice = self.player_one.choose_move(
+                &self.player_view(&state, SideReference::SideOne),
+                SideReference::SideOne,
+                &side_one_options,
+            );
+            let side_two_choice = self.player_two.choose_move(
+                &self.player_view(&state, SideReference::SideTwo),
+                SideReference::SideTwo,
+                &side_two_options,
+            );
 
             // Reopen log file to write the selected moves
             if self.verbose && self.log_file.is_some() {
@@ -468,8 +512,10 @@ impl BattleEnvironment {
             }
 
             // Apply the instructions (sampling from possibilities)
+            let mut chosen_sequence = None;
             if !instructions.is_empty() {
                 let chosen_index = self.sample_instruction_index(&instructions);
+                chosen_sequence = Some(chosen_index);
 
                 if self.verbose {
                     let chosen_msg = format!("Applying instruction sequence {}\n", chosen_index);
@@ -481,6 +527,7 @@ impl BattleEnvironment {
                     }
                 }
 
+                state.record_revealed((&side_one_choice, &side_two_choice), &instructions[chosen_index].instruction_list);
                 state.apply_instructions(&instructions[chosen_index].instruction_list);
             }
 
@@ -491,6 +538,7 @@ impl BattleEnvironment {
                 side_one_choice,
                 side_two_choice,
                 instructions_generated: instructions,
+                chosen_sequence,
                 state_after: state.clone(),
             });
         }
@@ -7,7 +7,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use crate::generation::Generation;
-use crate::types::{PokemonName, Moves, Items, Abilities};
+use crate::types::{PokemonName, Moves, Items, Abilities, PokemonType};
 
 /// Format types for battle mechanics
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -85,6 +85,181 @@ pub enum FormatClause {
     EndlessBattleClause,
 }
 
+/// Level rule applied to every Pokemon when a battle is built
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LevelRule {
+    /// Levels are used as given, such as the per-species levels of random battle sets
+    #[default]
+    AsGiven,
+    /// Every Pokemon is set to this level (VGC plays at 50, Little Cup at 5)
+    Fixed(u8),
+    /// Pokemon above this level are scaled down to it
+    Cap(u8),
+}
+
+impl LevelRule {
+    /// Default level rule for a format type: VGC scales everything to level 50
+    pub fn for_format_type(format_type: FormatType) -> Self {
+        match format_type {
+            FormatType::Vgc => LevelRule::Fixed(50),
+            _ => LevelRule::AsGiven,
+        }
+    }
+
+    /// The level a Pokemon of this level battles at
+    pub fn apply(&self, level: u8) -> u8 {
+        match *self {
+            LevelRule::AsGiven => level,
+            LevelRule::Fixed(fixed) => fixed,
+            LevelRule::Cap(cap) => level.min(cap),
+        }
+    }
+
+    /// Serialize the rule to a compact string (empty when levels are used as given)
+    fn serialize(&self) -> String {
+        match self {
+            LevelRule::AsGiven => String::new(),
+            LevelRule::Fixed(level) => format!("level{}", level),
+            LevelRule::Cap(level) => format!("cap{}", level),
+        }
+    }
+
+    /// Deserialize a rule written by `serialize`
+    fn deserialize(serialized: &str) -> Result<Self, String> {
+        let parse_level = |level: &str| level.parse::<u8>().map_err(|_| format!("Invalid level rule: {}", serialized));
+        if serialized.is_empty() {
+            Ok(LevelRule::AsGiven)
+        } else if let Some(level) = serialized.strip_prefix("level") {
+            Ok(LevelRule::Fixed(parse_level(level)?))
+        } else if let Some(level) = serialized.strip_prefix("cap") {
+            Ok(LevelRule::Cap(parse_level(level)?))
+        } else {
+            Err(format!("Invalid level rule: {}", serialized))
+        }
+    }
+}
+
+/// Effectiveness of one type against another in a custom type chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effectiveness {
+    Immune,
+    Resisted,
+    Neutral,
+    SuperEffective,
+}
+
+impl Effectiveness {
+    /// Damage multiplier for this effectiveness
+    pub fn multiplier(&self) -> f32 {
+        match self {
+            Effectiveness::Immune => 0.0,
+            Effectiveness::Resisted => 0.5,
+            Effectiveness::Neutral => 1.0,
+            Effectiveness::SuperEffective => 2.0,
+        }
+    }
+
+    /// Effectiveness for a chart multiplier (0, 0.5, 1 or 2)
+    pub fn from_multiplier(multiplier: f32) -> Option<Self> {
+        match multiplier {
+            m if m == 0.0 => Some(Effectiveness::Immune),
+            m if m == 0.5 => Some(Effectiveness::Resisted),
+            m if m == 1.0 => Some(Effectiveness::Neutral),
+            m if m == 2.0 => Some(Effectiveness::SuperEffective),
+            _ => None,
+        }
+    }
+}
+
+/// A single matchup in a custom type chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeMatchup {
+    pub attacking: PokemonType,
+    pub defending: PokemonType,
+    pub effectiveness: Effectiveness,
+}
+
+/// Type chart a format is played with, on top of its generation's chart
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeChartRule {
+    /// The generation's own type chart
+    #[default]
+    Standard,
+    /// Inverse Battle: every matchup is flipped and immunities become weaknesses
+    Inverse,
+    /// House-rule chart: the listed matchups replace the generation's
+    Custom(Vec<TypeMatchup>),
+}
+
+impl TypeChartRule {
+    /// Load a custom type chart from JSON keyed by attacking, then defending type
+    ///
+    /// `{"Fire": {"Grass": 2, "Water": 0.5}}` - matchups left out keep their standard value.
+    pub fn custom_from_json(json: &str) -> Result<Self, String> {
+        let chart: std::collections::BTreeMap<String, std::collections::BTreeMap<String, f32>> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid type chart JSON: {}", e))?;
+
+        let parse_type = |name: &str| {
+            PokemonType::from_normalized_str(name).ok_or_else(|| format!("Unknown type in type chart: {}", name))
+        };
+        let mut matchups = Vec::new();
+        for (attacking, row) in &chart {
+            let attacking = parse_type(attacking)?;
+            for (defending, &multiplier) in row {
+                let effectiveness = Effectiveness::from_multiplier(multiplier)
+                    .ok_or_else(|| format!("Invalid type chart multiplier: {}", multiplier))?;
+                matchups.push(TypeMatchup {
+                    attacking,
+                    defending: parse_type(defending)?,
+                    effectiveness,
+                });
+            }
+        }
+        Ok(TypeChartRule::Custom(matchups))
+    }
+
+    /// Serialize the rule to a compact string (empty for the standard chart)
+    fn serialize(&self) -> String {
+        match self {
+            TypeChartRule::Standard => String::new(),
+            TypeChartRule::Inverse => "inverse".to_string(),
+            TypeChartRule::Custom(matchups) => matchups
+                .iter()
+                .map(|m| format!("{}>{}>{}", m.attacking.to_normalized_str(), m.defending.to_normalized_str(), m.effectiveness.multiplier()))
+                .collect::<Vec<_>>()
+                .join("~"),
+        }
+    }
+
+    /// Deserialize a rule written by `serialize`
+    fn deserialize(serialized: &str) -> Result<Self, String> {
+        match serialized {
+            "" => Ok(TypeChartRule::Standard),
+            "inverse" => Ok(TypeChartRule::Inverse),
+            _ => serialized
+                .split('~')
+                .map(|matchup| {
+                    let parts: Vec<&str> = matchup.split('>').collect();
+                    let [attacking, defending, multiplier] = parts[..] else {
+                        return Err(format!("Invalid type matchup: {}", matchup));
+                    };
+                    let effectiveness = multiplier
+                        .parse::<f32>()
+                        .ok()
+                        .and_then(Effectiveness::from_multiplier)
+                        .ok_or_else(|| format!("Invalid type matchup: {}", matchup))?;
+                    Ok(TypeMatchup {
+                        attacking: PokemonType::from_normalized_str(attacking).ok_or_else(|| format!("Invalid type matchup: {}", matchup))?,
+                        defending: PokemonType::from_normalized_str(defending).ok_or_else(|| format!("Invalid type matchup: {}", matchup))?,
+                        effectiveness,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(TypeChartRule::Custom),
+        }
+    }
+}
+
 /// Specific Pokemon, moves, items, or abilities that are banned
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BanList {
@@ -138,6 +313,12 @@ pub struct BattleFormat {
     pub clauses: Vec<FormatClause>,
     /// Banned content for this format
     pub ban_list: BanList,
+    /// Type chart rule (standard, Inverse Battle or a custom chart)
+    #[serde(default)]
+    pub type_chart: TypeChartRule,
+    /// Level rule applied to both teams when the battle is built
+    #[serde(default)]
+    pub level_rule: LevelRule,
 }
 
 impl BattleFormat {
@@ -156,6 +337,8 @@ impl BattleFormat {
             active_per_side,
             clauses: Vec::new(),
             ban_list: BanList::empty(),
+            type_chart: TypeChartRule::Standard,
+            level_rule: LevelRule::for_format_type(format_type),
         }
     }
 
@@ -174,11 +357,13 @@ impl BattleFormat {
             active_per_side,
             clauses: Vec::new(),
             ban_list: BanList::empty(),
+            type_chart: TypeChartRule::Standard,
+            level_rule: LevelRule::for_format_type(format_type),
         }
     }
 
     /// Serialize the battle format to a compact string format
-    /// Format: name|generation|format_type|team_size|active_per_side|clauses|ban_list
+    /// Format: name|generation|format_type|team_size|active_per_side|clauses|ban_list[|type_chart|level_rule]
     pub fn serialize(&self) -> String {
         let clauses = self.clauses.iter()
             .map(|clause| (*clause as u8).to_string())
@@ -192,7 +377,7 @@ impl BattleFormat {
             self.ban_list.abilities.iter().map(|a| a.as_str()).collect::<Vec<_>>().join("~")
         );
 
-        format!("{}|{}|{}|{}|{}|{}|{}",
+        let serialized = format!("{}|{}|{}|{}|{}|{}|{}",
             self.name,
             self.generation as u8,
             self.format_type as u8,
@@ -200,7 +385,14 @@ impl BattleFormat {
             self.active_per_side,
             clauses,
             ban_list
-        )
+        );
+
+        // The type chart and level rule parts are only written when they differ from the defaults
+        if self.type_chart == TypeChartRule::Standard && self.level_rule == LevelRule::for_format_type(self.format_type) {
+            serialized
+        } else {
+            format!("{}|{}|{}", serialized, self.type_chart.serialize(), self.level_rule.serialize())
+        }
     }
 
     /// Check if this format allows switching during battle
@@ -220,8 +412,9 @@ impl BattleFormat {
     /// Deserialize a battle format from a string
     pub fn deserialize(serialized: &str) -> Result<Self, String> {
         let parts: Vec<&str> = serialized.split('|').collect();
-        if parts.len() != 7 {
-            return Err(format!("Invalid battle format: expected 7 parts, got {}", parts.len()));
+        // Strings written before level rules existed end at the type chart
+        if !(7..=9).contains(&parts.len()) {
+            return Err(format!("Invalid battle format: expected 7 to 9 parts, got {}", parts.len()));
         }
 
         let name = parts[0].to_string();
@@ -280,6 +473,15 @@ impl BattleFormat {
         
         let ban_list = BanList::new(species, moves, items, abilities);
 
+        let type_chart = match parts.get(7) {
+            Some(type_chart) => TypeChartRule::deserialize(type_chart)?,
+            None => TypeChartRule::Standard,
+        };
+        let level_rule = match parts.get(8) {
+            Some(level_rule) => LevelRule::deserialize(level_rule)?,
+            None => LevelRule::for_format_type(format_type),
+        };
+
         Ok(Self {
             name,
             generation,
@@ -288,6 +490,8 @@ impl BattleFormat {
             active_per_side,
             clauses,
             ban_list,
+            type_chart,
+            level_rule,
         })
     }
 
@@ -303,6 +507,18 @@ impl BattleFormat {
         self
     }
 
+    /// Play this format with a different type chart
+    pub fn with_type_chart(mut self, type_chart: TypeChartRule) -> Self {
+        self.type_chart = type_chart;
+        self
+    }
+
+    /// Play this format with a level rule
+    pub fn with_level_rule(mut self, level_rule: LevelRule) -> Self {
+        self.level_rule = level_rule;
+        self
+    }
+
     /// Returns the number of active Pokemon per side for this format
     pub fn active_pokemon_count(&self) -> usize {
         self.active_per_side
@@ -513,6 +729,23 @@ impl BattleFormat {
         )
     }
 
+    /// Little Cup - every Pokemon battles at level 5
+    pub fn gen9_little_cup() -> Self {
+        Self::new(
+            "Gen 9 LC".to_string(),
+            Generation::Gen9,
+            FormatType::Singles,
+        )
+        .with_clauses(vec![
+            FormatClause::SleepClause,
+            FormatClause::SpeciesClause,
+            FormatClause::EvasionClause,
+            FormatClause::OhkoClause,
+            FormatClause::EndlessBattleClause,
+        ])
+        .with_level_rule(LevelRule::Fixed(5))
+    }
+
     /// Random Battle Formats - These use predetermined teams from data files
     pub fn gen9_random_battle() -> Self {
         Self::new(
@@ -546,6 +779,7 @@ impl BattleFormat {
             FormatClause::SpeciesClause,
             FormatClause::ItemClause,
         ])
+        .with_level_rule(LevelRule::Fixed(50))
     }
 
     pub fn gen8_random_battle() -> Self {
@@ -725,3 +959,89 @@ impl fmt::Display for BattlePosition {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::FormatBuilder;
+    use crate::core::battle_state::Pokemon;
+
+    #[test]
+    fn test_level_rule_apply() {
+        assert_eq!(LevelRule::AsGiven.apply(84), 84);
+        assert_eq!(LevelRule::Fixed(50).apply(84), 50);
+        assert_eq!(LevelRule::Fixed(50).apply(5), 50);
+        assert_eq!(LevelRule::Cap(50).apply(84), 50);
+        assert_eq!(LevelRule::Cap(50).apply(30), 30);
+    }
+
+    #[test]
+    fn test_level_rule_recomputes_stats() {
+        use crate::data::random_team_loader::RandomPokemonSet;
+        use crate::data::GameDataRepository;
+        use crate::types::PokemonName;
+
+        let repository = GameDataRepository::from_path("data/ps-extracted").unwrap();
+        let set = |species: PokemonName| RandomPokemonSet {
+            name: species.as_str().to_string(),
+            species,
+            level: 100,
+            gender: None,
+            shiny: None,
+            ability: None,
+            item: None,
+            moves: Vec::new(),
+            nature: None,
+            evs: None,
+            ivs: None,
+            tera_type: None,
+            gigantamax: None,
+        };
+        // Max HP is (2 * base + IV + EV / 4) * level / 100 + level + 10
+        let expected_hp = |pokemon: &Pokemon, base: i16| {
+            let level = pokemon.level as i16;
+            (2 * base + pokemon.ivs.hp + pokemon.evs.hp / 4) * level / 100 + level + 10
+        };
+
+        let garchomp = set(PokemonName::GARCHOMP).to_battle_pokemon_for_format(&repository, &BattleFormat::gen9_vgc());
+        assert_eq!(garchomp.level, 50);
+        assert_eq!(garchomp.max_hp, expected_hp(&garchomp, 108));
+        assert_eq!(garchomp.hp, garchomp.max_hp);
+
+        let gible = set(PokemonName::GIBLE).to_battle_pokemon_for_format(&repository, &BattleFormat::gen9_little_cup());
+        assert_eq!(gible.level, 5);
+        assert_eq!(gible.max_hp, expected_hp(&gible, 58));
+
+        let uncapped = set(PokemonName::GARCHOMP).to_battle_pokemon_for_format(&repository, &BattleFormat::gen9_ou());
+        assert_eq!(uncapped.level, 100);
+        assert!(uncapped.stats.speed > garchomp.stats.speed);
+    }
+
+    #[test]
+    fn test_level_rule_builder_bounds() {
+        assert!(FormatBuilder::gen9_ou().level(0).build().is_err());
+        assert!(FormatBuilder::gen9_ou().level_cap(101).build().is_err());
+        assert_eq!(FormatBuilder::gen9_ou().level(5).build().unwrap().level_rule, LevelRule::Fixed(5));
+        assert_eq!(FormatBuilder::gen9_ou().level_cap(100).build().unwrap().level_rule, LevelRule::Cap(100));
+    }
+
+    #[test]
+    fn test_level_rule_serialize_round_trip() {
+        for format in [
+            BattleFormat::gen9_vgc(),
+            BattleFormat::gen9_little_cup(),
+            BattleFormat::gen9_ou().with_level_rule(LevelRule::Cap(50)),
+            FormatBuilder::gen9_ou().inverse().build().unwrap(),
+        ] {
+            let round_trip = BattleFormat::deserialize(&format.serialize()).unwrap();
+            assert_eq!(round_trip.level_rule, format.level_rule);
+            assert_eq!(round_trip.type_chart, format.type_chart);
+        }
+
+        // Without its last part the string keeps the type chart and falls back to VGC's level 50
+        let inverse_vgc = FormatBuilder::new().vgc().inverse().level(50).build().unwrap().serialize();
+        let without_level_rule = inverse_vgc.rsplit_once('|').unwrap().0;
+        let format = BattleFormat::deserialize(without_level_rule).unwrap();
+        assert_eq!(format.type_chart, TypeChartRule::Inverse);
+        assert_eq!(format.level_rule, LevelRule::Fixed(50));
+    }
+}
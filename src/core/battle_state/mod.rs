@@ -8,6 +8,11 @@ use crate::core::instructions::{
 };
 use crate::core::move_choice::{MoveChoice, PokemonIndex};
 use crate::generation::GenerationBattleMechanics;
+use crate::engine::combat::core::move_prevention::move_selection_restriction;
+use crate::engine::combat::core::trapping::{is_trapped, SOURCE_BOUND_TRAPPING_VOLATILES, TRAPPING_VOLATILES};
+use crate::engine::combat::moves::special::two_turn::SEMI_INVULNERABLE_STATES;
+use crate::engine::coverage::{check_battle_coverage, CoverageGap};
+use crate::types::errors::BattleResult;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -27,6 +32,10 @@ pub use field::*;
 mod side;
 pub use side::*;
 
+// Re-export partial observability types from observation module
+mod observation;
+pub use observation::*;
+
 
 /// The main battle state with decomposed components
 #[derive(Clone, Serialize)]
@@ -130,23 +139,28 @@ impl BattleState {
     }
 
     /// Create a new battle state with teams from random team data
+    ///
+    /// Both teams are checked for effects the engine doesn't implement: with
+    /// `strict_validation` any gap is an error, otherwise the gaps are returned
+    /// alongside the state for the caller to warn about.
     pub fn new_with_teams(
         format: BattleFormat,
         team_one: Vec<crate::data::RandomPokemonSet>,
         team_two: Vec<crate::data::RandomPokemonSet>,
         generation_repo: Arc<crate::data::generation_loader::GenerationRepository>,
         game_data_repo: Arc<crate::data::GameDataRepository>,
-    ) -> Self {
+        strict_validation: bool,
+    ) -> BattleResult<(Self, Vec<CoverageGap>)> {
         let mut state = Self::new(format.clone(), generation_repo, game_data_repo.clone());
 
-        // Convert and add Pokemon to each side
+        // Convert and add Pokemon to each side, at the levels the format sets
         for pokemon_set in team_one {
-            let pokemon = pokemon_set.to_battle_pokemon(&game_data_repo);
+            let pokemon = pokemon_set.to_battle_pokemon_for_format(&game_data_repo, &format);
             state.sides[0].add_pokemon(pokemon);
         }
 
         for pokemon_set in team_two {
-            let pokemon = pokemon_set.to_battle_pokemon(&game_data_repo);
+            let pokemon = pokemon_set.to_battle_pokemon_for_format(&game_data_repo, &format);
             state.sides[1].add_pokemon(pokemon);
         }
 
@@ -161,7 +175,8 @@ impl BattleState {
             }
         }
 
-        state
+        let gaps = check_battle_coverage(&state, strict_validation)?;
+        Ok((state, gaps))
     }
 
     /// Create a new battle state with pre-constructed Pokemon (for tests, direct team creation)
@@ -371,50 +386,15 @@ impl BattleState {
         use crate::engine::combat::core::SubstituteDamageResult;
         match instruction {
             PokemonInstruction::Damage { target, amount, .. } => {
+                // Hits on a substitute are generated as DamageSubstitute, so damage
+                // here always reaches the Pokemon (residual damage, recoil, HP costs)
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
-                    // Check if Pokemon has a substitute
-                    if pokemon.volatile_statuses.contains(VolatileStatus::Substitute) && pokemon.substitute_health > 0 {
-                        // Damage goes to substitute first
-                        let current_substitute_health = pokemon.substitute_health;
-                        let remaining_substitute_health = current_substitute_health - amount;
-                        
-                        if remaining_substitute_health <= 0 {
-                            // Substitute is broken, apply excess damage to Pokemon
-                            pokemon.substitute_health = 0;
-                            pokemon.volatile_statuses.remove(VolatileStatus::Substitute);
-                            let excess_damage = amount - current_substitute_health;
-                            let damage_to_pokemon = if excess_damage > 0 {
-                                pokemon.hp = (pokemon.hp - excess_damage).max(0);
-                                excess_damage
-                            } else {
-                                0
-                            };
-                            
-                            return Some(SubstituteDamageResult {
-                                hit_substitute: true,
-                                substitute_broken: true,
-                                damage_to_pokemon,
-                            });
-                        } else {
-                            // Substitute absorbs all damage
-                            pokemon.substitute_health = remaining_substitute_health;
-                            
-                            return Some(SubstituteDamageResult {
-                                hit_substitute: true,
-                                substitute_broken: false,
-                                damage_to_pokemon: 0,
-                            });
-                        }
-                    } else {
-                        // No substitute or substitute broken, damage goes to Pokemon directly
-                        pokemon.hp = (pokemon.hp - amount).max(0);
-                        
-                        return Some(SubstituteDamageResult {
-                            hit_substitute: false,
-                            substitute_broken: false,
-                            damage_to_pokemon: *amount,
-                        });
-                    }
+                    pokemon.hp = (pokemon.hp - amount).max(0);
+                    return Some(SubstituteDamageResult {
+                        hit_substitute: false,
+                        substitute_broken: false,
+                        damage_to_pokemon: *amount,
+                    });
                 }
             }
             PokemonInstruction::Heal { target, amount, .. } => {
@@ -449,6 +429,39 @@ impl BattleState {
                     SideReference::SideTwo => 1,
                 };
                 if side_index < self.sides.len() {
+                    // A pending forced switch is resolved once the Pokemon leaves the field,
+                    // and switching out ends choice lock, Torment, trapping, charging, ability
+                    // suppression, substitutes, Tar Shot and the like
+                    if let Some(outgoing) = self.get_pokemon_at_position_mut(*position) {
+                        outgoing.must_switch = false;
+                        outgoing.last_used_move = None;
+                        outgoing.illusion = None;
+                        outgoing.ability_suppressed = false;
+                        outgoing.substitute_health = 0;
+                        let move_locks = [VolatileStatus::MustRecharge, VolatileStatus::TwoTurnMove, VolatileStatus::GastroAcid];
+                        // Baton Pass and Shed Tail hand the substitute over with explicit instructions
+                        let passing = [VolatileStatus::Substitute, VolatileStatus::BatonPass, VolatileStatus::ShedTail];
+                        for status in TRAPPING_VOLATILES.into_iter().chain(move_locks).chain(passing).chain(SEMI_INVULNERABLE_STATES).chain([VolatileStatus::TarShot]) {
+                            outgoing.volatile_statuses.remove(status);
+                            outgoing.volatile_status_durations.remove(&status);
+                        }
+                        outgoing.trap_sources.clear();
+                    }
+                    // Traps set by the outgoing Pokemon end once it leaves
+                    for opponent_position in self.get_all_active_positions() {
+                        if opponent_position.side == position.side {
+                            continue;
+                        }
+                        if let Some(opponent) = self.get_pokemon_at_position_mut(opponent_position) {
+                            for status in SOURCE_BOUND_TRAPPING_VOLATILES {
+                                if opponent.trap_sources.get(&status) == Some(position) {
+                                    opponent.volatile_statuses.remove(status);
+                                    opponent.volatile_status_durations.remove(&status);
+                                    opponent.trap_sources.remove(&status);
+                                }
+                            }
+                        }
+                    }
                     self.sides[side_index]
                         .set_active_pokemon_at_slot(position.slot, Some(*new_pokemon));
                 }
@@ -460,6 +473,10 @@ impl BattleState {
             } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.ability = *new_ability;
+                    // Losing Illusion drops the disguise
+                    if *new_ability != crate::types::Abilities::ILLUSION {
+                        pokemon.illusion = None;
+                    }
                 }
             }
             PokemonInstruction::ChangeItem {
@@ -467,6 +484,18 @@ impl BattleState {
             } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.item = *new_item;
+                    // Holding an item again ends Unburden and forgets the consumed item
+                    if new_item.is_some() {
+                        pokemon.item_consumed = false;
+                        pokemon.consumed_item = None;
+                    }
+                }
+            }
+            PokemonInstruction::ConsumeItem { target, item, .. } => {
+                if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
+                    pokemon.item = None;
+                    pokemon.item_consumed = true;
+                    pokemon.consumed_item = Some(*item);
                 }
             }
             PokemonInstruction::ChangeType {
@@ -554,11 +583,18 @@ impl BattleState {
             PokemonInstruction::ChangeAbility { target, new_ability, .. } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.ability = *new_ability;
+                    // Losing Illusion drops the disguise
+                    if *new_ability != crate::types::Abilities::ILLUSION {
+                        pokemon.illusion = None;
+                    }
                 }
             }
             PokemonInstruction::ToggleAbility { target, suppressed, .. } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.ability_suppressed = *suppressed;
+                    if *suppressed {
+                        pokemon.illusion = None;
+                    }
                 }
             }
             PokemonInstruction::ChangeItem { target, new_item, .. } => {
@@ -575,9 +611,18 @@ impl BattleState {
                         .collect();
                 }
             }
+            PokemonInstruction::ChangeIllusion { target, new_illusion, .. } => {
+                if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
+                    pokemon.illusion = *new_illusion;
+                }
+            }
             PokemonInstruction::FormeChange { target, new_forme, .. } => {
+                let repository = Arc::clone(&self.game_data_repo);
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.forme = Some(new_forme.clone());
+                    if let Some(forme_data) = repository.pokemon.find_by_name(new_forme) {
+                        pokemon.change_forme(forme_data);
+                    }
                 }
             }
             PokemonInstruction::ToggleTerastallized { target, terastallized, tera_type, .. } => {
@@ -679,6 +724,19 @@ impl BattleState {
                     }
                 }
             }
+            PokemonInstruction::Revive { side, pokemon_index, new_hp, .. } => {
+                let side_index = side.to_index();
+                if let Some(pokemon) = self.sides[side_index].pokemon.get_mut(*pokemon_index) {
+                    pokemon.hp = (*new_hp).min(pokemon.max_hp);
+                    // Revived Pokemon come back cured of any status they fainted with
+                    pokemon.status = PokemonStatus::None;
+                    pokemon.status_duration = None;
+                    pokemon.sleep_turns = None;
+                }
+                self.sides[side_index]
+                    .side_conditions
+                    .remove(&crate::core::instructions::SideCondition::RevivalBlessing);
+            }
             PokemonInstruction::Message { .. } => {
                 // Messages are for logging/debugging, no state change needed
             }
@@ -803,6 +861,9 @@ impl BattleState {
                 // Baton passing logic would be handled at a higher level
                 // This is more of a metadata instruction for switch mechanics
             }
+            FieldInstruction::SaveMove { side, choice, .. } => {
+                self.sides[side.to_index()].saved_move = choice.clone();
+            }
             FieldInstruction::Message { .. } => {
                 // Messages are for logging/debugging purposes and don't change state
                 // Could be logged to a battle log if needed
@@ -850,16 +911,16 @@ impl BattleState {
             } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.volatile_statuses.insert(*status);
-                    if let Some(_dur) = duration {
-                        // Duration tracking is handled within VolatileStatusStorage
-                        // For now, just insert the status - duration logic can be added later
+                    if let Some(duration) = duration {
+                        pokemon.volatile_status_durations.insert(*status, *duration);
                     }
                 }
             }
             StatusInstruction::RemoveVolatile { target, status, .. } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.volatile_statuses.remove(*status);
-                    // Duration removal is handled by VolatileStatusStorage
+                    pokemon.volatile_status_durations.remove(status);
+                    pokemon.trap_sources.remove(status);
                 }
             }
             StatusInstruction::ChangeVolatileDuration {
@@ -870,12 +931,14 @@ impl BattleState {
             } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     if let Some(new_dur) = new_duration {
-                        // Duration tracking is handled within VolatileStatusStorage
                         if *new_dur == 0 {
                             pokemon.volatile_statuses.remove(*status);
+                            pokemon.volatile_status_durations.remove(status);
+                        } else {
+                            pokemon.volatile_status_durations.insert(*status, *new_dur);
                         }
                     } else {
-                        // Duration removal is handled by VolatileStatusStorage
+                        pokemon.volatile_status_durations.remove(status);
                     }
                 }
             }
@@ -918,6 +981,18 @@ impl BattleState {
                     }
                 }
             }
+            StatusInstruction::RestorePP {
+                target,
+                move_index,
+                amount,
+                ..
+            } => {
+                if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
+                    if let Some(move_data) = pokemon.get_move_mut(*move_index) {
+                        move_data.pp = move_data.pp.saturating_add(*amount).min(move_data.max_pp);
+                    }
+                }
+            }
             StatusInstruction::Apply { target, status, duration, .. } => {
                 if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
                     pokemon.status = *status;
@@ -1000,6 +1075,11 @@ impl BattleState {
                     pokemon.last_used_move = Some(move_name.clone());
                 }
             }
+            StatusInstruction::SetTrapSource { target, status, source } => {
+                if let Some(pokemon) = self.get_pokemon_at_position_mut(*target) {
+                    pokemon.trap_sources.insert(*status, *source);
+                }
+            }
         }
     }
 
@@ -1155,34 +1235,141 @@ impl BattleState {
 
     /// Get all legal move options for both sides
     pub fn get_all_options(&self) -> (Vec<MoveChoice>, Vec<MoveChoice>) {
+        // A pending Revival Blessing pick is a decision for that side only;
+        // the other side waits with no action
+        let wait_or = |options: Vec<MoveChoice>| {
+            if options.is_empty() {
+                vec![MoveChoice::None]
+            } else {
+                options
+            }
+        };
+
+        let side_one_revive = self.get_revive_options(0);
+        let side_two_revive = self.get_revive_options(1);
+        if !side_one_revive.is_empty() || !side_two_revive.is_empty() {
+            return (wait_or(side_one_revive), wait_or(side_two_revive));
+        }
+
+        // Likewise for a mid-turn forced switch (Eject Button, Emergency Exit, ...)
+        let side_one_forced = self.get_forced_switch_options(0);
+        let side_two_forced = self.get_forced_switch_options(1);
+        if !side_one_forced.is_empty() || !side_two_forced.is_empty() {
+            return (wait_or(side_one_forced), wait_or(side_two_forced));
+        }
+
         let side_one_options = self.get_side_options(0);
         let side_two_options = self.get_side_options(1);
         (side_one_options, side_two_options)
     }
 
+    /// Whether a side still has to pick a revive or a forced switch before the turn can end
+    pub fn awaiting_mid_turn_decision(&self) -> bool {
+        (0..2).any(|side_index| {
+            !self.get_revive_options(side_index).is_empty()
+                || !self.get_forced_switch_options(side_index).is_empty()
+        })
+    }
+
+    /// Get Revival Blessing revive options for a side with a pending revive
+    fn get_revive_options(&self, side_index: usize) -> Vec<MoveChoice> {
+        let mut options = Vec::new();
+
+        if let Some(side) = self.get_side(side_index) {
+            if side
+                .side_conditions
+                .contains_key(&crate::core::instructions::SideCondition::RevivalBlessing)
+            {
+                for (i, pokemon) in side.pokemon.iter().enumerate() {
+                    if pokemon.hp == 0 {
+                        if let Some(pokemon_index) = PokemonIndex::from_index(i) {
+                            options.push(MoveChoice::Revive(pokemon_index));
+                        }
+                    }
+                }
+            }
+        }
+
+        options
+    }
+
+    /// Get switch options for a side whose active Pokemon must switch out mid-turn
+    fn get_forced_switch_options(&self, side_index: usize) -> Vec<MoveChoice> {
+        let mut options = Vec::new();
+
+        if let Some(side) = self.get_side(side_index) {
+            let active_count = self.format.active_pokemon_count();
+            let must_switch = (0..active_count).any(|slot| {
+                side.get_active_pokemon_at_slot(slot)
+                    .is_some_and(|pokemon| pokemon.must_switch)
+            });
+            if must_switch {
+                for (i, bench_pokemon) in side.pokemon.iter().enumerate() {
+                    if bench_pokemon.hp > 0 && !side.active_pokemon_indices.contains(&Some(i)) {
+                        if let Some(pokemon_index) = PokemonIndex::from_index(i) {
+                            options.push(MoveChoice::new_switch(pokemon_index));
+                        }
+                    }
+                }
+            }
+        }
+
+        options
+    }
+
     /// Get move options for a specific side
     fn get_side_options(&self, side_index: usize) -> Vec<MoveChoice> {
         let mut options = Vec::new();
 
         if let Some(side) = self.get_side(side_index) {
             let active_count = self.format.active_pokemon_count();
+            let side_ref = if side_index == 0 {
+                SideReference::SideOne
+            } else {
+                SideReference::SideTwo
+            };
 
             for slot in 0..active_count {
                 if let Some(pokemon) = side.get_active_pokemon_at_slot(slot) {
                     if pokemon.hp > 0 {
-                        // Add move options
+                        let position = BattlePosition::new(side_ref, slot);
+
+                        // A recharging or charging Pokemon has no choice to make this turn
+                        if let Some(forced) = self.get_locked_in_option(side_index, slot, pokemon) {
+                            options.push(forced);
+                            continue;
+                        }
+
+                        // Add move options, leaving out moves that can't be selected
+                        let mut has_usable_move = false;
                         for (move_index, move_data) in &pokemon.moves {
-                            if move_data.pp > 0 {
+                            if move_data.pp > 0
+                                && move_selection_restriction(self, position, pokemon, *move_index).is_none()
+                            {
                                 let targets = self.get_valid_targets_for_move(
                                     side_index,
                                     slot,
                                     &move_data.target,
                                 );
                                 options.push(MoveChoice::new_move(*move_index, targets));
+                                has_usable_move = true;
                             }
                         }
 
-                        // Add switch options if there are benched Pokemon
+                        // Struggle is all that's left when every move is out of PP or restricted
+                        if !has_usable_move {
+                            let targets = self.get_valid_targets_for_move(
+                                side_index,
+                                slot,
+                                &crate::data::showdown_types::MoveTarget::RandomNormal,
+                            );
+                            options.push(MoveChoice::Struggle { target_positions: targets });
+                        }
+
+                        // Add switch options if there are benched Pokemon and nothing traps this one
+                        if is_trapped(self, position) {
+                            continue;
+                        }
                         for (i, bench_pokemon) in side.pokemon.iter().enumerate() {
                             if bench_pokemon.hp > 0
                                 && !side.active_pokemon_indices.contains(&Some(i))
@@ -1200,6 +1387,25 @@ impl BattleState {
         options
     }
 
+    /// The only option of a Pokemon that is recharging or releasing a charged move
+    fn get_locked_in_option(&self, side_index: usize, slot: usize, pokemon: &Pokemon) -> Option<MoveChoice> {
+        if pokemon.volatile_statuses.contains(VolatileStatus::MustRecharge) {
+            return Some(MoveChoice::None);
+        }
+
+        if pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
+            let last_used_move = pokemon.last_used_move?;
+            let (move_index, charged_move) = pokemon
+                .moves
+                .iter()
+                .find(|(_, known)| known.name == last_used_move)?;
+            let targets = self.get_valid_targets_for_move(side_index, slot, &charged_move.target);
+            return Some(MoveChoice::new_move(*move_index, targets));
+        }
+
+        None
+    }
+
     /// Get valid targets for a move based on its target type and format
     fn get_valid_targets_for_move(
         &self,
@@ -1446,6 +1652,20 @@ impl BattleState {
         output
     }
 
+    /// The state as one side's player sees it
+    ///
+    /// Opposing Pokemon under Illusion appear as the party member they're disguised as.
+    pub fn view_for_side(&self, side_ref: SideReference) -> BattleState {
+        let mut view = self.clone();
+        let opposing_side = view.get_side_by_ref_mut(side_ref.opposite());
+        for index in 0..opposing_side.pokemon.len() {
+            if let Some(disguise) = opposing_side.apparent_pokemon(index) {
+                opposing_side.pokemon[index] = disguise;
+            }
+        }
+        view
+    }
+
     /// Format a side for pretty printing
     fn format_side(&self, side: &BattleSide, side_index: usize) -> String {
         let mut output = String::new();
@@ -1454,9 +1674,14 @@ impl BattleState {
         output.push_str("Active Pokemon:\n");
         for slot in 0..active_count {
             if let Some(pokemon) = side.get_active_pokemon_at_slot(slot) {
+                // Pokemon under Illusion are shown as they appear, with the real species noted
+                let name = match pokemon.illusion.and_then(|index| side.pokemon.get(index)) {
+                    Some(disguise) => format!("{} [Illusion: {}]", disguise.species, pokemon.species),
+                    None => pokemon.species.to_string(),
+                };
                 output.push_str(&format!(
                     "  Slot {}: {} ({}/{} HP)\n",
-                    slot, pokemon.species, pokemon.hp, pokemon.max_hp
+                    slot, name, pokemon.hp, pokemon.max_hp
                 ));
 
                 if pokemon.status != PokemonStatus::None {
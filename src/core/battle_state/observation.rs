@@ -0,0 +1,398 @@
+//! Partial observability for battle states
+//!
+//! A side only learns about the opposing team as the battle reveals it: a Pokemon
+//! once it's sent out, its moves once used, its item once shown or consumed and its
+//! ability once announced. Each side records what it has given away in
+//! `BattleSide::revealed`, and `BattleState::view_for` builds the battle as one
+//! side can see it.
+
+use super::{BattleSide, BattleState, Pokemon};
+use crate::core::battle_format::{BattlePosition, SideReference};
+use crate::core::instructions::{BattleInstruction, PokemonInstruction};
+use crate::core::move_choice::MoveChoice;
+use crate::data::types::{Nature, Stats};
+use crate::types::from_string::FromNormalizedString;
+use crate::core::instructions::{MoveCategory, VolatileStatus};
+use crate::types::{Abilities, Items, Moves, PokemonName, StatBoostArray};
+use serde::{Deserialize, Serialize};
+
+/// Abilities that announce themselves as their Pokemon enters the field
+const ANNOUNCED_ON_SWITCH_IN: &[Abilities] = &[
+    Abilities::AIRLOCK,
+    Abilities::ANTICIPATION,
+    Abilities::CLOUDNINE,
+    Abilities::DELTASTREAM,
+    Abilities::DESOLATELAND,
+    Abilities::DOWNLOAD,
+    Abilities::DRIZZLE,
+    Abilities::DROUGHT,
+    Abilities::ELECTRICSURGE,
+    Abilities::FOREWARN,
+    Abilities::FRISK,
+    Abilities::GRASSYSURGE,
+    Abilities::HADRONENGINE,
+    Abilities::INTIMIDATE,
+    Abilities::MISTYSURGE,
+    Abilities::MOLDBREAKER,
+    Abilities::NEUTRALIZINGGAS,
+    Abilities::ORICHALCUMPULSE,
+    Abilities::PRESSURE,
+    Abilities::PRIMORDIALSEA,
+    Abilities::PSYCHICSURGE,
+    Abilities::SANDSTREAM,
+    Abilities::SCREENCLEANER,
+    Abilities::SNOWWARNING,
+    Abilities::TERAVOLT,
+    Abilities::TRACE,
+    Abilities::TURBOBLAZE,
+    Abilities::UNNERVE,
+];
+
+/// Abilities whose forme changes give them away
+const FORME_CHANGE_ABILITIES: &[Abilities] = &[
+    Abilities::DISGUISE,
+    Abilities::ICEFACE,
+    Abilities::POWERCONSTRUCT,
+    Abilities::SCHOOLING,
+    Abilities::SHIELDSDOWN,
+    Abilities::STANCECHANGE,
+    Abilities::ZENMODE,
+];
+
+/// What the opponent has learned about one party member
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RevealedPokemon {
+    /// Whether it has been on the field
+    pub seen: bool,
+    /// Moves it has been seen using
+    pub moves: Vec<Moves>,
+    /// Its held item once shown, `Some(None)` once it's known to hold nothing
+    pub item: Option<Option<Items>>,
+    /// Its ability once announced
+    pub ability: Option<Abilities>,
+    /// Hits it has been seen taking from opposing moves
+    #[serde(default)]
+    pub hits_taken: Vec<ObservedHit>,
+    /// Hits it has been seen dealing with its own moves
+    #[serde(default)]
+    pub hits_dealt: Vec<ObservedHit>,
+}
+
+/// A hit seen landing between a Pokemon and an opposing one
+///
+/// Only the first hit of a move chosen that turn is kept, and only if it neither
+/// knocked the defender out nor struck a substitute, so the damage is the move's
+/// full single hit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObservedHit {
+    /// The move used
+    pub move_name: Moves,
+    /// Party index of the opposing Pokemon involved, on its own side
+    pub opponent_index: usize,
+    /// Percentage of the defender's max HP the hit took
+    pub percent: i16,
+    /// The attacker's boosts when the hit landed
+    pub attacker_boosts: StatBoostArray,
+    /// The defender's boosts when the hit landed
+    pub defender_boosts: StatBoostArray,
+}
+
+/// The battle as one side can observe it
+#[derive(Debug, Clone)]
+pub struct BattleObservation {
+    /// The observing side
+    pub side: SideReference,
+    /// The battle with the opposing team cut down to what has been revealed
+    pub state: BattleState,
+}
+
+impl BattleObservation {
+    /// The opposing side as observed
+    pub fn opponent(&self) -> &BattleSide {
+        self.state.get_side_by_ref(self.side.opposite())
+    }
+}
+
+impl BattleSide {
+    /// What the opponent knows about a party member
+    pub fn revealed_pokemon(&self, pokemon_index: usize) -> RevealedPokemon {
+        self.revealed.get(pokemon_index).cloned().unwrap_or_default()
+    }
+
+    fn revealed_mut(&mut self, pokemon_index: usize) -> &mut RevealedPokemon {
+        if self.revealed.len() <= pokemon_index {
+            self.revealed.resize(pokemon_index + 1, RevealedPokemon::default());
+        }
+        &mut self.revealed[pokemon_index]
+    }
+
+    /// Mark a party member as seen, announcing its ability if it does so on entry
+    ///
+    /// `appears_as` differs from `pokemon_index` while Illusion disguises it, in
+    /// which case everything seen is credited to the disguise.
+    fn reveal_on_field(&mut self, pokemon_index: usize, appears_as: usize) {
+        let ability = self.pokemon.get(pokemon_index).map(|pokemon| pokemon.ability);
+        let revealed = self.revealed_mut(appears_as);
+        revealed.seen = true;
+        if let Some(ability) = ability.filter(|ability| appears_as == pokemon_index && ANNOUNCED_ON_SWITCH_IN.contains(ability)) {
+            revealed.ability = Some(ability);
+        }
+    }
+}
+
+impl BattleState {
+    /// The battle as seen from one side
+    ///
+    /// The side's own team is left as is. Opposing Pokemon that haven't been sent
+    /// out become placeholders, and the rest keep only the moves, item and ability
+    /// they've revealed. Their HP is out of 100 and their stats are estimated from
+    /// base stats, since EVs and IVs aren't visible. Illusion disguises hold.
+    pub fn view_for(&self, side_ref: SideReference) -> BattleObservation {
+        let mut state = self.view_for_side(side_ref);
+        let real_side = self.get_side_by_ref(side_ref.opposite());
+        let opponent = state.get_side_by_ref_mut(side_ref.opposite());
+
+        // An action waiting on a forced switch hasn't been shown yet
+        opponent.saved_move = None;
+
+        // What's revealed is listed against the Pokemon each party member appears as
+        let mut revealed_pokemon = Vec::with_capacity(opponent.pokemon.len());
+        for index in 0..opponent.pokemon.len() {
+            let real = &real_side.pokemon[index];
+            let appears_as = real.illusion.unwrap_or(index);
+            let revealed = real_side.revealed_pokemon(appears_as);
+            opponent.pokemon[index] = if revealed.seen {
+                observed_pokemon(&opponent.pokemon[index], real, &revealed)
+            } else {
+                unseen_pokemon(real)
+            };
+            revealed_pokemon.push(revealed);
+        }
+        opponent.revealed = revealed_pokemon;
+
+        BattleObservation { side: side_ref, state }
+    }
+
+    /// Record what a turn reveals to each side's opponent
+    ///
+    /// Takes the turn's choices and the instructions about to be applied. Pokemon
+    /// on the field count as seen, a chosen move is revealed if its user is still
+    /// standing, and switch-ins, hits, item and ability changes and broken
+    /// Illusions are read from the instructions. Items that activate without changing hands
+    /// aren't detected, since heals and damage don't record their source.
+    pub fn record_revealed(&mut self, choices: (&MoveChoice, &MoveChoice), instructions: &[BattleInstruction]) {
+        // Each position's occupant as (party index, party index it appears as)
+        let mut occupants: Vec<(BattlePosition, usize, usize)> = self
+            .get_all_active_positions()
+            .into_iter()
+            .filter_map(|position| {
+                let side = self.get_side_by_ref(position.side);
+                let index = side.active_pokemon_indices.get(position.slot).copied().flatten()?;
+                let appears_as = side.pokemon.get(index)?.illusion.unwrap_or(index);
+                Some((position, index, appears_as))
+            })
+            .collect();
+        let starting_occupants = occupants.clone();
+        let mut hit_targets: Vec<BattlePosition> = Vec::new();
+
+        for (side_ref, choice) in [(SideReference::SideOne, choices.0), (SideReference::SideTwo, choices.1)] {
+            let Some(move_index) = choice.move_index() else {
+                continue;
+            };
+            let Some(&(position, index, appears_as)) = occupants.iter().find(|(position, ..)| position.side == side_ref && position.slot == 0) else {
+                continue;
+            };
+            let side = self.get_side_by_ref_mut(position.side);
+            let used_move = side.pokemon.get(index).filter(|pokemon| pokemon.hp > 0).and_then(|pokemon| pokemon.get_move(move_index)).map(|used| used.name);
+            if let Some(name) = used_move {
+                let revealed = side.revealed_mut(appears_as);
+                if !revealed.moves.contains(&name) {
+                    revealed.moves.push(name);
+                }
+            }
+        }
+
+        for (instruction_index, instruction) in instructions.iter().enumerate() {
+            let BattleInstruction::Pokemon(instruction) = instruction else {
+                continue;
+            };
+            match instruction {
+                PokemonInstruction::Switch { position, new_pokemon, .. } => {
+                    // Illusion takes hold right after the switch
+                    let appears_as = instructions[instruction_index + 1..]
+                        .iter()
+                        .find_map(|later| match later {
+                            BattleInstruction::Pokemon(PokemonInstruction::ChangeIllusion { target, new_illusion, .. }) if target == position => Some(*new_illusion),
+                            _ => None,
+                        })
+                        .flatten()
+                        .unwrap_or(*new_pokemon);
+                    occupants.retain(|(occupied, ..)| occupied != position);
+                    occupants.push((*position, *new_pokemon, appears_as));
+                    self.get_side_by_ref_mut(position.side).reveal_on_field(*new_pokemon, appears_as);
+                }
+                PokemonInstruction::ChangeIllusion { target, new_illusion, .. } => {
+                    let Some(occupant) = occupants.iter_mut().find(|(position, ..)| position == target) else {
+                        continue;
+                    };
+                    let index = occupant.1;
+                    occupant.2 = new_illusion.unwrap_or(index);
+                    if new_illusion.is_none() {
+                        let side = self.get_side_by_ref_mut(target.side);
+                        side.reveal_on_field(index, index);
+                        side.revealed_mut(index).ability = Some(Abilities::ILLUSION);
+                    }
+                }
+                PokemonInstruction::ChangeItem { target, new_item, .. } => {
+                    if let Some(&(_, _, appears_as)) = occupants.iter().find(|(position, ..)| position == target) {
+                        self.get_side_by_ref_mut(target.side).revealed_mut(appears_as).item = Some(*new_item);
+                    }
+                }
+                PokemonInstruction::ConsumeItem { target, .. } => {
+                    if let Some(&(_, _, appears_as)) = occupants.iter().find(|(position, ..)| position == target) {
+                        self.get_side_by_ref_mut(target.side).revealed_mut(appears_as).item = Some(None);
+                    }
+                }
+                PokemonInstruction::ItemTransfer { from, to, item, .. } => {
+                    let item = Items::from_normalized_str(&crate::utils::normalize_name(item));
+                    for (target, held) in [(from, None), (to, item)] {
+                        if let Some(&(_, _, appears_as)) = occupants.iter().find(|(position, ..)| position == target) {
+                            self.get_side_by_ref_mut(target.side).revealed_mut(appears_as).item = Some(held);
+                        }
+                    }
+                }
+                PokemonInstruction::ChangeAbility { target, new_ability, .. } => {
+                    if let Some(&(_, _, appears_as)) = occupants.iter().find(|(position, ..)| position == target) {
+                        self.get_side_by_ref_mut(target.side).revealed_mut(appears_as).ability = Some(*new_ability);
+                    }
+                }
+                PokemonInstruction::FormeChange { target, .. } => {
+                    if let Some(&(_, index, appears_as)) = occupants.iter().find(|(position, ..)| position == target) {
+                        let side = self.get_side_by_ref_mut(target.side);
+                        let ability = side.pokemon.get(index).map(|pokemon| pokemon.ability);
+                        if let Some(ability) = ability.filter(|ability| FORME_CHANGE_ABILITIES.contains(ability)) {
+                            side.revealed_mut(appears_as).ability = Some(ability);
+                        }
+                    }
+                }
+                PokemonInstruction::Damage { target, amount, previous_hp } => {
+                    let Some(&(_, index, appears_as)) = occupants.iter().find(|(position, ..)| position == target) else {
+                        continue;
+                    };
+                    // Hazards and residuals hit Pokemon that arrived this turn, moves don't
+                    let Some(&(attacker_position, attacker_index, attacker_appears_as)) = starting_occupants
+                        .iter()
+                        .find(|&&(position, starting_index, _)| position == *target && starting_index == index)
+                        .and_then(|_| starting_occupants.iter().find(|(position, ..)| position.side != target.side && position.slot == 0))
+                    else {
+                        continue;
+                    };
+                    if hit_targets.contains(target) {
+                        continue;
+                    }
+                    hit_targets.push(*target);
+
+                    let choice = if attacker_position.side == SideReference::SideOne { choices.0 } else { choices.1 };
+                    let (Some(move_index), Some(attacker), Some(defender)) = (
+                        choice.move_index(),
+                        self.get_side_by_ref(attacker_position.side).pokemon.get(attacker_index),
+                        self.get_side_by_ref(target.side).pokemon.get(index),
+                    ) else {
+                        continue;
+                    };
+                    let Some(used_move) = attacker.get_move(move_index).filter(|used| used.category != MoveCategory::Status) else {
+                        continue;
+                    };
+                    let behind_substitute = defender.volatile_statuses.contains(VolatileStatus::Substitute) && defender.substitute_health > 0;
+                    if attacker.hp == 0 || behind_substitute || *amount >= previous_hp.unwrap_or(defender.hp) {
+                        continue;
+                    }
+
+                    let max_hp = defender.max_hp.max(1) as i32;
+                    let hit = ObservedHit {
+                        move_name: used_move.name,
+                        opponent_index: attacker_index,
+                        percent: ((*amount as i32 * 100 + max_hp / 2) / max_hp) as i16,
+                        attacker_boosts: attacker.stat_boosts,
+                        defender_boosts: defender.stat_boosts,
+                    };
+                    self.get_side_by_ref_mut(attacker_position.side)
+                        .revealed_mut(attacker_appears_as)
+                        .hits_dealt
+                        .push(ObservedHit { opponent_index: index, ..hit.clone() });
+                    self.get_side_by_ref_mut(target.side).revealed_mut(appears_as).hits_taken.push(hit);
+                }
+                _ => {}
+            }
+        }
+
+        for (position, index, appears_as) in occupants {
+            self.get_side_by_ref_mut(position.side).reveal_on_field(index, appears_as);
+        }
+    }
+}
+
+/// An opposing Pokemon reduced to what its side has revealed
+///
+/// `apparent` is how it looks on the field, `real` supplies the data of moves it
+/// used while disguised.
+fn observed_pokemon(apparent: &Pokemon, real: &Pokemon, revealed: &RevealedPokemon) -> Pokemon {
+    let moves = revealed
+        .moves
+        .iter()
+        .filter_map(|name| apparent.moves.iter().chain(real.moves.iter()).find(|(_, known)| known.name == *name).cloned())
+        .collect();
+    let hp = if apparent.hp > 0 {
+        ((apparent.hp as i32 * 100 + apparent.max_hp as i32 / 2) / apparent.max_hp.max(1) as i32).clamp(1, 100) as i16
+    } else {
+        0
+    };
+
+    Pokemon {
+        hp,
+        max_hp: 100,
+        stats: estimated_stats(&apparent.base_stats, apparent.level),
+        nature: Nature::Hardy,
+        ivs: uniform_stats(31),
+        evs: uniform_stats(85),
+        moves,
+        item: revealed.item.flatten(),
+        ability: revealed.ability.unwrap_or(Abilities::NONE),
+        tera_type: if apparent.is_terastallized { apparent.tera_type } else { None },
+        ..apparent.clone()
+    }
+}
+
+/// A placeholder for an opposing Pokemon that hasn't been sent out
+fn unseen_pokemon(real: &Pokemon) -> Pokemon {
+    let mut pokemon = Pokemon::new(PokemonName::NONE);
+    if real.hp == 0 {
+        pokemon.hp = 0;
+    }
+    pokemon
+}
+
+/// The same value in every stat
+fn uniform_stats(value: i16) -> Stats {
+    Stats {
+        hp: value,
+        attack: value,
+        defense: value,
+        special_attack: value,
+        special_defense: value,
+        speed: value,
+    }
+}
+
+/// Stats as a 31 IV, 85 EV, neutral nature Pokemon would have them, out of 100 HP
+fn estimated_stats(base_stats: &Stats, level: u8) -> Stats {
+    let stat = |base: i16| ((2 * base as i32 + 31 + 85 / 4) * level as i32 / 100 + 5) as i16;
+    Stats {
+        hp: 100,
+        attack: stat(base_stats.attack),
+        defense: stat(base_stats.defense),
+        special_attack: stat(base_stats.special_attack),
+        special_defense: stat(base_stats.special_defense),
+        speed: stat(base_stats.speed),
+    }
+}
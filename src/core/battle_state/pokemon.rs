@@ -3,7 +3,7 @@
 use crate::core::battle_format::BattlePosition;
 use crate::core::instructions::{MoveCategory, PokemonStatus};
 use crate::core::move_choice::MoveIndex;
-use crate::data::types::Stats;
+use crate::data::types::{Nature, Stats};
 use crate::types::{PokemonType, PokemonName, Abilities, Items, Moves, StatBoostArray, VolatileStatusStorage};
 use crate::types::from_string::FromNormalizedString;
 use serde::{Deserialize, Serialize};
@@ -149,6 +149,12 @@ pub struct Pokemon {
     pub stats: Stats,
     /// Base species stats (used for Gen 1 critical hit calculation)
     pub base_stats: Stats,
+    /// Nature the stats were calculated with
+    pub nature: Nature,
+    /// IVs the stats were calculated with
+    pub ivs: Stats,
+    /// EVs the stats were calculated with
+    pub evs: Stats,
     /// Current stat boosts (-6 to +6)
     pub stat_boosts: StatBoostArray,
     /// Current status condition
@@ -181,10 +187,14 @@ pub struct Pokemon {
     pub ability_triggered_this_turn: bool,
     /// Whether the held item has been consumed this battle
     pub item_consumed: bool,
+    /// The item this Pokemon last consumed (for Harvest, Recycle and Cud Chew)
+    pub consumed_item: Option<crate::types::Items>,
     /// Weight in kilograms (for moves like Heavy Slam, Heat Crash)
     pub weight_kg: f32,
     /// Current forme (for Pokemon with multiple formes)
     pub forme: Option<String>,
+    /// Party index of the Pokemon this one appears as (Illusion)
+    pub illusion: Option<usize>,
     /// Last used move (for moves like Disable, Encore)
     pub last_used_move: Option<Moves>,
     /// Whether this Pokemon must switch out (forced by items/abilities)
@@ -197,6 +207,8 @@ pub struct Pokemon {
     pub disabled_moves: std::collections::HashMap<MoveIndex, u8>,
     /// Volatile status durations
     pub volatile_status_durations: std::collections::HashMap<crate::types::VolatileStatus, u8>,
+    /// Position of the Pokemon that set each source-bound trapping volatile
+    pub trap_sources: std::collections::HashMap<crate::types::VolatileStatus, BattlePosition>,
 }
 
 impl Pokemon {
@@ -222,6 +234,23 @@ impl Pokemon {
                 special_defense: 100,
                 speed: 100,
             },
+            nature: Nature::Hardy,
+            ivs: Stats {
+                hp: 31,
+                attack: 31,
+                defense: 31,
+                special_attack: 31,
+                special_defense: 31,
+                speed: 31,
+            },
+            evs: Stats {
+                hp: 0,
+                attack: 0,
+                defense: 0,
+                special_attack: 0,
+                special_defense: 0,
+                speed: 0,
+            },
             stat_boosts: StatBoostArray::default(),
             status: PokemonStatus::None,
             status_duration: None,
@@ -238,14 +267,17 @@ impl Pokemon {
             ability_suppressed: false,
             ability_triggered_this_turn: false,
             item_consumed: false,
+            consumed_item: None,
             weight_kg: 50.0, // Default weight for unknown Pokemon
             forme: None,
+            illusion: None,
             last_used_move: None,
             must_switch: false,
             sleep_turns: None,
             rest_turns: None,
             disabled_moves: std::collections::HashMap::new(),
             volatile_status_durations: std::collections::HashMap::new(),
+            trap_sources: std::collections::HashMap::new(),
         }
     }
 
@@ -307,29 +339,17 @@ impl Pokemon {
             speed = (speed as f32 * 0.5) as u16;
         }
         
-        // Weather modifiers (simplified - in real implementation check abilities)
-        match battle_state.weather() {
-            Weather::Sun => {
-                if self.ability == crate::types::Abilities::CHLOROPHYLL {
-                    speed *= 2;
-                }
-            }
-            Weather::Rain => {
-                if self.ability == crate::types::Abilities::SWIFTSWIM {
-                    speed *= 2;
-                }
-            }
-            Weather::Sandstorm => {
-                if self.ability == crate::types::Abilities::SANDRUSH {
-                    speed *= 2;
-                }
-            }
-            Weather::Hail => {
-                if self.ability == crate::types::Abilities::SLUSHRUSH {
-                    speed *= 2;
-                }
-            }
-            _ => {}
+        // Weather speed abilities, which Cloud Nine, Air Lock and Utility Umbrella can switch off
+        let weather_doubles_speed = !self.ability_suppressed
+            && match crate::engine::combat::core::field_system::weather_for(battle_state, self) {
+                Weather::Sun | Weather::HarshSun | Weather::HarshSunlight => self.ability == Abilities::CHLOROPHYLL,
+                Weather::Rain | Weather::HeavyRain => self.ability == Abilities::SWIFTSWIM,
+                Weather::Sandstorm | Weather::Sand => self.ability == Abilities::SANDRUSH,
+                Weather::Hail | Weather::Snow => self.ability == Abilities::SLUSHRUSH,
+                _ => false,
+            };
+        if weather_doubles_speed {
+            speed *= 2;
         }
         
         // Item modifiers (simplified examples)
@@ -386,6 +406,72 @@ impl Pokemon {
     pub fn get_move_indices(&self) -> Vec<MoveIndex> {
         self.moves.iter().map(|(idx, _)| *idx).collect()
     }
+
+    /// How this Pokemon looks to the opponent while Illusion disguises it as `disguise`
+    ///
+    /// Everything identifying comes from the disguise, while HP (as a fraction),
+    /// status, boosts and volatiles stay this Pokemon's own.
+    pub fn disguised_as(&self, disguise: &Pokemon) -> Pokemon {
+        let hp = if self.hp > 0 {
+            ((self.hp as i32 * disguise.max_hp as i32) / self.max_hp.max(1) as i32).max(1) as i16
+        } else {
+            0
+        };
+
+        Pokemon {
+            hp,
+            stat_boosts: self.stat_boosts,
+            status: self.status,
+            status_duration: self.status_duration,
+            volatile_statuses: self.volatile_statuses.clone(),
+            volatile_status_durations: self.volatile_status_durations.clone(),
+            trap_sources: self.trap_sources.clone(),
+            substitute_health: self.substitute_health,
+            is_terastallized: self.is_terastallized,
+            tera_type: if self.is_terastallized { self.tera_type } else { disguise.tera_type },
+            last_used_move: self.last_used_move,
+            must_switch: self.must_switch,
+            sleep_turns: self.sleep_turns,
+            rest_turns: self.rest_turns,
+            illusion: None,
+            ..disguise.clone()
+        }
+    }
+
+    /// Take on another forme's species, base stats, types, ability and weight
+    ///
+    /// Stats are recalculated from the new base stats with the Pokemon's own
+    /// nature, IVs and EVs. A higher base HP (Zygarde-Complete) raises current
+    /// HP by the same amount as max HP.
+    pub fn change_forme(&mut self, new_forme: &crate::data::showdown_types::PokemonData) {
+        let new_base_stats = new_forme.base_stats.to_engine_stats();
+        let old_stats = Stats::calculate(&self.base_stats, &self.ivs, &self.evs, self.nature, self.level);
+        let new_stats = Stats::calculate(&new_base_stats, &self.ivs, &self.evs, self.nature, self.level);
+
+        let hp_change = new_stats.hp - old_stats.hp;
+        if hp_change != 0 {
+            self.max_hp = (self.max_hp + hp_change).max(1);
+            if self.hp > 0 {
+                self.hp = (self.hp + hp_change).clamp(1, self.max_hp);
+            }
+        }
+        self.stats = Stats { hp: self.max_hp, ..new_stats };
+        self.base_stats = new_base_stats;
+
+        if let Some(species) = PokemonName::from_normalized_str(&crate::utils::normalize_name(&new_forme.name)) {
+            self.species = species;
+        }
+        if !self.is_terastallized {
+            self.types = new_forme.types.clone();
+        }
+        // Formes that share the ability keep it; otherwise take the forme's first ability
+        if !new_forme.abilities.values().any(|&ability| ability == self.ability) {
+            if let Some(&ability) = new_forme.abilities.get("0") {
+                self.ability = ability;
+            }
+        }
+        self.weight_kg = new_forme.weight_kg;
+    }
 }
 
 impl Default for Pokemon {
@@ -85,6 +85,12 @@ pub struct BattleSide {
     pub last_move_category: Option<MoveCategory>,
     /// Whether last damage hit a substitute
     pub last_hit_substitute: bool,
+    /// Action deferred until the opponent finishes a mid-turn forced switch
+    #[serde(default)]
+    pub saved_move: Option<crate::core::move_choice::MoveChoice>,
+    /// What the opponent has learned about each party member, by party index
+    #[serde(default)]
+    pub revealed: Vec<super::RevealedPokemon>,
 }
 
 impl BattleSide {
@@ -103,6 +109,8 @@ impl BattleSide {
             last_damage_taken: 0,
             last_move_category: None,
             last_hit_substitute: false,
+            saved_move: None,
+            revealed: Vec::new(),
         }
     }
 
@@ -135,4 +143,14 @@ impl BattleSide {
             None
         }
     }
+
+    /// How a party member appears to the opponent, if Illusion disguises it
+    pub fn apparent_pokemon(&self, pokemon_index: usize) -> Option<Pokemon> {
+        let pokemon = self.pokemon.get(pokemon_index)?;
+        let disguise = pokemon
+            .illusion
+            .filter(|&index| index != pokemon_index)
+            .and_then(|index| self.pokemon.get(index))?;
+        Some(pokemon.disguised_as(disguise))
+    }
 }
\ No newline at end of file
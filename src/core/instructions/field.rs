@@ -31,6 +31,7 @@ pub enum SideCondition {
     QuickGuard,
     WideGuard,
     LuckyChant,
+    RevivalBlessing,
 }
 
 impl From<u8> for SideCondition {
@@ -55,6 +56,8 @@ impl From<u8> for SideCondition {
             16 => SideCondition::MatBlock,
             17 => SideCondition::QuickGuard,
             18 => SideCondition::WideGuard,
+            19 => SideCondition::LuckyChant,
+            20 => SideCondition::RevivalBlessing,
             _ => SideCondition::Reflect, // Default fallback
         }
     }
@@ -142,6 +145,12 @@ pub enum FieldInstruction {
         active: bool,
         previous_state: bool,
     },
+    /// Save (or clear) a side's action until the opponent's mid-turn forced switch resolves
+    SaveMove {
+        side: SideReference,
+        choice: Option<crate::core::move_choice::MoveChoice>,
+        previous_choice: Option<crate::core::move_choice::MoveChoice>,
+    },
     /// Display a message (for debugging/logging)
     Message {
         message: String,
@@ -202,6 +211,12 @@ impl FieldInstruction {
                     .map(|slot| BattlePosition::new(*side, slot))
                     .collect()
             },
+            // A saved move affects the side that will use it
+            FieldInstruction::SaveMove { side, .. } => {
+                (0..format.active_pokemon_count())
+                    .map(|slot| BattlePosition::new(*side, slot))
+                    .collect()
+            },
             FieldInstruction::Message { affected_positions, .. } => affected_positions.clone(),
         }
     }
@@ -223,6 +238,7 @@ impl FieldInstruction {
             FieldInstruction::DecrementGravityTurns { previous_turns, .. } => previous_turns.is_some(),
             FieldInstruction::ToggleForceSwitch { .. } => true,
             FieldInstruction::ToggleBatonPassing { .. } => true,
+            FieldInstruction::SaveMove { .. } => true,
             FieldInstruction::Message { .. } => false, // Messages are not undoable
         }
     }
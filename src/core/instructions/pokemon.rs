@@ -95,6 +95,12 @@ pub enum PokemonInstruction {
         new_item: Option<crate::types::Items>,
         previous_item: Option<crate::types::Items>,
     },
+    /// Eat or use up the held item, remembering it for Harvest, Recycle and Cud Chew
+    ConsumeItem {
+        target: BattlePosition,
+        item: crate::types::Items,
+        previous_consumed_item: Option<crate::types::Items>,
+    },
     /// Change Pokemon's types
     ChangeType {
         target: BattlePosition,
@@ -107,6 +113,12 @@ pub enum PokemonInstruction {
         new_forme: String,
         previous_forme: String,
     },
+    /// Change which party member a Pokemon appears as (Illusion)
+    ChangeIllusion {
+        target: BattlePosition,
+        new_illusion: Option<usize>,
+        previous_illusion: Option<usize>,
+    },
     /// Toggle Terastallization state
     ToggleTerastallized {
         target: BattlePosition,
@@ -181,6 +193,14 @@ pub enum PokemonInstruction {
         amount: i16,
         previous_health: i16,
     },
+    /// Revive a fainted party member (Revival Blessing)
+    Revive {
+        side: crate::core::battle_format::SideReference,
+        pokemon_index: usize,
+        new_hp: i16,
+        previous_hp: i16,
+        previous_status: PokemonStatus,
+    },
 }
 
 impl PokemonInstruction {
@@ -197,8 +217,10 @@ impl PokemonInstruction {
             PokemonInstruction::ChangeAbility { target, .. } => vec![*target],
             PokemonInstruction::ToggleAbility { target, .. } => vec![*target],
             PokemonInstruction::ChangeItem { target, .. } => vec![*target],
+            PokemonInstruction::ConsumeItem { target, .. } => vec![*target],
             PokemonInstruction::ChangeType { target, .. } => vec![*target],
             PokemonInstruction::FormeChange { target, .. } => vec![*target],
+            PokemonInstruction::ChangeIllusion { target, .. } => vec![*target],
             PokemonInstruction::ToggleTerastallized { target, .. } => vec![*target],
             PokemonInstruction::ChangeSubstituteHealth { target, .. } => vec![*target],
             PokemonInstruction::SetWish { target, .. } => vec![*target],
@@ -212,6 +234,7 @@ impl PokemonInstruction {
             PokemonInstruction::ItemTransfer { from, to, .. } => vec![*from, *to],
             PokemonInstruction::ForceSwitch { target, .. } => vec![*target],
             PokemonInstruction::DamageSubstitute { target, .. } => vec![*target],
+            PokemonInstruction::Revive { .. } => vec![], // Revived Pokemon is in the party, not on the field
         }
     }
 
@@ -227,8 +250,10 @@ impl PokemonInstruction {
             PokemonInstruction::ChangeAbility { previous_ability, .. } => previous_ability.is_some(),
             PokemonInstruction::ToggleAbility { .. } => true,
             PokemonInstruction::ChangeItem { .. } => true,
+            PokemonInstruction::ConsumeItem { .. } => true,
             PokemonInstruction::ChangeType { .. } => true,
             PokemonInstruction::FormeChange { .. } => true,
+            PokemonInstruction::ChangeIllusion { .. } => true,
             PokemonInstruction::ToggleTerastallized { .. } => true,
             PokemonInstruction::ChangeSubstituteHealth { .. } => true,
             PokemonInstruction::SetWish { previous_wish, .. } => previous_wish.is_some(),
@@ -240,6 +265,7 @@ impl PokemonInstruction {
             PokemonInstruction::ItemTransfer { .. } => true,
             PokemonInstruction::ForceSwitch { .. } => true,
             PokemonInstruction::DamageSubstitute { .. } => true,
+            PokemonInstruction::Revive { .. } => true,
         }
     }
 }
\ No newline at end of file
@@ -91,6 +91,13 @@ pub enum StatusInstruction {
         amount: u8,
         previous_pp: u8,
     },
+    /// Restore PP of a move (Leppa Berry)
+    RestorePP {
+        target: BattlePosition,
+        move_index: MoveIndex,
+        amount: u8,
+        previous_pp: u8,
+    },
     /// Set the last used move
     SetLastUsedMove {
         target: BattlePosition,
@@ -102,6 +109,12 @@ pub enum StatusInstruction {
         target: BattlePosition,
         move_name: Moves,
     },
+    /// Record which Pokemon keeps a trapping volatile in place
+    SetTrapSource {
+        target: BattlePosition,
+        status: VolatileStatus,
+        source: BattlePosition,
+    },
 }
 
 impl StatusInstruction {
@@ -120,8 +133,10 @@ impl StatusInstruction {
             StatusInstruction::DisableMove { target, .. } => vec![*target],
             StatusInstruction::EnableMove { target, .. } => vec![*target],
             StatusInstruction::DecrementPP { target, .. } => vec![*target],
+            StatusInstruction::RestorePP { target, .. } => vec![*target],
             StatusInstruction::SetLastUsedMove { target, .. } => vec![*target],
             StatusInstruction::RestoreLastUsedMove { target, .. } => vec![*target],
+            StatusInstruction::SetTrapSource { target, .. } => vec![*target],
         }
     }
 
@@ -141,8 +156,10 @@ impl StatusInstruction {
             StatusInstruction::DisableMove { .. } => true,
             StatusInstruction::EnableMove { .. } => true,
             StatusInstruction::DecrementPP { .. } => true,
+            StatusInstruction::RestorePP { .. } => true,
             StatusInstruction::SetLastUsedMove { previous_move, .. } => previous_move.is_some(),
             StatusInstruction::RestoreLastUsedMove { .. } => true,
+            StatusInstruction::SetTrapSource { .. } => true,
         }
     }
 }
\ No newline at end of file
@@ -22,6 +22,10 @@
 //!   atomic battle actions. Instructions are generated during move resolution
 //!   and applied to transform battle states.
 //!
+//! - **Set Inference** (`set_inference`): Infers the posterior over each
+//!   opposing Pokemon's item, EV spread, nature and ability from the damage
+//!   and turn order seen in a battle's turn history.
+//!
 //! - **Move Choice** (`move_choice`): Represents player move selections with
 //!   explicit target specification. Supports all move types including switches,
 //!   mega evolution, and Z-moves with format-aware targeting.
@@ -52,4 +56,5 @@ pub mod battle_environment;
 pub mod battle_state;
 pub mod instructions;
 pub mod move_choice;
+pub mod set_inference;
 pub mod targeting;
\ No newline at end of file
@@ -22,6 +22,12 @@ pub enum MoveChoice {
     },
     /// Switch to a different Pokemon
     Switch(PokemonIndex),
+    /// Revive a fainted party member (Revival Blessing follow-up)
+    Revive(PokemonIndex),
+    /// Struggle, the only option when none of the Pokemon's moves can be selected
+    Struggle {
+        target_positions: Vec<BattlePosition>,
+    },
     /// Do nothing (used for speed calculations or when no valid moves)
     None,
 }
@@ -58,7 +64,8 @@ impl MoveChoice {
         match self {
             Self::Move { target_positions, .. } => Some(target_positions),
             Self::MoveTera { target_positions, .. } => Some(target_positions),
-            Self::Switch(_) | Self::None => None,
+            Self::Struggle { target_positions } => Some(target_positions),
+            Self::Switch(_) | Self::Revive(_) | Self::None => None,
         }
     }
 
@@ -67,7 +74,7 @@ impl MoveChoice {
         match self {
             Self::Move { move_index, .. } => Some(*move_index),
             Self::MoveTera { move_index, .. } => Some(*move_index),
-            Self::Switch(_) | Self::None => None,
+            Self::Switch(_) | Self::Revive(_) | Self::Struggle { .. } | Self::None => None,
         }
     }
 
@@ -76,6 +83,7 @@ impl MoveChoice {
         match self {
             Self::Move { .. } => true,
             Self::MoveTera { .. } => true,
+            Self::Struggle { .. } => true,
             _ => false,
         }
     }
@@ -85,6 +93,11 @@ impl MoveChoice {
         matches!(self, Self::Switch(_))
     }
 
+    /// Returns true if this is a Revival Blessing revive choice
+    pub fn is_revive(&self) -> bool {
+        matches!(self, Self::Revive(_))
+    }
+
     /// Returns true if this choice uses Terastallization (Gen 9+ only)
     pub fn is_tera(&self) -> bool {
         matches!(self, Self::MoveTera { .. })
@@ -140,6 +153,7 @@ impl MoveChoice {
         match self {
             Self::Move { target_positions, .. } => *target_positions = new_targets,
             Self::MoveTera { target_positions, .. } => *target_positions = new_targets,
+            Self::Struggle { target_positions } => *target_positions = new_targets,
             _ => {} // No effect on switch or none choices
         }
     }
@@ -195,6 +209,24 @@ impl MoveChoice {
                 };
                 format!("Switch to {}", pokemon_name)
             }
+            Self::Revive(pokemon_index) => {
+                let pokemon_name = if let Some(pokemon) = side.pokemon.get(pokemon_index.to_index()) {
+                    pokemon.species.as_str().to_string()
+                } else {
+                    format!("Pokemon{:?}", pokemon_index)
+                };
+                format!("Revive {}", pokemon_name)
+            }
+            Self::Struggle { target_positions } => {
+                if target_positions.is_empty() {
+                    "Struggle".to_string()
+                } else {
+                    let targets: Vec<String> = target_positions.iter()
+                        .map(|pos| format!("{}:{}", pos.side.to_string(), pos.slot))
+                        .collect();
+                    format!("Struggle -> [{}]", targets.join(", "))
+                }
+            }
             Self::None => "None".to_string(),
         }
     }
@@ -0,0 +1,463 @@
+//! # Opponent Set Inference
+//!
+//! Works out what an opponent is likely running from how the battle has gone.
+//! Every opposing Pokemon starts from a uniform prior over combinations of item,
+//! EV spread, nature and ability. Each hit it deals or takes, and each time it
+//! moves before or after us at equal priority, reweights those combinations by
+//! how well they explain what was seen. Damage is priced with the engine's own
+//! calculator spread over `calculate_all_damage_rolls`, and speed comes from
+//! `Pokemon::get_effective_speed`.
+//!
+//! Only what the inferring side could see is used: exact damage to its own
+//! Pokemon, HP percentages for the opponent's and the order moves landed in.
+
+use crate::core::battle_environment::TurnInfo;
+use crate::core::battle_format::{BattlePosition, SideReference};
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::{BattleInstruction, MoveCategory, PokemonInstruction, VolatileStatus};
+use crate::core::move_choice::MoveChoice;
+use crate::data::types::{Nature, Stats};
+use crate::engine::combat::damage::{calculate_all_damage_rolls, calculate_damage_with_positions, critical_hit_probability, DamageRolls};
+use crate::engine::turn::generate_move_pair_instructions;
+use crate::types::{Abilities, Items, PokemonName, Stat};
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Items whose effect on damage or speed shows in battle; `None` stands for any other item
+const CANDIDATE_ITEMS: &[Option<Items>] = &[
+    None,
+    Some(Items::CHOICESCARF),
+    Some(Items::CHOICEBAND),
+    Some(Items::CHOICESPECS),
+    Some(Items::LIFEORB),
+    Some(Items::EXPERTBELT),
+    Some(Items::ASSAULTVEST),
+];
+
+/// Natures raising each stat at the expense of an unused one, plus a neutral nature
+const CANDIDATE_NATURES: &[Nature] = &[
+    Nature::Hardy,
+    Nature::Adamant,
+    Nature::Modest,
+    Nature::Jolly,
+    Nature::Timid,
+    Nature::Bold,
+    Nature::Calm,
+];
+
+/// Common EV spreads, starting with the even spread random battles use
+pub const EV_SPREADS: &[EvSpread] = &[
+    EvSpread { name: "85 All", evs: Stats { hp: 85, attack: 85, defense: 85, special_attack: 85, special_defense: 85, speed: 85 } },
+    EvSpread { name: "252 Atk / 252 Spe", evs: Stats { hp: 4, attack: 252, defense: 0, special_attack: 0, special_defense: 0, speed: 252 } },
+    EvSpread { name: "252 SpA / 252 Spe", evs: Stats { hp: 4, attack: 0, defense: 0, special_attack: 252, special_defense: 0, speed: 252 } },
+    EvSpread { name: "252 HP / 252 Def", evs: Stats { hp: 252, attack: 0, defense: 252, special_attack: 0, special_defense: 4, speed: 0 } },
+    EvSpread { name: "252 HP / 252 SpD", evs: Stats { hp: 252, attack: 0, defense: 4, special_attack: 0, special_defense: 252, speed: 0 } },
+];
+
+/// Likelihood given to evidence no hypothesis explains, so that effects the model
+/// doesn't cover (Quick Claw, unusual priority, multi-target moves) can't rule out
+/// every set
+const UNEXPLAINED_LIKELIHOOD: f64 = 0.01;
+
+/// A named EV spread
+#[derive(Debug, Clone, Copy)]
+pub struct EvSpread {
+    pub name: &'static str,
+    pub evs: Stats,
+}
+
+/// One combination of an opposing Pokemon's hidden set details
+#[derive(Debug, Clone, Copy)]
+pub struct SetHypothesis {
+    /// The held item, `None` for one that doesn't affect damage or speed
+    pub item: Option<Items>,
+    pub ev_spread: EvSpread,
+    pub nature: Nature,
+    pub ability: Abilities,
+}
+
+/// The posterior over one opposing Pokemon's hidden set
+#[derive(Debug, Clone)]
+pub struct SetPosterior {
+    /// Party index on the opposing side
+    pub pokemon_index: usize,
+    pub species: PokemonName,
+    /// Every hypothesis with its probability, most likely first
+    pub hypotheses: Vec<(SetHypothesis, f64)>,
+    /// Probability of each item, most likely first
+    pub items: Vec<(Option<Items>, f64)>,
+    /// Probability of each EV spread, most likely first
+    pub ev_spreads: Vec<(&'static str, f64)>,
+    /// Probability of each nature, most likely first
+    pub natures: Vec<(Nature, f64)>,
+    /// Probability of each ability, most likely first
+    pub abilities: Vec<(Abilities, f64)>,
+    /// Conclusions the evidence supports, such as "Choice Scarf likely: outsped at +0"
+    pub notes: Vec<String>,
+}
+
+/// Infer the set of every opposing Pokemon `side` has seen from a battle's turn history
+pub fn infer_opponent_sets(history: &[TurnInfo], side: SideReference) -> Vec<SetPosterior> {
+    let Some(last_turn) = history.last() else {
+        return Vec::new();
+    };
+    let final_view = last_turn.state_after.view_for(side);
+    let opponent = final_view.opponent();
+    let mut trackers: Vec<PosteriorTracker> = (0..opponent.pokemon.len())
+        .filter(|&index| opponent.revealed_pokemon(index).seen)
+        .map(|index| PosteriorTracker::new(&final_view.state, side.opposite(), index))
+        .collect();
+
+    for turn in history {
+        observe_turn(turn, side, &mut trackers);
+    }
+    trackers.into_iter().map(PosteriorTracker::into_posterior).collect()
+}
+
+/// Running posterior for one opposing Pokemon
+struct PosteriorTracker {
+    pokemon_index: usize,
+    species: PokemonName,
+    hypotheses: Vec<SetHypothesis>,
+    weights: Vec<f64>,
+    /// Whether the item was revealed rather than inferred
+    item_known: bool,
+    /// Its Speed boost the first time it moved first at equal priority
+    outsped_at: Option<i8>,
+    dealt_hits: bool,
+    took_hits: bool,
+}
+
+impl PosteriorTracker {
+    fn new(state: &BattleState, side_ref: SideReference, pokemon_index: usize) -> Self {
+        let side = state.get_side_by_ref(side_ref);
+        let species = side.pokemon[pokemon_index].species;
+        let revealed = side.revealed_pokemon(pokemon_index);
+
+        let items = match revealed.item {
+            Some(Some(item)) => vec![Some(item)],
+            _ => CANDIDATE_ITEMS.to_vec(),
+        };
+        let abilities = match revealed.ability {
+            Some(ability) => vec![ability],
+            None => {
+                let mut abilities: Vec<(String, Abilities)> = state
+                    .game_data_repo
+                    .pokemon
+                    .find_by_name(species.as_str())
+                    .map(|data| data.abilities.iter().map(|(slot, ability)| (slot.clone(), *ability)).collect())
+                    .unwrap_or_default();
+                abilities.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut abilities: Vec<Abilities> = abilities.into_iter().map(|(_, ability)| ability).collect();
+                abilities.dedup();
+                if abilities.is_empty() {
+                    abilities.push(Abilities::NONE);
+                }
+                abilities
+            }
+        };
+
+        let mut hypotheses = Vec::new();
+        for &item in &items {
+            for &ev_spread in EV_SPREADS {
+                for &nature in CANDIDATE_NATURES {
+                    for &ability in &abilities {
+                        hypotheses.push(SetHypothesis { item, ev_spread, nature, ability });
+                    }
+                }
+            }
+        }
+
+        Self {
+            pokemon_index,
+            species,
+            weights: vec![1.0 / hypotheses.len() as f64; hypotheses.len()],
+            hypotheses,
+            item_known: items.len() == 1,
+            outsped_at: None,
+            dealt_hits: false,
+            took_hits: false,
+        }
+    }
+
+    /// Reweight every hypothesis by the likelihood of an observation and renormalize
+    fn update(&mut self, likelihood: impl Fn(&SetHypothesis) -> f64) {
+        for (weight, hypothesis) in self.weights.iter_mut().zip(&self.hypotheses) {
+            *weight *= likelihood(hypothesis).max(UNEXPLAINED_LIKELIHOOD);
+        }
+        let total: f64 = self.weights.iter().sum();
+        if total > 0.0 {
+            self.weights.iter_mut().for_each(|weight| *weight /= total);
+        }
+    }
+
+    fn into_posterior(self) -> SetPosterior {
+        let mut hypotheses: Vec<(SetHypothesis, f64)> = self.hypotheses.into_iter().zip(self.weights).collect();
+        hypotheses.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+        let items = marginal(&hypotheses, |hypothesis| hypothesis.item);
+        let ev_spreads = marginal(&hypotheses, |hypothesis| hypothesis.ev_spread.name);
+        let natures = marginal(&hypotheses, |hypothesis| hypothesis.nature);
+        let abilities = marginal(&hypotheses, |hypothesis| hypothesis.ability);
+
+        let mut notes = Vec::new();
+        if !self.item_known {
+            for &(item, probability) in &items {
+                let Some(item) = item.filter(|_| probability >= 0.5) else {
+                    continue;
+                };
+                let evidence = match item {
+                    Items::CHOICESCARF => self.outsped_at.map(|boost| format!("outsped at {:+}", boost)),
+                    Items::CHOICEBAND | Items::CHOICESPECS | Items::LIFEORB | Items::EXPERTBELT if self.dealt_hits => {
+                        Some("its damage fits the boost".to_string())
+                    }
+                    Items::ASSAULTVEST if self.took_hits => Some("damage taken fits the Special Defense boost".to_string()),
+                    _ => None,
+                };
+                notes.push(match evidence {
+                    Some(evidence) => format!("{} likely: {}", item.display_name(), evidence),
+                    None => format!("{} likely", item.display_name()),
+                });
+            }
+        }
+
+        SetPosterior {
+            pokemon_index: self.pokemon_index,
+            species: self.species,
+            hypotheses,
+            items,
+            ev_spreads,
+            natures,
+            abilities,
+            notes,
+        }
+    }
+}
+
+/// Sum the posterior over everything but one detail of the hypotheses
+fn marginal<T: PartialEq + Copy>(hypotheses: &[(SetHypothesis, f64)], detail: impl Fn(&SetHypothesis) -> T) -> Vec<(T, f64)> {
+    let mut totals: Vec<(T, f64)> = Vec::new();
+    for (hypothesis, probability) in hypotheses {
+        let value = detail(hypothesis);
+        match totals.iter_mut().find(|(known, _)| *known == value) {
+            Some((_, total)) => *total += probability,
+            None => totals.push((value, *probability)),
+        }
+    }
+    totals.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+    totals
+}
+
+/// A hit landed by a chosen move: its position in the turn's instructions, the damage
+/// dealt and whether it knocked the target out
+struct LandedHit {
+    index: usize,
+    amount: i16,
+    knocked_out: bool,
+}
+
+/// Update the posteriors with what one turn showed
+fn observe_turn(turn: &TurnInfo, side: SideReference, trackers: &mut [PosteriorTracker]) {
+    let Some(instructions) = turn
+        .chosen_sequence
+        .and_then(|index| turn.instructions_generated.get(index))
+        .map(|sequence| &sequence.instruction_list)
+    else {
+        return;
+    };
+    let own_position = BattlePosition::new(side, 0);
+    let opponent_position = BattlePosition::new(side.opposite(), 0);
+    let view = turn.state_before.view_for(side);
+
+    // The opponent is judged as the Pokemon it appears to be
+    let Some(real_opponent) = turn.state_before.get_pokemon_at_position(opponent_position) else {
+        return;
+    };
+    let Some(opponent_index) = turn
+        .state_before
+        .get_side_by_ref(side.opposite())
+        .active_pokemon_indices
+        .first()
+        .copied()
+        .flatten()
+        .map(|index| real_opponent.illusion.unwrap_or(index))
+    else {
+        return;
+    };
+    let Some(tracker) = trackers.iter_mut().find(|tracker| tracker.pokemon_index == opponent_index) else {
+        return;
+    };
+    let (Some(own_pokemon), Some(observed_opponent)) =
+        (view.state.get_pokemon_at_position(own_position), view.state.get_pokemon_at_position(opponent_position))
+    else {
+        return;
+    };
+    let turn_item = view.opponent().revealed_pokemon(opponent_index).item;
+
+    let (own_choice, opponent_choice) = match side {
+        SideReference::SideOne => (&turn.side_one_choice, &turn.side_two_choice),
+        SideReference::SideTwo => (&turn.side_two_choice, &turn.side_one_choice),
+    };
+    let own_move = own_choice.move_index().and_then(|index| own_pokemon.get_move(index)).filter(|used| used.category != MoveCategory::Status);
+    // A move is revealed as it's used, so the real moveset can be read here
+    let opponent_move = opponent_choice.move_index().and_then(|index| real_opponent.get_move(index)).filter(|used| used.category != MoveCategory::Status);
+
+    let [side_one_span, side_two_span] = move_spans(turn, instructions);
+    let (own_span, opponent_span) = match side {
+        SideReference::SideOne => (side_one_span, side_two_span),
+        SideReference::SideTwo => (side_two_span, side_one_span),
+    };
+    let opponent_hit = opponent_move.and_then(|_| landed_hit(instructions, opponent_span, own_position, own_pokemon));
+    let own_hit = own_move.and_then(|_| landed_hit(instructions, own_span, opponent_position, real_opponent));
+    let real_opponent_max_hp = real_opponent.max_hp.max(1) as i32;
+
+    // Damage the opponent dealt, known exactly
+    if let (Some(used), Some(hit)) = (opponent_move, opponent_hit.as_ref().filter(|hit| !hit.knocked_out)) {
+        if let Ok(move_data) = view.state.game_data_repo.moves.find_by_id(&used.name) {
+            tracker.dealt_hits = true;
+            tracker.update(|hypothesis| {
+                let attacker = hypothesized_pokemon(observed_opponent, hypothesis, turn_item);
+                damage_likelihood(&view.state, (&attacker, opponent_position), (own_pokemon, own_position), move_data, |roll| {
+                    (roll - hit.amount).abs() <= 1
+                })
+            });
+        }
+    }
+
+    // Damage the opponent took, seen as a percentage
+    if let (Some(used), Some(hit)) = (own_move, own_hit.as_ref().filter(|hit| !hit.knocked_out)) {
+        if let Ok(move_data) = view.state.game_data_repo.moves.find_by_id(&used.name) {
+            let percent = (hit.amount as i32 * 100 + real_opponent_max_hp / 2) / real_opponent_max_hp;
+            tracker.took_hits = true;
+            tracker.update(|hypothesis| {
+                let defender = hypothesized_pokemon(observed_opponent, hypothesis, turn_item);
+                let max_hp = defender.max_hp.max(1) as i32;
+                damage_likelihood(&view.state, (own_pokemon, own_position), (&defender, opponent_position), move_data, |roll| {
+                    ((roll as i32 * 100 + max_hp / 2) / max_hp - percent).abs() <= 1
+                })
+            });
+        }
+    }
+
+    // Turn order at equal priority, known when both moves landed or one knocked out the other first
+    let (Some(own_used), Some(opponent_used)) = (own_move, opponent_move) else {
+        return;
+    };
+    if own_used.priority != opponent_used.priority {
+        return;
+    }
+    let opponent_first = match (&opponent_hit, &own_hit) {
+        (Some(opponent_hit), Some(own_hit)) => opponent_hit.index < own_hit.index,
+        (Some(opponent_hit), None) if opponent_hit.knocked_out => true,
+        (None, Some(own_hit)) if own_hit.knocked_out => false,
+        _ => return,
+    };
+    if opponent_first && tracker.outsped_at.is_none() {
+        tracker.outsped_at = Some(observed_opponent.stat_boosts.get_direct(Stat::Speed));
+    }
+    let own_speed = own_pokemon.get_effective_speed(&view.state, own_position);
+    tracker.update(|hypothesis| {
+        let opponent = hypothesized_pokemon(observed_opponent, hypothesis, turn_item);
+        match (opponent.get_effective_speed(&view.state, opponent_position).cmp(&own_speed), opponent_first) {
+            (Ordering::Equal, _) => 0.5,
+            (Ordering::Greater, true) | (Ordering::Less, false) => 1.0,
+            _ => 0.0,
+        }
+    });
+}
+
+/// Where each side's chosen move sits in a turn's instructions, side one first
+///
+/// The moves are generated again from the state before the turn: the pair of
+/// moves without residuals shows where end-of-turn effects start, and each move
+/// on its own shows which side moved first and where the second move starts. If
+/// neither matches, both spans cover every move of the turn.
+fn move_spans(turn: &TurnInfo, instructions: &[BattleInstruction]) -> [Range<usize>; 2] {
+    // Length of the longest generated branch the instructions start with
+    let matched_len = |choices: (&MoveChoice, &MoveChoice), within: &[BattleInstruction]| {
+        generate_move_pair_instructions(&turn.state_before, choices, false)
+            .ok()?
+            .into_iter()
+            .filter(|branch| within.starts_with(&branch.instruction_list))
+            .map(|branch| branch.instruction_list.len())
+            .max()
+    };
+
+    let moves_end = matched_len((&turn.side_one_choice, &turn.side_two_choice), instructions).unwrap_or(instructions.len());
+    let moves = &instructions[..moves_end];
+    let side_one_alone = matched_len((&turn.side_one_choice, &MoveChoice::None), moves).filter(|&len| len > 0);
+    let side_two_alone = matched_len((&MoveChoice::None, &turn.side_two_choice), moves).filter(|&len| len > 0);
+    match (side_one_alone, side_two_alone) {
+        (Some(split), None) => [0..split, split..moves_end],
+        (None, Some(split)) => [split..moves_end, 0..split],
+        _ => [0..moves_end, 0..moves_end],
+    }
+}
+
+/// The first hit a move landed on the Pokemon at a position, within the move's span
+///
+/// Hits on a substitute, or after the Pokemon was switched out, say nothing
+/// about the set.
+fn landed_hit(instructions: &[BattleInstruction], span: Range<usize>, target_position: BattlePosition, target: &Pokemon) -> Option<LandedHit> {
+    if target.volatile_statuses.contains(VolatileStatus::Substitute) && target.substitute_health > 0 {
+        return None;
+    }
+    let start = span.start;
+    instructions[span]
+        .iter()
+        .enumerate()
+        .take_while(|(_, instruction)| {
+            !matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Switch { position, .. }) if *position == target_position)
+        })
+        .find_map(|(offset, instruction)| match instruction {
+            BattleInstruction::Pokemon(PokemonInstruction::Damage { target: damaged, amount, previous_hp }) if *damaged == target_position => Some(LandedHit {
+                index: start + offset,
+                amount: *amount,
+                knocked_out: *amount >= previous_hp.unwrap_or(target.hp),
+            }),
+            _ => None,
+        })
+}
+
+/// Probability that a move's damage satisfies `observed`, over its rolls and a possible critical hit
+fn damage_likelihood(
+    state: &BattleState,
+    attacker: (&Pokemon, BattlePosition),
+    defender: (&Pokemon, BattlePosition),
+    move_data: &crate::data::showdown_types::MoveData,
+    observed: impl Fn(i16) -> bool,
+) -> f64 {
+    let share_matching = |is_critical| {
+        let max_damage =
+            calculate_damage_with_positions(state, attacker.0, defender.0, move_data, is_critical, DamageRolls::Max, 1, attacker.1, defender.1);
+        let rolls = if max_damage > 0 { calculate_all_damage_rolls(max_damage as f32) } else { vec![0] };
+        rolls.iter().filter(|&&roll| observed(roll)).count() as f64 / rolls.len() as f64
+    };
+    let critical_chance = critical_hit_probability(attacker.0, defender.0, move_data, state.format.generation) as f64;
+    (1.0 - critical_chance) * share_matching(false) + critical_chance * share_matching(true)
+}
+
+/// An observed opposing Pokemon given a hypothesis's stats, item and ability
+///
+/// `revealed_item` overrides the hypothesis once the item has been seen that turn.
+fn hypothesized_pokemon(observed: &Pokemon, hypothesis: &SetHypothesis, revealed_item: Option<Option<Items>>) -> Pokemon {
+    let evs = hypothesis.ev_spread.evs;
+    let stats = Stats::calculate(&observed.base_stats, &observed.ivs, &evs, hypothesis.nature, observed.level);
+    // Observed HP is out of 100
+    let hp = if observed.hp > 0 {
+        ((observed.hp as i32 * stats.hp as i32 + 50) / 100).clamp(1, stats.hp as i32) as i16
+    } else {
+        0
+    };
+
+    Pokemon {
+        hp,
+        max_hp: stats.hp,
+        stats,
+        nature: hypothesis.nature,
+        evs,
+        item: revealed_item.unwrap_or(hypothesis.item),
+        ability: hypothesis.ability,
+        ..observed.clone()
+    }
+}
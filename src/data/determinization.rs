@@ -0,0 +1,316 @@
+//! # Determinization Sampler
+//!
+//! Searching a battle with hidden information means guessing what the opponent
+//! is holding. This sampler fills in one side's observation of the battle with
+//! random battle sets from `RandomTeamLoader`: each revealed opponent gets a set
+//! matching its species, the moves, item and ability it has shown and the damage
+//! it has been seen taking and dealing, and each unrevealed one gets a set of a
+//! species not already on the team. Sets are drawn uniformly from the candidates
+//! and every sample is weighted by how often its sets appear in the team data, so
+//! weighted averages over samples follow the set distribution.
+
+use crate::core::battle_format::{BattleFormat, BattlePosition, SideReference};
+use crate::core::battle_state::{BattleObservation, BattleState, ObservedHit, Pokemon, RevealedPokemon};
+use crate::data::random_team_loader::{RandomPokemonSet, RandomTeam, RandomTeamLoader};
+use crate::engine::combat::damage::{calculate_damage_with_positions, DamageRolls};
+use crate::types::from_string::FromNormalizedString;
+use crate::types::PokemonName;
+use crate::utils::normalize_name;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+/// A complete battle state sampled from one side's observation
+#[derive(Debug, Clone)]
+pub struct DeterminizedState {
+    /// The battle with every opposing set filled in
+    pub state: BattleState,
+    /// Likelihood of the sampled sets given what has been observed, unnormalized
+    pub weight: f64,
+}
+
+/// Samples complete battle states consistent with an observation
+#[derive(Debug)]
+pub struct DeterminizationSampler {
+    /// Distinct sets for each species, with how many times each appears
+    sets: HashMap<PokemonName, Vec<(RandomPokemonSet, usize)>>,
+    rng: StdRng,
+}
+
+impl DeterminizationSampler {
+    /// Create a sampler from the random battle teams for a format
+    pub fn new(loader: &mut RandomTeamLoader, format: &BattleFormat) -> Result<Self, String> {
+        let teams = loader.get_teams(format)?;
+        Ok(Self::from_teams(teams))
+    }
+
+    /// Create a sampler from a pool of teams
+    pub fn from_teams(teams: &[RandomTeam]) -> Self {
+        let mut sets: HashMap<PokemonName, Vec<(RandomPokemonSet, usize)>> = HashMap::new();
+        for set in teams.iter().flatten() {
+            let species_sets = sets.entry(set.species).or_default();
+            match species_sets.iter_mut().find(|(known, _)| same_set(known, set)) {
+                Some((_, count)) => *count += 1,
+                None => species_sets.push((set.clone(), 1)),
+            }
+        }
+
+        Self {
+            sets,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Use a specific seed for deterministic sampling
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Sets a revealed opposing Pokemon could have, with the likelihood of each
+    ///
+    /// Sets must match the Pokemon's species and everything it has revealed. Damage
+    /// is only a soft constraint: if no set explains every observed hit, which can
+    /// happen with effects the check doesn't replay, the damage is ignored.
+    pub fn consistent_sets(&self, observation: &BattleObservation, pokemon_index: usize) -> Vec<(RandomPokemonSet, f64)> {
+        let opponent = observation.opponent();
+        let Some(observed) = opponent.pokemon.get(pokemon_index) else {
+            return Vec::new();
+        };
+        let revealed = opponent.revealed_pokemon(pokemon_index);
+        let Some(species_sets) = self.species_sets(&observation.state, observed.species) else {
+            return Vec::new();
+        };
+
+        let matching: Vec<&(RandomPokemonSet, usize)> = species_sets
+            .iter()
+            .filter(|(set, _)| set_matches(set, &revealed))
+            .collect();
+        let explaining_hits: Vec<&(RandomPokemonSet, usize)> = matching
+            .iter()
+            .copied()
+            .filter(|(set, _)| {
+                let mut state = observation.state.clone();
+                let candidate = determinized_pokemon(set, observed, &revealed, &state);
+                state.get_side_by_ref_mut(observation.side.opposite()).pokemon[pokemon_index] = candidate;
+                hits_consistent(&state, observation.side.opposite(), pokemon_index, &revealed)
+            })
+            .collect();
+
+        let candidates = if explaining_hits.is_empty() { matching } else { explaining_hits };
+        with_likelihoods(candidates)
+    }
+
+    /// Sample complete battle states from an observation
+    pub fn sample(&mut self, observation: &BattleObservation, count: usize) -> Vec<DeterminizedState> {
+        (0..count).map(|_| self.sample_one(observation)).collect()
+    }
+
+    /// Sample one complete battle state from an observation
+    fn sample_one(&mut self, observation: &BattleObservation) -> DeterminizedState {
+        let opponent_ref = observation.side.opposite();
+        let opponent = observation.opponent();
+        let mut state = observation.state.clone();
+        let mut weight = 1.0;
+
+        // Revealed Pokemon keep their species; a Pokemon with no matching set is left as observed
+        let mut team_species = Vec::new();
+        let mut unseen = Vec::new();
+        for (index, observed) in opponent.pokemon.iter().enumerate() {
+            let revealed = opponent.revealed_pokemon(index);
+            if !revealed.seen {
+                unseen.push(index);
+                continue;
+            }
+            team_species.push(self.base_species(&state, observed.species));
+
+            let candidates = self.consistent_sets(observation, index);
+            if let Some((set, likelihood)) = candidates.choose(&mut self.rng) {
+                state.get_side_by_ref_mut(opponent_ref).pokemon[index] = determinized_pokemon(set, observed, &revealed, &state);
+                weight *= likelihood;
+            }
+        }
+
+        // Species Clause keeps the rest of the team to species not already on it
+        for index in unseen {
+            let candidates = with_likelihoods(
+                self.sets
+                    .iter()
+                    .filter(|(species, _)| !team_species.contains(species))
+                    .flat_map(|(_, species_sets)| species_sets.iter())
+                    .collect(),
+            );
+            if let Some((set, likelihood)) = candidates.choose(&mut self.rng) {
+                let observed = &opponent.pokemon[index];
+                let pokemon = determinized_pokemon(set, observed, &RevealedPokemon::default(), &state);
+                state.get_side_by_ref_mut(opponent_ref).pokemon[index] = pokemon;
+                team_species.push(set.species);
+                weight *= likelihood;
+            }
+        }
+
+        DeterminizedState { state, weight }
+    }
+
+    /// The sets for a species, falling back to its base species for other formes
+    fn species_sets(&self, state: &BattleState, species: PokemonName) -> Option<&Vec<(RandomPokemonSet, usize)>> {
+        self.sets
+            .get(&species)
+            .or_else(|| self.sets.get(&self.base_species(state, species)))
+    }
+
+    /// The species a forme belongs to
+    fn base_species(&self, state: &BattleState, species: PokemonName) -> PokemonName {
+        state
+            .game_data_repo
+            .pokemon
+            .find_by_name(species.as_str())
+            .and_then(|data| data.base_species.as_deref())
+            .and_then(|base| PokemonName::from_normalized_str(&normalize_name(base)))
+            .unwrap_or(species)
+    }
+}
+
+/// Whether two entries in the team data describe the same set
+fn same_set(a: &RandomPokemonSet, b: &RandomPokemonSet) -> bool {
+    let mut a_moves = a.moves.clone();
+    let mut b_moves = b.moves.clone();
+    a_moves.sort_by_key(|name| name.as_str());
+    b_moves.sort_by_key(|name| name.as_str());
+
+    a.species == b.species
+        && a.level == b.level
+        && a.ability == b.ability
+        && a.item == b.item
+        && a.tera_type == b.tera_type
+        && a_moves == b_moves
+}
+
+/// Whether a set agrees with the moves, item and ability a Pokemon has revealed
+///
+/// An item that was knocked off or consumed leaves the original item unknown.
+fn set_matches(set: &RandomPokemonSet, revealed: &RevealedPokemon) -> bool {
+    let moves_match = revealed.moves.iter().all(|name| set.moves.contains(name));
+    let item_matches = match revealed.item {
+        Some(Some(item)) => set.item == Some(item),
+        _ => true,
+    };
+    let ability_matches = match (revealed.ability, set.ability) {
+        (Some(ability), Some(set_ability)) => ability == set_ability,
+        _ => true,
+    };
+    moves_match && item_matches && ability_matches
+}
+
+/// Candidate sets with their share of the candidates' total count
+fn with_likelihoods(candidates: Vec<&(RandomPokemonSet, usize)>) -> Vec<(RandomPokemonSet, f64)> {
+    let total: usize = candidates.iter().map(|(_, count)| count).sum();
+    candidates
+        .into_iter()
+        .map(|(set, count)| (set.clone(), *count as f64 / total.max(1) as f64))
+        .collect()
+}
+
+/// A Pokemon built from a set, carrying over everything the battle has done to it
+fn determinized_pokemon(set: &RandomPokemonSet, observed: &Pokemon, revealed: &RevealedPokemon, state: &BattleState) -> Pokemon {
+    let repository = &state.game_data_repo;
+    let mut pokemon = set.to_battle_pokemon_for_format(repository, &state.format);
+
+    // A forme change mid-battle moves its stats along with it
+    if observed.species != PokemonName::NONE && observed.species != pokemon.species {
+        if let Some(forme) = repository.pokemon.find_by_name(observed.species.as_str()) {
+            pokemon.change_forme(forme);
+        }
+        pokemon.species = observed.species;
+    }
+
+    // Observed HP is out of 100
+    pokemon.hp = if observed.hp > 0 {
+        ((observed.hp as i32 * pokemon.max_hp as i32 + 50) / 100).clamp(1, pokemon.max_hp as i32) as i16
+    } else {
+        0
+    };
+    if let Some(item) = revealed.item {
+        pokemon.item = item;
+    }
+    if let Some(ability) = revealed.ability {
+        pokemon.ability = ability;
+    }
+    if observed.species != PokemonName::NONE {
+        pokemon.types = observed.types.clone();
+    }
+    if observed.is_terastallized {
+        pokemon.tera_type = observed.tera_type;
+    }
+
+    Pokemon {
+        stat_boosts: observed.stat_boosts,
+        status: observed.status,
+        status_duration: observed.status_duration,
+        volatile_statuses: observed.volatile_statuses.clone(),
+        volatile_status_durations: observed.volatile_status_durations.clone(),
+        trap_sources: observed.trap_sources.clone(),
+        substitute_health: observed.substitute_health,
+        is_terastallized: observed.is_terastallized,
+        ability_suppressed: observed.ability_suppressed,
+        item_consumed: observed.item_consumed,
+        consumed_item: observed.consumed_item,
+        forme: observed.forme.clone(),
+        last_used_move: observed.last_used_move,
+        must_switch: observed.must_switch,
+        sleep_turns: observed.sleep_turns,
+        rest_turns: observed.rest_turns,
+        ..pokemon
+    }
+}
+
+/// Whether the observed hits fit a Pokemon's damage rolls in a sampled state
+fn hits_consistent(state: &BattleState, side_ref: SideReference, pokemon_index: usize, revealed: &RevealedPokemon) -> bool {
+    let own_side = state.get_side_by_ref(side_ref);
+    let other_side = state.get_side_by_ref(side_ref.opposite());
+    let own_position = BattlePosition::new(side_ref, 0);
+    let other_position = BattlePosition::new(side_ref.opposite(), 0);
+    let Some(pokemon) = own_side.pokemon.get(pokemon_index) else {
+        return true;
+    };
+
+    let taken = revealed.hits_taken.iter().all(|hit| {
+        other_side.pokemon.get(hit.opponent_index).is_none_or(|attacker| {
+            hit_in_range(state, hit, (attacker, other_position), (pokemon, own_position))
+        })
+    });
+    let dealt = revealed.hits_dealt.iter().all(|hit| {
+        other_side.pokemon.get(hit.opponent_index).is_none_or(|defender| {
+            hit_in_range(state, hit, (pokemon, own_position), (defender, other_position))
+        })
+    });
+    taken && dealt
+}
+
+/// Whether a hit's damage lies between the lowest roll and the highest critical roll
+///
+/// Observed damage is a whole percentage, so the range gets a point of slack on
+/// either side. Moves the calculator can't price are taken as consistent.
+fn hit_in_range(state: &BattleState, hit: &ObservedHit, attacker: (&Pokemon, BattlePosition), defender: (&Pokemon, BattlePosition)) -> bool {
+    let Ok(move_data) = state.game_data_repo.moves.find_by_id(&hit.move_name) else {
+        return true;
+    };
+    let mut attacking = attacker.0.clone();
+    attacking.stat_boosts = hit.attacker_boosts;
+    let mut defending = defender.0.clone();
+    defending.stat_boosts = hit.defender_boosts;
+
+    let damage = |is_critical, rolls| {
+        calculate_damage_with_positions(state, &attacking, &defending, move_data, is_critical, rolls, 1, attacker.1, defender.1)
+    };
+    let (min_damage, max_damage) = (damage(false, DamageRolls::Min), damage(true, DamageRolls::Max));
+    if max_damage <= 0 {
+        return true;
+    }
+
+    let max_hp = defending.max_hp.max(1) as i32;
+    let min_percent = min_damage as i32 * 100 / max_hp - 1;
+    let max_percent = (max_damage as i32 * 100 + max_hp - 1) / max_hp + 1;
+    (min_percent..=max_percent).contains(&(hit.percent as i32))
+}
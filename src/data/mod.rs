@@ -22,6 +22,10 @@
 //!   for generating random teams for testing and battles, with format-specific
 //!   team generation capabilities.
 //!
+//! - **Determinization** (`determinization`): Samples complete opposing teams
+//!   from random battle sets that agree with what one side has observed, for
+//!   searching battles with hidden information.
+//!
 //! ## Key Features
 //!
 //! - **Format-Aware Loading**: Data loading respects different battle formats
@@ -44,6 +48,7 @@ pub mod repositories;
 pub mod generation_loader;
 pub mod showdown_types;
 pub mod random_team_loader;
+pub mod determinization;
 pub mod types;
 
 // Re-exports for convenience
@@ -51,5 +56,6 @@ pub use repositories::{GameDataRepository, MoveRepository, PokemonRepository, It
 pub use generation_loader::GenerationRepository;
 pub use showdown_types::*;
 pub use random_team_loader::{RandomTeamLoader, RandomPokemonSet, RandomTeam};
+pub use determinization::{DeterminizationSampler, DeterminizedState};
 
 // Core types still needed for compatibility
@@ -239,6 +239,18 @@ impl RandomTeamLoader {
         Ok(teams)
     }
 
+    /// Get every loaded team for the specified format
+    pub fn get_teams(&mut self, format: &BattleFormat) -> Result<&[RandomTeam], String> {
+        // Ensure teams are loaded
+        self.load_teams(format)?;
+
+        let format_key = self.format_to_file_key(format);
+        self.teams_cache
+            .get(&format_key)
+            .map(|teams| teams.as_slice())
+            .ok_or_else(|| format!("Teams not loaded for format: {}", format.name))
+    }
+
     /// Get team count for a format
     pub fn get_team_count(&self, format: &BattleFormat) -> Option<usize> {
         let format_key = self.format_to_file_key(format);
@@ -412,6 +424,22 @@ impl RandomPokemonSet {
         self.gigantamax.unwrap_or(false)
     }
 
+    /// Convert to battle engine Pokemon at the level the format's level rule sets
+    ///
+    /// Stats and max HP are calculated at that level.
+    pub fn to_battle_pokemon_for_format(
+        &self,
+        repository: &crate::data::GameDataRepository,
+        format: &crate::core::battle_format::BattleFormat,
+    ) -> Pokemon {
+        let level = format.level_rule.apply(self.level);
+        if level == self.level {
+            self.to_battle_pokemon(repository)
+        } else {
+            Self { level, ..self.clone() }.to_battle_pokemon(repository)
+        }
+    }
+
     /// Convert to battle engine Pokemon
     pub fn to_battle_pokemon(
         &self,
@@ -470,56 +498,13 @@ impl RandomPokemonSet {
         let evs = self.get_evs(repository);
 
         // Calculate actual stats using Pokemon formula
-        // HP = floor(((2 * base + iv + floor(ev / 4)) * level) / 100) + level + 10
-        // Use i32 to prevent overflow during multiplication
-        let hp = (((2 * base_stats.hp as i32 + ivs.hp as i32 + evs.hp as i32 / 4)
-            * self.level as i32)
-            / 100)
-            + self.level as i32
-            + 10;
-
-        // Other stats = floor((floor(((2 * base + iv + floor(ev / 4)) * level) / 100) + 5) * nature_modifier)
-        let attack = self.calculate_stat(
-            base_stats.attack as i16,
-            ivs.attack,
-            evs.attack,
-            nature.attack_modifier(),
-        );
-        let defense = self.calculate_stat(
-            base_stats.defense as i16,
-            ivs.defense,
-            evs.defense,
-            nature.defense_modifier(),
-        );
-        let special_attack = self.calculate_stat(
-            base_stats.special_attack as i16,
-            ivs.special_attack,
-            evs.special_attack,
-            nature.special_attack_modifier(),
-        );
-        let special_defense = self.calculate_stat(
-            base_stats.special_defense as i16,
-            ivs.special_defense,
-            evs.special_defense,
-            nature.special_defense_modifier(),
-        );
-        let speed = self.calculate_stat(
-            base_stats.speed as i16,
-            ivs.speed,
-            evs.speed,
-            nature.speed_modifier(),
-        );
-
-        pokemon.hp = hp as i16;
-        pokemon.max_hp = hp as i16;
-        pokemon.stats = Stats {
-            hp: hp as i16,
-            attack,
-            defense,
-            special_attack,
-            special_defense,
-            speed,
-        };
+        let stats = Stats::calculate(&base_stats, &ivs, &evs, nature, self.level);
+        pokemon.hp = stats.hp;
+        pokemon.max_hp = stats.hp;
+        pokemon.stats = stats;
+        pokemon.nature = nature;
+        pokemon.ivs = ivs;
+        pokemon.evs = evs;
 
         // Set base species stats for critical hit calculation
         pokemon.base_stats = base_stats;
@@ -581,13 +566,5 @@ impl RandomPokemonSet {
 
         pokemon
     }
-
-    /// Calculate a stat using the Pokemon formula
-    fn calculate_stat(&self, base: i16, iv: i16, ev: i16, nature_modifier: f64) -> i16 {
-        // Use i32 to prevent overflow during multiplication
-        let stat = (((2 * base as i32 + iv as i32 + ev as i32 / 4) * self.level as i32) / 100) + 5;
-        let final_stat = (stat as f64 * nature_modifier) as i16;
-        final_stat
-    }
 }
 
@@ -472,6 +472,10 @@ pub struct MoveData {
     // Nonstandard designation
     #[serde(rename = "isNonstandard")]
     pub is_nonstandard: Option<String>,
+
+    /// Set on the copy of a move used for Parental Bond's second hit
+    #[serde(skip)]
+    pub parental_bond_hit: bool,
 }
 
 impl Default for MoveData {
@@ -514,6 +518,7 @@ impl Default for MoveData {
             desc: "Default move for testing".to_string(),
             short_desc: "Default move".to_string(),
             is_nonstandard: None,
+            parental_bond_hit: false,
         }
     }
 }
@@ -641,9 +646,10 @@ impl Default for SelfSwitchData {
 
 impl SelfSwitchData {
     pub fn causes_switch(&self) -> bool {
+        // Untagged deserialization puts both booleans in the first variant
         matches!(
             self,
-            SelfSwitchData::Normal(true) | SelfSwitchData::Special(_)
+            SelfSwitchData::None(true) | SelfSwitchData::Normal(true) | SelfSwitchData::Special(_)
         )
     }
 
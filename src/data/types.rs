@@ -16,6 +16,39 @@ pub struct Stats {
     pub speed: i16,
 }
 
+impl Stats {
+    /// Battle stats from base stats, IVs, EVs and nature at a level
+    ///
+    /// Uses the formula from Gen 3 onwards; `hp` holds the max HP.
+    pub fn calculate(base: &Stats, ivs: &Stats, evs: &Stats, nature: Nature, level: u8) -> Stats {
+        // Use i32 to prevent overflow during multiplication
+        let level = level as i32;
+        let scaled = |base: i16, iv: i16, ev: i16| (2 * base as i32 + iv as i32 + ev as i32 / 4) * level / 100;
+        let stat = |base: i16, iv: i16, ev: i16, nature_modifier: f64| {
+            ((scaled(base, iv, ev) + 5) as f64 * nature_modifier) as i16
+        };
+
+        Stats {
+            hp: (scaled(base.hp, ivs.hp, evs.hp) + level + 10) as i16,
+            attack: stat(base.attack, ivs.attack, evs.attack, nature.attack_modifier()),
+            defense: stat(base.defense, ivs.defense, evs.defense, nature.defense_modifier()),
+            special_attack: stat(
+                base.special_attack,
+                ivs.special_attack,
+                evs.special_attack,
+                nature.special_attack_modifier(),
+            ),
+            special_defense: stat(
+                base.special_defense,
+                ivs.special_defense,
+                evs.special_defense,
+                nature.special_defense_modifier(),
+            ),
+            speed: stat(base.speed, ivs.speed, evs.speed, nature.speed_modifier()),
+        }
+    }
+}
+
 /// Pokemon natures that affect stat growth
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Nature {
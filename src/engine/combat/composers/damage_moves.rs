@@ -15,12 +15,10 @@ use super::super::core::{
     },
     status_system::{StatusApplication, VolatileStatusApplication, apply_multiple_status_effects},
     contact_effects::{apply_recoil_damage, apply_drain_healing},
-    substitute_protection::{EffectType, should_block_effect, get_effect_type_for_status_instruction},
 };
 use crate::types::StatBoostArray;
 use std::collections::HashMap;
 use crate::core::instructions::{StatusInstruction, VolatileStatus};
-use crate::core::instructions::VolatileStatus as VS;
 
 /// Check if the user moved first this turn by comparing with targets
 fn check_moved_first(
@@ -54,6 +52,20 @@ fn check_moved_first(
     true
 }
 
+/// Check whether the user's Sheer Force suppresses this move's secondary effects
+fn sheer_force_active_for(state: &BattleState, user_position: BattlePosition, move_data: &MoveData) -> bool {
+    state
+        .get_pokemon_at_position(user_position)
+        .is_some_and(|user| crate::engine::mechanics::abilities::sheer_force_active(user, move_data))
+}
+
+/// Check whether the target's Covert Cloak keeps a move's secondary effects off it
+fn secondary_effects_blocked_for(state: &BattleState, target_position: BattlePosition) -> bool {
+    state
+        .get_pokemon_at_position(target_position)
+        .is_some_and(crate::engine::mechanics::items::blocks_secondary_effects)
+}
+
 /// Modifiers for damage calculations
 #[derive(Debug, Clone)]
 pub struct DamageModifiers {
@@ -127,6 +139,7 @@ pub fn simple_damage_move(
     generation: &GenerationMechanics,
 ) -> Vec<BattleInstruction> {
     let mut instructions = Vec::new();
+    let sheer_force = sheer_force_active_for(state, user_position, move_data);
 
     for &target_position in target_positions {
         // Create damage context
@@ -192,18 +205,18 @@ pub fn simple_damage_move(
             }
         }
 
-        // Apply secondary status effects (with substitute protection)
-        if !modifiers.secondary_effects.is_empty() {
-            let status_instructions = apply_multiple_status_effects_with_substitute_protection(
+        // Apply secondary status effects (substitutes drop them later in the move pipeline)
+        let secondaries = !sheer_force && !secondary_effects_blocked_for(state, target_position);
+        if secondaries && !modifiers.secondary_effects.is_empty() {
+            let status_instructions = apply_multiple_status_effects(
                 state,
                 modifiers.secondary_effects.clone(),
-                target_position,
             );
             instructions.extend(status_instructions);
         }
 
         // Apply stat changes
-        if let Some(ref stat_changes) = modifiers.stat_changes {
+        if let Some(stat_changes) = modifiers.stat_changes.as_ref().filter(|_| secondaries) {
             let mut non_zero_changes = StatBoostArray::default();
             for (stat, change) in stat_changes {
                 if *change != 0 {
@@ -522,35 +535,6 @@ pub fn dynamic_category_move(
     )
 }
 
-/// Apply multiple status effects with substitute protection
-/// 
-/// This function checks if the target has a substitute and blocks effects accordingly.
-/// Unlike the regular apply_multiple_status_effects, this version considers whether
-/// the damage hit a substitute to determine if secondary effects should be blocked.
-pub fn apply_multiple_status_effects_with_substitute_protection(
-    state: &BattleState,
-    applications: Vec<StatusApplication>,
-    target_position: BattlePosition,
-) -> Vec<BattleInstruction> {
-    let mut instructions = Vec::new();
-    
-    // Check if target has substitute
-    if let Some(target_pokemon) = state.get_pokemon_at_position(target_position) {
-        let has_substitute = target_pokemon.volatile_statuses.contains(VS::Substitute) 
-            && target_pokemon.substitute_health > 0;
-        
-        if has_substitute {
-            // If substitute is present, block secondary effects
-            // (In real implementation, we would check if damage actually hit the substitute
-            // but for now we assume any damage move with substitute present hits substitute)
-            return instructions; // Return empty - substitute blocks secondary effects
-        }
-    }
-    
-    // No substitute protection, apply effects normally
-    apply_multiple_status_effects(state, applications)
-}
-
 /// Compose a damage move with volatile status secondary effects
 pub fn damage_move_with_secondary_volatile_status(
     state: &BattleState,
@@ -561,6 +545,7 @@ pub fn damage_move_with_secondary_volatile_status(
     generation: &GenerationMechanics,
 ) -> Vec<BattleInstruction> {
     let mut instructions = Vec::new();
+    let sheer_force = sheer_force_active_for(state, user_position, move_data);
 
     for &target_position in target_positions {
         // Create damage context
@@ -603,7 +588,7 @@ pub fn damage_move_with_secondary_volatile_status(
             .cloned()
             .collect();
 
-        if !target_applications.is_empty() {
+        if !sheer_force && !secondary_effects_blocked_for(state, target_position) && !target_applications.is_empty() {
             let volatile_status_instructions = super::super::core::status_system::apply_multiple_volatile_status_effects(
                 state,
                 target_applications,
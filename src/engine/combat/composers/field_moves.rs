@@ -7,7 +7,7 @@ use crate::core::battle_format::BattlePosition;
 use crate::core::battle_state::BattleState;
 use crate::core::instructions::{BattleInstruction, FieldInstruction, Terrain, Weather};
 use super::super::core::field_system::{
-    weather_move_with_extension, terrain_move_with_extension, screen_move, hazard_move,
+    weather_move_with_extension, terrain_move_with_extension, screen_move_with_extension, hazard_move,
     hazard_removal_move, ScreenType, HazardType, HazardRemovalType,
 };
 
@@ -160,8 +160,7 @@ pub fn screen_setting_move(
     user_position: BattlePosition,
     screen_type: ScreenType,
 ) -> Vec<BattleInstruction> {
-    let duration = Some(5); // Standard screen duration
-    screen_move(state, user_position, screen_type, duration)
+    screen_move_with_extension(state, user_position, screen_type)
 }
 
 /// Hazard-setting move
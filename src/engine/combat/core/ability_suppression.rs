@@ -0,0 +1,243 @@
+//! Ability Suppression System
+//!
+//! Neutralizing Gas switches off every other ability on the field while its
+//! user is out, and Gastro Acid and Core Enforcer suppress a single target until
+//! it switches out. All of them set the Pokemon's `ability_suppressed` flag, so
+//! every check that honours the flag covers each source. Mold Breaker, Turboblaze
+//! and Teravolt instead ignore the target's defensive ability for a single move.
+//! An Ability Shield protects its holder from all of these.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::{BattleInstruction, PokemonInstruction, StatusInstruction, VolatileStatus};
+use crate::types::{Abilities, Items};
+
+/// Whether a Pokemon holds an Ability Shield
+pub fn has_ability_shield(pokemon: &Pokemon) -> bool {
+    pokemon.item == Some(Items::ABILITYSHIELD)
+}
+
+/// Whether Neutralizing Gas is in effect on the field
+pub fn neutralizing_gas_active(state: &BattleState) -> bool {
+    state.get_all_active_positions().into_iter().any(|position| {
+        state.get_pokemon_at_position(position).is_some_and(|pokemon| {
+            pokemon.hp > 0 && pokemon.ability == Abilities::NEUTRALIZINGGAS && !pokemon.ability_suppressed
+        })
+    })
+}
+
+/// Instructions suppressing a Pokemon's ability through Gastro Acid or Core Enforcer
+///
+/// Nothing happens to an Ability Shield holder, an ability that can't be
+/// suppressed, or a Pokemon already under Gastro Acid.
+pub fn suppress_ability(position: BattlePosition, pokemon: &Pokemon) -> Vec<BattleInstruction> {
+    if pokemon.hp == 0
+        || has_ability_shield(pokemon)
+        || is_unsuppressable_ability(pokemon.ability)
+        || pokemon.volatile_statuses.contains(VolatileStatus::GastroAcid)
+    {
+        return Vec::new();
+    }
+
+    let mut instructions = vec![BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+        target: position,
+        status: VolatileStatus::GastroAcid,
+        duration: None,
+        previous_had_status: false,
+        previous_duration: None,
+    })];
+    if !pokemon.ability_suppressed {
+        instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ToggleAbility {
+            target: position,
+            suppressed: true,
+            previous_state: false,
+        }));
+    }
+    instructions
+}
+
+/// Bring every active Pokemon's ability suppression in line with Neutralizing Gas and Gastro Acid
+///
+/// Run after anything that can start or end Neutralizing Gas: a gas user entering
+/// suppresses everyone else, a Pokemon entering while the gas is out loses its
+/// ability, and once the gas is gone the others get their abilities back and
+/// their switch-in abilities activate again.
+pub fn sync_ability_suppression(state: &BattleState) -> Vec<BattleInstruction> {
+    let gas = neutralizing_gas_active(state);
+    let mut instructions = Vec::new();
+
+    for position in state.get_all_active_positions() {
+        let Some(pokemon) = state.get_pokemon_at_position(position).filter(|pokemon| pokemon.hp > 0) else {
+            continue;
+        };
+        let suppressed = pokemon.volatile_statuses.contains(VolatileStatus::GastroAcid)
+            || (gas && suppressed_by_neutralizing_gas(pokemon));
+        if suppressed == pokemon.ability_suppressed {
+            continue;
+        }
+
+        instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ToggleAbility {
+            target: position,
+            suppressed,
+            previous_state: pokemon.ability_suppressed,
+        }));
+        if !suppressed {
+            let mut restored = pokemon.clone();
+            restored.ability_suppressed = false;
+            instructions.extend(super::ability_triggers::trigger_switch_in_abilities(&restored, position, state));
+        }
+    }
+    instructions
+}
+
+/// Re-sync ability suppression when something fainted between `before` and `after`
+///
+/// Neutralizing Gas ends as soon as its user faints, so the other Pokemon get
+/// their abilities back before the next action rather than on the next switch.
+pub fn sync_ability_suppression_after_faints(before: &BattleState, after: &BattleState) -> Vec<BattleInstruction> {
+    let fainted = after.get_all_active_positions().into_iter().any(|position| {
+        let was_standing = before.get_pokemon_at_position(position).is_some_and(|pokemon| pokemon.hp > 0);
+        let has_fainted = after.get_pokemon_at_position(position).is_some_and(|pokemon| pokemon.hp == 0);
+        was_standing && has_fainted
+    });
+    if !fainted {
+        return Vec::new();
+    }
+    sync_ability_suppression(after)
+}
+
+/// Whether a Pokemon's moves ignore the target's ability through Mold Breaker, Turboblaze or Teravolt
+pub fn breaks_molds(pokemon: &Pokemon) -> bool {
+    !pokemon.ability_suppressed
+        && matches!(pokemon.ability, Abilities::MOLDBREAKER | Abilities::TURBOBLAZE | Abilities::TERAVOLT)
+}
+
+/// The ability `target` defends with against a move from `attacker`
+///
+/// `None` when the ability is suppressed, or when the attacker breaks molds and
+/// the ability is one a move can ignore. An Ability Shield keeps it working
+/// against Mold Breaker.
+pub fn defending_ability(attacker: &Pokemon, target: &Pokemon) -> Option<Abilities> {
+    if target.ability_suppressed {
+        return None;
+    }
+    if breaks_molds(attacker) && is_breakable_ability(target.ability) && !has_ability_shield(target) {
+        return None;
+    }
+    Some(target.ability)
+}
+
+/// Whether Neutralizing Gas switches this Pokemon's ability off
+fn suppressed_by_neutralizing_gas(pokemon: &Pokemon) -> bool {
+    pokemon.ability != Abilities::NEUTRALIZINGGAS
+        && !is_unsuppressable_ability(pokemon.ability)
+        && !has_ability_shield(pokemon)
+}
+
+/// Abilities that stay active through Neutralizing Gas and Gastro Acid
+fn is_unsuppressable_ability(ability: Abilities) -> bool {
+    matches!(
+        ability,
+        Abilities::ASONEGLASTRIER
+            | Abilities::ASONESPECTRIER
+            | Abilities::BATTLEBOND
+            | Abilities::COMATOSE
+            | Abilities::COMMANDER
+            | Abilities::DISGUISE
+            | Abilities::GULPMISSILE
+            | Abilities::ICEFACE
+            | Abilities::MULTITYPE
+            | Abilities::POWERCONSTRUCT
+            | Abilities::RKSSYSTEM
+            | Abilities::SCHOOLING
+            | Abilities::SHIELDSDOWN
+            | Abilities::STANCECHANGE
+            | Abilities::TERASHIFT
+            | Abilities::ZENMODE
+            | Abilities::ZEROTOHERO
+    )
+}
+
+/// Defensive abilities that Mold Breaker, Turboblaze and Teravolt ignore
+fn is_breakable_ability(ability: Abilities) -> bool {
+    matches!(
+        ability,
+        Abilities::ARMORTAIL
+            | Abilities::AROMAVEIL
+            | Abilities::BATTLEARMOR
+            | Abilities::BIGPECKS
+            | Abilities::BULLETPROOF
+            | Abilities::CLEARBODY
+            | Abilities::CONTRARY
+            | Abilities::DAMP
+            | Abilities::DAZZLING
+            | Abilities::DISGUISE
+            | Abilities::DRYSKIN
+            | Abilities::EARTHEATER
+            | Abilities::FILTER
+            | Abilities::FLASHFIRE
+            | Abilities::FLOWERGIFT
+            | Abilities::FLOWERVEIL
+            | Abilities::FLUFFY
+            | Abilities::FRIENDGUARD
+            | Abilities::FURCOAT
+            | Abilities::GOODASGOLD
+            | Abilities::GRASSPELT
+            | Abilities::HEATPROOF
+            | Abilities::HEAVYMETAL
+            | Abilities::HYPERCUTTER
+            | Abilities::ICEFACE
+            | Abilities::ICESCALES
+            | Abilities::IMMUNITY
+            | Abilities::INNERFOCUS
+            | Abilities::INSOMNIA
+            | Abilities::KEENEYE
+            | Abilities::LEAFGUARD
+            | Abilities::LEVITATE
+            | Abilities::LIGHTMETAL
+            | Abilities::LIGHTNINGROD
+            | Abilities::LIMBER
+            | Abilities::MAGICBOUNCE
+            | Abilities::MAGMAARMOR
+            | Abilities::MARVELSCALE
+            | Abilities::MINDSEYE
+            | Abilities::MIRRORARMOR
+            | Abilities::MOTORDRIVE
+            | Abilities::MULTISCALE
+            | Abilities::OBLIVIOUS
+            | Abilities::OVERCOAT
+            | Abilities::OWNTEMPO
+            | Abilities::PASTELVEIL
+            | Abilities::PUNKROCK
+            | Abilities::PURIFYINGSALT
+            | Abilities::QUEENLYMAJESTY
+            | Abilities::SANDVEIL
+            | Abilities::SAPSIPPER
+            | Abilities::SHELLARMOR
+            | Abilities::SHIELDDUST
+            | Abilities::SIMPLE
+            | Abilities::SNOWCLOAK
+            | Abilities::SOLIDROCK
+            | Abilities::SOUNDPROOF
+            | Abilities::STICKYHOLD
+            | Abilities::STORMDRAIN
+            | Abilities::STURDY
+            | Abilities::SUCTIONCUPS
+            | Abilities::SWEETVEIL
+            | Abilities::TANGLEDFEET
+            | Abilities::TELEPATHY
+            | Abilities::THERMALEXCHANGE
+            | Abilities::THICKFAT
+            | Abilities::UNAWARE
+            | Abilities::VITALSPIRIT
+            | Abilities::VOLTABSORB
+            | Abilities::WATERABSORB
+            | Abilities::WATERBUBBLE
+            | Abilities::WATERVEIL
+            | Abilities::WELLBAKEDBODY
+            | Abilities::WHITESMOKE
+            | Abilities::WINDRIDER
+            | Abilities::WONDERGUARD
+            | Abilities::WONDERSKIN
+    )
+}
@@ -6,10 +6,11 @@
 use crate::core::battle_format::BattlePosition;
 use crate::core::battle_state::{BattleState, Pokemon};
 use crate::core::instructions::{
-    BattleInstruction, BattleInstructions, PokemonInstruction, StatusInstruction,
+    BattleInstruction, BattleInstructions, PokemonInstruction,
     StatsInstruction, PokemonStatus, VolatileStatus, Stat
 };
 use crate::types::StatBoostArray;
+use super::field_system::weather_for;
 use std::collections::HashMap;
 
 /// Types of ability triggers
@@ -42,59 +43,49 @@ impl Default for AbilityTriggerResult {
     }
 }
 
-/// Trigger end-of-turn abilities for all Pokemon
-pub fn trigger_end_of_turn_abilities(
+/// Trigger a Pokemon's weather ability in place of weather damage
+///
+/// Dry Skin, Rain Dish, Ice Body and Solar Power resolve with the weather
+/// residual, each Pokemon in speed order.
+pub fn trigger_weather_ability(
+    pokemon: &Pokemon,
+    position: BattlePosition,
     battle_state: &BattleState,
 ) -> Vec<BattleInstruction> {
-    let mut instructions = Vec::new();
-    
-    // Process abilities in speed order (fastest first)
-    let mut positions_with_speeds = Vec::new();
-    for position in battle_state.get_all_active_positions() {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            let speed = pokemon.get_effective_speed(battle_state, position);
-            positions_with_speeds.push((position, speed));
-        }
-    }
-    
-    // Sort by speed (fastest first)
-    positions_with_speeds.sort_by(|a, b| b.1.cmp(&a.1));
-    
-    for (position, _) in positions_with_speeds {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            if let Some(ability_result) = trigger_end_of_turn_ability(pokemon, position, battle_state) {
-                instructions.extend(ability_result.instructions);
-            }
-        }
+    if pokemon.ability_suppressed {
+        return Vec::new();
     }
-    
-    instructions
+
+    let result = match pokemon.ability.as_str() {
+        "dryskin" => trigger_dry_skin(pokemon, position, battle_state),
+        "raindish" => trigger_rain_dish(pokemon, position, battle_state),
+        "icebody" => trigger_ice_body(pokemon, position, battle_state),
+        "solarpower" => trigger_solar_power(pokemon, position, battle_state),
+        _ => return Vec::new(),
+    };
+    result.instructions
 }
 
-/// Trigger a specific Pokemon's end-of-turn ability
-fn trigger_end_of_turn_ability(
+/// Trigger a Pokemon's end-of-turn ability
+///
+/// Speed Boost, Moody and Bad Dreams resolve late in the residual order, after
+/// every timer has counted down.
+pub fn trigger_end_of_turn_ability(
     pokemon: &Pokemon,
     position: BattlePosition,
     battle_state: &BattleState,
-) -> Option<AbilityTriggerResult> {
+) -> Vec<BattleInstruction> {
     if pokemon.ability_suppressed || pokemon.ability_triggered_this_turn {
-        return None;
-    }
-    
-    match pokemon.ability.as_str() {
-        "speedboost" => Some(trigger_speed_boost(position)),
-        "moody" => Some(trigger_moody(position, battle_state)),
-        "shedskin" => Some(trigger_shed_skin(pokemon, position)),
-        "dryskin" => Some(trigger_dry_skin(pokemon, position, battle_state)),
-        "raindish" => Some(trigger_rain_dish(pokemon, position, battle_state)),
-        "icebody" => Some(trigger_ice_body(pokemon, position, battle_state)),
-        "solarpower" => Some(trigger_solar_power(pokemon, position, battle_state)),
-        "poisonheal" => Some(trigger_poison_heal(pokemon, position)),
-        "magicguard" => None, // Magic Guard is passive, handled in damage prevention
-        "naturalcure" => None, // Natural Cure triggers on switch-out, not end-of-turn
-        "regenerator" => None, // Regenerator triggers on switch-out, not end-of-turn
-        _ => None,
+        return Vec::new();
     }
+
+    let result = match pokemon.ability.as_str() {
+        "speedboost" => trigger_speed_boost(position),
+        "moody" => trigger_moody(position, battle_state),
+        "baddreams" => trigger_bad_dreams(position, battle_state),
+        _ => return Vec::new(),
+    };
+    result.instructions
 }
 
 /// Speed Boost - Increases Speed by 1 stage at the end of each turn
@@ -165,29 +156,32 @@ fn trigger_moody(position: BattlePosition, battle_state: &BattleState) -> Abilit
     }
 }
 
-/// Shed Skin - 30% chance to cure status condition at the end of each turn
-fn trigger_shed_skin(pokemon: &Pokemon, position: BattlePosition) -> AbilityTriggerResult {
-    if pokemon.status == PokemonStatus::None {
-        return AbilityTriggerResult::default();
-    }
-    
-    // 30% chance to cure status condition
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
-    if rng.gen_range(0.0..100.0) < 30.0 {
-        AbilityTriggerResult {
-            instructions: vec![
-                BattleInstruction::Status(StatusInstruction::Remove {
-                    target: position,
-                    status: pokemon.status,
-                    previous_duration: pokemon.status_duration,
-                })
-            ],
-            prevents_other_abilities: false,
-            blocks_effect: false,
+/// Bad Dreams - Opponents that are asleep lose 1/8 HP at the end of each turn
+fn trigger_bad_dreams(position: BattlePosition, battle_state: &BattleState) -> AbilityTriggerResult {
+    let mut instructions = Vec::new();
+    for opponent_position in battle_state.get_all_active_positions() {
+        if opponent_position.side == position.side {
+            continue;
+        }
+        let Some(opponent) = battle_state.get_pokemon_at_position(opponent_position) else {
+            continue;
+        };
+        let asleep = opponent.status == PokemonStatus::Sleep
+            || (opponent.ability.as_str() == "comatose" && !opponent.ability_suppressed);
+        let magic_guard = opponent.ability.as_str() == "magicguard" && !opponent.ability_suppressed;
+        if opponent.hp > 0 && asleep && !magic_guard {
+            instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: opponent_position,
+                amount: (opponent.max_hp / 8).max(1),
+                previous_hp: Some(opponent.hp),
+            }));
         }
-    } else {
-        AbilityTriggerResult::default()
+    }
+
+    AbilityTriggerResult {
+        instructions,
+        prevents_other_abilities: false,
+        blocks_effect: false,
     }
 }
 
@@ -199,8 +193,8 @@ fn trigger_dry_skin(
 ) -> AbilityTriggerResult {
     use crate::core::instructions::Weather;
     
-    match battle_state.weather() {
-        Weather::Rain => {
+    match weather_for(battle_state, pokemon) {
+        Weather::Rain | Weather::HeavyRain => {
             if pokemon.hp < pokemon.max_hp {
                 AbilityTriggerResult {
                     instructions: vec![
@@ -217,7 +211,7 @@ fn trigger_dry_skin(
                 AbilityTriggerResult::default()
             }
         }
-        Weather::Sun => {
+        Weather::Sun | Weather::HarshSun | Weather::HarshSunlight => {
             AbilityTriggerResult {
                 instructions: vec![
                     BattleInstruction::Pokemon(PokemonInstruction::Damage {
@@ -242,7 +236,7 @@ fn trigger_rain_dish(
 ) -> AbilityTriggerResult {
     use crate::core::instructions::Weather;
     
-    if battle_state.weather() == Weather::Rain && pokemon.hp < pokemon.max_hp {
+    if matches!(weather_for(battle_state, pokemon), Weather::Rain | Weather::HeavyRain) && pokemon.hp < pokemon.max_hp {
         AbilityTriggerResult {
             instructions: vec![
                 BattleInstruction::Pokemon(PokemonInstruction::Heal {
@@ -259,7 +253,7 @@ fn trigger_rain_dish(
     }
 }
 
-/// Ice Body - Heals 1/16 HP in hail
+/// Ice Body - Heals 1/16 HP in hail or snow
 fn trigger_ice_body(
     pokemon: &Pokemon,
     position: BattlePosition,
@@ -267,7 +261,7 @@ fn trigger_ice_body(
 ) -> AbilityTriggerResult {
     use crate::core::instructions::Weather;
     
-    if battle_state.weather() == Weather::Hail && pokemon.hp < pokemon.max_hp {
+    if matches!(weather_for(battle_state, pokemon), Weather::Hail | Weather::Snow) && pokemon.hp < pokemon.max_hp {
         AbilityTriggerResult {
             instructions: vec![
                 BattleInstruction::Pokemon(PokemonInstruction::Heal {
@@ -292,7 +286,7 @@ fn trigger_solar_power(
 ) -> AbilityTriggerResult {
     use crate::core::instructions::Weather;
     
-    if battle_state.weather() == Weather::Sun {
+    if matches!(weather_for(battle_state, pokemon), Weather::Sun | Weather::HarshSun | Weather::HarshSunlight) {
         AbilityTriggerResult {
             instructions: vec![
                 BattleInstruction::Pokemon(PokemonInstruction::Damage {
@@ -309,34 +303,6 @@ fn trigger_solar_power(
     }
 }
 
-/// Poison Heal - Heals 1/8 HP when poisoned instead of taking damage
-fn trigger_poison_heal(pokemon: &Pokemon, position: BattlePosition) -> AbilityTriggerResult {
-    match pokemon.status {
-        PokemonStatus::Poison | PokemonStatus::BadlyPoisoned => {
-            if pokemon.hp < pokemon.max_hp {
-                AbilityTriggerResult {
-                    instructions: vec![
-                        BattleInstruction::Pokemon(PokemonInstruction::Heal {
-                            target: position,
-                            amount: (pokemon.max_hp / 8).max(1),
-                            previous_hp: Some(pokemon.hp),
-                        })
-                    ],
-                    prevents_other_abilities: false,
-                    blocks_effect: true, // Prevents poison damage
-                }
-            } else {
-                AbilityTriggerResult {
-                    instructions: Vec::new(),
-                    prevents_other_abilities: false,
-                    blocks_effect: true, // Still blocks poison damage even if at full HP
-                }
-            }
-        }
-        _ => AbilityTriggerResult::default(),
-    }
-}
-
 /// Trigger switch-in abilities (for when Pokemon enter the battle)
 pub fn trigger_switch_in_abilities(
     pokemon: &Pokemon,
@@ -361,14 +327,15 @@ pub fn trigger_switch_in_abilities(
 
 /// Intimidate - Lowers opponent's Attack by 1 stage
 fn trigger_intimidate(
-    _user_position: BattlePosition,
+    user_position: BattlePosition,
     battle_state: &BattleState,
 ) -> Vec<BattleInstruction> {
     let mut instructions = Vec::new();
     
-    // TODO: Implement proper opponent targeting for all battle formats
-    // For now, simplified for singles
     for position in battle_state.get_all_active_positions() {
+        if position.side == user_position.side {
+            continue;
+        }
         if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
             // Check if immune to Intimidate
             if !is_immune_to_intimidate(pokemon) {
@@ -381,6 +348,16 @@ fn trigger_intimidate(
                     },
                     previous_boosts: std::collections::HashMap::new(),
                 }));
+                
+                // The Attack drop sets off Eject Pack
+                let mut lowered = pokemon.clone();
+                lowered.stat_boosts.modify(Stat::Attack, -1);
+                instructions.extend(super::forced_switch::eject_pack_instructions(
+                    battle_state,
+                    position,
+                    pokemon,
+                    &lowered,
+                ));
             }
         }
     }
@@ -390,6 +367,13 @@ fn trigger_intimidate(
 
 /// Check if Pokemon is immune to Intimidate
 fn is_immune_to_intimidate(pokemon: &Pokemon) -> bool {
+    // Intimidate doesn't reach a Pokemon behind a substitute
+    if super::substitute_protection::has_substitute(pokemon) {
+        return true;
+    }
+    if pokemon.ability_suppressed {
+        return false;
+    }
     match pokemon.ability.as_str() {
         "clearbody" | "whitesmoke" | "hypercutter" | "fullmetalbody" => true,
         _ => false,
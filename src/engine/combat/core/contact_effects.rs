@@ -17,7 +17,7 @@ use std::collections::HashMap;
 /// This centralized function handles:
 /// - Rocky Helmet, Static, Flame Body, etc.
 /// - Ability triggers (Mummy, Cursed Body)
-/// - Item effects (Red Card, Eject Button)
+/// - Item effects (Rocky Helmet, Sticky Barb)
 pub fn apply_contact_effects(
     state: &BattleState,
     move_data: &MoveData,
@@ -27,8 +27,10 @@ pub fn apply_contact_effects(
 ) -> Vec<BattleInstruction> {
     let mut instructions = Vec::new();
 
-    // Only apply contact effects if the move makes contact
-    if !move_data.flags.contains_key("contact") {
+    // Only apply contact effects if the move makes contact, and not when a substitute took the hit
+    if !move_data.flags.contains_key("contact")
+        || super::substitute_protection::behind_substitute(state, move_data, user_position, target_position)
+    {
         return instructions;
     }
 
@@ -223,30 +225,6 @@ fn apply_contact_items(
                         .map(|i| i.as_str().to_string()),
                 }));
             }
-            crate::types::Items::REDCARD => {
-                // Force the attacker to switch out (in formats that allow it)
-                if state.format.allows_switching() {
-                    instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ForceSwitch {
-                        target: user_position,
-                        source: Some(target_position),
-                        previous_can_switch: state.get_pokemon_at_position(user_position)
-                            .map(|p| !p.must_switch)
-                            .unwrap_or(true),
-                    }));
-                }
-            }
-            crate::types::Items::EJECTBUTTON => {
-                // Force the target to switch out if it took damage
-                if state.format.allows_switching() {
-                    instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ForceSwitch {
-                        target: target_position,
-                        source: Some(user_position),
-                        previous_can_switch: state.get_pokemon_at_position(target_position)
-                            .map(|p| !p.must_switch)
-                            .unwrap_or(true),
-                    }));
-                }
-            }
             _ => {
                 // No contact item effect
             }
@@ -181,7 +181,7 @@ pub fn calculate_damage_with_effects(
     };
 
     // Check for type immunities first
-    if is_immune_to_move(target, &context.move_data) {
+    if is_immune_to_move(state, user, target, &context.move_data) {
         return DamageResult {
             damage: 0,
             blocked: true,
@@ -229,7 +229,7 @@ pub fn calculate_damage_with_effects(
     );
 
     // Calculate type effectiveness for result
-    let type_effectiveness = calculate_type_effectiveness(target, &move_data);
+    let type_effectiveness = calculate_type_effectiveness(state, target, &move_data);
 
     DamageResult {
         damage,
@@ -256,6 +256,13 @@ pub fn execute_multi_hit_sequence(
 ) -> Vec<BattleInstruction> {
     let mut instructions = Vec::new();
     let mut current_state = state.clone();
+    // Infiltrator and sound moves go straight past the substitute
+    let shielded = super::substitute_protection::behind_substitute(
+        state,
+        &context.move_data,
+        context.user_position,
+        context.target_position,
+    );
 
     for hit_number in 1..=hit_count {
         // Calculate power modifier for this hit if provided
@@ -273,11 +280,20 @@ pub fn execute_multi_hit_sequence(
 
         if damage_result.damage > 0 {
             // Use substitute-aware damage generation with tracking
-            let (damage_instructions, hit_substitute) = crate::engine::combat::moves::generate_substitute_aware_damage_with_tracking(
-                &current_state,
-                context.target_position,
-                damage_result.damage,
-            );
+            let (damage_instructions, hit_substitute) = if shielded {
+                crate::engine::combat::moves::generate_substitute_aware_damage_with_tracking(
+                    &current_state,
+                    context.target_position,
+                    damage_result.damage,
+                )
+            } else {
+                let damage = BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: context.target_position,
+                    amount: damage_result.damage,
+                    previous_hp: None,
+                });
+                (vec![damage], false)
+            };
             
             instructions.extend(damage_instructions.clone());
 
@@ -319,25 +335,23 @@ pub fn execute_multi_hit_sequence(
 }
 
 /// Check if a Pokemon is immune to a move
-fn is_immune_to_move(pokemon: &Pokemon, move_data: &MoveData) -> bool {
+fn is_immune_to_move(state: &BattleState, user: &Pokemon, pokemon: &Pokemon, move_data: &MoveData) -> bool {
     use crate::engine::combat::type_effectiveness::TypeChart;
     use crate::types::PokemonType;
-    use std::str::FromStr;
 
-    // Get type chart for current generation (assume Gen 9 for now)
-    let type_chart = TypeChart::get_cached(9);
+    let type_chart = TypeChart::for_format(&state.format);
     let move_type = move_data.move_type;
 
     // Check type immunity
-    for &pokemon_type in &pokemon.types {
-        let effectiveness = type_chart.get_effectiveness(move_type, pokemon_type);
-        if effectiveness == 0.0 {
-            return true;
-        }
+    if type_chart.move_effectiveness(move_data.name, move_type, pokemon) == 0.0 {
+        return true;
     }
 
     // Check ability immunities (simplified for now)
-    match pokemon.ability.as_str() {
+    let Some(ability) = super::ability_suppression::defending_ability(user, pokemon) else {
+        return false;
+    };
+    match ability.as_str() {
         "flashfire" if move_type == PokemonType::Fire => true,
         "voltabsorb" | "lightningrod" if move_type == PokemonType::Electric => true,
         "waterabsorb" | "stormdrain" if move_type == PokemonType::Water => true,
@@ -362,21 +376,10 @@ fn should_critical_hit(
 }
 
 /// Calculate type effectiveness for a move against a Pokemon
-fn calculate_type_effectiveness(pokemon: &Pokemon, move_data: &MoveData) -> f32 {
+fn calculate_type_effectiveness(state: &BattleState, pokemon: &Pokemon, move_data: &MoveData) -> f32 {
     use crate::engine::combat::type_effectiveness::TypeChart;
-    use crate::types::PokemonType;
-    use std::str::FromStr;
-
-    // Get type chart for current generation (assume Gen 9 for now)
-    let type_chart = TypeChart::get_cached(9);
-    let move_type = move_data.move_type;
-
-    let mut effectiveness = 1.0;
-    for &pokemon_type in &pokemon.types {
-        effectiveness *= type_chart.get_effectiveness(move_type, pokemon_type);
-    }
 
-    effectiveness
+    TypeChart::for_format(&state.format).move_effectiveness(move_data.name, move_data.move_type, pokemon)
 }
 
 /// Simple damage move implementation using the core system
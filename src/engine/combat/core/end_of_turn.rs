@@ -1,55 +1,331 @@
 //! End-of-Turn Processing Pipeline
 //!
-//! This module implements comprehensive end-of-turn processing following poke-engine order:
-//! 1. Remove single-turn volatile statuses (Flinch, etc.)
-//! 2. Weather effects (damage + ability triggers)
-//! 3. Terrain effects
-//! 4. Field effect timers (Trick Room, Light Screen, etc.)
-//! 5. Status condition damage
-//! 6. Ability end-of-turn triggers
-//! 7. Item end-of-turn effects
+//! Residual effects resolve in Showdown's residual order. Field-wide steps run
+//! once; per-Pokemon steps run for each active Pokemon from fastest to slowest
+//! (slowest first under Trick Room) before the next step starts:
+//! 1. Remove single-turn volatile statuses (Flinch, Protect, etc.)
+//! 2. Weather countdown, then weather damage and weather abilities
+//! 3. Future Sight and Wish
+//! 4. Grassy Terrain, Shed Skin and Hydration, Leftovers and Black Sludge
+//! 5. Aqua Ring, Ingrain and Leech Seed
+//! 6. Poison, Burn, Nightmare and Curse
+//! 7. Binding moves, Salt Cure, Octolock and Syrup Bomb
+//! 8. Volatile countdowns, Yawn and Perish Song
+//! 9. Side condition timers, then terrain, Trick Room and Gravity timers
+//! 10. Speed Boost, Moody, Bad Dreams, Harvest and Cud Chew, then Leppa and
+//!     pinch berries, Sticky Barb, Toxic Orb and Flame Orb
+//! 11. Forme changes
+//!
+//! Speeds are read once as the turn ends. Pokemon with tied speeds resolve in
+//! every possible order with equal chance, and orders that end in the same
+//! state are merged. Residuals stop as soon as one side has no Pokemon left,
+//! so whoever faints first loses.
 
 use crate::core::battle_format::{BattlePosition, SideReference};
-use crate::core::battle_state::BattleState;
+use crate::core::battle_state::{BattleState, Pokemon};
 use crate::core::instructions::{
-    BattleInstruction, BattleInstructions, PokemonInstruction, StatusInstruction, 
+    BattleInstruction, BattleInstructions, PokemonInstruction, StatusInstruction,
     PokemonStatus, VolatileStatus, Weather, Terrain, FieldInstruction
 };
-use crate::types::PokemonType;
+use crate::types::{Abilities, Items, PokemonType};
+use crate::engine::mechanics::items::{
+    berry_effect_instructions, eat_berry_instructions, generate_berry_activation_instructions,
+    is_berry, pinch_berry_threshold,
+};
+use super::field_system::{field_weather, weather_for};
+use super::move_protection::{protected_this_turn, reset_stall, PROTECTION_VOLATILES};
+use super::status_system::{apply_status_effect, StatusApplication};
 use std::collections::HashMap;
 
-/// Generate comprehensive end-of-turn instructions following poke-engine order
+/// A residual that resolves once for the whole field
+type FieldResidual = fn(&BattleState) -> Vec<BattleInstructions>;
+
+/// A residual that resolves for one active Pokemon that is still standing
+type PokemonResidual = fn(&BattleState, BattlePosition, &Pokemon) -> Vec<BattleInstructions>;
+
+/// A step of end-of-turn processing, run on the state left by the steps before it
+#[derive(Clone, Copy)]
+enum ResidualStep {
+    Field(FieldResidual),
+    Pokemon(PokemonResidual),
+}
+
+/// Residual steps in the order they resolve
+const RESIDUAL_ORDER: [ResidualStep; 25] = [
+    ResidualStep::Field(remove_expiring_volatile_statuses),
+    // Weather countdown comes first, so weather that ends deals no damage
+    ResidualStep::Field(decrement_weather_timer),
+    ResidualStep::Pokemon(apply_weather_residual),
+    ResidualStep::Pokemon(apply_future_sight),
+    ResidualStep::Pokemon(apply_wish),
+    ResidualStep::Pokemon(apply_grassy_terrain_healing),
+    ResidualStep::Pokemon(apply_status_cure_abilities),
+    ResidualStep::Pokemon(apply_leftovers),
+    ResidualStep::Pokemon(apply_aqua_ring),
+    ResidualStep::Pokemon(apply_ingrain),
+    ResidualStep::Pokemon(apply_leech_seed),
+    ResidualStep::Pokemon(apply_poison_damage),
+    ResidualStep::Pokemon(apply_burn_damage),
+    ResidualStep::Pokemon(apply_nightmare),
+    ResidualStep::Pokemon(apply_curse),
+    ResidualStep::Pokemon(apply_partial_trap),
+    ResidualStep::Pokemon(apply_salt_cure),
+    ResidualStep::Pokemon(apply_stat_drop_residuals),
+    ResidualStep::Pokemon(count_down_volatile_statuses),
+    ResidualStep::Pokemon(apply_yawn),
+    ResidualStep::Pokemon(apply_perish_count),
+    ResidualStep::Field(decrement_side_condition_timers),
+    ResidualStep::Field(decrement_field_timers),
+    // Abilities, then Sticky Barb, then Toxic Orb and Flame Orb
+    ResidualStep::Pokemon(apply_ability_and_item_residuals),
+    ResidualStep::Pokemon(apply_forme_changes),
+];
+
+/// Volatile statuses that simply wear off when their duration runs out
+const COUNTDOWN_VOLATILE_STATUSES: [VolatileStatus; 8] = [
+    VolatileStatus::Taunt,
+    VolatileStatus::Encore,
+    VolatileStatus::Disable,
+    VolatileStatus::MagnetRise,
+    VolatileStatus::Telekinesis,
+    VolatileStatus::HealBlock,
+    VolatileStatus::Embargo,
+    VolatileStatus::Uproar,
+];
+
+/// A branch of end-of-turn processing along with the state it leads to
+type ResidualBranch = (BattleInstructions, BattleState);
+
+/// Generate end-of-turn instructions in Showdown's residual order
+///
+/// Each step sees the state after the steps before it, and every branch a
+/// step produces is combined with every branch so far, so the returned
+/// percentages always sum to 100.
 pub fn generate_end_of_turn_instructions(
     battle_state: &BattleState
 ) -> Vec<BattleInstructions> {
-    let mut all_instructions = Vec::new();
-    
-    // 1. Remove single-turn volatile statuses
-    all_instructions.extend(remove_expiring_volatile_statuses(battle_state));
-    
-    // 2. Weather effects
-    all_instructions.extend(apply_weather_effects(battle_state));
-    
-    // 3. Terrain effects  
-    all_instructions.extend(apply_terrain_effects(battle_state));
-    
-    // 4. Field effect timers
-    all_instructions.extend(decrement_field_timers(battle_state));
-    
-    // 5. Status condition damage
-    all_instructions.extend(apply_status_damage(battle_state));
-    
-    // 6. Ability end-of-turn triggers
-    all_instructions.extend(trigger_end_of_turn_abilities_wrapper(battle_state));
-    
-    // 7. Item end-of-turn effects
-    all_instructions.extend(apply_item_effects(battle_state));
-    
-    // If no effects, return empty instruction set
-    if all_instructions.is_empty() {
-        vec![BattleInstructions::new(100.0, vec![])]
+    let orders = residual_orders(battle_state);
+    let order_share = 1.0 / orders.len() as f32;
+    let mut outcomes: Vec<ResidualBranch> = Vec::new();
+
+    for order in &orders {
+        // Only merge with the orders before this one, so the branches within
+        // an order stay as the steps produced them
+        let earlier_outcomes = outcomes.len();
+        for (mut branch, state) in resolve_residuals(battle_state, order) {
+            branch.percentage *= order_share;
+            let same_outcome = outcomes[..earlier_outcomes]
+                .iter_mut()
+                .find(|(_, outcome)| same_residual_outcome(outcome, &state));
+            match same_outcome {
+                Some((existing, _)) => existing.percentage += branch.percentage,
+                None => outcomes.push((branch, state)),
+            }
+        }
+    }
+
+    outcomes.into_iter().map(|(branch, _)| branch).collect()
+}
+
+/// Run every residual step with per-Pokemon steps in the given order
+fn resolve_residuals(battle_state: &BattleState, order: &[BattlePosition]) -> Vec<ResidualBranch> {
+    let mut branches = vec![(BattleInstructions::new(100.0, vec![]), battle_state.clone())];
+
+    for step in RESIDUAL_ORDER {
+        match step {
+            ResidualStep::Field(residual) => {
+                branches = run_residual(branches, residual);
+            }
+            ResidualStep::Pokemon(residual) => {
+                for &position in order {
+                    branches = run_residual(branches, |state: &BattleState| {
+                        match state.get_pokemon_at_position(position).filter(|pokemon| pokemon.hp > 0) {
+                            Some(pokemon) => residual(state, position, pokemon),
+                            None => Vec::new(),
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    branches
+}
+
+/// Run one residual on every branch whose battle is still going
+fn run_residual(
+    branches: Vec<ResidualBranch>,
+    residual: impl Fn(&BattleState) -> Vec<BattleInstructions>,
+) -> Vec<ResidualBranch> {
+    branches
+        .into_iter()
+        .flat_map(|branch| {
+            if branch.1.is_battle_over() {
+                return vec![branch];
+            }
+            let effects = residual(&branch.1);
+            combine_residual_effects(branch, effects)
+        })
+        .collect()
+}
+
+/// Add a residual's effects to a branch
+///
+/// The effects of a residual are independent of each other: a certain effect
+/// is appended to the branch, and one with a chance splits it into a branch
+/// where it happens and one where it doesn't.
+fn combine_residual_effects(
+    branch: ResidualBranch,
+    effects: Vec<BattleInstructions>,
+) -> Vec<ResidualBranch> {
+    let mut branches = vec![branch];
+
+    for effect in effects {
+        if effect.percentage >= 100.0 {
+            for (branch, state) in &mut branches {
+                state.apply_instructions(&effect.instruction_list);
+                branch.instruction_list.extend(effect.instruction_list.iter().cloned());
+            }
+            continue;
+        }
+
+        branches = branches
+            .into_iter()
+            .flat_map(|(branch, state)| {
+                let mut happens_state = state.clone();
+                happens_state.apply_instructions(&effect.instruction_list);
+                let mut happens = BattleInstructions::new(
+                    branch.percentage * effect.percentage / 100.0,
+                    branch.instruction_list.clone(),
+                );
+                happens.instruction_list.extend(effect.instruction_list.iter().cloned());
+                let misses = BattleInstructions::new(
+                    branch.percentage * (100.0 - effect.percentage) / 100.0,
+                    branch.instruction_list,
+                );
+                [(happens, happens_state), (misses, state)]
+            })
+            .filter(|(branch, _)| branch.percentage > 0.0)
+            .collect();
+    }
+
+    branches
+}
+
+/// Every order the active Pokemon's residuals can resolve in
+///
+/// Faster Pokemon go first, or slower ones under Trick Room, which effective
+/// speed already accounts for. Tied Pokemon are arranged every possible way,
+/// starting with slot order.
+fn residual_orders(battle_state: &BattleState) -> Vec<Vec<BattlePosition>> {
+    let mut speeds: Vec<(BattlePosition, u16)> = battle_state
+        .get_all_active_positions()
+        .into_iter()
+        .filter_map(|position| {
+            let pokemon = battle_state.get_pokemon_at_position(position).filter(|pokemon| pokemon.hp > 0)?;
+            Some((position, pokemon.get_effective_speed(battle_state, position)))
+        })
+        .collect();
+    speeds.sort_by_key(|&(_, speed)| std::cmp::Reverse(speed));
+
+    let mut orders = vec![Vec::new()];
+    for tied in speeds.chunk_by(|a, b| a.1 == b.1) {
+        let positions: Vec<BattlePosition> = tied.iter().map(|&(position, _)| position).collect();
+        let arrangements = permutations(&positions);
+        orders = orders
+            .into_iter()
+            .flat_map(|order: Vec<BattlePosition>| {
+                arrangements.iter().map(move |arrangement| {
+                    let mut order = order.clone();
+                    order.extend(arrangement);
+                    order
+                })
+            })
+            .collect();
+    }
+    orders
+}
+
+/// Every arrangement of the positions, starting with the given order
+fn permutations(positions: &[BattlePosition]) -> Vec<Vec<BattlePosition>> {
+    if positions.len() <= 1 {
+        return vec![positions.to_vec()];
+    }
+    (0..positions.len())
+        .flat_map(|first| {
+            let mut rest = positions.to_vec();
+            let head = rest.remove(first);
+            permutations(&rest).into_iter().map(move |mut order| {
+                order.insert(0, head);
+                order
+            })
+        })
+        .collect()
+}
+
+/// Whether two residual orders left every Pokemon the same way
+fn same_residual_outcome(a: &BattleState, b: &BattleState) -> bool {
+    a.sides.iter().zip(&b.sides).all(|(side_a, side_b)| {
+        side_a.pokemon.iter().zip(&side_b.pokemon).all(|(a, b)| {
+            a.hp == b.hp
+                && a.status == b.status
+                && a.status_duration == b.status_duration
+                && a.stat_boosts == b.stat_boosts
+                && a.item == b.item
+                && a.volatile_statuses.to_hashset() == b.volatile_statuses.to_hashset()
+        })
+    })
+}
+
+/// A residual that always happens, or nothing when there is nothing to do
+fn certain(instructions: Vec<BattleInstruction>) -> Vec<BattleInstructions> {
+    if instructions.is_empty() {
+        Vec::new()
     } else {
-        all_instructions
+        vec![BattleInstructions::new(100.0, instructions)]
+    }
+}
+
+/// Residual damage of a fraction of max HP
+fn residual_damage(position: BattlePosition, pokemon: &Pokemon, divisor: i16) -> BattleInstruction {
+    BattleInstruction::Pokemon(PokemonInstruction::Damage {
+        target: position,
+        amount: (pokemon.max_hp / divisor).max(1),
+        previous_hp: Some(pokemon.hp),
+    })
+}
+
+/// Residual healing of a fraction of max HP, or nothing at full HP
+fn residual_heal(position: BattlePosition, pokemon: &Pokemon, divisor: i16) -> Option<BattleInstruction> {
+    (pokemon.hp < pokemon.max_hp).then(|| {
+        BattleInstruction::Pokemon(PokemonInstruction::Heal {
+            target: position,
+            amount: (pokemon.max_hp / divisor).max(1),
+            previous_hp: Some(pokemon.hp),
+        })
+    })
+}
+
+/// Whether Magic Guard keeps this Pokemon from taking indirect damage
+fn has_magic_guard(pokemon: &Pokemon) -> bool {
+    pokemon.ability == Abilities::MAGICGUARD && !pokemon.ability_suppressed
+}
+
+/// Tick a volatile status down, removing it when its duration runs out
+fn count_down_volatile(position: BattlePosition, status: VolatileStatus, remaining: u8) -> BattleInstruction {
+    if remaining <= 1 {
+        BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+            target: position,
+            status,
+            previous_duration: Some(remaining),
+        })
+    } else {
+        BattleInstruction::Status(StatusInstruction::ChangeVolatileDuration {
+            target: position,
+            status,
+            new_duration: Some(remaining - 1),
+            previous_duration: Some(remaining),
+        })
     }
 }
 
@@ -61,16 +337,19 @@ fn remove_expiring_volatile_statuses(
     // Single-turn statuses that automatically expire
     let single_turn_statuses = [
         VolatileStatus::Flinch,
-        VolatileStatus::Protect,
         VolatileStatus::Endure,
         VolatileStatus::MagicCoat,
         VolatileStatus::FollowMe,
         VolatileStatus::HelpingHand,
     ];
-    
+
     for position in battle_state.get_all_active_positions() {
         if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            for &status in &single_turn_statuses {
+            // A turn without protection ends the run of consecutive stalls
+            if !protected_this_turn(battle_state, position, pokemon) {
+                instructions.extend(certain(reset_stall(pokemon, position)));
+            }
+            for &status in single_turn_statuses.iter().chain(&PROTECTION_VOLATILES) {
                 if pokemon.volatile_statuses.contains(status) {
                     instructions.push(BattleInstructions::new(
                         100.0,
@@ -86,99 +365,73 @@ fn remove_expiring_volatile_statuses(
             }
         }
     }
-    
+
     instructions
 }
 
-/// Apply weather effects in proper order
-fn apply_weather_effects(
+/// Decrement the weather timer, ending the weather when it runs out
+fn decrement_weather_timer(
     battle_state: &BattleState
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    match battle_state.weather() {
-        Weather::Sandstorm => {
-            instructions.extend(apply_sandstorm_damage(battle_state));
-        }
-        Weather::Hail => {
-            instructions.extend(apply_hail_damage(battle_state));
+    let Some(weather_turns) = battle_state.field.weather.turns_remaining.filter(|&turns| turns > 0) else {
+        return Vec::new();
+    };
+
+    let instruction = if weather_turns == 1 {
+        // Weather is about to end
+        FieldInstruction::Weather {
+            new_weather: Weather::None,
+            turns: None,
+            source: None,
+            previous_weather: battle_state.field.weather.condition,
+            previous_turns: Some(weather_turns),
         }
-        Weather::Sun | Weather::Rain => {
-            // These don't do direct damage but may trigger abilities
-            instructions.extend(trigger_weather_abilities(battle_state));
+    } else {
+        // Just decrement timer
+        FieldInstruction::Weather {
+            new_weather: battle_state.field.weather.condition,
+            turns: Some(weather_turns - 1),
+            source: battle_state.field.weather.source,
+            previous_weather: battle_state.field.weather.condition,
+            previous_turns: Some(weather_turns),
         }
-        _ => {}
-    }
-    
-    instructions
+    };
+    certain(vec![BattleInstruction::Field(instruction)])
 }
 
-/// Apply sandstorm damage to non-immune Pokemon
-fn apply_sandstorm_damage(
-    battle_state: &BattleState
+/// Weather damage, or the Pokemon's weather ability instead
+fn apply_weather_residual(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    for position in battle_state.get_all_active_positions() {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            // Skip if immune to sandstorm
-            if is_sandstorm_immune(pokemon) {
-                continue;
-            }
-            
-            let damage = (pokemon.max_hp / 16).max(1);
-            instructions.push(BattleInstructions::new(
-                100.0,
-                vec![BattleInstruction::Pokemon(
-                    PokemonInstruction::Damage {
-                        target: position,
-                        amount: damage,
-                        previous_hp: Some(pokemon.hp),
-                    }
-                )]
-            ));
-        }
+    let ability_instructions = super::ability_triggers::trigger_weather_ability(pokemon, position, battle_state);
+    if !ability_instructions.is_empty() {
+        return certain(ability_instructions);
     }
-    
-    instructions
-}
 
-/// Apply hail damage to non-immune Pokemon
-fn apply_hail_damage(
-    battle_state: &BattleState
-) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    for position in battle_state.get_all_active_positions() {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            // Skip if immune to hail
-            if is_hail_immune(pokemon) {
-                continue;
-            }
-            
-            let damage = (pokemon.max_hp / 16).max(1);
-            instructions.push(BattleInstructions::new(
-                100.0,
-                vec![BattleInstruction::Pokemon(
-                    PokemonInstruction::Damage {
-                        target: position,
-                        amount: damage,
-                        previous_hp: Some(pokemon.hp),
-                    }
-                )]
-            ));
-        }
+    // Snow deals no damage; only the Hail of Gens 2-8 does
+    let damaged = match field_weather(battle_state) {
+        Weather::Sandstorm | Weather::Sand => !is_sandstorm_immune(pokemon),
+        Weather::Hail => !is_hail_immune(pokemon),
+        _ => false,
+    };
+    if damaged {
+        certain(vec![residual_damage(position, pokemon, 16)])
+    } else {
+        Vec::new()
     }
-    
-    instructions
 }
 
 /// Check if Pokemon is immune to sandstorm damage
-fn is_sandstorm_immune(pokemon: &crate::core::battle_state::Pokemon) -> bool {
+fn is_sandstorm_immune(pokemon: &Pokemon) -> bool {
     // Immune types
     if pokemon.types.contains(&PokemonType::Ground) ||
        pokemon.types.contains(&PokemonType::Rock) ||
        pokemon.types.contains(&PokemonType::Steel) {
         return true;
     }
-    
+
     // Immune abilities
     match pokemon.ability.as_str() {
         "sandveil" | "sandrush" | "sandforce" | "overcoat" | "magicguard" => true,
@@ -187,12 +440,12 @@ fn is_sandstorm_immune(pokemon: &crate::core::battle_state::Pokemon) -> bool {
 }
 
 /// Check if Pokemon is immune to hail damage
-fn is_hail_immune(pokemon: &crate::core::battle_state::Pokemon) -> bool {
+fn is_hail_immune(pokemon: &Pokemon) -> bool {
     // Immune types
     if pokemon.types.contains(&PokemonType::Ice) {
         return true;
     }
-    
+
     // Immune abilities
     match pokemon.ability.as_str() {
         "icebody" | "snowcloak" | "overcoat" | "magicguard" => true,
@@ -200,217 +453,489 @@ fn is_hail_immune(pokemon: &crate::core::battle_state::Pokemon) -> bool {
     }
 }
 
-/// Trigger weather-related abilities
-fn trigger_weather_abilities(
-    battle_state: &BattleState
+/// Future Sight and Doom Desire count down and strike when they arrive
+fn apply_future_sight(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    _pokemon: &Pokemon,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    let current_weather = battle_state.weather();
-    
-    for position in battle_state.get_all_active_positions() {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            if pokemon.hp == 0 {
-                continue; // Skip fainted Pokemon
-            }
-            
-            match pokemon.ability.as_str() {
-                "dryskin" => {
-                    match current_weather {
-                        Weather::Rain => {
-                            if pokemon.hp < pokemon.max_hp {
-                                let heal_amount = (pokemon.max_hp / 8).max(1);
-                                instructions.push(BattleInstructions::new(
-                                    100.0,
-                                    vec![BattleInstruction::Pokemon(PokemonInstruction::Heal {
-                                        target: position,
-                                        amount: heal_amount,
-                                        previous_hp: Some(pokemon.hp),
-                                    })]
-                                ));
-                            }
-                        }
-                        Weather::Sun => {
-                            let damage_amount = (pokemon.max_hp / 8).max(1);
-                            instructions.push(BattleInstructions::new(
-                                100.0,
-                                vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                                    target: position,
-                                    amount: damage_amount,
-                                    previous_hp: Some(pokemon.hp),
-                                })]
-                            ));
-                        }
-                        _ => {}
-                    }
-                }
-                "raindish" => {
-                    if current_weather == Weather::Rain && pokemon.hp < pokemon.max_hp {
-                        let heal_amount = (pokemon.max_hp / 16).max(1);
-                        instructions.push(BattleInstructions::new(
-                            100.0,
-                            vec![BattleInstruction::Pokemon(PokemonInstruction::Heal {
-                                target: position,
-                                amount: heal_amount,
-                                previous_hp: Some(pokemon.hp),
-                            })]
-                        ));
-                    }
-                }
-                "icebody" => {
-                    if current_weather == Weather::Hail && pokemon.hp < pokemon.max_hp {
-                        let heal_amount = (pokemon.max_hp / 16).max(1);
-                        instructions.push(BattleInstructions::new(
-                            100.0,
-                            vec![BattleInstruction::Pokemon(PokemonInstruction::Heal {
-                                target: position,
-                                amount: heal_amount,
-                                previous_hp: Some(pokemon.hp),
-                            })]
-                        ));
-                    }
-                }
-                "solarpower" => {
-                    if current_weather == Weather::Sun {
-                        let damage_amount = (pokemon.max_hp / 8).max(1);
-                        instructions.push(BattleInstructions::new(
-                            100.0,
-                            vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                                target: position,
-                                amount: damage_amount,
-                                previous_hp: Some(pokemon.hp),
-                            })]
-                        ));
-                    }
-                }
-                "poisonheal" => {
-                    if matches!(pokemon.status, PokemonStatus::Poison | PokemonStatus::BadlyPoisoned) 
-                        && pokemon.hp < pokemon.max_hp {
-                        let heal_amount = (pokemon.max_hp / 8).max(1);
-                        instructions.push(BattleInstructions::new(
-                            100.0,
-                            vec![BattleInstruction::Pokemon(PokemonInstruction::Heal {
-                                target: position,
-                                amount: heal_amount,
-                                previous_hp: Some(pokemon.hp),
-                            })]
-                        ));
-                    }
-                }
-                _ => {}
-            }
-        }
+    let side = &battle_state.sides[position.side.to_index()];
+    match side.future_sight.get(&position.slot) {
+        Some(&(_, _, turns, _)) => certain(vec![BattleInstruction::Pokemon(
+            PokemonInstruction::DecrementFutureSight {
+                target: position,
+                previous_turns: turns,
+            },
+        )]),
+        None => Vec::new(),
     }
-    
-    instructions
 }
 
-/// Apply terrain effects
-fn apply_terrain_effects(
-    battle_state: &BattleState
+/// Wish counts down and heals whoever is in the slot when it comes true
+fn apply_wish(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    _pokemon: &Pokemon,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    match battle_state.terrain() {
-        Terrain::Grassy => {
-            instructions.extend(apply_grassy_terrain_healing(battle_state));
-        }
-        _ => {}
+    let side = &battle_state.sides[position.side.to_index()];
+    match side.wish_healing.get(&position.slot) {
+        Some(&(_, turns)) => certain(vec![BattleInstruction::Pokemon(
+            PokemonInstruction::DecrementWish {
+                target: position,
+                previous_turns: turns,
+            },
+        )]),
+        None => Vec::new(),
     }
-    
-    instructions
 }
 
-/// Apply Grassy Terrain healing
+/// Grassy Terrain heals grounded Pokemon 1/16 max HP
 fn apply_grassy_terrain_healing(
-    battle_state: &BattleState
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    for position in battle_state.get_all_active_positions() {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            // Only heal grounded Pokemon
-            if is_grounded(pokemon) {
-                let healing = (pokemon.max_hp / 16).max(1);
-                if pokemon.hp < pokemon.max_hp {
-                    instructions.push(BattleInstructions::new(
-                        100.0,
-                        vec![BattleInstruction::Pokemon(
-                            PokemonInstruction::Heal {
-                                target: position,
-                                amount: healing,
-                                previous_hp: Some(pokemon.hp),
-                            }
-                        )]
-                    ));
-                }
-            }
+    if battle_state.terrain() != Terrain::Grassy || !is_grounded(pokemon) {
+        return Vec::new();
+    }
+    certain(residual_heal(position, pokemon, 16).into_iter().collect())
+}
+
+/// Hydration cures status in rain, Shed Skin has a 30% chance to every turn
+fn apply_status_cure_abilities(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if pokemon.status == PokemonStatus::None || pokemon.ability_suppressed {
+        return Vec::new();
+    }
+    let cure_chance = match pokemon.ability {
+        Abilities::HYDRATION if matches!(weather_for(battle_state, pokemon), Weather::Rain | Weather::HeavyRain) => 100.0,
+        Abilities::SHEDSKIN => 30.0,
+        _ => return Vec::new(),
+    };
+    vec![BattleInstructions::new(
+        cure_chance,
+        vec![BattleInstruction::Status(StatusInstruction::Remove {
+            target: position,
+            status: pokemon.status,
+            previous_duration: pokemon.status_duration,
+        })],
+    )]
+}
+
+/// Leftovers heal 1/16 max HP, as does Black Sludge for Poison types while it hurts others 1/8
+fn apply_leftovers(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    match pokemon.item {
+        Some(Items::LEFTOVERS) => certain(residual_heal(position, pokemon, 16).into_iter().collect()),
+        Some(Items::BLACKSLUDGE) if pokemon.types.contains(&PokemonType::Poison) => {
+            certain(residual_heal(position, pokemon, 16).into_iter().collect())
         }
+        Some(Items::BLACKSLUDGE) if !has_magic_guard(pokemon) => {
+            certain(vec![residual_damage(position, pokemon, 8)])
+        }
+        _ => Vec::new(),
     }
-    
-    instructions
 }
 
 /// Check if Pokemon is grounded (affected by terrain effects)
-fn is_grounded(pokemon: &crate::core::battle_state::Pokemon) -> bool {
+fn is_grounded(pokemon: &Pokemon) -> bool {
     // Not grounded if Flying type or has Air Balloon or Levitate
     if pokemon.types.contains(&PokemonType::Flying) {
         return false;
     }
-    
+
     if let Some(item) = pokemon.item {
-        if item == crate::types::Items::AIRBALLOON {
+        if item == Items::AIRBALLOON {
             return false;
         }
     }
-    
-    if pokemon.ability == crate::types::Abilities::LEVITATE {
+
+    if pokemon.ability == Abilities::LEVITATE {
         return false;
     }
-    
+
     // Check for Magnet Rise, Telekinesis, etc.
     if pokemon.volatile_statuses.contains(VolatileStatus::MagnetRise) ||
        pokemon.volatile_statuses.contains(VolatileStatus::Telekinesis) {
         return false;
     }
-    
+
     true
 }
 
-/// Decrement field effect timers
-fn decrement_field_timers(
+/// Aqua Ring restores 1/16 max HP
+fn apply_aqua_ring(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::AquaRing) {
+        return Vec::new();
+    }
+    certain(residual_heal(position, pokemon, 16).into_iter().collect())
+}
+
+/// Ingrain restores 1/16 max HP
+fn apply_ingrain(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::Ingrain) {
+        return Vec::new();
+    }
+    certain(residual_heal(position, pokemon, 16).into_iter().collect())
+}
+
+/// Leech Seed drains 1/8 max HP to the opposing Pokemon in the same slot
+fn apply_leech_seed(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::LeechSeed) || has_magic_guard(pokemon) {
+        return Vec::new();
+    }
+
+    let drained = (pokemon.max_hp / 8).max(1).min(pokemon.hp);
+    let mut instructions = vec![residual_damage(position, pokemon, 8)];
+    let seeder_position = BattlePosition::new(position.side.opposite(), position.slot);
+    if let Some(seeder) = battle_state
+        .get_pokemon_at_position(seeder_position)
+        .filter(|seeder| seeder.hp > 0 && seeder.hp < seeder.max_hp)
+    {
+        instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Heal {
+            target: seeder_position,
+            amount: drained,
+            previous_hp: Some(seeder.hp),
+        }));
+    }
+    certain(instructions)
+}
+
+/// Poison and bad poison damage, which Poison Heal turns into healing
+fn apply_poison_damage(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !matches!(pokemon.status, PokemonStatus::Poison | PokemonStatus::BadlyPoisoned) {
+        return Vec::new();
+    }
+    if pokemon.ability == Abilities::POISONHEAL && !pokemon.ability_suppressed {
+        return certain(residual_heal(position, pokemon, 8).into_iter().collect());
+    }
+    if has_magic_guard(pokemon) {
+        return Vec::new();
+    }
+
+    let damage = if pokemon.status == PokemonStatus::BadlyPoisoned {
+        // Toxic damage increases each turn
+        // TODO: Track toxic counter properly
+        let toxic_counter = pokemon.status_duration.unwrap_or(1);
+        (pokemon.max_hp * toxic_counter as i16 / 16).max(1)
+    } else {
+        (pokemon.max_hp / 8).max(1)
+    };
+    certain(vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
+        target: position,
+        amount: damage,
+        previous_hp: Some(pokemon.hp),
+    })])
+}
+
+/// Burn damage: 1/16 max HP from Gen 7 on, 1/8 before, halved by Heatproof
+fn apply_burn_damage(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if pokemon.status != PokemonStatus::Burn || has_magic_guard(pokemon) {
+        return Vec::new();
+    }
+    let mut divisor = if battle_state.format.generation.number() >= 7 { 16 } else { 8 };
+    if pokemon.ability == Abilities::HEATPROOF && !pokemon.ability_suppressed {
+        divisor *= 2;
+    }
+    certain(vec![residual_damage(position, pokemon, divisor)])
+}
+
+/// Nightmare deals 1/4 max HP to a sleeping Pokemon and ends once it wakes
+fn apply_nightmare(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::Nightmare) {
+        return Vec::new();
+    }
+    if pokemon.status != PokemonStatus::Sleep {
+        return certain(vec![BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+            target: position,
+            status: VolatileStatus::Nightmare,
+            previous_duration: None,
+        })]);
+    }
+    if has_magic_guard(pokemon) {
+        return Vec::new();
+    }
+    certain(vec![residual_damage(position, pokemon, 4)])
+}
+
+/// Curse deals 1/4 max HP
+fn apply_curse(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::Curse) || has_magic_guard(pokemon) {
+        return Vec::new();
+    }
+    certain(vec![residual_damage(position, pokemon, 4)])
+}
+
+/// Binding moves deal 1/8 max HP per turn (1/16 before Gen 6) until the trap runs out
+fn apply_partial_trap(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::PartiallyTrapped) {
+        return Vec::new();
+    }
+    let remaining = pokemon
+        .volatile_status_durations
+        .get(&VolatileStatus::PartiallyTrapped)
+        .copied()
+        .unwrap_or(1);
+
+    let mut instructions = Vec::new();
+    if !has_magic_guard(pokemon) {
+        let divisor = if battle_state.format.generation.number() >= 6 { 8 } else { 16 };
+        instructions.push(residual_damage(position, pokemon, divisor));
+    }
+    instructions.push(count_down_volatile(position, VolatileStatus::PartiallyTrapped, remaining));
+    certain(instructions)
+}
+
+/// Salt Cure deals 1/8 max HP per turn, 1/4 against Water and Steel types
+fn apply_salt_cure(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::SaltCure) || has_magic_guard(pokemon) {
+        return Vec::new();
+    }
+    let divisor = if pokemon.types.contains(&PokemonType::Water)
+        || pokemon.types.contains(&PokemonType::Steel)
+    {
+        4
+    } else {
+        8
+    };
+    certain(vec![residual_damage(position, pokemon, divisor)])
+}
+
+/// Octolock and Syrup Bomb lower stats each turn
+fn apply_stat_drop_residuals(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    let mut instructions = Vec::new();
+
+    // Octolock: -1 Defense and Special Defense each turn
+    if pokemon.volatile_statuses.contains(VolatileStatus::Octolock) {
+        let mut defense_drop = HashMap::new();
+        defense_drop.insert(crate::core::instructions::Stat::Defense, -1);
+        defense_drop.insert(crate::core::instructions::Stat::SpecialDefense, -1);
+        instructions.push(BattleInstructions::new(
+            100.0,
+            vec![BattleInstruction::Stats(
+                crate::core::instructions::StatsInstruction::BoostStats {
+                    target: position,
+                    stat_changes: defense_drop,
+                    previous_boosts: HashMap::new(),
+                }
+            )]
+        ));
+    }
+
+    // Syrup Bomb: -1 Speed at the end of each of the next three turns
+    if pokemon.volatile_statuses.contains(VolatileStatus::SyrupBomb) {
+        let remaining = pokemon
+            .volatile_status_durations
+            .get(&VolatileStatus::SyrupBomb)
+            .copied()
+            .unwrap_or(1);
+        let mut speed_drop = HashMap::new();
+        speed_drop.insert(crate::core::instructions::Stat::Speed, -1);
+        instructions.push(BattleInstructions::new(
+            100.0,
+            vec![
+                BattleInstruction::Stats(crate::core::instructions::StatsInstruction::BoostStats {
+                    target: position,
+                    stat_changes: speed_drop,
+                    previous_boosts: HashMap::new(),
+                }),
+                count_down_volatile(position, VolatileStatus::SyrupBomb, remaining),
+            ],
+        ));
+    }
+
+    instructions
+}
+
+/// Taunt, Encore, Disable, Magnet Rise and the like wear off when their turns run out
+fn count_down_volatile_statuses(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    let instructions = COUNTDOWN_VOLATILE_STATUSES
+        .iter()
+        .filter(|&&status| pokemon.volatile_statuses.contains(status))
+        .filter_map(|&status| {
+            let remaining = pokemon.volatile_status_durations.get(&status).copied()?;
+            Some(count_down_volatile(position, status, remaining))
+        })
+        .collect();
+    certain(instructions)
+}
+
+/// Yawn puts its target to sleep at the end of the next turn
+fn apply_yawn(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::Yawn) {
+        return Vec::new();
+    }
+    let remaining = pokemon
+        .volatile_status_durations
+        .get(&VolatileStatus::Yawn)
+        .copied()
+        .unwrap_or(1);
+
+    let mut instructions = vec![count_down_volatile(position, VolatileStatus::Yawn, remaining)];
+    if remaining <= 1 {
+        let sleep = apply_status_effect(
+            battle_state,
+            StatusApplication {
+                status: PokemonStatus::Sleep,
+                target: position,
+                chance: 100.0,
+                duration: None,
+            },
+        );
+        instructions.extend(sleep.instruction);
+    }
+    certain(instructions)
+}
+
+/// Perish Song counts down from 3 and faints the Pokemon once the count runs out
+fn apply_perish_count(
+    _battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    let Some(status) = [VolatileStatus::Perish3, VolatileStatus::Perish2, VolatileStatus::Perish1]
+        .into_iter()
+        .find(|&status| pokemon.volatile_statuses.contains(status))
+    else {
+        return Vec::new();
+    };
+    let remaining = pokemon.volatile_status_durations.get(&status).copied().unwrap_or(1);
+
+    let count = remaining.saturating_sub(1);
+    let next_status = match count {
+        0 => None,
+        1 => Some(VolatileStatus::Perish1),
+        2 => Some(VolatileStatus::Perish2),
+        _ => Some(VolatileStatus::Perish3),
+    };
+    let instructions = match next_status {
+        Some(next_status) if next_status == status => vec![count_down_volatile(position, status, remaining)],
+        Some(next_status) => vec![
+            BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+                target: position,
+                status,
+                previous_duration: Some(remaining),
+            }),
+            BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+                target: position,
+                status: next_status,
+                duration: Some(count),
+                previous_had_status: false,
+                previous_duration: None,
+            }),
+        ],
+        None => vec![BattleInstruction::Pokemon(PokemonInstruction::Faint {
+            target: position,
+            previous_hp: pokemon.hp,
+            previous_status: Some(pokemon.status),
+        })],
+    };
+    certain(instructions)
+}
+
+/// Decrement side condition timers (Reflect, Light Screen, Tailwind, etc.)
+fn decrement_side_condition_timers(
     battle_state: &BattleState
 ) -> Vec<BattleInstructions> {
     let mut instructions = Vec::new();
-    
-    // Decrement weather timer
-    if let Some(weather_turns) = battle_state.field.weather.turns_remaining {
-        if weather_turns > 0 {
-            if weather_turns == 1 {
-                // Weather is about to end
-                instructions.push(BattleInstructions::new(
-                    100.0,
-                    vec![BattleInstruction::Field(FieldInstruction::Weather {
-                        new_weather: Weather::None,
-                        turns: None,
-                        source: None,
-                        previous_weather: battle_state.field.weather.condition,
-                        previous_turns: Some(weather_turns),
-                    })]
-                ));
-            } else {
-                // Just decrement timer
-                instructions.push(BattleInstructions::new(
-                    100.0,
-                    vec![BattleInstruction::Field(FieldInstruction::Weather {
-                        new_weather: battle_state.field.weather.condition,
-                        turns: Some(weather_turns - 1),
-                        source: battle_state.field.weather.source,
-                        previous_weather: battle_state.field.weather.condition,
-                        previous_turns: Some(weather_turns),
-                    })]
-                ));
+
+    for (side_index, side) in battle_state.sides.iter().enumerate() {
+        let side_ref = if side_index == 0 {
+            SideReference::SideOne
+        } else {
+            SideReference::SideTwo
+        };
+
+        for (condition, duration) in &side.side_conditions {
+            if *duration > 0 {
+                if *duration == 1 {
+                    // Side condition is about to end
+                    instructions.push(BattleInstructions::new(
+                        100.0,
+                        vec![BattleInstruction::Field(FieldInstruction::RemoveSideCondition {
+                            side: side_ref,
+                            condition: *condition,
+                            previous_duration: *duration,
+                        })]
+                    ));
+                } else {
+                    // Just decrement timer
+                    instructions.push(BattleInstructions::new(
+                        100.0,
+                        vec![BattleInstruction::Field(FieldInstruction::DecrementSideConditionDuration {
+                            side: side_ref,
+                            condition: *condition,
+                            previous_duration: *duration,
+                        })]
+                    ));
+                }
             }
         }
     }
-    
+
+    instructions
+}
+
+/// Decrement terrain, Trick Room and Gravity timers
+fn decrement_field_timers(
+    battle_state: &BattleState
+) -> Vec<BattleInstructions> {
+    let mut instructions = Vec::new();
+
     // Decrement terrain timer
     if let Some(terrain_turns) = battle_state.field.terrain.turns_remaining {
         if terrain_turns > 0 {
@@ -441,7 +966,7 @@ fn decrement_field_timers(
             }
         }
     }
-    
+
     // Decrement global effect timers
     if let Some(trick_room_state) = &battle_state.field.global_effects.trick_room {
         if trick_room_state.turns_remaining > 0 {
@@ -472,7 +997,7 @@ fn decrement_field_timers(
             }
         }
     }
-    
+
     if let Some(gravity_state) = &battle_state.field.global_effects.gravity {
         if gravity_state.turns_remaining > 0 {
             if gravity_state.turns_remaining == 1 {
@@ -502,188 +1027,113 @@ fn decrement_field_timers(
             }
         }
     }
-    
-    // Decrement side condition timers
-    for (side_index, side) in battle_state.sides.iter().enumerate() {
-        let side_ref = if side_index == 0 {
-            crate::core::battle_format::SideReference::SideOne
-        } else {
-            crate::core::battle_format::SideReference::SideTwo
-        };
-        
-        for (condition, duration) in &side.side_conditions {
-            if *duration > 0 {
-                if *duration == 1 {
-                    // Side condition is about to end
-                    instructions.push(BattleInstructions::new(
-                        100.0,
-                        vec![BattleInstruction::Field(FieldInstruction::RemoveSideCondition {
-                            side: side_ref,
-                            condition: *condition,
-                            previous_duration: *duration,
-                        })]
-                    ));
-                } else {
-                    // Just decrement timer
-                    instructions.push(BattleInstructions::new(
-                        100.0,
-                        vec![BattleInstruction::Field(FieldInstruction::DecrementSideConditionDuration {
-                            side: side_ref,
-                            condition: *condition,
-                            previous_duration: *duration,
-                        })]
-                    ));
-                }
-            }
-        }
-    }
-    
+
     instructions
 }
 
-/// Apply status condition damage (burn, poison, toxic) with ability interactions
-fn apply_status_damage(
-    battle_state: &BattleState
+/// Speed Boost, Moody, Bad Dreams, Harvest and Cud Chew, then berries, Sticky
+/// Barb, then Toxic Orb and Flame Orb
+fn apply_ability_and_item_residuals(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    for position in battle_state.get_all_active_positions() {
-            if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-                // Check for abilities that modify status damage
-                let blocks_status_damage = match pokemon.ability.as_str() {
-                    "magicguard" => true, // Magic Guard prevents all indirect damage
-                    "poisonheal" => matches!(pokemon.status, PokemonStatus::Poison | PokemonStatus::BadlyPoisoned),
-                    _ => false,
-                };
-                
-                if blocks_status_damage {
-                    continue;
-                }
-                
-                let damage = match pokemon.status {
-                    PokemonStatus::Burn => {
-                        Some((pokemon.max_hp / 16).max(1))
-                    }
-                    PokemonStatus::Poison => {
-                        Some((pokemon.max_hp / 8).max(1))
-                    }
-                    PokemonStatus::BadlyPoisoned => {
-                        // Toxic damage increases each turn
-                        // TODO: Track toxic counter properly
-                        let toxic_counter = pokemon.status_duration.unwrap_or(1);
-                        Some((pokemon.max_hp * toxic_counter as i16 / 16).max(1))
-                    }
-                    _ => None,
-                };
-                
-                if let Some(damage_amount) = damage {
-                    instructions.push(BattleInstructions::new(
-                        100.0,
-                        vec![BattleInstruction::Pokemon(
-                            PokemonInstruction::Damage {
-                                target: position,
-                                amount: damage_amount,
-                                previous_hp: Some(pokemon.hp),
-                            }
-                        )]
-                    ));
-                }
-            }
+    // Speed Boost and Moody raises set off an opposing Mirror Herb
+    let mut ability_instructions = super::ability_triggers::trigger_end_of_turn_ability(pokemon, position, battle_state);
+    let copies = crate::engine::mechanics::items::mirror_herb_instructions(battle_state, &ability_instructions);
+    ability_instructions.extend(copies);
+    let mut instructions = certain(ability_instructions);
+    instructions.extend(apply_berry_abilities(battle_state, position, pokemon));
+
+    let generation = battle_state.get_generation_mechanics();
+    match pokemon.item {
+        // Leppa Berry is eaten once a move runs out of PP
+        Some(Items::LEPPABERRY) if pokemon.moves.iter().any(|(_, move_data)| move_data.pp == 0) => {
+            instructions.extend(certain(eat_berry_instructions(Items::LEPPABERRY, pokemon, position, pokemon.hp, &generation)));
         }
-    
+        // Residual damage can leave the Pokemon low enough to eat a pinch berry
+        Some(item) if pinch_berry_threshold(pokemon, item).is_some() => {
+            instructions.extend(certain(generate_berry_activation_instructions(pokemon, position, pokemon.hp, &generation)));
+        }
+        Some(Items::STICKYBARB) if !has_magic_guard(pokemon) => {
+            instructions.extend(certain(vec![residual_damage(position, pokemon, 8)]));
+        }
+        Some(item @ (Items::TOXICORB | Items::FLAMEORB)) => {
+            let status = if item == Items::TOXICORB {
+                PokemonStatus::BadlyPoisoned
+            } else {
+                PokemonStatus::Burn
+            };
+            let activation = apply_status_effect(
+                battle_state,
+                StatusApplication {
+                    status,
+                    target: position,
+                    chance: 100.0,
+                    duration: None,
+                },
+            );
+            instructions.extend(certain(activation.instruction.into_iter().collect()));
+        }
+        _ => {}
+    }
+
     instructions
 }
 
-/// Trigger end-of-turn abilities using the centralized ability system
-fn trigger_end_of_turn_abilities_wrapper(
-    battle_state: &BattleState
+/// Harvest restores a consumed berry half the time, or always in sun, and Cud
+/// Chew eats its last berry again at the end of the turn after eating it
+fn apply_berry_abilities(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
 ) -> Vec<BattleInstructions> {
-    // Use the centralized ability trigger system
-    let ability_instructions = super::ability_triggers::trigger_end_of_turn_abilities(battle_state);
-    
-    // Convert individual instructions to BattleInstructions
-    let mut result = Vec::new();
-    for instruction in ability_instructions {
-        result.push(BattleInstructions::new(100.0, vec![instruction]));
+    if pokemon.ability_suppressed {
+        return Vec::new();
     }
-    
-    result
-}
+    let generation = battle_state.get_generation_mechanics();
 
-/// Apply item end-of-turn effects
-fn apply_item_effects(
-    battle_state: &BattleState
-) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    for position in battle_state.get_all_active_positions() {
-        if let Some(pokemon) = battle_state.get_pokemon_at_position(position) {
-            if let Some(item) = pokemon.item {
-                match item {
-                    crate::types::Items::LEFTOVERS => {
-                        if pokemon.hp < pokemon.max_hp {
-                            let healing = (pokemon.max_hp / 16).max(1);
-                            instructions.push(BattleInstructions::new(
-                                100.0,
-                                vec![BattleInstruction::Pokemon(
-                                    PokemonInstruction::Heal {
-                                        target: position,
-                                        amount: healing,
-                                        previous_hp: Some(pokemon.hp),
-                                    }
-                                )]
-                            ));
-                        }
-                    }
-                    crate::types::Items::BLACKSLUDGE => {
-                        if pokemon.types.contains(&PokemonType::Poison) {
-                            // Heal if Poison type
-                            if pokemon.hp < pokemon.max_hp {
-                                let healing = (pokemon.max_hp / 16).max(1);
-                                instructions.push(BattleInstructions::new(
-                                    100.0,
-                                    vec![BattleInstruction::Pokemon(
-                                        PokemonInstruction::Heal {
-                                            target: position,
-                                            amount: healing,
-                                            previous_hp: Some(pokemon.hp),
-                                        }
-                                    )]
-                                ));
-                            }
-                        } else {
-                            // Damage if not Poison type
-                            let damage = (pokemon.max_hp / 8).max(1);
-                            instructions.push(BattleInstructions::new(
-                                100.0,
-                                vec![BattleInstruction::Pokemon(
-                                    PokemonInstruction::Damage {
-                                        target: position,
-                                        amount: damage,
-                                        previous_hp: Some(pokemon.hp),
-                                    }
-                                )]
-                            ));
-                        }
-                    }
-                    crate::types::Items::STICKYBARB => {
-                        let damage = (pokemon.max_hp / 8).max(1);
-                        instructions.push(BattleInstructions::new(
-                            100.0,
-                            vec![BattleInstruction::Pokemon(
-                                PokemonInstruction::Damage {
-                                    target: position,
-                                    amount: damage,
-                                    previous_hp: Some(pokemon.hp),
-                                }
-                            )]
-                        ));
-                    }
-                    _ => {}
-                }
+    match pokemon.ability {
+        Abilities::HARVEST => {
+            let berry = match pokemon.consumed_item {
+                Some(item) if pokemon.item.is_none() && is_berry(item) => item,
+                _ => return Vec::new(),
+            };
+            let mut instructions = vec![BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
+                target: position,
+                new_item: Some(berry),
+                previous_item: None,
+            })];
+            // A restored pinch berry is eaten straight away if the Pokemon is already low
+            let mut harvested = pokemon.clone();
+            harvested.item = Some(berry);
+            harvested.consumed_item = None;
+            instructions.extend(generate_berry_activation_instructions(&harvested, position, pokemon.hp, &generation));
+
+            let sunny = matches!(weather_for(battle_state, pokemon), Weather::Sun | Weather::HarshSun | Weather::HarshSunlight);
+            vec![BattleInstructions::new(if sunny { 100.0 } else { 50.0 }, instructions)]
+        }
+        Abilities::CUDCHEW if pokemon.volatile_statuses.contains(VolatileStatus::CudChew) => {
+            let remaining = pokemon
+                .volatile_status_durations
+                .get(&VolatileStatus::CudChew)
+                .copied()
+                .unwrap_or(1);
+            let mut instructions = vec![count_down_volatile(position, VolatileStatus::CudChew, remaining)];
+            if let Some(berry) = pokemon.consumed_item.filter(|_| remaining <= 1) {
+                instructions.extend(berry_effect_instructions(berry, pokemon, position, pokemon.hp, &generation));
             }
+            certain(instructions)
         }
+        _ => Vec::new(),
     }
-    
-    instructions
-}
\ No newline at end of file
+}
+
+/// Zen Mode, Schooling, Shields Down and Power Construct change forme with HP
+fn apply_forme_changes(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    _pokemon: &Pokemon,
+) -> Vec<BattleInstructions> {
+    certain(super::forme_change::end_of_turn_forme_change(battle_state, position).into_iter().collect())
+}
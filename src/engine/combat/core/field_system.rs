@@ -5,7 +5,7 @@
 //! across move implementations and ensures consistent handling of field interactions.
 
 use crate::core::battle_format::{BattlePosition, SideReference};
-use crate::core::battle_state::BattleState;
+use crate::core::battle_state::{BattleState, Pokemon};
 use crate::core::instructions::{
     BattleInstruction, FieldInstruction, SideCondition, Terrain, Weather,
 };
@@ -282,6 +282,67 @@ pub fn check_terrain_extension(
     }
 }
 
+/// Check if a screen should be extended by an item
+pub fn check_screen_extension(
+    state: &BattleState,
+    screen_type: ScreenType,
+    source_position: BattlePosition,
+) -> Option<u8> {
+    let pokemon = state.get_pokemon_at_position(source_position)?;
+
+    match (screen_type, pokemon.item) {
+        (
+            ScreenType::Reflect | ScreenType::LightScreen | ScreenType::AuroraVeil,
+            Some(crate::types::Items::LIGHTCLAY),
+        ) => Some(8),
+        _ => None,
+    }
+}
+
+/// The weather in effect on the field
+///
+/// Cloud Nine and Air Lock on an active Pokemon suppress it. Hail became Snow
+/// in Gen 9, so each is read as the one that exists in the battle's generation.
+pub fn field_weather(battle_state: &BattleState) -> Weather {
+    let negated = battle_state.get_all_active_positions().into_iter().any(|position| {
+        battle_state.get_pokemon_at_position(position).is_some_and(|pokemon| {
+            pokemon.hp > 0
+                && !pokemon.ability_suppressed
+                && matches!(pokemon.ability, crate::types::Abilities::CLOUDNINE | crate::types::Abilities::AIRLOCK)
+        })
+    });
+    if negated {
+        return Weather::None;
+    }
+
+    match battle_state.weather() {
+        Weather::Hail if battle_state.get_generation().number() >= 9 => Weather::Snow,
+        Weather::Snow if battle_state.get_generation().number() < 9 => Weather::Hail,
+        weather => weather,
+    }
+}
+
+/// The field weather as it affects a particular Pokemon
+pub fn weather_for(battle_state: &BattleState, pokemon: &Pokemon) -> Weather {
+    effective_weather(field_weather(battle_state), pokemon)
+}
+
+/// The weather as it affects a particular Pokemon
+///
+/// A Utility Umbrella holder is unaffected by sun and rain, so for it those
+/// count as clear skies.
+pub fn effective_weather(weather: Weather, pokemon: &Pokemon) -> Weather {
+    let sun_or_rain = matches!(
+        weather,
+        Weather::Sun | Weather::HarshSun | Weather::HarshSunlight | Weather::Rain | Weather::HeavyRain
+    );
+    if sun_or_rain && pokemon.item == Some(crate::types::Items::UTILITYUMBRELLA) {
+        Weather::None
+    } else {
+        weather
+    }
+}
+
 /// Types of screen moves
 #[derive(Debug, Clone, Copy)]
 pub enum ScreenType {
@@ -336,6 +397,19 @@ pub fn terrain_move_with_extension(
     vec![set_terrain(terrain, extended_duration, Some(source))]
 }
 
+/// Screen-setting move with item duration extension
+pub fn screen_move_with_extension(
+    state: &BattleState,
+    user_position: BattlePosition,
+    screen_type: ScreenType,
+) -> Vec<BattleInstruction> {
+    let base_duration = Some(5); // Standard screen duration
+    let extended_duration = check_screen_extension(state, screen_type, user_position)
+        .or(base_duration);
+
+    screen_move(state, user_position, screen_type, extended_duration)
+}
+
 /// Check if a side condition can be applied (not blocked by other effects)
 pub fn can_apply_side_condition(
     state: &BattleState,
@@ -0,0 +1,375 @@
+//! Mid-turn Forced Switch System
+//!
+//! This module handles the effects that pull a Pokemon out of battle in the
+//! middle of a turn: Eject Button, Eject Pack, Red Card, Emergency Exit and
+//! Wimp Out. Each move's branches are checked after the move resolves, so the
+//! triggers see the final HP and stat stages rather than individual hits.
+//!
+//! Eject Button, Eject Pack, Emergency Exit and Wimp Out mark the Pokemon with
+//! `must_switch`, which makes its side pick a replacement before the turn goes
+//! on. Red Card drags in a random Pokemon, so it branches over the attacker's
+//! bench directly. Pivot moves such as U-turn and Parting Shot mark their user
+//! the same way, which also lets them get past trapping. Baton Pass and Shed
+//! Tail leave a marker volatile as well, and whatever they pass on is handed to
+//! the replacement as it comes in.
+
+use crate::core::battle_format::{BattlePosition, SideReference};
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::{
+    BattleInstruction, BattleInstructions, PokemonInstruction, StatsInstruction, StatusInstruction, VolatileStatus,
+};
+use crate::data::showdown_types::MoveData;
+use crate::engine::mechanics::abilities::sheer_force_active;
+use crate::types::{Abilities, Items, Moves, StatBoostArray};
+
+/// Volatile statuses Baton Pass hands over besides the substitute
+const BATON_PASS_VOLATILES: [VolatileStatus; 13] = [
+    VolatileStatus::Confusion,
+    VolatileStatus::FocusEnergy,
+    VolatileStatus::LaserFocus,
+    VolatileStatus::LeechSeed,
+    VolatileStatus::Curse,
+    VolatileStatus::Ingrain,
+    VolatileStatus::AquaRing,
+    VolatileStatus::MagnetRise,
+    VolatileStatus::Embargo,
+    VolatileStatus::HealBlock,
+    VolatileStatus::Perish1,
+    VolatileStatus::Perish2,
+    VolatileStatus::Perish3,
+];
+
+/// Check if a side has a healthy Pokemon on the bench to switch in
+pub fn has_switch_target(state: &BattleState, side: SideReference) -> bool {
+    !bench_indices(state, side).is_empty()
+}
+
+/// Indices of the healthy Pokemon a side can bring in
+fn bench_indices(state: &BattleState, side: SideReference) -> Vec<usize> {
+    let side = state.get_side_by_ref(side);
+    side.pokemon
+        .iter()
+        .enumerate()
+        .filter(|(i, pokemon)| pokemon.hp > 0 && !side.active_pokemon_indices.contains(&Some(*i)))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Consume the holder's item and mark it to switch out
+fn consume_and_switch(
+    holder: &Pokemon,
+    position: BattlePosition,
+    source: Option<BattlePosition>,
+) -> Vec<BattleInstruction> {
+    vec![
+        BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
+            target: position,
+            new_item: None,
+            previous_item: holder.item,
+        }),
+        force_switch(holder, position, source),
+    ]
+}
+
+fn force_switch(
+    pokemon: &Pokemon,
+    position: BattlePosition,
+    source: Option<BattlePosition>,
+) -> BattleInstruction {
+    BattleInstruction::Pokemon(PokemonInstruction::ForceSwitch {
+        target: position,
+        source,
+        previous_can_switch: !pokemon.must_switch,
+    })
+}
+
+/// Eject Pack activates when any of the holder's stats is lowered
+///
+/// `before` is the holder as it was before the drop and `after` as it is now.
+pub fn eject_pack_instructions(
+    state: &BattleState,
+    position: BattlePosition,
+    before: &Pokemon,
+    after: &Pokemon,
+) -> Vec<BattleInstruction> {
+    if after.item != Some(Items::EJECTPACK)
+        || after.hp == 0
+        || after.must_switch
+        || !state.format.allows_switching()
+        || !has_switch_target(state, position.side)
+    {
+        return Vec::new();
+    }
+
+    let lowered = before
+        .stat_boosts
+        .iter()
+        .zip(after.stat_boosts.iter())
+        .any(|((_, old), (_, new))| new < old);
+    if !lowered {
+        return Vec::new();
+    }
+
+    consume_and_switch(after, position, None)
+}
+
+/// What a Pokemon does in response to the move that just hit it
+enum ForcedSwitchTrigger {
+    /// The Pokemon leaves and its side picks the replacement
+    SwitchOut(Vec<BattleInstruction>),
+    /// The attacker is dragged out in favour of a random benched Pokemon
+    RedCard(Vec<BattleInstruction>),
+}
+
+/// Check the forced-switch triggers of a single Pokemon after a move
+fn find_trigger(
+    before_state: &BattleState,
+    after_state: &BattleState,
+    user_position: BattlePosition,
+    position: BattlePosition,
+    sheer_force: bool,
+) -> Option<ForcedSwitchTrigger> {
+    let before = before_state.get_pokemon_at_position(position)?;
+    let after = after_state.get_pokemon_at_position(position)?;
+
+    // The Pokemon must still be the same one, on the field and free to leave
+    let same_pokemon = before_state.get_side_by_ref(position.side).active_pokemon_indices.get(position.slot)
+        == after_state.get_side_by_ref(position.side).active_pokemon_indices.get(position.slot);
+    if !same_pokemon || after.hp == 0 || after.must_switch {
+        return None;
+    }
+
+    let hit_by_move = position != user_position && after.hp < before.hp;
+    if hit_by_move && !sheer_force {
+        match after.item {
+            Some(Items::EJECTBUTTON) if has_switch_target(after_state, position.side) => {
+                return Some(ForcedSwitchTrigger::SwitchOut(consume_and_switch(after, position, Some(user_position))));
+            }
+            Some(Items::REDCARD) => {
+                let attacker_stays = after_state
+                    .get_pokemon_at_position(user_position)
+                    .is_some_and(|user| user.hp > 0 && !user.must_switch);
+                if attacker_stays && has_switch_target(after_state, user_position.side) {
+                    return Some(ForcedSwitchTrigger::RedCard(vec![BattleInstruction::Pokemon(
+                        PokemonInstruction::ChangeItem {
+                            target: position,
+                            new_item: None,
+                            previous_item: after.item,
+                        },
+                    )]));
+                }
+            }
+            _ => {}
+        }
+
+        // Emergency Exit and Wimp Out activate when the move drops the Pokemon to half HP or less
+        let half_hp = after.max_hp / 2;
+        if matches!(after.ability, Abilities::EMERGENCYEXIT | Abilities::WIMPOUT)
+            && before.hp > half_hp
+            && after.hp <= half_hp
+            && has_switch_target(after_state, position.side)
+        {
+            return Some(ForcedSwitchTrigger::SwitchOut(vec![force_switch(after, position, Some(user_position))]));
+        }
+    }
+
+    // Stat drops can come from the move itself (Close Combat, Parting Shot, ...)
+    let eject_pack = eject_pack_instructions(after_state, position, before, after);
+    if !eject_pack.is_empty() {
+        return Some(ForcedSwitchTrigger::SwitchOut(eject_pack));
+    }
+
+    None
+}
+
+/// Add mid-turn forced switches to every branch of a move
+///
+/// Each branch is applied to a copy of the state and compared against the state
+/// before the move. Red Card splits a branch evenly over the attacker's bench.
+pub fn apply_forced_switch_triggers(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    if !state.format.allows_switching() {
+        return instruction_sets;
+    }
+
+    // Sheer Force keeps Red Card, Eject Button, Emergency Exit and Wimp Out from activating
+    let sheer_force = state
+        .get_pokemon_at_position(user_position)
+        .is_some_and(|user| sheer_force_active(user, move_data));
+
+    let mut result = Vec::with_capacity(instruction_sets.len());
+    for instruction_set in instruction_sets {
+        let mut after_state = state.clone();
+        after_state.apply_instructions(&instruction_set.instruction_list);
+
+        let pivot = pivot_instruction(&after_state, move_data, user_position, &instruction_set);
+        let mut branches = vec![instruction_set];
+        let mut triggered = false;
+        for position in state.get_all_active_positions() {
+            let trigger = find_trigger(state, &after_state, user_position, position, sheer_force);
+            triggered |= trigger.is_some();
+            match trigger {
+                Some(ForcedSwitchTrigger::SwitchOut(instructions)) => {
+                    for branch in branches.iter_mut() {
+                        branch.instruction_list.extend(instructions.iter().cloned());
+                    }
+                }
+                Some(ForcedSwitchTrigger::RedCard(instructions)) => {
+                    let previous_pokemon = after_state
+                        .get_side_by_ref(user_position.side)
+                        .active_pokemon_indices
+                        .get(user_position.slot)
+                        .copied()
+                        .flatten();
+                    let bench = bench_indices(&after_state, user_position.side);
+                    let share = 1.0 / bench.len() as f32;
+
+                    branches = branches
+                        .into_iter()
+                        .flat_map(|branch| {
+                            let instructions = &instructions;
+                            bench.iter().map(move |&new_pokemon| {
+                                let mut instruction_list = branch.instruction_list.clone();
+                                instruction_list.extend(instructions.iter().cloned());
+                                instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::Switch {
+                                    position: user_position,
+                                    new_pokemon,
+                                    previous_pokemon,
+                                }));
+                                BattleInstructions::new_with_positions(
+                                    branch.percentage * share,
+                                    instruction_list,
+                                    branch.affected_positions.clone(),
+                                )
+                            })
+                        })
+                        .collect();
+                }
+                None => {}
+            }
+        }
+
+        // A target's Eject Button, Red Card or Emergency Exit takes priority over the pivot
+        if let Some(pivot) = pivot.filter(|_| !triggered) {
+            for branch in branches.iter_mut() {
+                branch.instruction_list.push(pivot.clone());
+            }
+        }
+        result.extend(branches);
+    }
+
+    result
+}
+
+/// Pivot moves switch the user out once the move has landed
+///
+/// Baton Pass and Shed Tail switch out the same way and pass their effects on
+/// through `passed_on_switch`. Revival Blessing picks a fainted Pokemon instead.
+fn pivot_instruction(
+    after_state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    instruction_set: &BattleInstructions,
+) -> Option<BattleInstruction> {
+    let passes_effects = matches!(move_data.self_switch.switch_type(), Some("copyvolatile" | "shedtail"));
+    if !move_data.self_switch.causes_switch()
+        || (move_data.self_switch.switch_type().is_some() && !passes_effects)
+        || move_data.name == Moves::REVIVALBLESSING
+    {
+        return None;
+    }
+
+    let user = after_state.get_pokemon_at_position(user_position)?;
+    if user.hp == 0 || user.must_switch || !has_switch_target(after_state, user_position.side) {
+        return None;
+    }
+
+    // Damaging pivots need to hit something, even if only a substitute; status
+    // pivots go through unless the move failed
+    let landed = if move_data.base_power == 0 {
+        !passes_effects || user.volatile_statuses.contains(VolatileStatus::BatonPass) || user.volatile_statuses.contains(VolatileStatus::ShedTail)
+    } else {
+        instruction_set.instruction_list.iter().any(|instruction| {
+            matches!(
+                instruction,
+                BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. } | PokemonInstruction::DamageSubstitute { target, .. })
+                    if *target != user_position
+            )
+        })
+    };
+    if !landed {
+        return None;
+    }
+
+    Some(force_switch(user, user_position, None))
+}
+
+/// What Baton Pass or Shed Tail hands to the Pokemon switching in
+///
+/// Baton Pass passes stat stages, the substitute and volatiles such as Leech
+/// Seed, Focus Energy and Perish Song; Shed Tail only passes its substitute.
+/// The instructions go after the switch, so they target the incoming Pokemon.
+pub fn passed_on_switch(state: &BattleState, position: BattlePosition, incoming_index: usize) -> Vec<BattleInstruction> {
+    let (outgoing, incoming) = match (
+        state.get_pokemon_at_position(position),
+        state.get_side_by_ref(position.side).pokemon.get(incoming_index),
+    ) {
+        (Some(outgoing), Some(incoming)) => (outgoing, incoming),
+        _ => return Vec::new(),
+    };
+    let baton_pass = outgoing.volatile_statuses.contains(VolatileStatus::BatonPass);
+    if !baton_pass && !outgoing.volatile_statuses.contains(VolatileStatus::ShedTail) {
+        return Vec::new();
+    }
+
+    let mut instructions = Vec::new();
+    let apply_volatile = |status: VolatileStatus| {
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: position,
+            status,
+            duration: outgoing.volatile_status_durations.get(&status).copied(),
+            previous_had_status: incoming.volatile_statuses.contains(status),
+            previous_duration: incoming.volatile_status_durations.get(&status).copied(),
+        })
+    };
+
+    if outgoing.volatile_statuses.contains(VolatileStatus::Substitute) && outgoing.substitute_health > 0 {
+        instructions.push(apply_volatile(VolatileStatus::Substitute));
+        instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth {
+            target: position,
+            new_health: outgoing.substitute_health,
+            previous_health: incoming.substitute_health,
+        }));
+    }
+    if !baton_pass {
+        return instructions;
+    }
+
+    instructions.extend(
+        BATON_PASS_VOLATILES
+            .into_iter()
+            .filter(|&status| outgoing.volatile_statuses.contains(status))
+            .map(apply_volatile),
+    );
+
+    // The incoming Pokemon ends up with exactly the outgoing one's stat stages
+    let mut stat_changes = StatBoostArray::default();
+    for (stat, stage) in outgoing.stat_boosts.iter() {
+        let change = stage - incoming.stat_boosts.get_direct(stat);
+        if change != 0 {
+            stat_changes.insert(stat, change);
+        }
+    }
+    if !stat_changes.is_empty() {
+        instructions.push(BattleInstruction::Stats(StatsInstruction::BoostStats {
+            target: position,
+            stat_changes: stat_changes.to_hashmap(),
+            previous_boosts: incoming.stat_boosts.to_hashmap(),
+        }));
+    }
+    instructions
+}
@@ -0,0 +1,199 @@
+//! Forme Change System
+//!
+//! This module handles the abilities that change a Pokemon's forme mid-battle:
+//! Zen Mode, Schooling, Shields Down and Power Construct follow the Pokemon's HP,
+//! Stance Change reacts to the move being used, and Disguise and Ice Face absorb
+//! a hit before breaking. The new forme's base stats, types and ability are
+//! swapped in when the `FormeChange` instruction is applied.
+//!
+//! Only formes listed under the species' `otherFormes` are reachable, so a
+//! Pokemon without the matching forme data simply keeps its current forme.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::{BattleInstruction, BattleInstructions, MoveCategory, PokemonInstruction, Weather};
+use crate::data::showdown_types::MoveData;
+use crate::types::{Abilities, Moves};
+use crate::utils::normalize_name;
+use super::field_system::weather_for;
+
+/// Forme changes that follow HP or weather, checked at the end of each turn
+pub fn end_of_turn_forme_change(state: &BattleState, position: BattlePosition) -> Option<BattleInstruction> {
+    let pokemon = ability_active(state, position)?;
+    let species = pokemon.species.as_str();
+    let half_hp_or_less = pokemon.hp <= pokemon.max_hp / 2;
+
+    match pokemon.ability {
+        Abilities::ZENMODE => forme_change(state, position, &with_forme(species, "zen", half_hp_or_less)),
+        Abilities::POWERCONSTRUCT if half_hp_or_less => forme_change(state, position, "zygardecomplete"),
+        Abilities::SCHOOLING | Abilities::SHIELDSDOWN | Abilities::ICEFACE => switch_in_forme_change(state, position),
+        _ => None,
+    }
+}
+
+/// Forme changes checked as a Pokemon enters the field
+pub fn switch_in_forme_change(state: &BattleState, position: BattlePosition) -> Option<BattleInstruction> {
+    let pokemon = ability_active(state, position)?;
+    let species = pokemon.species.as_str();
+
+    match pokemon.ability {
+        // Wishiwashi only schools from level 20 while above 1/4 HP
+        Abilities::SCHOOLING => {
+            let school = pokemon.level >= 20 && pokemon.hp > pokemon.max_hp / 4;
+            forme_change(state, position, &with_forme(species, "school", school))
+        }
+        // Minior keeps its shell above half HP
+        Abilities::SHIELDSDOWN => {
+            let meteor = pokemon.hp > pokemon.max_hp / 2;
+            forme_change(state, position, &with_forme("minior", "meteor", meteor))
+        }
+        // Hail and snow rebuild Eiscue's ice
+        Abilities::ICEFACE if matches!(weather_for(state, pokemon), Weather::Hail | Weather::Snow) => {
+            forme_change(state, position, &with_forme(species, "noice", false))
+        }
+        _ => None,
+    }
+}
+
+/// Formes that revert once the Pokemon leaves the field
+pub fn switch_out_forme_change(state: &BattleState, position: BattlePosition) -> Option<BattleInstruction> {
+    let pokemon = state.get_pokemon_at_position(position)?;
+    let species = pokemon.species.as_str();
+
+    match pokemon.ability {
+        Abilities::ZENMODE => forme_change(state, position, &with_forme(species, "zen", false)),
+        Abilities::SCHOOLING => forme_change(state, position, &with_forme(species, "school", false)),
+        Abilities::STANCECHANGE => forme_change(state, position, &with_forme(species, "blade", false)),
+        _ => None,
+    }
+}
+
+/// Stance Change - Aegislash attacks in Blade Forme and uses King's Shield in Shield Forme
+pub fn stance_change(state: &BattleState, position: BattlePosition, move_data: &MoveData) -> Option<BattleInstruction> {
+    let pokemon = ability_active(state, position)?;
+    if pokemon.ability != Abilities::STANCECHANGE {
+        return None;
+    }
+
+    let blade = if move_data.name == Moves::KINGSSHIELD {
+        false
+    } else if move_data.category != MoveCategory::Status {
+        true
+    } else {
+        return None;
+    };
+    forme_change(state, position, &with_forme(pokemon.species.as_str(), "blade", blade))
+}
+
+/// Let Disguise and Ice Face take the first hit of a move in the Pokemon's place
+///
+/// The absorbed hit deals no damage and breaks the disguise, though from Gen 8 a
+/// busted Disguise still costs Mimikyu 1/8 of its max HP. Later hits of a
+/// multi-hit move land normally.
+pub fn apply_disguise_triggers(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    let Some(user) = state.get_pokemon_at_position(user_position) else {
+        return instruction_sets;
+    };
+    let disguises: Vec<(BattlePosition, Vec<BattleInstruction>)> = state
+        .get_all_active_positions()
+        .into_iter()
+        .filter(|&position| position != user_position)
+        .filter_map(|position| disguise_instructions(state, position, user, move_data).map(|instructions| (position, instructions)))
+        .collect();
+    if disguises.is_empty() {
+        return instruction_sets;
+    }
+
+    for instruction_set in &mut instruction_sets {
+        for (position, instructions) in &disguises {
+            let first_hit = instruction_set.instruction_list.iter().position(|instruction| {
+                matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. }) if target == position)
+            });
+            if let Some(index) = first_hit {
+                instruction_set.instruction_list.splice(index..=index, instructions.iter().cloned());
+            }
+        }
+    }
+    instruction_sets
+}
+
+/// Instructions replacing the hit a disguise absorbs, if it can absorb this move
+///
+/// A Mold Breaker user hits straight through the disguise.
+fn disguise_instructions(
+    state: &BattleState,
+    position: BattlePosition,
+    user: &Pokemon,
+    move_data: &MoveData,
+) -> Option<Vec<BattleInstruction>> {
+    let pokemon = ability_active(state, position)?;
+    let species = pokemon.species.as_str();
+
+    match super::ability_suppression::defending_ability(user, pokemon)? {
+        Abilities::DISGUISE if move_data.category != MoveCategory::Status => {
+            let mut instructions = vec![forme_change(state, position, &with_forme(species, "busted", true))?];
+            if state.format.generation.number() >= 8 {
+                instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: position,
+                    amount: (pokemon.max_hp / 8).max(1),
+                    previous_hp: Some(pokemon.hp),
+                }));
+            }
+            Some(instructions)
+        }
+        Abilities::ICEFACE if move_data.category == MoveCategory::Physical => {
+            Some(vec![forme_change(state, position, &with_forme(species, "noice", true))?])
+        }
+        _ => None,
+    }
+}
+
+/// The Pokemon at a position, if it's on the field with a working ability
+fn ability_active(state: &BattleState, position: BattlePosition) -> Option<&Pokemon> {
+    state
+        .get_pokemon_at_position(position)
+        .filter(|pokemon| pokemon.hp > 0 && !pokemon.ability_suppressed)
+}
+
+/// A species name with a forme suffix added or removed
+fn with_forme(species: &str, suffix: &str, active: bool) -> String {
+    let base = species.strip_suffix(suffix).unwrap_or(species);
+    if active {
+        format!("{}{}", base, suffix)
+    } else {
+        base.to_string()
+    }
+}
+
+/// Change a Pokemon into another forme of its species
+fn forme_change(state: &BattleState, position: BattlePosition, new_forme: &str) -> Option<BattleInstruction> {
+    let pokemon = state.get_pokemon_at_position(position)?;
+    let current_forme = pokemon.species.as_str();
+    if new_forme == current_forme {
+        return None;
+    }
+
+    let repository = &state.game_data_repo.pokemon;
+    let current_data = repository.find_by_name(current_forme)?;
+    let base_species = repository.find_by_name(current_data.base_species.as_deref().unwrap_or(&current_data.name))?;
+    let is_forme_of_species = normalize_name(&base_species.name) == new_forme
+        || base_species
+            .other_formes
+            .iter()
+            .flatten()
+            .any(|forme| normalize_name(forme) == new_forme);
+    if !is_forme_of_species {
+        return None;
+    }
+
+    Some(BattleInstruction::Pokemon(PokemonInstruction::FormeChange {
+        target: position,
+        new_forme: new_forme.to_string(),
+        previous_forme: current_forme.to_string(),
+    }))
+}
@@ -0,0 +1,80 @@
+//! Illusion System
+//!
+//! Zoroark and Zorua enter the field looking like the last healthy member of
+//! their party and keep the disguise until a damaging move hits them. The
+//! disguise is stored as a party index on the Pokemon; what the opponent sees
+//! comes from `BattleState::view_for_side`.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::BattleState;
+use crate::core::instructions::{BattleInstruction, BattleInstructions, PokemonInstruction, VolatileStatus};
+use crate::types::Abilities;
+
+/// Disguise a Pokemon with Illusion as it enters the field
+pub fn illusion_on_switch_in(state: &BattleState, position: BattlePosition) -> Option<BattleInstruction> {
+    let side = state.get_side_by_ref(position.side);
+    let own_index = side.active_pokemon_indices.get(position.slot).copied().flatten()?;
+    let pokemon = side.pokemon.get(own_index)?;
+    if pokemon.ability != Abilities::ILLUSION || pokemon.ability_suppressed || pokemon.hp == 0 {
+        return None;
+    }
+
+    // With nobody else left to copy, there's no disguise
+    let disguise = side
+        .pokemon
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|&(index, member)| index != own_index && member.hp > 0)
+        .map(|(index, _)| index)?;
+
+    Some(BattleInstruction::Pokemon(PokemonInstruction::ChangeIllusion {
+        target: position,
+        new_illusion: Some(disguise),
+        previous_illusion: pokemon.illusion,
+    }))
+}
+
+/// Break the Illusion of every disguised Pokemon a move damages
+///
+/// Hits taken by a substitute leave the disguise in place.
+pub fn apply_illusion_breaks(
+    state: &BattleState,
+    user_position: BattlePosition,
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    let disguised: Vec<BattlePosition> = state
+        .get_all_active_positions()
+        .into_iter()
+        .filter(|&position| position != user_position)
+        .filter(|&position| {
+            state.get_pokemon_at_position(position).is_some_and(|pokemon| {
+                pokemon.illusion.is_some()
+                    && !(pokemon.volatile_statuses.contains(VolatileStatus::Substitute) && pokemon.substitute_health > 0)
+            })
+        })
+        .collect();
+    if disguised.is_empty() {
+        return instruction_sets;
+    }
+
+    for instruction_set in &mut instruction_sets {
+        for &position in &disguised {
+            let first_hit = instruction_set.instruction_list.iter().position(|instruction| {
+                matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. }) if *target == position && *amount > 0)
+            });
+            if let Some(index) = first_hit {
+                let previous_illusion = state.get_pokemon_at_position(position).and_then(|pokemon| pokemon.illusion);
+                instruction_set.instruction_list.insert(
+                    index + 1,
+                    BattleInstruction::Pokemon(PokemonInstruction::ChangeIllusion {
+                        target: position,
+                        new_illusion: None,
+                        previous_illusion,
+                    }),
+                );
+            }
+        }
+    }
+    instruction_sets
+}
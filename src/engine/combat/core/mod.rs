@@ -12,6 +12,13 @@ pub mod move_prevention;
 pub mod substitute_protection;
 pub mod end_of_turn;
 pub mod ability_triggers;
+pub mod move_reflection;
+pub mod move_protection;
+pub mod forced_switch;
+pub mod trapping;
+pub mod forme_change;
+pub mod illusion;
+pub mod ability_suppression;
 
 pub use damage_system::*;
 pub use status_system::*;
@@ -20,4 +27,11 @@ pub use field_system::*;
 pub use move_prevention::*;
 pub use substitute_protection::*;
 pub use end_of_turn::*;
-pub use ability_triggers::*;
\ No newline at end of file
+pub use ability_triggers::*;
+pub use move_reflection::*;
+pub use move_protection::*;
+pub use forced_switch::*;
+pub use trapping::*;
+pub use forme_change::*;
+pub use illusion::*;
+pub use ability_suppression::*;
\ No newline at end of file
@@ -9,9 +9,9 @@ use crate::core::instructions::{
     BattleInstruction, BattleInstructions, PokemonInstruction, PokemonStatus, StatusInstruction,
     VolatileStatus,
 };
-use crate::core::move_choice::MoveChoice;
+use crate::core::move_choice::{MoveChoice, MoveIndex};
 use crate::data::showdown_types::MoveData;
-use crate::types::PokemonType;
+use crate::types::{Abilities, Items, Moves, PokemonType};
 use serde::{Deserialize, Serialize};
 
 /// Reasons why a move might be prevented from being used
@@ -37,6 +37,12 @@ pub enum MovePreventionReason {
     Encore,
     /// Choice item locks into the last used move
     ChoiceLock,
+    /// Imprison stops moves that the imprisoning Pokemon also knows
+    Imprison,
+    /// Assault Vest prevents status moves
+    AssaultVest,
+    /// Gigaton Hammer and Blood Moon can't be used twice in a row
+    NoConsecutiveUse,
 }
 
 /// Check if a Pokemon cannot use a move due to status conditions or other effects
@@ -210,8 +216,8 @@ fn check_other_prevention_effects(
     pokemon: &Pokemon,
     move_choice: &MoveChoice,
     move_data: Option<&MoveData>,
-    _battle_state: &BattleState,
-    _position: BattlePosition,
+    battle_state: &BattleState,
+    position: BattlePosition,
 ) -> Option<MovePreventionReason> {
     // Check Taunt (prevents status moves)
     if pokemon.volatile_statuses.contains(VolatileStatus::Taunt) {
@@ -232,28 +238,88 @@ fn check_other_prevention_effects(
         }
     }
 
-    // Check Torment (prevents using same move twice in a row)
-    if pokemon.volatile_statuses.contains(VolatileStatus::Torment) {
-        // For now, implement basic torment check
-        // TODO: Implement proper last move tracking
-        // return Some(MovePreventionReason::Torment);
+    // Torment, Disable, Encore, choice lock, ... make some moves unusable outright
+    if let Some(move_index) = move_choice.move_index() {
+        if let Some(reason) = move_selection_restriction(battle_state, position, pokemon, move_index) {
+            return Some(reason);
+        }
+    }
+
+    None
+}
+
+/// Check if a move can't be selected this turn
+///
+/// Option generation drops these moves, and the same checks stop a move that
+/// became restricted between being chosen and being used.
+pub fn move_selection_restriction(
+    battle_state: &BattleState,
+    position: BattlePosition,
+    pokemon: &Pokemon,
+    move_index: MoveIndex,
+) -> Option<MovePreventionReason> {
+    let move_data = pokemon.get_move(move_index)?;
+    let is_status_move = move_data.category == crate::core::battle_state::MoveCategory::Status;
+
+    if pokemon.disabled_moves.contains_key(&move_index) {
+        return Some(MovePreventionReason::Disable);
+    }
+
+    if let Some(last_move) = pokemon.last_used_move {
+        let knows_last_move = pokemon.moves.iter().any(|(_, known)| known.name == last_move);
+
+        // Encore and choice lock only bind to a move the Pokemon still knows
+        if knows_last_move && move_data.name != last_move {
+            if pokemon.volatile_statuses.contains(VolatileStatus::Encore) {
+                return Some(MovePreventionReason::Encore);
+            }
+            if is_choice_locked(pokemon) {
+                return Some(MovePreventionReason::ChoiceLock);
+            }
+        }
+
+        if move_data.name == last_move {
+            if pokemon.volatile_statuses.contains(VolatileStatus::Torment) {
+                return Some(MovePreventionReason::Torment);
+            }
+            if matches!(last_move, Moves::GIGATONHAMMER | Moves::BLOODMOON) {
+                return Some(MovePreventionReason::NoConsecutiveUse);
+            }
+        }
+    }
+
+    if is_status_move && pokemon.volatile_statuses.contains(VolatileStatus::Taunt) {
+        return Some(MovePreventionReason::Taunt);
     }
 
-    // Check Disable (prevents using a specific disabled move)
-    if pokemon.volatile_statuses.contains(VolatileStatus::Disable) {
-        // TODO: Implement proper move disable tracking
-        // return Some(MovePreventionReason::Disable);
+    if is_status_move && pokemon.item == Some(Items::ASSAULTVEST) {
+        return Some(MovePreventionReason::AssaultVest);
     }
 
-    // Check Encore (forces using the same move)
-    if pokemon.volatile_statuses.contains(VolatileStatus::Encore) {
-        // TODO: Implement proper encore tracking
-        // return Some(MovePreventionReason::Encore);
+    let imprisoned = battle_state
+        .get_all_active_positions()
+        .into_iter()
+        .filter(|other| other.side != position.side)
+        .filter_map(|other| battle_state.get_pokemon_at_position(other))
+        .any(|opponent| {
+            opponent.volatile_statuses.contains(VolatileStatus::Imprison)
+                && opponent.moves.iter().any(|(_, known)| known.name == move_data.name)
+        });
+    if imprisoned {
+        return Some(MovePreventionReason::Imprison);
     }
 
     None
 }
 
+/// Choice items and Gorilla Tactics lock the holder into its last used move
+fn is_choice_locked(pokemon: &Pokemon) -> bool {
+    matches!(
+        pokemon.item,
+        Some(Items::CHOICEBAND) | Some(Items::CHOICESPECS) | Some(Items::CHOICESCARF)
+    ) || pokemon.ability == Abilities::GORILLATACTICS
+}
+
 /// Calculate confusion self-damage (base 40 power physical move)
 fn calculate_confusion_self_damage(pokemon: &Pokemon) -> i16 {
     // Confusion self-damage formula: base 40 power physical move
@@ -404,6 +470,13 @@ pub fn generate_prevention_instructions(
             // Choice lock is deterministic - wrong move is always prevented
             vec![BattleInstructions::new(100.0, vec![])]
         }
+
+        MovePreventionReason::Imprison
+        | MovePreventionReason::AssaultVest
+        | MovePreventionReason::NoConsecutiveUse => {
+            // These restrictions are deterministic - the move always fails
+            vec![BattleInstructions::new(100.0, vec![])]
+        }
     }
 }
 
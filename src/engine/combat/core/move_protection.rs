@@ -0,0 +1,278 @@
+//! Move Protection System
+//!
+//! This module decides which targets Protect and its relatives shield from a
+//! move. Single-Pokemon protections are volatile statuses on the protected
+//! Pokemon, while Quick Guard, Wide Guard, Crafty Shield and Mat Block are side
+//! conditions covering the whole side. Blocking a contact move with King's
+//! Shield, Spiky Shield, Baneful Bunker, Silk Trap, Burning Bulwark or Obstruct
+//! punishes the attacker.
+//!
+//! Stalling moves grow less reliable when used in a row: each consecutive
+//! success is recorded as the duration of the Stall volatile, and the next
+//! attempt succeeds a third as often.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::{
+    BattleInstruction, FieldInstruction, PokemonInstruction, PokemonStatus, SideCondition, Stat,
+    StatsInstruction, StatusInstruction, VolatileStatus,
+};
+use crate::data::showdown_types::{MoveData, MoveTarget};
+use crate::types::{Abilities, Items, StatBoostArray};
+use super::status_system::{apply_status_effect, StatusApplication};
+
+/// Volatile statuses that shield a single Pokemon for the rest of the turn
+pub const PROTECTION_VOLATILES: [VolatileStatus; 8] = [
+    VolatileStatus::Protect,
+    VolatileStatus::KingsShield,
+    VolatileStatus::SpikyShield,
+    VolatileStatus::BanefulBunker,
+    VolatileStatus::SilkTrap,
+    VolatileStatus::BurningBulwark,
+    VolatileStatus::Obstruct,
+    VolatileStatus::MaxGuard,
+];
+
+/// Side conditions that shield a whole side for the rest of the turn
+pub const SIDE_GUARDS: [SideCondition; 4] = [
+    SideCondition::QuickGuard,
+    SideCondition::WideGuard,
+    SideCondition::CraftyShield,
+    SideCondition::MatBlock,
+];
+
+/// What shields a target from a move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shield {
+    /// A protection volatile on the target itself
+    Volatile(VolatileStatus),
+    /// A guard covering the target's side
+    Side(SideCondition),
+}
+
+/// Consecutive stalling moves the Pokemon has successfully used
+pub fn consecutive_stalls(pokemon: &Pokemon) -> u8 {
+    if pokemon.volatile_statuses.contains(VolatileStatus::Stall) {
+        pokemon.volatile_status_durations.get(&VolatileStatus::Stall).copied().unwrap_or(1)
+    } else {
+        0
+    }
+}
+
+/// Chance in percent that the Pokemon's next stalling move succeeds
+pub fn stall_success_chance(pokemon: &Pokemon) -> f32 {
+    100.0 / 3f32.powi(consecutive_stalls(pokemon) as i32)
+}
+
+/// Record one more consecutive successful stalling move
+pub fn record_stall(pokemon: &Pokemon, position: BattlePosition) -> BattleInstruction {
+    let stalls = consecutive_stalls(pokemon);
+    if stalls == 0 {
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: position,
+            status: VolatileStatus::Stall,
+            duration: Some(1),
+            previous_had_status: false,
+            previous_duration: None,
+        })
+    } else {
+        BattleInstruction::Status(StatusInstruction::ChangeVolatileDuration {
+            target: position,
+            status: VolatileStatus::Stall,
+            new_duration: Some(stalls.saturating_add(1)),
+            previous_duration: Some(stalls),
+        })
+    }
+}
+
+/// A failed stalling move resets the run of consecutive successes
+pub fn reset_stall(pokemon: &Pokemon, position: BattlePosition) -> Vec<BattleInstruction> {
+    if pokemon.volatile_statuses.contains(VolatileStatus::Stall) {
+        vec![BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+            target: position,
+            status: VolatileStatus::Stall,
+            previous_duration: Some(consecutive_stalls(pokemon)),
+        })]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Check whether anything on the Pokemon's side shielded it this turn
+///
+/// Pokemon that didn't protect this turn lose their run of consecutive stalls.
+pub fn protected_this_turn(state: &BattleState, position: BattlePosition, pokemon: &Pokemon) -> bool {
+    PROTECTION_VOLATILES
+        .into_iter()
+        .chain(std::iter::once(VolatileStatus::Endure))
+        .any(|status| pokemon.volatile_statuses.contains(status))
+        || SIDE_GUARDS
+            .into_iter()
+            .any(|guard| state.get_side_by_ref(position.side).side_conditions.contains_key(&guard))
+}
+
+/// Check if the move goes through every protection
+///
+/// Moves without the `protect` flag are never blocked, and Unseen Fist lets
+/// contact moves through.
+fn ignores_protection(move_data: &MoveData, user: &Pokemon) -> bool {
+    !move_data.flags.contains_key("protect")
+        || (user.ability == Abilities::UNSEENFIST
+            && !user.ability_suppressed
+            && move_data.flags.contains_key("contact"))
+}
+
+/// Find what shields the target from this move, if anything
+///
+/// Feint, Phantom Force and other `breaksProtect` moves go through everything
+/// but Max Guard. King's Shield, Silk Trap and Obstruct let status moves through.
+pub fn find_shield(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_position: BattlePosition,
+    priority: i8,
+) -> Option<Shield> {
+    if target_position == user_position {
+        return None;
+    }
+    let user = state.get_pokemon_at_position(user_position)?;
+    let target = state.get_pokemon_at_position(target_position).filter(|pokemon| pokemon.hp > 0)?;
+    if ignores_protection(move_data, user) {
+        return None;
+    }
+    if target.volatile_statuses.contains(VolatileStatus::MaxGuard) {
+        return Some(Shield::Volatile(VolatileStatus::MaxGuard));
+    }
+    if move_data.breaks_protect {
+        return None;
+    }
+
+    let is_status = move_data.category == crate::core::battle_state::MoveCategory::Status;
+    let volatile = PROTECTION_VOLATILES.into_iter().find(|&status| {
+        target.volatile_statuses.contains(status)
+            && !(is_status
+                && matches!(status, VolatileStatus::KingsShield | VolatileStatus::SilkTrap | VolatileStatus::Obstruct))
+    });
+    if let Some(status) = volatile {
+        return Some(Shield::Volatile(status));
+    }
+
+    let side = state.get_side_by_ref(target_position.side);
+    SIDE_GUARDS
+        .into_iter()
+        .filter(|guard| side.side_conditions.contains_key(guard))
+        .find(|guard| match guard {
+            SideCondition::QuickGuard => priority > 0,
+            SideCondition::WideGuard => matches!(move_data.target, MoveTarget::AllAdjacent | MoveTarget::AllAdjacentFoes),
+            SideCondition::CraftyShield => is_status,
+            SideCondition::MatBlock => !is_status,
+            _ => false,
+        })
+        .map(Shield::Side)
+}
+
+/// Check if the move touches its target, allowing for Protective Pads,
+/// Punching Glove and Long Reach
+fn makes_contact(move_data: &MoveData, user: &Pokemon) -> bool {
+    move_data.flags.contains_key("contact")
+        && user.item != Some(Items::PROTECTIVEPADS)
+        && !(user.item == Some(Items::PUNCHINGGLOVE) && move_data.flags.contains_key("punch"))
+        && !(user.ability == Abilities::LONGREACH && !user.ability_suppressed)
+}
+
+/// What happens to a user whose contact move the target's shield blocked
+pub fn shield_punishment(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_position: BattlePosition,
+    shield: Shield,
+) -> Vec<BattleInstruction> {
+    let user = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) if pokemon.hp > 0 && makes_contact(move_data, pokemon) => pokemon,
+        _ => return Vec::new(),
+    };
+    let lower = |stat: Stat, amount: i8| {
+        let mut stat_changes = StatBoostArray::default();
+        stat_changes.insert(stat, -amount);
+        vec![BattleInstruction::Stats(StatsInstruction::BoostStats {
+            target: user_position,
+            stat_changes: stat_changes.to_hashmap(),
+            previous_boosts: user.stat_boosts.to_hashmap(),
+        })]
+    };
+    let afflict = |status: PokemonStatus| {
+        apply_status_effect(state, StatusApplication { status, target: user_position, chance: 100.0, duration: None })
+            .instruction
+            .into_iter()
+            .collect()
+    };
+
+    let instructions = match shield {
+        // King's Shield lowered Attack by 2 before Gen 8
+        Shield::Volatile(VolatileStatus::KingsShield) if state.get_generation().number() < 8 => lower(Stat::Attack, 2),
+        Shield::Volatile(VolatileStatus::KingsShield) => lower(Stat::Attack, 1),
+        Shield::Volatile(VolatileStatus::SilkTrap) => lower(Stat::Speed, 1),
+        Shield::Volatile(VolatileStatus::Obstruct) => lower(Stat::Defense, 2),
+        Shield::Volatile(VolatileStatus::SpikyShield) if user.ability != Abilities::MAGICGUARD => {
+            vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: user_position,
+                amount: (user.max_hp / 8).max(1),
+                previous_hp: Some(user.hp),
+            })]
+        }
+        Shield::Volatile(VolatileStatus::BanefulBunker) => afflict(PokemonStatus::Poison),
+        Shield::Volatile(VolatileStatus::BurningBulwark) => afflict(PokemonStatus::Burn),
+        _ => Vec::new(),
+    };
+    crate::engine::mechanics::items::remove_clear_amulet_drops(state, target_position, instructions)
+}
+
+/// Instructions for a `breaksProtect` move lifting its targets' protection
+///
+/// The targets lose their protection volatiles, other than Max Guard, and
+/// their sides lose Quick Guard, Wide Guard, Crafty Shield and Mat Block.
+pub fn break_protection_instructions(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+) -> Vec<BattleInstruction> {
+    if !move_data.breaks_protect {
+        return Vec::new();
+    }
+
+    let mut instructions = Vec::new();
+    let mut broken_sides = Vec::new();
+    for &target_position in target_positions.iter().filter(|&&position| position != user_position) {
+        let Some(target) = state.get_pokemon_at_position(target_position) else {
+            continue;
+        };
+        instructions.extend(
+            PROTECTION_VOLATILES
+                .into_iter()
+                .filter(|&status| status != VolatileStatus::MaxGuard && target.volatile_statuses.contains(status))
+                .map(|status| BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+                    target: target_position,
+                    status,
+                    previous_duration: target.volatile_status_durations.get(&status).copied(),
+                })),
+        );
+
+        if target_position.side == user_position.side || broken_sides.contains(&target_position.side) {
+            continue;
+        }
+        broken_sides.push(target_position.side);
+        let side = state.get_side_by_ref(target_position.side);
+        instructions.extend(SIDE_GUARDS.into_iter().filter_map(|condition| {
+            let &previous_duration = side.side_conditions.get(&condition)?;
+            Some(BattleInstruction::Field(FieldInstruction::RemoveSideCondition {
+                side: target_position.side,
+                condition,
+                previous_duration,
+            }))
+        }));
+    }
+    instructions
+}
@@ -0,0 +1,74 @@
+//! Move Reflection System
+//!
+//! This module handles Magic Coat and Magic Bounce, which send reflectable
+//! status moves back at their user. A bounced move is used by the reflecting
+//! Pokemon, so side-targeting moves like Spikes land on the original user's side.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::VolatileStatus;
+use crate::data::showdown_types::{MoveData, MoveTarget};
+use crate::types::Abilities;
+
+/// Check if a move can be reflected (the `reflectable` move flag)
+pub fn is_reflectable(move_data: &MoveData) -> bool {
+    move_data.flags.contains_key("reflectable")
+}
+
+/// Check if a Pokemon is currently reflecting moves used by `user`
+///
+/// Magic Bounce does nothing while suppressed or against a Mold Breaker user.
+pub fn can_reflect(pokemon: &Pokemon, user: &Pokemon) -> bool {
+    pokemon.volatile_statuses.contains(VolatileStatus::MagicCoat)
+        || super::ability_suppression::defending_ability(user, pokemon) == Some(Abilities::MAGICBOUNCE)
+}
+
+/// Find the Pokemon that bounce this move back at its user
+///
+/// Moves aimed at the foe's side are bounced by at most one Pokemon on that side.
+/// A bounced move is never bounced again, so callers should not re-check the result.
+pub fn find_reflectors(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+) -> Vec<BattlePosition> {
+    let Some(user) = state.get_pokemon_at_position(user_position).filter(|_| is_reflectable(move_data)) else {
+        return Vec::new();
+    };
+
+    let candidates: Vec<BattlePosition> = if move_data.target == MoveTarget::FoeSide {
+        state
+            .get_all_active_positions()
+            .into_iter()
+            .filter(|position| position.side == user_position.side.opposite())
+            .collect()
+    } else {
+        target_positions.to_vec()
+    };
+
+    let mut reflectors: Vec<BattlePosition> = candidates
+        .into_iter()
+        .filter(|&position| position != user_position)
+        .filter(|&position| {
+            state
+                .get_pokemon_at_position(position)
+                .is_some_and(|pokemon| pokemon.hp > 0 && can_reflect(pokemon, user))
+        })
+        .collect();
+
+    if move_data.target == MoveTarget::FoeSide {
+        reflectors.truncate(1);
+    }
+    reflectors
+}
+
+/// Targets of a bounced move when used by the reflecting Pokemon
+pub fn reflected_targets(move_data: &MoveData, original_user: BattlePosition) -> Vec<BattlePosition> {
+    if move_data.target == MoveTarget::FoeSide {
+        // Side moves resolve their side from the (new) user's position
+        Vec::new()
+    } else {
+        vec![original_user]
+    }
+}
@@ -509,17 +509,7 @@ fn check_volatile_status_immunity(
         return Some(StatusFailureReason::Safeguard);
     }
 
-    // Check substitute protection
-    if target.volatile_statuses.contains(VolatileStatus::Substitute) && target.substitute_health > 0 {
-        // Substitute blocks most volatile statuses except for certain ones
-        match volatile_status {
-            // These can bypass substitute
-            VolatileStatus::Attract | VolatileStatus::Torment | VolatileStatus::Disable => {},
-            // Most others are blocked
-            _ => return Some(StatusFailureReason::ItemImmunity), // Using ItemImmunity as a generic "blocked" reason
-        }
-    }
-
+    // Substitutes are handled per move, from the move's `bypasssub` flag
     None
 }
 
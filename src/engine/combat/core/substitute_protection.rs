@@ -1,9 +1,23 @@
 //! Substitute Protection System
 //!
-//! This module handles substitute interactions with moves and effects,
-//! including which effects are blocked by hitting a substitute.
+//! This module handles substitute interactions with moves and effects. A move
+//! that hits a substitute damages the substitute instead of the Pokemon behind
+//! it, and none of the move's effects on that Pokemon - status, stat changes,
+//! item removal, forced switches - go through. Status moves aimed at a Pokemon
+//! behind a substitute fail outright.
+//!
+//! Moves flagged `bypasssub` go around substitutes, as do sound moves from
+//! Gen 6 and every move used by a Pokemon with Infiltrator.
+
+use std::collections::HashMap;
 
-use crate::core::instructions::{StatusInstruction, StatsInstruction};
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, MoveCategory, Pokemon};
+use crate::core::instructions::{
+    BattleInstruction, BattleInstructions, PokemonInstruction, StatusInstruction, VolatileStatus,
+};
+use crate::data::showdown_types::{MoveData, MoveTarget};
+use crate::types::Abilities;
 
 /// Result of applying damage to a Pokemon with substitute consideration
 #[derive(Debug, Clone, PartialEq)]
@@ -16,67 +30,190 @@ pub struct SubstituteDamageResult {
     pub damage_to_pokemon: i16,
 }
 
-/// Types of effects that can be blocked by substitute
-#[derive(Debug, Clone, PartialEq)]
-pub enum EffectType {
-    StatusCondition,
-    StatChange,
-    VolatileStatus,
-    DirectDamage,
-    Healing,
-    AbilityChange,
-    ItemChange,
-    TypeChange,
+/// Check if the Pokemon has a substitute up
+pub fn has_substitute(pokemon: &Pokemon) -> bool {
+    pokemon.volatile_statuses.contains(VolatileStatus::Substitute) && pokemon.substitute_health > 0
 }
 
-/// Determines if a substitute should block a specific effect
-pub fn substitute_blocks_effect(effect_type: &EffectType, hit_substitute: bool) -> bool {
-    if !hit_substitute {
+/// Check if the move goes around substitutes
+///
+/// Sound moves only do so from Gen 6; before that their `bypasssub` flag is ignored.
+pub fn bypasses_substitute(state: &BattleState, move_data: &MoveData, user: &Pokemon) -> bool {
+    if user.ability == Abilities::INFILTRATOR && !user.ability_suppressed {
+        return true;
+    }
+    if move_data.flags.contains_key("sound") {
+        return state.get_generation().number() >= 6;
+    }
+    move_data.flags.contains_key("bypasssub")
+}
+
+/// Check if the target's substitute stands between it and the user's move
+pub fn behind_substitute(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_position: BattlePosition,
+) -> bool {
+    // Field, side and self moves never reach a substitute
+    let targets_pokemon = matches!(
+        move_data.target,
+        MoveTarget::Normal
+            | MoveTarget::AdjacentAlly
+            | MoveTarget::AdjacentAllyOrSelf
+            | MoveTarget::AdjacentFoe
+            | MoveTarget::AllAdjacentFoes
+            | MoveTarget::AllAdjacent
+            | MoveTarget::Any
+            | MoveTarget::RandomNormal
+    );
+    if !targets_pokemon || target_position == user_position {
         return false;
     }
-    
-    match effect_type {
-        // Substitute blocks most secondary effects when hit
-        EffectType::StatusCondition => true,
-        EffectType::StatChange => true,
-        EffectType::VolatileStatus => true,
-        
-        // Direct effects still apply
-        EffectType::DirectDamage => false, // Damage was already handled by substitute
-        EffectType::Healing => false,      // Healing can still work
-        EffectType::AbilityChange => false, // Some moves like Gastro Acid bypass substitute
-        EffectType::ItemChange => false,   // Item manipulation can bypass substitute
-        EffectType::TypeChange => false,   // Type changes can bypass substitute
+
+    match (state.get_pokemon_at_position(user_position), state.get_pokemon_at_position(target_position)) {
+        (Some(user), Some(target)) => has_substitute(target) && !bypasses_substitute(state, move_data, user),
+        _ => false,
+    }
+}
+
+/// Targets a status move can still affect, leaving out those behind a substitute
+pub fn status_move_targets(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+) -> Vec<BattlePosition> {
+    if move_data.category != MoveCategory::Status {
+        return target_positions.to_vec();
     }
+    target_positions
+        .iter()
+        .copied()
+        .filter(|&target| !behind_substitute(state, move_data, user_position, target))
+        .collect()
 }
 
-/// Categorizes different instruction types for substitute blocking
-pub fn get_effect_type_for_status_instruction(instruction: &StatusInstruction) -> EffectType {
+/// Check if a substitute keeps this instruction off the Pokemon it shields
+fn blocked_by_substitute(instruction: &BattleInstruction) -> bool {
     match instruction {
-        StatusInstruction::Apply { .. } => EffectType::StatusCondition,
-        StatusInstruction::Remove { .. } => EffectType::StatusCondition,
-        StatusInstruction::ChangeDuration { .. } => EffectType::StatusCondition,
-        StatusInstruction::ApplyVolatile { .. } => EffectType::VolatileStatus,
-        StatusInstruction::RemoveVolatile { .. } => EffectType::VolatileStatus,
-        StatusInstruction::ChangeVolatileDuration { .. } => EffectType::VolatileStatus,
-        _ => EffectType::StatusCondition,
+        // Multi-hit moves break the substitute themselves
+        BattleInstruction::Status(StatusInstruction::RemoveVolatile { status: VolatileStatus::Substitute, .. }) => false,
+        BattleInstruction::Status(_) | BattleInstruction::Stats(_) => true,
+        BattleInstruction::Pokemon(instruction) => matches!(
+            instruction,
+            PokemonInstruction::ForceSwitch { .. }
+                | PokemonInstruction::ChangeItem { .. }
+                | PokemonInstruction::ConsumeItem { .. }
+                | PokemonInstruction::ItemTransfer { .. }
+                | PokemonInstruction::ChangeAbility { .. }
+        ),
+        _ => false,
     }
 }
 
-// This function is redundant since get_effect_type_for_status_instruction handles both
-// status and volatile status instructions. Keeping for API compatibility.
-pub fn get_effect_type_for_volatile_instruction(instruction: &StatusInstruction) -> EffectType {
-    get_effect_type_for_status_instruction(instruction)
-}
+/// Send a move's hits on substitutes to the substitutes instead
+///
+/// Damage to a target behind a substitute becomes `DamageSubstitute`, capped at
+/// the substitute's remaining HP, and the rest of that hit's effects on the
+/// target are dropped. Once the substitute breaks, later hits land normally.
+pub fn redirect_to_substitutes(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    // Remaining substitute HP, and whether the latest hit struck the substitute
+    let shielded: HashMap<BattlePosition, (i16, bool)> = target_positions
+        .iter()
+        .filter(|&&target| behind_substitute(state, move_data, user_position, target))
+        .filter_map(|&target| Some((target, (state.get_pokemon_at_position(target)?.substitute_health, false))))
+        .collect();
+    if shielded.is_empty() {
+        return instruction_sets;
+    }
 
-pub fn get_effect_type_for_stats_instruction(instruction: &StatsInstruction) -> EffectType {
-    EffectType::StatChange
+    for branch in &mut instruction_sets {
+        let mut substitutes = shielded.clone();
+        let instructions = std::mem::take(&mut branch.instruction_list);
+        for instruction in instructions {
+            match instruction {
+                BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, previous_hp }) => {
+                    match substitutes.get_mut(&target) {
+                        Some((health, hit)) if *health > 0 => {
+                            let absorbed = amount.min(*health);
+                            branch.instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::DamageSubstitute {
+                                target,
+                                amount: absorbed,
+                                previous_health: *health,
+                            }));
+                            *health -= absorbed;
+                            *hit = true;
+                        }
+                        Some((_, hit)) => {
+                            *hit = false;
+                            branch.instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, previous_hp }));
+                        }
+                        None => {
+                            branch.instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, previous_hp }));
+                        }
+                    }
+                }
+                BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth { target, new_health, previous_health }) => {
+                    if let Some((health, hit)) = substitutes.get_mut(&target) {
+                        *health = new_health;
+                        *hit = true;
+                    }
+                    branch.instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth {
+                        target,
+                        new_health,
+                        previous_health,
+                    }));
+                }
+                instruction => {
+                    let blocked = blocked_by_substitute(&instruction)
+                        && instruction
+                            .affected_positions(&state.format)
+                            .iter()
+                            .any(|position| substitutes.get(position).is_some_and(|&(_, hit)| hit));
+                    if !blocked {
+                        branch.instruction_list.push(instruction);
+                    }
+                }
+            }
+        }
+    }
+    instruction_sets
 }
 
-/// Checks if a Pokemon should block effects due to substitute being hit
-pub fn should_block_effect(
-    effect_type: EffectType,
-    substitute_damage_result: &SubstituteDamageResult,
-) -> bool {
-    substitute_blocks_effect(&effect_type, substitute_damage_result.hit_substitute)
-}
\ No newline at end of file
+/// Damage the user dealt to one target in a branch, counting hits on its substitute
+///
+/// Hits on the Pokemon itself are capped at the HP it had left.
+pub fn damage_dealt_to(
+    state: &BattleState,
+    target_position: BattlePosition,
+    instructions: &[BattleInstruction],
+) -> i16 {
+    let mut remaining_hp = state.get_pokemon_at_position(target_position).map_or(0, |pokemon| pokemon.hp);
+    let mut dealt = 0;
+    for instruction in instructions {
+        match instruction {
+            BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. }) if *target == target_position => {
+                let taken = (*amount).min(remaining_hp);
+                remaining_hp -= taken;
+                dealt += taken;
+            }
+            BattleInstruction::Pokemon(PokemonInstruction::DamageSubstitute { target, amount, .. }) if *target == target_position => {
+                dealt += amount;
+            }
+            BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth { target, new_health, previous_health })
+                if *target == target_position =>
+            {
+                dealt += (previous_health - new_health).max(0);
+            }
+            _ => {}
+        }
+    }
+    dealt
+}
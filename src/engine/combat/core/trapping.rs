@@ -0,0 +1,85 @@
+//! Trapping System
+//!
+//! This module decides whether an active Pokemon is kept from switching out.
+//! Trapping comes from the opposing abilities Shadow Tag, Arena Trap and Magnet
+//! Pull, and from the volatiles left behind by Mean Look, Block, Spirit Shackle,
+//! Jaw Lock, Octolock, No Retreat, Ingrain and the partial trapping moves.
+//!
+//! Only the voluntary switch options are affected. Pivot moves and forced
+//! switches go through `must_switch`, so a trapped Pokemon still leaves with
+//! U-turn, Eject Button or Roar.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::VolatileStatus;
+use crate::engine::combat::damage::modifiers::terrain;
+use crate::types::{Abilities, Items, PokemonType};
+
+/// Volatiles that keep the Pokemon holding them on the field
+pub const TRAPPING_VOLATILES: [VolatileStatus; 5] = [
+    VolatileStatus::Trapped,
+    VolatileStatus::PartiallyTrapped,
+    VolatileStatus::Octolock,
+    VolatileStatus::NoRetreat,
+    VolatileStatus::Ingrain,
+];
+
+/// Volatiles that only last while the Pokemon that caused them stays in
+pub const SOURCE_BOUND_TRAPPING_VOLATILES: [VolatileStatus; 3] = [
+    VolatileStatus::Trapped,
+    VolatileStatus::PartiallyTrapped,
+    VolatileStatus::Octolock,
+];
+
+/// Check if the Pokemon at a position is unable to switch out by choice
+pub fn is_trapped(state: &BattleState, position: BattlePosition) -> bool {
+    let pokemon = match state.get_pokemon_at_position(position) {
+        Some(pokemon) if pokemon.hp > 0 => pokemon,
+        _ => return false,
+    };
+
+    if pokemon.item == Some(Items::SHEDSHELL) || is_immune_to_trapping(state, pokemon) {
+        return false;
+    }
+
+    if TRAPPING_VOLATILES
+        .iter()
+        .any(|&status| pokemon.volatile_statuses.contains(status))
+    {
+        return true;
+    }
+
+    let opposing_side = position.side.opposite();
+    state
+        .get_all_active_positions()
+        .into_iter()
+        .filter(|opponent_position| opponent_position.side == opposing_side)
+        .filter_map(|opponent_position| state.get_pokemon_at_position(opponent_position))
+        .filter(|opponent| opponent.hp > 0)
+        .any(|opponent| traps_with_ability(state, opponent, pokemon))
+}
+
+/// Ghost types can't be trapped from Gen 6 onwards
+pub fn is_immune_to_trapping(state: &BattleState, pokemon: &Pokemon) -> bool {
+    state.format.generation.number() >= 6 && pokemon.types.contains(&PokemonType::Ghost)
+}
+
+/// Check if an opposing Pokemon's ability traps the given Pokemon
+fn traps_with_ability(state: &BattleState, trapper: &Pokemon, pokemon: &Pokemon) -> bool {
+    match trapper.ability {
+        // Two Shadow Tag users don't trap each other
+        Abilities::SHADOWTAG => pokemon.ability != Abilities::SHADOWTAG,
+        Abilities::ARENATRAP => is_grounded(state, pokemon),
+        Abilities::MAGNETPULL => pokemon.types.contains(&PokemonType::Steel),
+        _ => false,
+    }
+}
+
+/// Grounding for Arena Trap, including the effects that force a Pokemon down
+fn is_grounded(state: &BattleState, pokemon: &Pokemon) -> bool {
+    state.field.global_effects.gravity.is_some()
+        || pokemon.item == Some(Items::IRONBALL)
+        || pokemon.volatile_statuses.contains(VolatileStatus::SmackDown)
+        || pokemon.volatile_statuses.contains(VolatileStatus::Ingrain)
+        || terrain::is_grounded(pokemon)
+}
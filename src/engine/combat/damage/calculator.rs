@@ -80,20 +80,31 @@ pub fn calculate_damage_with_positions(
         item_effects: ItemEffects::from_pokemon(defender),
     };
 
+    // Sheer Force trades the move's secondary effects for a 5325/4096 (~1.3x) power boost
+    let mut base_power = move_data.base_power;
+    if crate::engine::mechanics::abilities::sheer_force_active(attacker, move_data) {
+        base_power = ((base_power as u32 * 5325 + 2047) / 4096) as u16;
+    }
+
     let move_context = MoveContext {
         name: move_data.name,
-        base_power: move_data.base_power as u8,
+        base_power: base_power.min(u8::MAX as u16) as u8,
         is_critical,
         is_contact: move_data.flags.contains_key("contact"),
         is_punch: move_data.flags.contains_key("punch"),
         is_sound: move_data.flags.contains_key("sound"),
         is_multihit: move_data.flags.contains_key("multihit"),
+        is_parental_bond_hit: move_data.parental_bond_hit,
         move_type: move_data.move_type,
         category: move_data.category,
     };
 
+    // Damage sees the weather after Cloud Nine, Air Lock and the Hail-to-Snow change
     let field_context = FieldContext {
-        weather: state.field.weather.clone(),
+        weather: crate::core::battle_state::WeatherState {
+            condition: crate::engine::combat::core::field_system::field_weather(state),
+            ..state.field.weather.clone()
+        },
         terrain: state.field.terrain.clone(),
         global_effects: state.field.global_effects.clone(),
     };
@@ -57,10 +57,11 @@ pub fn critical_hit_probability(
             | crate::generation::Generation::Gen8
             | crate::generation::Generation::Gen9
     ) {
-        let defender_ability = &defender.ability;
-        if *defender_ability == crate::types::Abilities::SHELLARMOR
-            || *defender_ability == crate::types::Abilities::BATTLEARMOR
-        {
+        let defender_ability = crate::engine::combat::core::defending_ability(attacker, defender);
+        if matches!(
+            defender_ability,
+            Some(crate::types::Abilities::SHELLARMOR | crate::types::Abilities::BATTLEARMOR)
+        ) {
             return 0.0; // No critical hit possible
         }
     }
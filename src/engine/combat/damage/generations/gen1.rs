@@ -167,8 +167,8 @@ pub fn calculate_damage_gen1(context: &DamageContext, damage_rolls: DamageRolls)
 
     // Apply additional modifiers before +2
 
-    // Type effectiveness calculation (using Gen 1 type chart)
-    let type_chart = TypeChart::get_cached(1); // Gen 1 type chart
+    // Type effectiveness calculation (using the format's type chart)
+    let type_chart = TypeChart::for_format(&context.format.format);
     let move_type = context.move_info.move_type;
 
     let defender_type1 = context.defender.pokemon.types[0];
@@ -179,9 +179,9 @@ pub fn calculate_damage_gen1(context: &DamageContext, damage_rolls: DamageRolls)
     };
 
     // Calculate type effectiveness against primary type
-    let type1_effectiveness = type_chart.get_effectiveness(move_type, defender_type1);
+    let type1_effectiveness = type_chart.move_matchup(context.move_info.name, move_type, defender_type1);
     let type2_effectiveness = if defender_type2 != defender_type1 {
-        type_chart.get_effectiveness(move_type, defender_type2)
+        type_chart.move_matchup(context.move_info.name, move_type, defender_type2)
     } else {
         1.0
     };
@@ -138,8 +138,8 @@ pub fn calculate_damage_gen2(context: &DamageContext, damage_rolls: DamageRolls)
     // Add +2 to base damage
     base_damage = base_damage + 2.0;
 
-    // Type effectiveness calculation (using Gen 2 type chart)
-    let type_chart = TypeChart::get_cached(2); // Gen 2 type chart
+    // Type effectiveness calculation (using the format's type chart)
+    let type_chart = TypeChart::for_format(&context.format.format);
     let move_type = context.move_info.move_type;
 
     let defender_type1 = context.defender.pokemon.types[0];
@@ -150,9 +150,9 @@ pub fn calculate_damage_gen2(context: &DamageContext, damage_rolls: DamageRolls)
     };
 
     // Calculate combined type effectiveness
-    let mut type_effectiveness = type_chart.get_effectiveness(move_type, defender_type1);
+    let mut type_effectiveness = type_chart.move_matchup(context.move_info.name, move_type, defender_type1);
     if defender_type2 != defender_type1 {
-        type_effectiveness *= type_chart.get_effectiveness(move_type, defender_type2);
+        type_effectiveness *= type_chart.move_matchup(context.move_info.name, move_type, defender_type2);
     }
 
     // STAB calculation
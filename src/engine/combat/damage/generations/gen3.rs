@@ -150,7 +150,7 @@ pub fn calculate_damage_gen3(context: &DamageContext, damage_rolls: DamageRolls)
     base_damage = base_damage * critical_modifier;
 
     // Apply STAB
-    let type_chart = TypeChart::get_cached(3); // Gen 3 type chart
+    let type_chart = TypeChart::for_format(&context.format.format);
     let move_type = context.move_info.move_type;
 
     let attacker_type1 = context.attacker.pokemon.types[0];
@@ -177,9 +177,9 @@ pub fn calculate_damage_gen3(context: &DamageContext, damage_rolls: DamageRolls)
         defender_type1
     };
 
-    let type1_effectiveness = type_chart.get_effectiveness(move_type, defender_type1);
+    let type1_effectiveness = type_chart.move_matchup(context.move_info.name, move_type, defender_type1);
     let type2_effectiveness = if defender_type2 != defender_type1 {
-        type_chart.get_effectiveness(move_type, defender_type2)
+        type_chart.move_matchup(context.move_info.name, move_type, defender_type2)
     } else {
         1.0
     };
@@ -6,6 +6,7 @@
 
 use crate::engine::combat::damage_context::{DamageContext, DamageResult, DamageEffect};
 use crate::engine::combat::type_effectiveness::TypeChart;
+use crate::engine::combat::core::ability_suppression::defending_ability;
 use crate::types::PokemonType;
 use crate::engine::combat::damage::DamageRolls;
 use crate::constants::moves::MIN_DAMAGE_PERCENT;
@@ -113,8 +114,13 @@ pub fn calculate_damage_gen4(context: &DamageContext, damage_rolls: DamageRolls)
         base_damage = (base_damage * 0.5).floor();
     }
 
+    // Life Orb boosts the base damage by 1.3x before the random roll
+    if context.attacker.pokemon.item == Some(crate::types::Items::LIFEORB) {
+        base_damage = (base_damage * 1.3).floor();
+    }
+
     // Get type effectiveness data
-    let type_chart = TypeChart::get_cached(4); // Gen 4 type chart
+    let type_chart = TypeChart::for_format(&context.format.format);
     let move_type = context.move_info.move_type;
 
     let defender_type1 = context.defender.pokemon.types[0];
@@ -124,9 +130,9 @@ pub fn calculate_damage_gen4(context: &DamageContext, damage_rolls: DamageRolls)
         defender_type1
     };
 
-    let type1_effectiveness = type_chart.get_effectiveness(move_type, defender_type1);
+    let type1_effectiveness = type_chart.move_matchup(context.move_info.name, move_type, defender_type1);
     let type2_effectiveness = if defender_type2 != defender_type1 {
-        type_chart.get_effectiveness(move_type, defender_type2)
+        type_chart.move_matchup(context.move_info.name, move_type, defender_type2)
     } else {
         1.0
     };
@@ -150,7 +156,8 @@ pub fn calculate_damage_gen4(context: &DamageContext, damage_rolls: DamageRolls)
     };
 
     // Gen 4 specific modifiers (following damage-calc gen4.ts exactly)
-    let filter_mod = if (context.defender.pokemon.ability == crate::types::Abilities::FILTER || context.defender.pokemon.ability == crate::types::Abilities::SOLIDROCK) 
+    let defender_ability = defending_ability(context.attacker.pokemon, context.defender.pokemon);
+    let filter_mod = if matches!(defender_ability, Some(crate::types::Abilities::FILTER | crate::types::Abilities::SOLIDROCK))
         && (type1_effectiveness * type2_effectiveness) > 1.0 {
         0.75
     } else {
@@ -124,7 +124,7 @@ pub fn calculate_damage_gen56(context: &DamageContext, damage_rolls: DamageRolls
     }
 
     // Get type effectiveness data
-    let type_chart = TypeChart::get_cached(6); // Gen 6 type chart (includes Fairy type)
+    let type_chart = TypeChart::for_format(&context.format.format);
     let move_type = context.move_info.move_type;
 
     let defender_type1 = context.defender.pokemon.types[0];
@@ -134,9 +134,9 @@ pub fn calculate_damage_gen56(context: &DamageContext, damage_rolls: DamageRolls
         defender_type1
     };
 
-    let type1_effectiveness = type_chart.get_effectiveness(move_type, defender_type1);
+    let type1_effectiveness = type_chart.move_matchup(context.move_info.name, move_type, defender_type1);
     let type2_effectiveness = if defender_type2 != defender_type1 {
-        type_chart.get_effectiveness(move_type, defender_type2)
+        type_chart.move_matchup(context.move_info.name, move_type, defender_type2)
     } else {
         1.0
     };
@@ -160,8 +160,17 @@ pub fn calculate_damage_gen56(context: &DamageContext, damage_rolls: DamageRolls
     // Convert to 4096-based multiplier for getFinalDamage
     let stab_mod_4096 = (stab_multiplier * 4096.0) as u32;
 
-    // TODO: Calculate final_mod (1.3x for Life Orb, etc.)
-    let final_mod = 4096; // No modifiers for now
+    // Life Orb boosts the final damage by 5324/4096
+    let mut final_mod = if context.attacker.pokemon.item == Some(crate::types::Items::LIFEORB) {
+        5324
+    } else {
+        4096
+    };
+
+    // Parental Bond's second hit deals half the damage
+    if context.move_info.is_parental_bond_hit {
+        final_mod /= 2;
+    }
 
     // Apply damage roll using Gen 5-6 specific system (getFinalDamage without pokeRound)
     let final_damage = calculate_final_damage_gen56(
@@ -6,6 +6,8 @@
 
 use crate::engine::combat::damage_context::{DamageContext, DamageResult, DamageEffect};
 use crate::engine::combat::type_effectiveness::TypeChart;
+use crate::engine::combat::core::ability_suppression::defending_ability;
+use crate::engine::combat::core::field_system::effective_weather;
 use crate::types::PokemonType;
 use crate::engine::combat::damage::DamageRolls;
 use crate::core::battle_state::Pokemon;
@@ -179,9 +181,12 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
 
     // Early immunity checks 
     
+    // Suppressed abilities and ones the attacker's Mold Breaker ignores don't protect
+    let defender_ability = defending_ability(context.attacker.pokemon, context.defender.pokemon);
+
     // Check for Levitate immunity to Ground-type moves
     if context.move_info.move_type == PokemonType::Ground && 
-       context.defender.pokemon.ability == crate::types::Abilities::LEVITATE {
+       defender_ability == Some(crate::types::Abilities::LEVITATE) {
         return DamageResult {
             damage: 0,
             blocked: true,
@@ -194,7 +199,7 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
     
     // Check for Flash Fire immunity to Fire-type moves (absorbs and boosts)
     if context.move_info.move_type == PokemonType::Fire && 
-       context.defender.pokemon.ability == crate::types::Abilities::FLASHFIRE {
+       defender_ability == Some(crate::types::Abilities::FLASHFIRE) {
         return DamageResult {
             damage: 0,
             blocked: true,
@@ -207,7 +212,7 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
     
     // Check for Water Absorb immunity to Water-type moves
     if context.move_info.move_type == PokemonType::Water && 
-       context.defender.pokemon.ability == crate::types::Abilities::WATERABSORB {
+       defender_ability == Some(crate::types::Abilities::WATERABSORB) {
         return DamageResult {
             damage: 0,
             blocked: true,
@@ -220,7 +225,7 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
     
     // Check for Volt Absorb immunity to Electric-type moves  
     if context.move_info.move_type == PokemonType::Electric && 
-       context.defender.pokemon.ability == crate::types::Abilities::VOLTABSORB {
+       defender_ability == Some(crate::types::Abilities::VOLTABSORB) {
         return DamageResult {
             damage: 0,
             blocked: true,
@@ -291,6 +296,29 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
         }
     };
 
+    // Weather effects, which a defending Utility Umbrella holder ignores for sun and rain
+    let weather = effective_weather(context.field.weather.condition, context.defender.pokemon);
+
+    // Snow raises the Defense of Ice types and sand the Special Defense of Rock types
+    let weather_defense_multiplier = match (weather, context.move_info.category) {
+        (crate::core::instructions::Weather::Snow, crate::core::battle_state::MoveCategory::Physical)
+            if context.defender.pokemon.types.contains(&PokemonType::Ice) => 1.5,
+        (crate::core::instructions::Weather::Sand | crate::core::instructions::Weather::Sandstorm, crate::core::battle_state::MoveCategory::Special)
+            if context.defender.pokemon.types.contains(&PokemonType::Rock) => 1.5,
+        _ => 1.0,
+    };
+    let defense_stat = (defense_stat * weather_defense_multiplier).floor();
+
+    // Sand Force powers up Rock, Ground and Steel moves in sand
+    let sand_force_multiplier = match context.field.weather.condition {
+        crate::core::instructions::Weather::Sand | crate::core::instructions::Weather::Sandstorm
+            if context.attacker.pokemon.ability == crate::types::Abilities::SANDFORCE
+                && !context.attacker.pokemon.ability_suppressed
+                && matches!(context.move_info.move_type, PokemonType::Rock | PokemonType::Ground | PokemonType::Steel) => 1.3,
+        _ => 1.0,
+    };
+    let base_power = (base_power * sand_force_multiplier).floor();
+
     // Calculate base damage using exact poke-engine formula with floor operations
     let mut damage = 2.0 * level;
     damage = damage.floor() / 5.0;
@@ -311,7 +339,7 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
     let damage = base_damage * critical_modifier;
 
     // Type effectiveness calculation
-    let type_chart = TypeChart::get_cached(8); // Modern type chart
+    let type_chart = TypeChart::for_format(&context.format.format);
     let move_type = context.move_info.move_type;
 
     let defender_type1 = context.defender.pokemon.types[0];
@@ -321,12 +349,7 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
         defender_type1
     };
 
-    // Calculate type effectiveness against primary type
-    let mut type_effectiveness = type_chart.get_effectiveness(move_type, defender_type1);
-    // Calculate type effectiveness against secondary type if it exists
-    if defender_type2 != defender_type1 {
-        type_effectiveness *= type_chart.get_effectiveness(move_type, defender_type2);
-    }
+    let mut type_effectiveness = type_chart.move_effectiveness(context.move_info.name, move_type, context.defender.pokemon);
     
     // Handle Mind's Eye ability: allows Normal and Fighting moves to hit Ghost types
     if context.attacker.pokemon.ability == crate::types::Abilities::MINDSEYE {
@@ -356,36 +379,35 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
         false, // Adaptability check would go here
     );
 
-    // Weather effects
     let mut weather_multiplier = 1.0;
-    if let crate::core::instructions::Weather::Sun = context.field.weather.condition {
+    if let crate::core::instructions::Weather::Sun = weather {
         match context.move_info.move_type {
             PokemonType::Fire => {
                 weather_multiplier = 1.5;
                 effects.push(DamageEffect::WeatherEffect {
-                    weather: context.field.weather.condition,
+                    weather,
                 });
             }
             PokemonType::Water => {
                 weather_multiplier = 0.5;
                 effects.push(DamageEffect::WeatherEffect {
-                    weather: context.field.weather.condition,
+                    weather,
                 });
             }
             _ => {}
         }
-    } else if let crate::core::instructions::Weather::Rain = context.field.weather.condition {
+    } else if let crate::core::instructions::Weather::Rain = weather {
         match context.move_info.move_type {
             PokemonType::Water => {
                 weather_multiplier = 1.5;
                 effects.push(DamageEffect::WeatherEffect {
-                    weather: context.field.weather.condition,
+                    weather,
                 });
             }
             PokemonType::Fire => {
                 weather_multiplier = 0.5;
                 effects.push(DamageEffect::WeatherEffect {
-                    weather: context.field.weather.condition,
+                    weather,
                 });
             }
             _ => {}
@@ -414,8 +436,64 @@ pub fn calculate_damage_modern_gen789(context: &DamageContext, damage_rolls: Dam
         1.0
     };
 
+    // Parental Bond's second hit deals a quarter of the damage
+    let parental_bond_multiplier = if context.move_info.is_parental_bond_hit {
+        crate::engine::mechanics::abilities::parental_bond_second_hit_multiplier(
+            context.format.format.generation.number(),
+        )
+    } else {
+        1.0
+    };
+
+    // Glaive Rush leaves the user taking double damage until its next move
+    let glaive_rush_multiplier = if context
+        .defender
+        .pokemon
+        .volatile_statuses
+        .contains(crate::core::instructions::VolatileStatus::GlaiveRush)
+    {
+        2.0
+    } else {
+        1.0
+    };
+
+    // Earthquake into Dig, Surf into Dive and Gust into Fly hit twice as hard
+    let semi_invulnerable_multiplier = crate::engine::combat::moves::special::two_turn::semi_invulnerable_damage_multiplier(
+        context.move_info.name,
+        context.defender.pokemon,
+    );
+
+    // Life Orb boosts every damaging move by 5324/4096
+    let life_orb_multiplier = if context.attacker.pokemon.item == Some(crate::types::Items::LIFEORB) {
+        5324.0 / 4096.0
+    } else {
+        1.0
+    };
+
+    // Resist berries halve the hit they weaken, or quarter it with Ripen, unless a substitute takes it
+    let attacker = context.attacker.pokemon;
+    let hits_substitute = crate::engine::combat::core::substitute_protection::has_substitute(context.defender.pokemon)
+        && !(context.move_info.is_sound && context.format.format.generation.number() >= 6)
+        && !(attacker.ability == crate::types::Abilities::INFILTRATOR && !attacker.ability_suppressed);
+    let resist_berry_multiplier = if hits_substitute {
+        1.0
+    } else {
+        crate::engine::mechanics::items::resist_berry_damage_multiplier(
+            context.defender.pokemon,
+            context.move_info.move_type,
+            type_effectiveness,
+        )
+    };
+
     // Final damage multiplier (combining all remaining modifiers except damage roll)
-    let final_multiplier = spread_multiplier * weather_multiplier * terrain_multiplier;
+    let final_multiplier = spread_multiplier
+        * parental_bond_multiplier
+        * weather_multiplier
+        * terrain_multiplier
+        * glaive_rush_multiplier
+        * semi_invulnerable_multiplier
+        * life_orb_multiplier
+        * resist_berry_multiplier;
 
     // Apply final damage roll using Pokemon's actual damage calculation sequence
     // This follows the exact sequence from damage-calc getFinalDamage function
@@ -40,6 +40,7 @@ pub fn get_screen_damage_modifier(
     let ability_id = attacker.ability;
     let context = AbilityContext {
         user_position: attacker_position,
+        attacker_position: Some(attacker_position),
         target_position: None,
         move_type: None,
         move_id: None,
@@ -23,6 +23,7 @@ pub fn is_weather_negated(state: &BattleState) -> bool {
             
             let context = AbilityContext {
                 user_position: position,
+                attacker_position: None,
                 target_position: None,
                 move_type: None,
                 move_id: None,
@@ -76,6 +76,8 @@ pub struct MoveContext {
     pub is_sound: bool,
     /// Whether this move is a multi-hit move (for Loaded Dice)
     pub is_multihit: bool,
+    /// Whether this is Parental Bond's weaker second hit
+    pub is_parental_bond_hit: bool,
     /// Type of the move (may differ from original due to abilities)
     pub move_type: PokemonType,
     /// Category of the move
@@ -236,6 +238,7 @@ impl<'a> DamageContext<'a> {
             is_punch: move_data.flags.contains_key("punch"),
             is_sound: move_data.flags.contains_key("sound"),
             is_multihit: move_data.flags.contains_key("multihit"),
+            is_parental_bond_hit: move_data.parental_bond_hit,
             move_type: move_data.move_type,
             category: move_data.category,
         };
@@ -447,6 +450,7 @@ impl Default for MoveContext {
             is_punch: false,
             is_sound: false,
             is_multihit: false,
+            is_parental_bond_hit: false,
             move_type: PokemonType::Normal,
             category: MoveCategory::Physical,
         }
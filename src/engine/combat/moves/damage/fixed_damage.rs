@@ -28,10 +28,10 @@ fn apply_fixed_damage_move(
     damage_calculator: FixedDamageCalculator,
     user_position: BattlePosition,
     target_positions: &[BattlePosition],
-    generation: &GenerationMechanics,
+    _generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
     if let Some(user) = state.get_pokemon_at_position(user_position) {
-        let type_chart = TypeChart::get_cached(generation.generation as u8);
+        let type_chart = TypeChart::for_format(&state.format);
         let mut instructions = Vec::new();
         
         for &target_position in target_positions {
@@ -146,10 +146,10 @@ pub fn apply_final_gambit(
     state: &BattleState,
     user_position: BattlePosition,
     target_positions: &[BattlePosition],
-    generation: &GenerationMechanics,
+    _generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
     if let Some(user) = state.get_pokemon_at_position(user_position) {
-        let type_chart = TypeChart::get_cached(generation.generation as u8);
+        let type_chart = TypeChart::for_format(&state.format);
         let user_hp = user.hp;
         let mut instruction_list = Vec::new();
         let mut affected_positions = vec![user_position]; // User always takes damage
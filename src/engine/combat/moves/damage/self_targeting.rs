@@ -9,7 +9,6 @@ use crate::core::battle_format::BattlePosition;
 use crate::generation::GenerationMechanics;
 use crate::data::showdown_types::MoveData;
 use crate::engine::combat::type_effectiveness::TypeChart;
-use crate::types::PokemonType;
 use crate::engine::combat::moves::apply_generic_effects;
 
 // =============================================================================
@@ -110,7 +109,7 @@ pub fn apply_explosion(
         .filter(|&&target_pos| {
             if let Some(target) = state.get_pokemon_at_position(target_pos) {
                 // Check type effectiveness - if it's 0x, the move can't hit
-                !is_move_immune("Normal", target, generation)
+                !is_move_immune(state, move_data, target)
             } else {
                 false
             }
@@ -200,37 +199,9 @@ fn has_damp_ability(state: &BattleState) -> bool {
 
 /// Check if a move is immune against a target due to type effectiveness
 fn is_move_immune(
-    move_type: &str,
+    state: &BattleState,
+    move_data: &MoveData,
     target: &crate::core::battle_state::Pokemon,
-    generation: &GenerationMechanics,
 ) -> bool {
-    let type_chart = TypeChart::get_cached(generation.generation as u8);
-    
-    let attacking_type = match PokemonType::from_normalized_str(move_type) {
-        Some(t) => t,
-        None => return false, // Unknown type, assume not immune
-    };
-    
-    // Get target types from the Vec<PokemonType>
-    let target_type1 = if let Some(pokemon_type) = target.types.get(0) {
-        *pokemon_type
-    } else {
-        return false; // No types defined
-    };
-    
-    let target_type2 = if let Some(pokemon_type) = target.types.get(1) {
-        *pokemon_type
-    } else {
-        target_type1 // Single type Pokemon
-    };
-    
-    // Check type effectiveness
-    let effectiveness = type_chart.calculate_damage_multiplier(
-        attacking_type,
-        (target_type1, target_type2),
-        None, // No tera type for now
-        None, // No special move name handling
-    );
-    
-    effectiveness == 0.0
+    TypeChart::for_format(&state.format).move_effectiveness(move_data.name, move_data.move_type, target) == 0.0
 }
\ No newline at end of file
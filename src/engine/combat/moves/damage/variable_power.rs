@@ -938,7 +938,7 @@ pub fn apply_collision_course(
             let mut modified_move_data = move_data.clone();
 
             // Check if move is super effective against target
-            if is_super_effective(move_type.to_normalized_str(), target, generation) {
+            if TypeChart::for_format(&state.format).move_effectiveness(move_data.name, *move_type, target) > 1.0 {
                 let current_power = modified_move_data.base_power.max(100);
                 modified_move_data.base_power = (current_power as f32 * 1.3) as u16;
             }
@@ -982,55 +982,6 @@ pub fn apply_electro_drift(
     )
 }
 
-/// Apply Freeze-Dry - Ice move that's super effective against Water types
-pub fn apply_freeze_dry(
-    state: &BattleState,
-    move_data: &MoveData,
-    user_position: BattlePosition,
-    target_positions: &[BattlePosition],
-    generation: &GenerationMechanics,
-    branch_on_damage: bool,
-) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-
-    for &target_position in target_positions {
-        if let Some(target) = state.get_pokemon_at_position(target_position) {
-            let mut modified_move_data = move_data.clone();
-
-            // Check if target is Water type - if so, boost damage significantly
-            let has_water_type = FREEZE_DRY_TARGETS.iter().any(|freeze_dry_type| {
-                target.types.iter().any(|target_type| {
-                    crate::utils::normalize_name(target_type.to_normalized_str())
-                        == crate::utils::normalize_name(freeze_dry_type.to_normalized_str())
-                })
-            });
-
-            if has_water_type {
-                // Freeze-Dry treats Water types as if they were weak to Ice
-                // This effectively makes it super effective (2x) against Water
-                let current_power = modified_move_data.base_power.max(70);
-                modified_move_data.base_power = (current_power as f32 * 2.0) as u16;
-            }
-
-            let target_instructions = apply_generic_effects(
-                state,
-                &modified_move_data,
-                user_position,
-                target_positions,
-                generation,
-                branch_on_damage,
-            );
-            instructions.extend(target_instructions);
-        }
-    }
-
-    if instructions.is_empty() {
-        instructions.push(BattleInstructions::new(100.0, vec![]));
-    }
-
-    instructions
-}
-
 /// Apply Hard Press - power decreases as target's HP increases (1-100 power)
 pub fn apply_hard_press(
     state: &BattleState,
@@ -4,9 +4,10 @@
 //! These are essential for competitive play and hazard management.
 
 use crate::core::battle_state::BattleState;
-use crate::core::instructions::{SideCondition, Stat};
+use crate::core::instructions::{SideCondition, Stat, VolatileStatus};
 use crate::core::instructions::{
-    BattleInstruction, BattleInstructions, FieldInstruction, StatsInstruction,
+    BattleInstruction, BattleInstructions, FieldInstruction, PokemonInstruction, StatsInstruction,
+    StatusInstruction,
 };
 use crate::core::battle_format::{BattlePosition, SideReference};
 use crate::generation::GenerationMechanics;
@@ -139,7 +140,7 @@ pub fn apply_court_change(
 
 /// Apply Tidy Up - removes hazards and substitutes, raises Attack and Speed
 pub fn apply_tidy_up(
-    _state: &BattleState,
+    state: &BattleState,
     user_position: BattlePosition,
     _target_positions: &[BattlePosition],
     _generation: &GenerationMechanics,
@@ -157,6 +158,24 @@ pub fn apply_tidy_up(
         }
     }
     
+    // Remove Substitutes from every active Pokemon on both sides
+    for position in state.get_all_active_positions() {
+        if let Some(pokemon) = state.get_pokemon_at_position(position) {
+            if pokemon.volatile_statuses.contains(VolatileStatus::Substitute) {
+                instructions.push(BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+                    target: position,
+                    status: VolatileStatus::Substitute,
+                    previous_duration: None,
+                }));
+                instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth {
+                    target: position,
+                    new_health: 0,
+                    previous_health: pokemon.substitute_health,
+                }));
+            }
+        }
+    }
+    
     // Boost user's Attack and Speed by 1 stage each
     instructions.push(BattleInstruction::Stats(StatsInstruction::BoostStats {
         target: user_position,
@@ -172,6 +172,64 @@ pub fn apply_move_effects(
     repository: &crate::data::GameDataRepository,
     branch_on_damage: bool,
 ) -> BattleResult<Vec<BattleInstructions>> {
+    use crate::engine::combat::core::substitute_protection::status_move_targets;
+
+    // Status moves fail against a Pokemon behind a substitute
+    let unblocked_targets = status_move_targets(state, move_data, user_position, target_positions);
+    if unblocked_targets.is_empty() && !target_positions.is_empty() {
+        return Ok(vec![BattleInstructions::new(100.0, vec![])]);
+    }
+    let target_positions = unblocked_targets.as_slice();
+
+    let mut instruction_sets = apply_single_hit_effects(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        context,
+        repository,
+        branch_on_damage,
+    )?;
+
+    if let Some(user) = state.get_pokemon_at_position(user_position) {
+        if crate::engine::mechanics::abilities::parental_bond_applies(user, move_data, target_positions.len()) {
+            instruction_sets = add_parental_bond_hit(
+                state,
+                move_data,
+                user_position,
+                target_positions,
+                generation,
+                context,
+                repository,
+                branch_on_damage,
+                instruction_sets,
+            )?;
+        }
+    }
+
+    let instruction_sets = apply_self_boosts(move_data, user_position, target_positions, instruction_sets);
+    let instruction_sets = apply_recharge(move_data, user_position, target_positions, instruction_sets);
+    let instruction_sets = apply_stat_change_items(state, user_position, instruction_sets);
+    let instruction_sets = apply_after_hit_berries(state, move_data, user_position, target_positions, instruction_sets);
+    let instruction_sets = apply_drain_and_recoil(state, move_data, user_position, target_positions, instruction_sets);
+    Ok(apply_life_orb_recoil(state, move_data, user_position, target_positions, instruction_sets))
+}
+
+/// Dispatch a single hit of a move through the registry, falling back to generic effects
+#[allow(clippy::too_many_arguments)]
+fn apply_single_hit_effects(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    context: &MoveContext,
+    repository: &crate::data::GameDataRepository,
+    branch_on_damage: bool,
+) -> BattleResult<Vec<BattleInstructions>> {
+    use crate::engine::combat::core::substitute_protection::redirect_to_substitutes;
+
     // Use the global registry system for all move dispatching
     let registry = registry::get_move_registry();
     registry.apply_move_effects(
@@ -193,6 +251,359 @@ pub fn apply_move_effects(
             Ok(apply_generic_secondary_effects(state, move_data, user_position, target_positions, generation))
         }
     })
+    .map(|instruction_sets| redirect_to_substitutes(state, move_data, user_position, target_positions, instruction_sets))
+}
+
+/// Check if the instruction is the move hitting one of its targets, or a substitute in its place
+fn hits_target(instruction: &BattleInstruction, user_position: BattlePosition, target_positions: &[BattlePosition]) -> bool {
+    matches!(
+        instruction,
+        BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. } | PokemonInstruction::DamageSubstitute { target, .. })
+            if *target != user_position && target_positions.contains(target)
+    )
+}
+
+/// Parental Bond - follow every first-hit branch with a weaker second hit
+///
+/// The second hit is generated from the state after the first hit, so it gets its own
+/// damage roll and secondary roll, and is skipped when either Pokemon has fainted.
+#[allow(clippy::too_many_arguments)]
+fn add_parental_bond_hit(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    context: &MoveContext,
+    repository: &crate::data::GameDataRepository,
+    branch_on_damage: bool,
+    first_hit: Vec<BattleInstructions>,
+) -> BattleResult<Vec<BattleInstructions>> {
+    let second_hit_data = MoveData {
+        parental_bond_hit: true,
+        ..move_data.clone()
+    };
+
+    let mut combined = Vec::with_capacity(first_hit.len());
+    for first in first_hit {
+        let landed = first.instruction_list.iter().any(|instruction| hits_target(instruction, user_position, target_positions));
+
+        let mut after_first = state.clone();
+        after_first.apply_instructions(&first.instruction_list);
+        let still_standing = |position: BattlePosition| {
+            after_first.get_pokemon_at_position(position).is_some_and(|pokemon| pokemon.hp > 0)
+        };
+
+        if !landed || !still_standing(user_position) || !target_positions.iter().all(|&target| still_standing(target)) {
+            combined.push(first);
+            continue;
+        }
+
+        let second_hit = apply_single_hit_effects(
+            &after_first,
+            &second_hit_data,
+            user_position,
+            target_positions,
+            generation,
+            context,
+            repository,
+            branch_on_damage,
+        )?;
+
+        for second in second_hit {
+            let mut instruction_list = first.instruction_list.clone();
+            instruction_list.extend(second.instruction_list);
+            let mut affected_positions = first.affected_positions.clone();
+            affected_positions.extend(second.affected_positions);
+            affected_positions.sort();
+            affected_positions.dedup();
+            combined.push(BattleInstructions::new_with_positions(
+                first.percentage * second.percentage / 100.0,
+                instruction_list,
+                affected_positions,
+            ));
+        }
+    }
+
+    Ok(combined)
+}
+
+/// Self stat changes of damaging moves (Close Combat, Overheat, ...) once per move that landed
+fn apply_self_boosts(
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    use crate::core::instructions::{Stat, StatsInstruction};
+
+    let boosts = match move_data.self_.as_ref().and_then(|self_effect| self_effect.boosts.as_ref()) {
+        Some(boosts) if move_data.base_power > 0 => boosts,
+        _ => return instruction_sets,
+    };
+
+    let mut stat_changes = StatBoostArray::default();
+    for (stat_name, change) in boosts {
+        let stat = match stat_name.as_str() {
+            "atk" => Stat::Attack,
+            "def" => Stat::Defense,
+            "spa" => Stat::SpecialAttack,
+            "spd" => Stat::SpecialDefense,
+            "spe" => Stat::Speed,
+            "accuracy" => Stat::Accuracy,
+            "evasion" => Stat::Evasion,
+            _ => continue,
+        };
+        stat_changes.insert(stat, *change);
+    }
+    if stat_changes.is_empty() {
+        return instruction_sets;
+    }
+
+    for branch in &mut instruction_sets {
+        let landed = branch.instruction_list.iter().any(|instruction| hits_target(instruction, user_position, target_positions));
+        if landed {
+            branch.instruction_list.push(BattleInstruction::Stats(StatsInstruction::BoostStats {
+                target: user_position,
+                stat_changes: stat_changes.to_hashmap(),
+                previous_boosts: std::collections::HashMap::new(),
+            }));
+            if !branch.affected_positions.contains(&user_position) {
+                branch.affected_positions.push(user_position);
+            }
+        }
+    }
+    instruction_sets
+}
+
+/// Hyper Beam and the other recharge moves leave the user unable to act next turn once they land
+fn apply_recharge(
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    use crate::core::instructions::{StatusInstruction, VolatileStatus};
+
+    if !move_data.flags.contains_key("recharge") {
+        return instruction_sets;
+    }
+
+    for branch in &mut instruction_sets {
+        let landed = branch.instruction_list.iter().any(|instruction| hits_target(instruction, user_position, target_positions));
+        if landed {
+            branch.instruction_list.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+                target: user_position,
+                status: VolatileStatus::MustRecharge,
+                duration: Some(1),
+                previous_had_status: false,
+                previous_duration: None,
+            }));
+        }
+    }
+    instruction_sets
+}
+
+/// Clear Amulet and Mirror Herb reacting to the stat changes a move makes
+///
+/// Clear Amulet holders ignore the drops the user inflicts on them, and Mirror
+/// Herb holders copy the raises their opponents get.
+fn apply_stat_change_items(
+    state: &BattleState,
+    user_position: BattlePosition,
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    use crate::engine::mechanics::items::{mirror_herb_instructions, remove_clear_amulet_drops};
+
+    for branch in &mut instruction_sets {
+        let instructions = std::mem::take(&mut branch.instruction_list);
+        branch.instruction_list = remove_clear_amulet_drops(state, user_position, instructions);
+
+        let copies = mirror_herb_instructions(state, &branch.instruction_list);
+        for instruction in &copies {
+            for position in instruction.affected_positions(&state.format) {
+                if !branch.affected_positions.contains(&position) {
+                    branch.affected_positions.push(position);
+                }
+            }
+        }
+        branch.instruction_list.extend(copies);
+    }
+    instruction_sets
+}
+
+/// Berries the targets eat after the move hits them, given the damage each took in the branch
+fn apply_after_hit_berries(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    use crate::engine::combat::core::substitute_protection::behind_substitute;
+    use crate::engine::mechanics::items::after_hit_berry_instructions;
+    use crate::engine::combat::type_effectiveness::TypeChart;
+
+    let user = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) => pokemon,
+        None => return instruction_sets,
+    };
+    let generation = state.get_generation_mechanics();
+    let type_chart = TypeChart::for_format(&state.format);
+
+    for branch in &mut instruction_sets {
+        let mut berries = Vec::new();
+        for &target_position in target_positions.iter().filter(|&&position| position != user_position) {
+            let target = match state.get_pokemon_at_position(target_position) {
+                Some(pokemon) if !behind_substitute(state, move_data, user_position, target_position) => pokemon,
+                _ => continue,
+            };
+            let damage: i16 = branch
+                .instruction_list
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. }) if *target == target_position => Some(*amount),
+                    _ => None,
+                })
+                .sum();
+            berries.extend(after_hit_berry_instructions(
+                target,
+                target_position,
+                user,
+                user_position,
+                move_data.move_type,
+                type_chart.move_effectiveness(move_data.name, move_data.move_type, target),
+                move_data.category,
+                damage,
+                &generation,
+            ));
+        }
+        for instruction in &berries {
+            for position in instruction.affected_positions(&state.format) {
+                if !branch.affected_positions.contains(&position) {
+                    branch.affected_positions.push(position);
+                }
+            }
+        }
+        branch.instruction_list.extend(berries);
+    }
+    instruction_sets
+}
+
+/// Drain healing and recoil from the move data, based on the damage the move dealt
+///
+/// Damage to a substitute counts, but only as much as the substitute had left.
+/// Big Root strengthens draining, Liquid Ooze turns it into damage, and Rock
+/// Head and Magic Guard prevent recoil.
+fn apply_drain_and_recoil(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    use crate::engine::combat::core::substitute_protection::damage_dealt_to;
+    use crate::types::{Abilities, Items};
+
+    if move_data.drain.is_none() && move_data.recoil.is_none() {
+        return instruction_sets;
+    }
+    let user = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) => pokemon,
+        None => return instruction_sets,
+    };
+    let user_ability = if user.ability_suppressed { Abilities::NONE } else { user.ability };
+
+    for branch in &mut instruction_sets {
+        let mut instructions = Vec::new();
+        for &target_position in target_positions.iter().filter(|&&position| position != user_position) {
+            let dealt = damage_dealt_to(state, target_position, &branch.instruction_list) as f32;
+            if dealt <= 0.0 {
+                continue;
+            }
+
+            if let Some([numerator, denominator]) = move_data.drain {
+                let mut amount = (dealt * numerator as f32 / denominator as f32).round();
+                if user.item == Some(Items::BIGROOT) {
+                    amount = (amount * 1.3).floor();
+                }
+                let amount = (amount as i16).max(1);
+                let liquid_ooze = state
+                    .get_pokemon_at_position(target_position)
+                    .is_some_and(|target| target.ability == Abilities::LIQUIDOOZE && !target.ability_suppressed);
+                if liquid_ooze {
+                    if user_ability != Abilities::MAGICGUARD {
+                        instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                            target: user_position,
+                            amount,
+                            previous_hp: None,
+                        }));
+                    }
+                } else if user.hp < user.max_hp {
+                    instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Heal {
+                        target: user_position,
+                        amount,
+                        previous_hp: Some(user.hp),
+                    }));
+                }
+            }
+
+            if let Some([numerator, denominator]) = move_data.recoil {
+                if !matches!(user_ability, Abilities::ROCKHEAD | Abilities::MAGICGUARD) {
+                    let amount = ((dealt * numerator as f32 / denominator as f32).round() as i16).max(1);
+                    instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                        target: user_position,
+                        amount,
+                        previous_hp: None,
+                    }));
+                }
+            }
+        }
+
+        if !instructions.is_empty() && !branch.affected_positions.contains(&user_position) {
+            branch.affected_positions.push(user_position);
+        }
+        branch.instruction_list.extend(instructions);
+    }
+    instruction_sets
+}
+
+/// Life Orb recoil - 1/10 of the user's max HP once per move that dealt damage
+///
+/// Sheer Force (when it activates) and Magic Guard prevent the recoil.
+fn apply_life_orb_recoil(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    mut instruction_sets: Vec<BattleInstructions>,
+) -> Vec<BattleInstructions> {
+    let user = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) => pokemon,
+        None => return instruction_sets,
+    };
+    if user.item != Some(crate::types::Items::LIFEORB)
+        || user.ability == crate::types::Abilities::MAGICGUARD
+        || crate::engine::mechanics::abilities::sheer_force_active(user, move_data)
+    {
+        return instruction_sets;
+    }
+
+    let recoil = (user.max_hp / 10).max(1);
+    for branch in &mut instruction_sets {
+        let dealt_damage = branch.instruction_list.iter().any(|instruction| hits_target(instruction, user_position, target_positions));
+        if dealt_damage {
+            branch.instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: user_position,
+                amount: recoil,
+                previous_hp: None,
+            }));
+            if !branch.affected_positions.contains(&user_position) {
+                branch.affected_positions.push(user_position);
+            }
+        }
+    }
+    instruction_sets
 }
 
 /// Helper function for moves that don't need context
@@ -557,6 +968,20 @@ fn apply_secondary_effects(
 
     let mut instructions = Vec::new();
     let probability = secondary.chance as f32;
+
+    // A Covert Cloak keeps the effects off its holder
+    let target_positions: Vec<BattlePosition> = target_positions
+        .iter()
+        .copied()
+        .filter(|&target| {
+            !state
+                .get_pokemon_at_position(target)
+                .is_some_and(crate::engine::mechanics::items::blocks_secondary_effects)
+        })
+        .collect();
+    if target_positions.is_empty() {
+        return vec![BattleInstructions::new(100.0, vec![])];
+    }
     
     // Miss chance
     if probability < 100.0 {
@@ -568,7 +993,7 @@ fn apply_secondary_effects(
     
     // Handle status effects
     if let Some(status_name) = &secondary.status {
-        for &target in target_positions {
+        for &target in &target_positions {
             let status = match status_name {
                 PokemonStatus::Paralysis => PokemonStatus::Paralysis,
                 PokemonStatus::Sleep => PokemonStatus::Sleep,
@@ -595,7 +1020,7 @@ fn apply_secondary_effects(
     
     // Handle stat boosts/drops
     if let Some(boosts) = &secondary.boosts {
-        for &target in target_positions {
+        for &target in &target_positions {
             let mut stat_changes = StatBoostArray::default();
             
             for (stat_name, change) in boosts {
@@ -623,7 +1048,7 @@ fn apply_secondary_effects(
     }
     
     if probability > 0.0 && !effect_instructions.is_empty() {
-        let affected_positions = target_positions.to_vec();
+        let affected_positions = target_positions.clone();
         instructions.push(BattleInstructions::new_with_positions(probability, effect_instructions, affected_positions));
     }
     
@@ -22,7 +22,7 @@ use super::status::status_effects::{
 use super::status::stat_modifying::{
     apply_swords_dance, apply_dragon_dance, apply_nasty_plot, apply_agility,
     apply_growl, apply_leer, apply_tail_whip, apply_string_shot, apply_acid,
-    apply_charm, apply_growth, apply_fillet_away, apply_clangorous_soul
+    apply_charm, apply_growth, apply_fillet_away, apply_clangorous_soul, apply_tar_shot
 };
 
 use super::status::healing::{
@@ -44,7 +44,7 @@ use super::field::hazards::{
 };
 
 use super::field::hazard_removal::{
-    apply_rapid_spin, apply_defog
+    apply_rapid_spin, apply_defog, apply_tidy_up
 };
 
 use super::damage::multi_hit::{
@@ -56,19 +56,23 @@ use super::damage::multi_hit::{
 
 
 use super::special::protection::{
-    apply_protect, apply_detect, apply_endure
+    apply_protect, apply_detect, apply_endure, apply_magic_coat, apply_kings_shield,
+    apply_spiky_shield, apply_baneful_bunker, apply_silk_trap, apply_burning_bulwark,
+    apply_obstruct, apply_max_guard, apply_quick_guard, apply_wide_guard,
+    apply_crafty_shield, apply_mat_block
 };
 
 // Additional imports for complex moves from the original match statement
 use super::damage::variable_power;
 use super::damage::{fixed_damage, self_targeting, multi_hit};
-use super::special::{complex, counter};
+use super::special::{complex, counter, substitute, trapping, two_turn, utility};
 use super::special_combat::{
     apply_body_press, apply_foul_play, apply_photon_geyser, apply_sky_drop
 };
 use super::secondary_effects::{
     apply_flamethrower, apply_fire_blast, apply_thunderbolt, apply_ice_beam,
-    apply_sludge_bomb, apply_air_slash, apply_iron_head, apply_rock_slide
+    apply_sludge_bomb, apply_air_slash, apply_iron_head, apply_rock_slide,
+    apply_salt_cure, apply_syrup_bomb, apply_freeze_dry
 };
 use super::status::healing;
 
@@ -162,6 +166,7 @@ impl MoveRegistry {
         self.register(Moves::GROWTH, adapt_simple_move(apply_growth));
         self.register(Moves::FILLETAWAY, adapt_simple_move(apply_fillet_away));
         self.register(Moves::CLANGOROUSSOUL, adapt_simple_move(apply_clangorous_soul));
+        self.register(Moves::TARSHOT, adapt_simple_move(apply_tar_shot));
 
         // Healing moves
         self.register(Moves::RECOVER, adapt_simple_move(apply_recover));
@@ -181,6 +186,12 @@ impl MoveRegistry {
         self.register(Moves::SANDSTORM, adapt_simple_move(apply_sandstorm));
         self.register(Moves::HAIL, adapt_simple_move(apply_hail));
 
+        // Room moves
+        self.register(Moves::TRICKROOM, adapt_simple_move(super::simple::apply_trick_room));
+
+        // Item moves
+        self.register(Moves::RECYCLE, adapt_simple_move(super::status::item_interaction::apply_recycle));
+
         // Screen moves
         self.register(Moves::REFLECT, adapt_simple_move(apply_reflect));
         self.register(Moves::LIGHTSCREEN, adapt_simple_move(apply_light_screen));
@@ -195,6 +206,7 @@ impl MoveRegistry {
         // Hazard removal moves
         self.register(Moves::RAPIDSPIN, adapt_simple_move(apply_rapid_spin));
         self.register(Moves::DEFOG, adapt_simple_move(apply_defog));
+        self.register(Moves::TIDYUP, adapt_simple_move(apply_tidy_up));
 
         // Multi-hit moves (use variable power adapter)
         self.register(Moves::SURGINGSTRIKES, adapt_variable_power_move(multi_hit::apply_surging_strikes));
@@ -221,6 +233,23 @@ impl MoveRegistry {
         self.register(Moves::PROTECT, adapt_simple_move(apply_protect));
         self.register(Moves::DETECT, adapt_simple_move(apply_detect));
         self.register(Moves::ENDURE, adapt_simple_move(apply_endure));
+        self.register(Moves::MAGICCOAT, adapt_simple_move(apply_magic_coat));
+        self.register(Moves::KINGSSHIELD, adapt_simple_move(apply_kings_shield));
+        self.register(Moves::SPIKYSHIELD, adapt_simple_move(apply_spiky_shield));
+        self.register(Moves::BANEFULBUNKER, adapt_simple_move(apply_baneful_bunker));
+        self.register(Moves::SILKTRAP, adapt_simple_move(apply_silk_trap));
+        self.register(Moves::BURNINGBULWARK, adapt_simple_move(apply_burning_bulwark));
+        self.register(Moves::OBSTRUCT, adapt_simple_move(apply_obstruct));
+        self.register(Moves::MAXGUARD, adapt_simple_move(apply_max_guard));
+        self.register(Moves::QUICKGUARD, adapt_simple_move(apply_quick_guard));
+        self.register(Moves::WIDEGUARD, adapt_simple_move(apply_wide_guard));
+        self.register(Moves::CRAFTYSHIELD, adapt_simple_move(apply_crafty_shield));
+        self.register(Moves::MATBLOCK, adapt_simple_move(apply_mat_block));
+
+        // Substitute and the moves that pass it on
+        self.register(Moves::SUBSTITUTE, adapt_simple_move(substitute::apply_substitute));
+        self.register(Moves::SHEDTAIL, adapt_simple_move(substitute::apply_shed_tail));
+        self.register(Moves::BATONPASS, adapt_simple_move(complex::apply_baton_pass));
 
         // Variable power moves (with branching support)
         self.register(Moves::FACADE, adapt_variable_power_move(variable_power::apply_facade));
@@ -243,7 +272,7 @@ impl MoveRegistry {
         self.register(Moves::BARBBARRAGE, adapt_variable_power_move(variable_power::apply_barb_barrage));
         self.register(Moves::COLLISIONCOURSE, adapt_variable_power_move(variable_power::apply_collision_course));
         self.register(Moves::ELECTRODRIFT, adapt_variable_power_move(variable_power::apply_electro_drift));
-        self.register(Moves::FREEZEDRY, adapt_variable_power_move(variable_power::apply_freeze_dry));
+        self.register(Moves::FREEZEDRY, adapt_extended_move(apply_freeze_dry));
         self.register(Moves::HARDPRESS, adapt_variable_power_move(variable_power::apply_hard_press));
         self.register(Moves::HYDROSTEAM, adapt_variable_power_move(variable_power::apply_hydro_steam));
         self.register(Moves::LASTRESPECTS, adapt_variable_power_move(variable_power::apply_last_respects));
@@ -295,6 +324,54 @@ impl MoveRegistry {
         self.register(Moves::IRONHEAD, adapt_extended_move(apply_iron_head));
         self.register(Moves::ROCKSLIDE, adapt_extended_move(apply_rock_slide));
 
+        // Lingering volatile moves (residuals handled at end of turn)
+        self.register(Moves::SALTCURE, adapt_variable_power_move(apply_salt_cure));
+        self.register(Moves::SYRUPBOMB, adapt_variable_power_move(apply_syrup_bomb));
+        self.register(Moves::GLAIVERUSH, adapt_variable_power_move(complex::apply_glaive_rush));
+        self.register(Moves::LEECHSEED, adapt_simple_move(complex::apply_leech_seed));
+        self.register(Moves::YAWN, adapt_simple_move(complex::apply_yawn));
+        self.register(Moves::AQUARING, adapt_simple_move(healing::apply_aqua_ring));
+        self.register(Moves::PERISHSONG, adapt_simple_move(super::simple::apply_perish_song));
+
+        // Two-turn moves
+        self.register(Moves::SOLARBEAM, adapt_variable_power_move(two_turn::apply_solar_beam));
+        self.register(Moves::SOLARBLADE, adapt_variable_power_move(two_turn::apply_solar_blade));
+        self.register(Moves::METEORBEAM, adapt_variable_power_move(two_turn::apply_meteor_beam));
+        self.register(Moves::ELECTROSHOT, adapt_variable_power_move(two_turn::apply_electro_shot));
+        self.register(Moves::DIG, adapt_variable_power_move(two_turn::apply_dig));
+        self.register(Moves::FLY, adapt_variable_power_move(two_turn::apply_fly));
+        self.register(Moves::BOUNCE, adapt_variable_power_move(two_turn::apply_bounce));
+        self.register(Moves::DIVE, adapt_variable_power_move(two_turn::apply_dive));
+        self.register(Moves::PHANTOMFORCE, adapt_variable_power_move(two_turn::apply_phantom_force));
+        self.register(Moves::SHADOWFORCE, adapt_variable_power_move(two_turn::apply_shadow_force));
+        self.register(Moves::RAZORWIND, adapt_variable_power_move(two_turn::apply_razor_wind));
+        self.register(Moves::SKULLBASH, adapt_variable_power_move(two_turn::apply_skull_bash));
+        self.register(Moves::SKYATTACK, adapt_variable_power_move(two_turn::apply_sky_attack));
+        self.register(Moves::FREEZESHOCK, adapt_variable_power_move(two_turn::apply_freeze_shock));
+        self.register(Moves::ICEBURN, adapt_variable_power_move(two_turn::apply_freeze_shock));
+
+        // Trapping moves
+        self.register(Moves::MEANLOOK, adapt_simple_move(trapping::apply_mean_look));
+        self.register(Moves::BLOCK, adapt_simple_move(trapping::apply_mean_look));
+        self.register(Moves::SPIDERWEB, adapt_simple_move(trapping::apply_mean_look));
+        self.register(Moves::OCTOLOCK, adapt_simple_move(trapping::apply_octolock));
+        self.register(Moves::SPIRITSHACKLE, adapt_variable_power_move(trapping::apply_trapping_hit));
+        self.register(Moves::ANCHORSHOT, adapt_variable_power_move(trapping::apply_trapping_hit));
+        self.register(Moves::THOUSANDWAVES, adapt_variable_power_move(trapping::apply_trapping_hit));
+        self.register(Moves::JAWLOCK, adapt_variable_power_move(trapping::apply_jaw_lock));
+        for partial_trap in [
+            Moves::BIND, Moves::WRAP, Moves::FIRESPIN, Moves::WHIRLPOOL, Moves::SANDTOMB,
+            Moves::CLAMP, Moves::MAGMASTORM, Moves::INFESTATION, Moves::SNAPTRAP, Moves::THUNDERCAGE,
+        ] {
+            self.register(partial_trap, adapt_variable_power_move(trapping::apply_partial_trap));
+        }
+
+        // Party and ability manipulation moves
+        self.register(Moves::REVIVALBLESSING, adapt_simple_move(complex::apply_revival_blessing));
+        self.register(Moves::DOODLE, adapt_simple_move(utility::apply_doodle));
+        self.register(Moves::GASTROACID, adapt_simple_move(utility::apply_gastro_acid));
+        self.register(Moves::COREENFORCER, adapt_context_aware_move(utility::apply_core_enforcer));
+
         // Counter moves
         self.register(Moves::COUNTER, adapt_simple_move(counter::apply_counter));
         self.register(Moves::MIRRORCOAT, adapt_simple_move(counter::apply_mirror_coat));
@@ -435,6 +512,32 @@ mod tests {
         assert!(!registry.is_move_registered(&Moves::NONE));
     }
 
+    #[test]
+    fn test_trapping_moves_registered() {
+        let registry = MoveRegistry::new();
+
+        for move_enum in [
+            Moves::MEANLOOK,
+            Moves::BLOCK,
+            Moves::SPIRITSHACKLE,
+            Moves::JAWLOCK,
+            Moves::OCTOLOCK,
+            Moves::WRAP,
+            Moves::FIRESPIN,
+        ] {
+            assert!(registry.is_move_registered(&move_enum), "{:?} should be registered", move_enum);
+        }
+    }
+
+    #[test]
+    fn test_ability_suppression_moves_registered() {
+        let registry = MoveRegistry::new();
+
+        for move_enum in [Moves::GASTROACID, Moves::COREENFORCER] {
+            assert!(registry.is_move_registered(&move_enum), "{:?} should be registered", move_enum);
+        }
+    }
+
     #[test]
     fn test_global_registry() {
         let registry1 = get_move_registry();
@@ -12,6 +12,8 @@ use crate::core::instructions::{BattleInstruction, BattleInstructions, PokemonSt
 use crate::data::showdown_types::MoveData;
 use crate::engine::combat::composers::damage_moves::{damage_move_with_secondary_status, damage_move_with_secondary_volatile_status};
 use crate::engine::combat::core::status_system::{StatusApplication, VolatileStatusApplication};
+use crate::engine::mechanics::abilities::sheer_force_active;
+use crate::engine::mechanics::items::blocks_secondary_effects;
 use crate::generation::GenerationMechanics;
 
 // =============================================================================
@@ -60,6 +62,9 @@ macro_rules! flinch_move {
         ) -> Vec<BattleInstructions> {
             use crate::core::instructions::{BattleInstruction, StatusInstruction, VolatileStatus};
             
+            if let Some(sheer_force_instructions) = sheer_force_hit(state, move_data, user_position, target_positions, generation) {
+                return sheer_force_instructions;
+            }
             
             let mut instruction_sets = Vec::new();
             
@@ -111,8 +116,11 @@ macro_rules! flinch_move {
                 // Add flinch status to all targets (with speed check)
                 for &target_position in target_positions {
                     if let Some(target_pokemon) = state.get_pokemon_at_position(target_position) {
-                        // Only apply flinch if target hasn't moved yet this turn and isn't already flinched
-                        if !target_pokemon.volatile_statuses.contains(VolatileStatus::Flinch) {
+                        // Only apply flinch if target hasn't moved yet this turn, isn't already flinched
+                        // and has no Covert Cloak
+                        if !target_pokemon.volatile_statuses.contains(VolatileStatus::Flinch)
+                            && !blocks_secondary_effects(target_pokemon)
+                        {
                             // Check if user is faster than target (speed-aware flinch)
                             let can_flinch = is_user_faster_than_target(state, user_position, target_position);
                             if can_flinch {
@@ -222,6 +230,9 @@ pub fn apply_acid_damage(
     target_positions: &[BattlePosition],
     generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
+    if let Some(sheer_force_instructions) = sheer_force_hit(state, move_data, user_position, target_positions, generation) {
+        return sheer_force_instructions;
+    }
     use crate::engine::combat::composers::damage_moves::{simple_damage_move, DamageModifiers};
     use std::collections::HashMap;
     
@@ -571,6 +582,123 @@ pub fn apply_ice_fang_dual(
     all_instructions
 }
 
+// =============================================================================
+// LINGERING VOLATILE EFFECTS
+// =============================================================================
+
+/// Salt Cure - damages and inflicts a lingering residual on the target
+/// The residual itself (1/8 max HP, 1/4 vs Water/Steel) is applied at end of turn
+pub fn apply_salt_cure(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    damage_with_lingering_volatile(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+        VolatileStatus::SaltCure,
+        None,
+    )
+}
+
+/// Syrup Bomb - damages and lowers the target's Speed at the end of the next three turns
+pub fn apply_syrup_bomb(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    damage_with_lingering_volatile(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+        VolatileStatus::SyrupBomb,
+        Some(3),
+    )
+}
+
+/// Deal the move's damage, then attach a volatile to every target that doesn't already have it
+#[allow(clippy::too_many_arguments)]
+fn damage_with_lingering_volatile(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+    status: VolatileStatus,
+    duration: Option<u8>,
+) -> Vec<BattleInstructions> {
+    use crate::core::instructions::StatusInstruction;
+
+    let sheer_force = state
+        .get_pokemon_at_position(user_position)
+        .is_some_and(|user| sheer_force_active(user, move_data));
+
+    let mut volatile_instructions = Vec::new();
+    for &target_position in target_positions.iter().filter(|_| !sheer_force) {
+        if let Some(target) = state.get_pokemon_at_position(target_position) {
+            if !target.volatile_statuses.contains(status) && !blocks_secondary_effects(target) {
+                volatile_instructions.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+                    target: target_position,
+                    status,
+                    duration,
+                    previous_had_status: false,
+                    previous_duration: None,
+                }));
+            }
+        }
+    }
+
+    let mut instructions = crate::engine::combat::moves::apply_generic_effects(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+    );
+    for branch in &mut instructions {
+        branch.instruction_list.extend(volatile_instructions.iter().cloned());
+    }
+    instructions
+}
+
+/// Plain damage with no secondary branches when the user's Sheer Force drops them
+fn sheer_force_hit(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+) -> Option<Vec<BattleInstructions>> {
+    let user = state.get_pokemon_at_position(user_position)?;
+    if !sheer_force_active(user, move_data) {
+        return None;
+    }
+    let instructions = crate::engine::combat::composers::damage_moves::simple_damage_move(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        crate::engine::combat::composers::damage_moves::DamageModifiers::default(),
+        generation,
+    );
+    Some(vec![BattleInstructions::new(100.0, instructions)])
+}
+
 /// Check if the user is faster than the target for speed-aware flinch application
 fn is_user_faster_than_target(
     state: &BattleState,
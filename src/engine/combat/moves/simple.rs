@@ -133,19 +133,28 @@ pub fn apply_tailwind(
 
 /// Apply Trick Room - reverses speed priority for 5 turns
 pub fn apply_trick_room(
-    _state: &BattleState,
-    _user_position: BattlePosition,
+    state: &BattleState,
+    user_position: BattlePosition,
     _target_positions: &[BattlePosition],
     _generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
     // Toggle trick room state - if active, turn off; if inactive, turn on for 5 turns
-    let instruction = BattleInstruction::Field(FieldInstruction::TrickRoom {
-        active: true, // Will be properly handled by state application
-        turns: Some(5),
-        source: None,
-        previous_active: false,
-        previous_turns: None,
-    });
+    let instruction = match &state.field.global_effects.trick_room {
+        Some(trick_room) => BattleInstruction::Field(FieldInstruction::TrickRoom {
+            active: false,
+            turns: None,
+            source: None,
+            previous_active: true,
+            previous_turns: Some(trick_room.turns_remaining),
+        }),
+        None => BattleInstruction::Field(FieldInstruction::TrickRoom {
+            active: true,
+            turns: Some(5),
+            source: Some(user_position),
+            previous_active: false,
+            previous_turns: None,
+        }),
+    };
     
     vec![BattleInstructions::new(100.0, vec![instruction])]
 }
@@ -354,7 +363,7 @@ pub fn apply_perish_song(
     instruction_list.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
         target: user_position,
         status: VolatileStatus::Perish3,
-        duration: Some(3),
+        duration: Some(4), // Count shows 3 at the end of this turn, faints at the end of the fourth
         previous_had_status: false,
         previous_duration: None,
     }));
@@ -364,7 +373,7 @@ pub fn apply_perish_song(
         instruction_list.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
             target: target_position,
             status: VolatileStatus::Perish3,
-            duration: Some(3),
+            duration: Some(4),
             previous_had_status: false,
             previous_duration: None,
         }));
@@ -20,19 +20,85 @@ use std::collections::HashMap;
 // COMPLEX MOVES WITH UNIQUE MECHANICS
 // =============================================================================
 
-/// Apply Baton Pass - enables stat boost passing when switching
+/// Apply Baton Pass - switches out, passing stat stages, the substitute and other volatiles
+/// The marker is read when the replacement comes in; Baton Pass fails with nothing to switch to
 pub fn apply_baton_pass(
-    _state: &BattleState,
+    state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    use crate::engine::combat::core::forced_switch::has_switch_target;
+
+    if !has_switch_target(state, user_position.side) {
+        return vec![BattleInstructions::new(100.0, vec![])];
+    }
+    vec![BattleInstructions::new(100.0, vec![
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: user_position,
+            status: VolatileStatus::BatonPass,
+            duration: None,
+            previous_had_status: false,
+            previous_duration: None,
+        })
+    ])]
+}
+
+/// Apply Glaive Rush - deals damage, then leaves the user exposed until its next move
+/// While exposed, moves targeting the user cannot miss and deal double damage
+pub fn apply_glaive_rush(
+    state: &BattleState,
+    move_data: &crate::data::showdown_types::MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    let mut instructions = crate::engine::combat::moves::apply_generic_effects(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+    );
+
+    // Any previous Glaive Rush state is cleared before the move runs, so always re-apply
+    for branch in &mut instructions {
+        branch.instruction_list.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: user_position,
+            status: VolatileStatus::GlaiveRush,
+            duration: None, // Cleared when the user next moves
+            previous_had_status: false,
+            previous_duration: None,
+        }));
+    }
+
+    instructions
+}
+
+/// Apply Revival Blessing - lets the user's side pick a fainted party member to revive
+/// The choice itself is made through `MoveChoice::Revive` on the following decision
+pub fn apply_revival_blessing(
+    state: &BattleState,
     user_position: BattlePosition,
     _target_positions: &[BattlePosition],
     _generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
-    // Baton Pass enables stat boost passing when switching
+    let side = state.get_side_by_ref(user_position.side);
+    let has_fainted_member = side.pokemon.iter().any(|pokemon| pokemon.hp == 0);
+
+    if !has_fainted_member || side.side_conditions.contains_key(&SideCondition::RevivalBlessing) {
+        // Fails when there is nobody to revive
+        return vec![BattleInstructions::new(100.0, vec![])];
+    }
+
     vec![BattleInstructions::new(100.0, vec![
-        BattleInstruction::Field(FieldInstruction::ToggleBatonPassing {
+        BattleInstruction::Field(FieldInstruction::ApplySideCondition {
             side: user_position.side,
-            active: true,
-            previous_state: false, // Assume it was false before
+            condition: SideCondition::RevivalBlessing,
+            duration: 0, // Persists until the revive choice is made
+            previous_duration: None,
         })
     ])]
 }
@@ -189,7 +255,7 @@ pub fn apply_encore(
 
 /// Apply Leech Seed - drains HP every turn
 pub fn apply_leech_seed(
-    _state: &BattleState,
+    state: &BattleState,
     _user_position: BattlePosition,
     target_positions: &[BattlePosition],
     _generation: &GenerationMechanics,
@@ -197,6 +263,14 @@ pub fn apply_leech_seed(
     let mut instructions = Vec::new();
     
     for &target_position in target_positions {
+        // Grass types can't be seeded, and a seeded Pokemon stays seeded once
+        let seedable = state.get_pokemon_at_position(target_position).is_some_and(|target| {
+            !target.types.contains(&PokemonType::Grass)
+                && !target.volatile_statuses.contains(VolatileStatus::LeechSeed)
+        });
+        if !seedable {
+            continue;
+        }
         let instruction = BattleInstruction::Status(StatusInstruction::ApplyVolatile {
             target: target_position,
             status: VolatileStatus::LeechSeed,
@@ -12,6 +12,7 @@ pub mod protection;
 pub mod substitute;
 pub mod type_changing;
 pub mod type_removal;
+pub mod trapping;
 pub mod utility;
 
 pub use two_turn::*;
@@ -23,4 +24,5 @@ pub use protection::*;
 pub use substitute::*;
 pub use type_changing::*;
 pub use type_removal::*;
+pub use trapping::*;
 pub use utility::*;
\ No newline at end of file
@@ -91,48 +91,6 @@ pub fn apply_fake_out(
     instructions
 }
 
-/// Apply Feint - breaks through protection
-pub fn apply_feint(
-    state: &BattleState,
-    _move_data: &MoveData,
-    _user_position: BattlePosition,
-    target_positions: &[BattlePosition],
-    _generation: &GenerationMechanics,
-) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    // Feint removes protection from targets
-    for &target_position in target_positions {
-        if let Some(target_pokemon) = state.get_pokemon_at_position(target_position) {
-            let mut instruction_list = Vec::new();
-            
-            // Remove Protect status
-            if target_pokemon.volatile_statuses.contains(VolatileStatus::Protect) {
-                instruction_list.push(BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: target_position,
-                    status: VolatileStatus::Protect,
-                    previous_duration: None,
-                }));
-            }
-            
-            // Remove other protection statuses
-            if target_pokemon.volatile_statuses.contains(VolatileStatus::Endure) {
-                instruction_list.push(BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: target_position,
-                    status: VolatileStatus::Endure,
-                    previous_duration: None,
-                }));
-            }
-            
-            if !instruction_list.is_empty() {
-                instructions.push(BattleInstructions::new(100.0, instruction_list));
-            }
-        }
-    }
-    
-    instructions
-}
-
 /// Apply First Impression - Bug-type priority, only works on first turn
 pub fn apply_first_impression(
     _state: &BattleState,
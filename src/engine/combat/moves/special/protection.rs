@@ -3,65 +3,208 @@
 //! This module contains implementations for protection and defensive moves.
 
 use crate::core::battle_state::BattleState;
-use crate::core::instructions::VolatileStatus;
-use crate::core::instructions::{BattleInstruction, BattleInstructions, StatusInstruction};
+use crate::core::instructions::{SideCondition, VolatileStatus};
+use crate::core::instructions::{BattleInstruction, BattleInstructions, FieldInstruction, StatusInstruction};
+use crate::engine::combat::core::move_protection::{record_stall, reset_stall, stall_success_chance};
 use crate::core::battle_format::BattlePosition;
 use crate::generation::GenerationMechanics;
 
-/// Apply Protect - protects user from most moves this turn
-pub fn apply_protect(
-    _state: &BattleState,
+/// Shield the user with a protection volatile for the rest of the turn
+///
+/// Each consecutive success makes the next attempt a third as likely, so the
+/// move branches into a success and a failure that resets the run.
+fn stalling_move(
+    state: &BattleState,
     user_position: BattlePosition,
-    target_positions: &[BattlePosition],
-    _generation: &GenerationMechanics,
+    status: VolatileStatus,
 ) -> Vec<BattleInstructions> {
-    let target_position = if target_positions.is_empty() {
-        user_position
-    } else {
-        target_positions[0]
+    let user = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) => pokemon,
+        None => return vec![BattleInstructions::new(100.0, vec![])],
     };
-    
-    let instruction = BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-        target: target_position,
-        status: VolatileStatus::Protect,
-        duration: Some(1), // Lasts for the rest of the turn
-        previous_had_status: false,
-        previous_duration: None,
-    });
-    
-    vec![BattleInstructions::new(100.0, vec![instruction])]
+
+    let chance = stall_success_chance(user);
+    let success = BattleInstructions::new(chance, vec![
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: user_position,
+            status,
+            duration: Some(1), // Lasts for the rest of the turn
+            previous_had_status: false,
+            previous_duration: None,
+        }),
+        record_stall(user, user_position),
+    ]);
+    if chance >= 100.0 {
+        return vec![success];
+    }
+    vec![success, BattleInstructions::new(100.0 - chance, reset_stall(user, user_position))]
 }
 
-/// Apply Detect - same as Protect
-pub fn apply_detect(
+/// Shield the user's whole side with a guard for the rest of the turn
+fn side_guard_move(
     state: &BattleState,
     user_position: BattlePosition,
-    target_positions: &[BattlePosition],
-    generation: &GenerationMechanics,
+    condition: SideCondition,
+) -> Vec<BattleInstruction> {
+    let side = state.get_side_by_ref(user_position.side);
+    vec![BattleInstruction::Field(FieldInstruction::ApplySideCondition {
+        side: user_position.side,
+        condition,
+        duration: 1,
+        previous_duration: side.side_conditions.get(&condition).copied(),
+    })]
+}
+
+/// Quick Guard and Wide Guard never fail to a run of stalls, but they extend it
+fn stall_extending_guard(
+    state: &BattleState,
+    user_position: BattlePosition,
+    condition: SideCondition,
+) -> Vec<BattleInstruction> {
+    let mut instructions = side_guard_move(state, user_position, condition);
+    if let Some(user) = state.get_pokemon_at_position(user_position) {
+        instructions.push(record_stall(user, user_position));
+    }
+    instructions
+}
+
+/// Macro for moves that protect the user with a volatile status
+macro_rules! protection_move {
+    ($(#[$meta:meta])* $func_name:ident, $status:expr) => {
+        $(#[$meta])*
+        pub fn $func_name(
+            state: &BattleState,
+            user_position: BattlePosition,
+            _target_positions: &[BattlePosition],
+            _generation: &GenerationMechanics,
+        ) -> Vec<BattleInstructions> {
+            stalling_move(state, user_position, $status)
+        }
+    };
+}
+
+protection_move!(
+    /// Apply Protect - protects user from most moves this turn
+    apply_protect,
+    VolatileStatus::Protect
+);
+
+protection_move!(
+    /// Apply Detect - same as Protect
+    apply_detect,
+    VolatileStatus::Protect
+);
+
+protection_move!(
+    /// Apply Endure - survives any attack with at least 1 HP
+    apply_endure,
+    VolatileStatus::Endure
+);
+
+protection_move!(
+    /// Apply King's Shield - blocks damaging moves, lowering a contact attacker's Attack
+    apply_kings_shield,
+    VolatileStatus::KingsShield
+);
+
+protection_move!(
+    /// Apply Spiky Shield - protects and hurts a contact attacker for 1/8 of its max HP
+    apply_spiky_shield,
+    VolatileStatus::SpikyShield
+);
+
+protection_move!(
+    /// Apply Baneful Bunker - protects and poisons a contact attacker
+    apply_baneful_bunker,
+    VolatileStatus::BanefulBunker
+);
+
+protection_move!(
+    /// Apply Silk Trap - blocks damaging moves, lowering a contact attacker's Speed
+    apply_silk_trap,
+    VolatileStatus::SilkTrap
+);
+
+protection_move!(
+    /// Apply Burning Bulwark - protects and burns a contact attacker
+    apply_burning_bulwark,
+    VolatileStatus::BurningBulwark
+);
+
+protection_move!(
+    /// Apply Obstruct - blocks damaging moves, sharply lowering a contact attacker's Defense
+    apply_obstruct,
+    VolatileStatus::Obstruct
+);
+
+protection_move!(
+    /// Apply Max Guard - protects from every move, including Feint
+    apply_max_guard,
+    VolatileStatus::MaxGuard
+);
+
+/// Apply Quick Guard - protects the user's side from priority moves this turn
+pub fn apply_quick_guard(
+    state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
-    apply_protect(state, user_position, target_positions, generation)
+    vec![BattleInstructions::new(100.0, stall_extending_guard(state, user_position, SideCondition::QuickGuard))]
 }
 
-/// Apply Endure - survives any attack with at least 1 HP
-pub fn apply_endure(
-    _state: &BattleState,
+/// Apply Wide Guard - protects the user's side from spread moves this turn
+pub fn apply_wide_guard(
+    state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    vec![BattleInstructions::new(100.0, stall_extending_guard(state, user_position, SideCondition::WideGuard))]
+}
+
+/// Apply Crafty Shield - protects the user's side from status moves this turn
+pub fn apply_crafty_shield(
+    state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    vec![BattleInstructions::new(100.0, side_guard_move(state, user_position, SideCondition::CraftyShield))]
+}
+
+/// Apply Mat Block - protects the user's side from damaging moves, only on its first turn out
+pub fn apply_mat_block(
+    state: &BattleState,
     user_position: BattlePosition,
-    target_positions: &[BattlePosition],
+    _target_positions: &[BattlePosition],
     _generation: &GenerationMechanics,
 ) -> Vec<BattleInstructions> {
-    let target_position = if target_positions.is_empty() {
-        user_position
+    let first_turn = state
+        .get_pokemon_at_position(user_position)
+        .is_some_and(|user| user.last_used_move.is_none());
+    if first_turn {
+        vec![BattleInstructions::new(100.0, side_guard_move(state, user_position, SideCondition::MatBlock))]
     } else {
-        target_positions[0]
-    };
-    
+        vec![BattleInstructions::new(100.0, vec![])]
+    }
+}
+
+/// Apply Magic Coat - reflects reflectable status moves back at their user this turn
+/// The reflection itself is handled by the turn engine's move reflection check
+pub fn apply_magic_coat(
+    _state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
     let instruction = BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-        target: target_position,
-        status: VolatileStatus::Endure,
+        target: user_position,
+        status: VolatileStatus::MagicCoat,
         duration: Some(1),
         previous_had_status: false,
         previous_duration: None,
     });
     
     vec![BattleInstructions::new(100.0, vec![instruction])]
-}
\ No newline at end of file
+}
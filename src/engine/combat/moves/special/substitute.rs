@@ -33,9 +33,9 @@ pub fn apply_substitute(
     };
     
     if let Some(pokemon) = state.get_pokemon_at_position(target_position) {
-        // Check if Pokemon has enough HP (need at least 25% max HP)
+        // Check if Pokemon has enough HP (need at least 25% max HP) and no substitute yet
         let cost = pokemon.max_hp / 4;
-        if pokemon.hp > cost {
+        if pokemon.hp > cost && !pokemon.volatile_statuses.contains(VolatileStatus::Substitute) {
             let mut instructions = Vec::new();
             
             // Damage user for 25% of max HP
@@ -69,4 +69,54 @@ pub fn apply_substitute(
     } else {
         vec![BattleInstructions::new(100.0, vec![])]
     }
-}
\ No newline at end of file
+}
+
+/// Apply Shed Tail - pays half the user's max HP for a substitute, then switches out
+/// The substitute is handed to the replacement, which gets a quarter of the user's max HP as its HP
+pub fn apply_shed_tail(
+    state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    use crate::engine::combat::core::forced_switch::has_switch_target;
+
+    let pokemon = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) => pokemon,
+        None => return vec![BattleInstructions::new(100.0, vec![])],
+    };
+    let cost = (pokemon.max_hp + 1) / 2;
+    if pokemon.hp <= cost
+        || pokemon.volatile_statuses.contains(VolatileStatus::Substitute)
+        || !has_switch_target(state, user_position.side)
+    {
+        return vec![BattleInstructions::new(100.0, vec![])];
+    }
+
+    vec![BattleInstructions::new(100.0, vec![
+        BattleInstruction::Pokemon(PokemonInstruction::Damage {
+            target: user_position,
+            amount: cost,
+            previous_hp: Some(pokemon.hp),
+        }),
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: user_position,
+            status: VolatileStatus::Substitute,
+            duration: None,
+            previous_had_status: false,
+            previous_duration: None,
+        }),
+        BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth {
+            target: user_position,
+            new_health: pokemon.max_hp / 4,
+            previous_health: pokemon.substitute_health,
+        }),
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: user_position,
+            status: VolatileStatus::ShedTail,
+            duration: None,
+            previous_had_status: false,
+            previous_duration: None,
+        }),
+    ])]
+}
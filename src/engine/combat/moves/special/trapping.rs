@@ -0,0 +1,207 @@
+//! # Trapping Move Effects
+//!
+//! This module contains the moves that keep their target from switching out:
+//! Mean Look, Block and Spider Web, the damaging traps Spirit Shackle, Anchor
+//! Shot and Thousand Waves, Jaw Lock, Octolock and the partial trapping moves.
+//! Whether a trapped Pokemon may still switch is decided by the trapping system.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::BattleState;
+use crate::core::instructions::{
+    BattleInstruction, BattleInstructions, PokemonInstruction, StatusInstruction, VolatileStatus,
+};
+use crate::data::showdown_types::MoveData;
+use crate::engine::combat::core::trapping::is_immune_to_trapping;
+use crate::generation::GenerationMechanics;
+use crate::types::Items;
+
+/// Partial trapping lasts four more turns, or seven with Grip Claw
+const PARTIAL_TRAP_TURNS: u8 = 4;
+const GRIP_CLAW_TRAP_TURNS: u8 = 7;
+
+/// Apply Mean Look, Block or Spider Web - the target can no longer switch out
+pub fn apply_mean_look(
+    state: &BattleState,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    let instructions = target_positions
+        .iter()
+        .flat_map(|&target_position| trap_instructions(state, target_position, user_position, VolatileStatus::Trapped, None))
+        .collect();
+
+    vec![BattleInstructions::new(100.0, instructions)]
+}
+
+/// Apply Octolock - traps the target and lowers its defenses at the end of each turn
+pub fn apply_octolock(
+    state: &BattleState,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    let instructions = target_positions
+        .iter()
+        .flat_map(|&target_position| trap_instructions(state, target_position, user_position, VolatileStatus::Octolock, None))
+        .collect();
+
+    vec![BattleInstructions::new(100.0, instructions)]
+}
+
+/// Apply Spirit Shackle, Anchor Shot or Thousand Waves - damages and traps the target
+pub fn apply_trapping_hit(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    damage_and_trap(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+        VolatileStatus::Trapped,
+        None,
+    )
+}
+
+/// Apply Jaw Lock - damages the target, then neither Pokemon can switch out
+pub fn apply_jaw_lock(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    let mut instructions = damage_and_trap(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+        VolatileStatus::Trapped,
+        None,
+    );
+
+    for branch in &mut instructions {
+        let trapped_target = branch.instruction_list.iter().find_map(|instruction| match instruction {
+            BattleInstruction::Status(StatusInstruction::ApplyVolatile { target, status: VolatileStatus::Trapped, .. })
+                if *target != user_position =>
+            {
+                Some(*target)
+            }
+            _ => None,
+        });
+        // The user stays for as long as the Pokemon it bit does
+        if let Some(trapped_target) = trapped_target {
+            branch.instruction_list.extend(trap_instructions(state, user_position, trapped_target, VolatileStatus::Trapped, None));
+        }
+    }
+    instructions
+}
+
+/// Apply Wrap, Bind, Fire Spin and the other partial trapping moves
+///
+/// The target takes residual damage each turn and can't switch out until the trap ends.
+pub fn apply_partial_trap(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    let turns = match state.get_pokemon_at_position(user_position) {
+        Some(user) if user.item == Some(Items::GRIPCLAW) => GRIP_CLAW_TRAP_TURNS,
+        _ => PARTIAL_TRAP_TURNS,
+    };
+
+    damage_and_trap(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+        VolatileStatus::PartiallyTrapped,
+        Some(turns),
+    )
+}
+
+/// Deal the move's damage, then trap every target the move damaged
+#[allow(clippy::too_many_arguments)]
+fn damage_and_trap(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+    status: VolatileStatus,
+    duration: Option<u8>,
+) -> Vec<BattleInstructions> {
+    let mut instructions = crate::engine::combat::moves::apply_generic_effects(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+    );
+
+    for branch in &mut instructions {
+        let damaged: Vec<BattlePosition> = branch
+            .instruction_list
+            .iter()
+            .filter_map(|instruction| match instruction {
+                BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. })
+                    if *target != user_position && target_positions.contains(target) =>
+                {
+                    Some(*target)
+                }
+                _ => None,
+            })
+            .collect();
+
+        for target_position in target_positions.iter().filter(|position| damaged.contains(position)) {
+            branch.instruction_list.extend(trap_instructions(state, *target_position, user_position, status, duration));
+        }
+    }
+    instructions
+}
+
+/// Trap a Pokemon unless it's already trapped this way or immune to trapping
+///
+/// The trap remembers `source`, so it ends when that Pokemon leaves the field.
+fn trap_instructions(
+    state: &BattleState,
+    position: BattlePosition,
+    source: BattlePosition,
+    status: VolatileStatus,
+    duration: Option<u8>,
+) -> Vec<BattleInstruction> {
+    let trappable = state.get_pokemon_at_position(position).is_some_and(|pokemon| {
+        pokemon.hp > 0 && !pokemon.volatile_statuses.contains(status) && !is_immune_to_trapping(state, pokemon)
+    });
+    if !trappable {
+        return Vec::new();
+    }
+
+    vec![
+        BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: position,
+            status,
+            duration,
+            previous_had_status: false,
+            previous_duration: None,
+        }),
+        BattleInstruction::Status(StatusInstruction::SetTrapSource { target: position, status, source }),
+    ]
+}
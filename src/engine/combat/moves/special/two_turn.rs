@@ -4,8 +4,13 @@
 //! This module contains moves that require charging or preparation on the first turn,
 //! then execute their effect on the second turn. This includes moves like Solar Beam,
 //! Fly, Dig, and other two-turn moves.
+//!
+//! The charging Pokemon holds `TwoTurnMove` (plus its semi-invulnerable state for Fly,
+//! Dig, Dive and the like) until the attack is released, and option generation only
+//! offers it the move it is charging. Power Herb, and sun or rain for the moves that
+//! draw on them, skip the charging turn.
 
-use crate::core::battle_state::BattleState;
+use crate::core::battle_state::{BattleState, Pokemon};
 use crate::core::instructions::{VolatileStatus, Weather, Stat, SideCondition};
 use crate::core::instructions::{
     BattleInstruction, BattleInstructions, StatusInstruction, StatsInstruction,
@@ -13,17 +18,86 @@ use crate::core::instructions::{
 };
 use crate::core::battle_format::BattlePosition;
 use crate::generation::GenerationMechanics;
-use crate::types::StatBoostArray;
-use std::collections::HashMap;
+use crate::types::{Items, Moves, StatBoostArray};
 use crate::data::showdown_types::MoveData;
 use crate::engine::combat::moves::apply_generic_effects;
+use crate::engine::combat::core::field_system::weather_for;
 
 // =============================================================================
 // TWO-TURN/CHARGE MOVES
 // =============================================================================
 
-/// Apply Solar Beam - no charge in sun, reduced power in other weather
-pub fn apply_solar_beam(
+/// What a charge move does on its charging turn
+struct ChargeTurn {
+    /// Semi-invulnerable state the user hides in until the attack
+    semi_invulnerable: Option<VolatileStatus>,
+    /// Stat raised by one stage when the charge starts
+    boost: Option<Stat>,
+    /// Weather in which the move fires without charging
+    instant_weather: &'static [Weather],
+}
+
+const SUN: &[Weather] = &[Weather::Sun, Weather::HarshSun, Weather::HarshSunlight];
+const RAIN: &[Weather] = &[Weather::Rain, Weather::HeavyRain];
+
+/// The charging turn of each two-turn move
+fn charge_turn(move_name: Moves) -> Option<ChargeTurn> {
+    let (semi_invulnerable, boost, instant_weather) = match move_name {
+        Moves::SOLARBEAM | Moves::SOLARBLADE => (None, None, SUN),
+        Moves::ELECTROSHOT => (None, Some(Stat::SpecialAttack), RAIN),
+        Moves::METEORBEAM => (None, Some(Stat::SpecialAttack), &[][..]),
+        Moves::SKULLBASH => (None, Some(Stat::Defense), &[][..]),
+        Moves::FLY | Moves::BOUNCE => (Some(VolatileStatus::Fly), None, &[][..]),
+        Moves::DIG => (Some(VolatileStatus::Dig), None, &[][..]),
+        Moves::DIVE => (Some(VolatileStatus::Dive), None, &[][..]),
+        Moves::PHANTOMFORCE | Moves::SHADOWFORCE => (Some(VolatileStatus::PhantomForce), None, &[][..]),
+        Moves::RAZORWIND | Moves::SKYATTACK | Moves::FREEZESHOCK | Moves::ICEBURN => (None, None, &[][..]),
+        _ => return None,
+    };
+    Some(ChargeTurn { semi_invulnerable, boost, instant_weather })
+}
+
+/// Check if a move charges for a turn before it attacks
+pub fn is_two_turn_move(move_name: Moves) -> bool {
+    charge_turn(move_name).is_some()
+}
+
+/// Check if the user is about to spend this turn charging the move
+///
+/// The charging turn never misses, so accuracy is only rolled when the attack is released.
+pub fn charges_this_turn(state: &BattleState, user_position: BattlePosition, move_name: Moves) -> bool {
+    let (user, charge) = match (state.get_pokemon_at_position(user_position), charge_turn(move_name)) {
+        (Some(user), Some(charge)) => (user, charge),
+        _ => return false,
+    };
+    !user.volatile_statuses.contains(VolatileStatus::TwoTurnMove)
+        && user.item != Some(Items::POWERHERB)
+        && !fires_instantly_in_weather(state, user, &charge)
+}
+
+/// Sun and rain don't affect a Utility Umbrella holder's moves
+fn fires_instantly_in_weather(state: &BattleState, user: &Pokemon, charge: &ChargeTurn) -> bool {
+    charge.instant_weather.contains(&weather_for(state, user))
+}
+
+/// Instructions that end a charge, whether the attack is released or interrupted
+pub fn release_charge_instructions(pokemon: &Pokemon, position: BattlePosition) -> Vec<BattleInstruction> {
+    if !pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
+        return Vec::new();
+    }
+
+    std::iter::once(VolatileStatus::TwoTurnMove)
+        .chain(SEMI_INVULNERABLE_STATES.into_iter().filter(|&status| pokemon.volatile_statuses.contains(status)))
+        .map(|status| BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+            target: position,
+            status,
+            previous_duration: None,
+        }))
+        .collect()
+}
+
+/// Charge on the first turn and attack on the second
+fn apply_charge_move(
     state: &BattleState,
     move_data: &MoveData,
     user_position: BattlePosition,
@@ -31,60 +105,101 @@ pub fn apply_solar_beam(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    // Check if user is already charging Solar Beam
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack with potentially modified power
-            let power_multiplier = match state.weather() {
-                Weather::Sun | Weather::HarshSun | Weather::HarshSunlight => 1.0, // Full power in sun
-                Weather::Rain | Weather::HeavyRain | Weather::Sand | Weather::Sandstorm |
-                Weather::Hail | Weather::Snow | Weather::StrongWinds => 0.5, // Half power in other weather
-                Weather::None => 1.0, // Full power in no weather
-            };
-            
-            let modified_move_data = MoveData {
-                base_power: ((move_data.base_power as f32 * power_multiplier) as u16),
-                ..move_data.clone()
-            };
-            
-            // Remove charging status
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                })
-            ]));
-            
-            // Apply damage
-            let generic_instructions = apply_generic_effects(state, &modified_move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - check if we can skip charging in sun
-            if state.weather() == Weather::Sun || state.weather() == Weather::HarshSun {
-                // Skip charging and attack immediately in sun
-                let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-                instructions.extend(generic_instructions);
-            } else {
-                // Start charging
-                instructions.push(BattleInstructions::new(100.0, vec![
-                    BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                        target: user_position,
-                        status: VolatileStatus::TwoTurnMove,
-                        duration: Some(1), // Charge for 1 turn
-                        previous_had_status: false,
-                        previous_duration: None,
-                    })
-                ]));
-            }
-        }
+    let (user, charge) = match (state.get_pokemon_at_position(user_position), charge_turn(move_data.name)) {
+        (Some(user), Some(charge)) => (user, charge),
+        _ => return apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage),
+    };
+
+    // Second turn - release the attack; the charge itself is cleared by the turn pipeline
+    if user.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
+        return attack_after(Vec::new(), state, move_data, user_position, target_positions, generation, branch_on_damage);
+    }
+
+    let mut charge_instructions = Vec::new();
+    if let Some(stat) = charge.boost {
+        let mut stat_boosts = StatBoostArray::default();
+        stat_boosts.insert(stat, 1);
+        charge_instructions.push(BattleInstruction::Stats(StatsInstruction::BoostStats {
+            target: user_position,
+            stat_changes: stat_boosts.to_hashmap(),
+            previous_boosts: std::collections::HashMap::new(),
+        }));
+    }
+
+    // Sun or rain lets some moves fire straight away; otherwise Power Herb is used up to skip the charge
+    if fires_instantly_in_weather(state, user, &charge) {
+        return attack_after(charge_instructions, state, move_data, user_position, target_positions, generation, branch_on_damage);
+    }
+    if user.item == Some(Items::POWERHERB) {
+        charge_instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
+            target: user_position,
+            new_item: None,
+            previous_item: user.item,
+        }));
+        return attack_after(charge_instructions, state, move_data, user_position, target_positions, generation, branch_on_damage);
+    }
+
+    // First turn - start charging
+    for status in std::iter::once(VolatileStatus::TwoTurnMove).chain(charge.semi_invulnerable) {
+        charge_instructions.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: user_position,
+            status,
+            duration: Some(1),
+            previous_had_status: false,
+            previous_duration: None,
+        }));
+    }
+    vec![BattleInstructions::new(100.0, charge_instructions)]
+}
+
+/// Run the attack with the given instructions placed ahead of every branch
+fn attack_after(
+    prefix: Vec<BattleInstruction>,
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    // Solar Beam and Solar Blade lose half their power in other weather
+    let weakened = matches!(move_data.name, Moves::SOLARBEAM | Moves::SOLARBLADE)
+        && state.get_pokemon_at_position(user_position).is_some_and(|user| {
+            matches!(
+                weather_for(state, user),
+                Weather::Rain | Weather::HeavyRain | Weather::Sand | Weather::Sandstorm | Weather::Hail | Weather::Snow
+            )
+        });
+    let modified_move_data;
+    let move_data = if weakened {
+        modified_move_data = MoveData {
+            base_power: move_data.base_power / 2,
+            ..move_data.clone()
+        };
+        &modified_move_data
+    } else {
+        move_data
+    };
+
+    let mut instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
+    for branch in &mut instructions {
+        branch.instruction_list.splice(0..0, prefix.iter().cloned());
     }
-    
     instructions
 }
 
+/// Apply Solar Beam - no charge in sun, reduced power in other weather
+pub fn apply_solar_beam(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
+}
+
 /// Apply Solar Blade - identical to Solar Beam but physical
 pub fn apply_solar_blade(
     state: &BattleState,
@@ -107,47 +222,10 @@ pub fn apply_meteor_beam(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - start charging and boost Special Attack
-            let mut stat_boosts = StatBoostArray::default();
-            stat_boosts.insert(Stat::SpecialAttack, 1);
-            
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Stats(StatsInstruction::BoostStats {
-                    target: user_position,
-                    stat_changes: stat_boosts.to_hashmap(),
-                    previous_boosts: std::collections::HashMap::new(),
-                }),
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
-    }
-    
-    instructions
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
-/// Apply Electro Shot - charges for one turn, raises Special Attack, then attacks
+/// Apply Electro Shot - like Meteor Beam, but fires without charging in rain
 pub fn apply_electro_shot(
     state: &BattleState,
     move_data: &MoveData,
@@ -156,8 +234,7 @@ pub fn apply_electro_shot(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    // Electro Shot has identical mechanics to Meteor Beam
-    apply_meteor_beam(state, move_data, user_position, target_positions, generation, branch_on_damage)
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Dig - semi-invulnerable underground on first turn, attacks on second
@@ -169,48 +246,7 @@ pub fn apply_dig(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack from underground
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                }),
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::Dig,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - go underground
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                }),
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::Dig,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
-    }
-    
-    instructions
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Fly - semi-invulnerable in the air on first turn, attacks on second
@@ -222,48 +258,7 @@ pub fn apply_fly(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack from the air
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                }),
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::Fly,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - fly up
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                }),
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::Fly,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
-    }
-    
-    instructions
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Bounce - semi-invulnerable in the air on first turn, attacks on second with paralysis chance
@@ -289,48 +284,7 @@ pub fn apply_dive(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack from underwater
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                }),
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::Dive,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - dive underwater
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                }),
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::Dive,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
-    }
-    
-    instructions
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Phantom Force - semi-invulnerable on first turn, bypasses protection on second
@@ -342,36 +296,7 @@ pub fn apply_phantom_force(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack with protection bypass
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - become semi-invulnerable
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
-    }
-    
-    instructions
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Shadow Force - identical to Phantom Force
@@ -453,8 +378,7 @@ pub fn apply_razor_wind(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    // Standard two-turn move pattern
-    apply_standard_two_turn_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Skull Bash - charges first turn with Defense boost, attacks second
@@ -466,44 +390,7 @@ pub fn apply_skull_bash(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - charge and boost Defense
-            let mut stat_boosts = StatBoostArray::default();
-            stat_boosts.insert(Stat::Defense, 1);
-            
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Stats(StatsInstruction::BoostStats {
-                    target: user_position,
-                    stat_changes: stat_boosts.to_hashmap(),
-                    previous_boosts: std::collections::HashMap::new(),
-                }),
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
-    }
-    
-    instructions
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Sky Attack - charges first turn, attacks second with high crit ratio
@@ -515,7 +402,19 @@ pub fn apply_sky_attack(
     generation: &GenerationMechanics,
     branch_on_damage: bool,
 ) -> Vec<BattleInstructions> {
-    apply_standard_two_turn_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
+}
+
+/// Apply Freeze Shock or Ice Burn - charges first turn, attacks second
+pub fn apply_freeze_shock(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    apply_charge_move(state, move_data, user_position, target_positions, generation, branch_on_damage)
 }
 
 /// Apply Focus Punch - charges with focus, fails if hit during charge
@@ -573,46 +472,48 @@ pub fn apply_focus_punch(
 }
 
 // =============================================================================
-// HELPER FUNCTIONS
+// SEMI-INVULNERABILITY
 // =============================================================================
 
-/// Standard two-turn move pattern
-fn apply_standard_two_turn_move(
-    state: &BattleState,
-    move_data: &MoveData,
-    user_position: BattlePosition,
-    target_positions: &[BattlePosition],
-    generation: &GenerationMechanics,
-    branch_on_damage: bool,
-) -> Vec<BattleInstructions> {
-    let mut instructions = Vec::new();
-    
-    if let Some(user_pokemon) = state.get_pokemon_at_position(user_position) {
-        if user_pokemon.volatile_statuses.contains(VolatileStatus::TwoTurnMove) {
-            // Second turn - attack
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    previous_duration: None,
-                })
-            ]));
-            
-            let generic_instructions = apply_generic_effects(state, move_data, user_position, target_positions, generation, branch_on_damage);
-            instructions.extend(generic_instructions);
-        } else {
-            // First turn - start charging
-            instructions.push(BattleInstructions::new(100.0, vec![
-                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                    target: user_position,
-                    status: VolatileStatus::TwoTurnMove,
-                    duration: Some(1),
-                    previous_had_status: false,
-                    previous_duration: None,
-                })
-            ]));
-        }
+/// States in which a charging Pokemon can't be reached by most moves
+pub const SEMI_INVULNERABLE_STATES: [VolatileStatus; 5] = [
+    VolatileStatus::Fly,
+    VolatileStatus::Dig,
+    VolatileStatus::Dive,
+    VolatileStatus::PhantomForce,
+    VolatileStatus::SkyDrop,
+];
+
+/// Check if a move can hit a Pokemon in its semi-invulnerable state
+///
+/// Returns true when the Pokemon isn't semi-invulnerable at all.
+pub fn can_hit_semi_invulnerable(move_name: Moves, target: &Pokemon) -> bool {
+    SEMI_INVULNERABLE_STATES
+        .into_iter()
+        .filter(|&status| target.volatile_statuses.contains(status))
+        .all(|status| match status {
+            VolatileStatus::Fly | VolatileStatus::SkyDrop => matches!(
+                move_name,
+                Moves::GUST | Moves::TWISTER | Moves::THUNDER | Moves::HURRICANE
+                    | Moves::SKYUPPERCUT | Moves::SMACKDOWN | Moves::THOUSANDARROWS
+            ),
+            VolatileStatus::Dig => matches!(move_name, Moves::EARTHQUAKE | Moves::MAGNITUDE | Moves::FISSURE),
+            VolatileStatus::Dive => matches!(move_name, Moves::SURF | Moves::WHIRLPOOL),
+            _ => false,
+        })
+}
+
+/// Moves that reach a semi-invulnerable Pokemon hit it twice as hard
+pub fn semi_invulnerable_damage_multiplier(move_name: Moves, target: &Pokemon) -> f32 {
+    let doubled = (target.volatile_statuses.contains(VolatileStatus::Fly)
+        && matches!(move_name, Moves::GUST | Moves::TWISTER))
+        || (target.volatile_statuses.contains(VolatileStatus::Dig)
+            && matches!(move_name, Moves::EARTHQUAKE | Moves::MAGNITUDE))
+        || (target.volatile_statuses.contains(VolatileStatus::Dive)
+            && matches!(move_name, Moves::SURF | Moves::WHIRLPOOL));
+    if doubled {
+        2.0
+    } else {
+        1.0
     }
-    
-    instructions
 }
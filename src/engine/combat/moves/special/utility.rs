@@ -5,11 +5,14 @@
 use crate::core::battle_state::BattleState;
 use crate::core::instructions::{PokemonStatus, VolatileStatus, Stat};
 use crate::core::instructions::{
-    BattleInstruction, BattleInstructions, StatusInstruction, StatsInstruction,
+    BattleInstruction, BattleInstructions, PokemonInstruction, StatusInstruction, StatsInstruction,
 };
 use crate::core::battle_format::{BattlePosition, SideReference};
+use crate::data::showdown_types::MoveData;
+use crate::engine::combat::core::ability_suppression::{suppress_ability, sync_ability_suppression};
+use crate::engine::combat::moves::MoveContext;
 use crate::generation::GenerationMechanics;
-use crate::types::StatBoostArray;
+use crate::types::{Abilities, StatBoostArray};
 use std::collections::HashMap;
 
 // =============================================================================
@@ -167,4 +170,137 @@ pub fn apply_clear_smog(
     }
     
     instructions
-}
\ No newline at end of file
+}
+
+/// Apply Doodle - the user and its ally copy the target's ability
+pub fn apply_doodle(
+    state: &BattleState,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    let copied_ability = match target_positions
+        .first()
+        .and_then(|&target| state.get_pokemon_at_position(target))
+    {
+        Some(target) if !is_uncopyable_ability(target.ability) => target.ability,
+        _ => return vec![BattleInstructions::new(100.0, vec![])],
+    };
+
+    let mut instructions = Vec::new();
+    let recipients = std::iter::once(user_position)
+        .chain(user_position.ally_position(&state.format));
+    for position in recipients {
+        if let Some(pokemon) = state.get_pokemon_at_position(position) {
+            if pokemon.hp > 0 && pokemon.ability != copied_ability && !is_uncopyable_ability(pokemon.ability) {
+                instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ChangeAbility {
+                    target: position,
+                    new_ability: copied_ability,
+                    previous_ability: Some(pokemon.ability),
+                }));
+            }
+        }
+    }
+
+    vec![BattleInstructions::new(100.0, instructions)]
+}
+
+/// Apply Gastro Acid - suppresses the target's ability until it switches out
+pub fn apply_gastro_acid(
+    state: &BattleState,
+    _user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    let instructions: Vec<BattleInstruction> = target_positions
+        .iter()
+        .filter_map(|&position| state.get_pokemon_at_position(position).map(|target| suppress_ability(position, target)))
+        .flatten()
+        .collect();
+    vec![BattleInstructions::new(100.0, with_suppression_synced(state, instructions))]
+}
+
+/// Apply Core Enforcer - damages, and suppresses the abilities of targets that already moved this turn
+pub fn apply_core_enforcer(
+    state: &BattleState,
+    move_data: &MoveData,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    generation: &GenerationMechanics,
+    context: &MoveContext,
+    branch_on_damage: bool,
+) -> Vec<BattleInstructions> {
+    let mut instructions = crate::engine::combat::moves::apply_generic_effects(
+        state,
+        move_data,
+        user_position,
+        target_positions,
+        generation,
+        branch_on_damage,
+    );
+    if context.going_first {
+        return instructions;
+    }
+
+    for branch in &mut instructions {
+        let mut hit_state = state.clone();
+        hit_state.apply_instructions(&branch.instruction_list);
+        let suppression: Vec<BattleInstruction> = target_positions
+            .iter()
+            .filter(|&&position| {
+                branch.instruction_list.iter().any(|instruction| {
+                    matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. }) if *target == position && *amount > 0)
+                })
+            })
+            .filter_map(|&position| hit_state.get_pokemon_at_position(position).map(|target| suppress_ability(position, target)))
+            .flatten()
+            .collect();
+        branch.instruction_list.extend(with_suppression_synced(&hit_state, suppression));
+    }
+    instructions
+}
+
+/// Follow ability suppression with its knock-on effects, such as a
+/// suppressed Neutralizing Gas giving everyone else their abilities back
+fn with_suppression_synced(state: &BattleState, mut instructions: Vec<BattleInstruction>) -> Vec<BattleInstruction> {
+    if instructions.is_empty() {
+        return instructions;
+    }
+    let mut suppressed_state = state.clone();
+    suppressed_state.apply_instructions(&instructions);
+    instructions.extend(sync_ability_suppression(&suppressed_state));
+    instructions
+}
+
+/// Abilities that cannot be copied or overwritten by Role Play-style effects
+fn is_uncopyable_ability(ability: Abilities) -> bool {
+    matches!(
+        ability,
+        Abilities::ASONEGLASTRIER
+            | Abilities::ASONESPECTRIER
+            | Abilities::BATTLEBOND
+            | Abilities::COMATOSE
+            | Abilities::COMMANDER
+            | Abilities::DISGUISE
+            | Abilities::FLOWERGIFT
+            | Abilities::FORECAST
+            | Abilities::GULPMISSILE
+            | Abilities::HUNGERSWITCH
+            | Abilities::ICEFACE
+            | Abilities::ILLUSION
+            | Abilities::IMPOSTER
+            | Abilities::MULTITYPE
+            | Abilities::NEUTRALIZINGGAS
+            | Abilities::POWERCONSTRUCT
+            | Abilities::POWEROFALCHEMY
+            | Abilities::RECEIVER
+            | Abilities::RKSSYSTEM
+            | Abilities::SCHOOLING
+            | Abilities::SHIELDSDOWN
+            | Abilities::STANCECHANGE
+            | Abilities::TRACE
+            | Abilities::WONDERGUARD
+            | Abilities::ZENMODE
+            | Abilities::ZEROTOHERO
+    )
+}
@@ -10,6 +10,7 @@ use crate::core::instructions::{BattleInstruction, BattleInstructions, PokemonIn
 use crate::core::battle_format::BattlePosition;
 use crate::generation::GenerationMechanics;
 use crate::engine::combat::composers::status_moves::healing_move;
+use crate::engine::combat::core::field_system::weather_for;
 use crate::engine::combat::core::status_system::{StatusApplication, apply_multiple_status_effects};
 
 // =============================================================================
@@ -69,11 +70,7 @@ pub fn apply_moonlight(
     };
     
     // Weather affects healing amount
-    let heal_fraction = match state.weather() {
-        Weather::Sun | Weather::HarshSun => 0.667, // 2/3 HP
-        Weather::Rain | Weather::HeavyRain | Weather::Sandstorm | Weather::Hail => 0.25, // 1/4 HP
-        _ => 0.5, // 1/2 HP in clear weather
-    };
+    let heal_fraction = weather_heal_fraction(state, user_position);
     
     vec![BattleInstructions::new(100.0, healing_move(state, target_position, heal_fraction, Some(user_position)))]
 }
@@ -92,11 +89,7 @@ pub fn apply_synthesis(
     };
     
     // Weather affects healing amount
-    let heal_fraction = match state.weather() {
-        Weather::Sun | Weather::HarshSun => 0.667, // 2/3 HP
-        Weather::Rain | Weather::HeavyRain | Weather::Sandstorm | Weather::Hail => 0.25, // 1/4 HP
-        _ => 0.5, // 1/2 HP in clear weather
-    };
+    let heal_fraction = weather_heal_fraction(state, user_position);
     
     vec![BattleInstructions::new(100.0, healing_move(state, target_position, heal_fraction, Some(user_position)))]
 }
@@ -115,15 +108,24 @@ pub fn apply_morning_sun(
     };
     
     // Weather affects healing amount
-    let heal_fraction = match state.weather() {
-        Weather::Sun | Weather::HarshSun => 0.667, // 2/3 HP
-        Weather::Rain | Weather::HeavyRain | Weather::Sandstorm | Weather::Hail => 0.25, // 1/4 HP
-        _ => 0.5, // 1/2 HP in clear weather
-    };
+    let heal_fraction = weather_heal_fraction(state, user_position);
     
     vec![BattleInstructions::new(100.0, healing_move(state, target_position, heal_fraction, Some(user_position)))]
 }
 
+/// Fraction of max HP that Moonlight, Synthesis and Morning Sun restore in the user's weather
+fn weather_heal_fraction(state: &BattleState, user_position: BattlePosition) -> f32 {
+    let weather = match state.get_pokemon_at_position(user_position) {
+        Some(user) => weather_for(state, user),
+        None => state.weather(),
+    };
+    match weather {
+        Weather::Sun | Weather::HarshSun => 0.667, // 2/3 HP
+        Weather::Rain | Weather::HeavyRain | Weather::Sandstorm | Weather::Sand | Weather::Hail | Weather::Snow => 0.25, // 1/4 HP
+        _ => 0.5, // 1/2 HP in clear weather
+    }
+}
+
 /// Apply Rest - restores full HP and induces sleep
 pub fn apply_rest(
     state: &BattleState,
@@ -77,6 +77,31 @@ pub fn apply_switcheroo(
     apply_trick(state, user_position, target_positions, generation)
 }
 
+/// Apply Recycle - restores the item the user last consumed, failing while it holds one
+pub fn apply_recycle(
+    state: &BattleState,
+    user_position: BattlePosition,
+    _target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    let user = match state.get_pokemon_at_position(user_position) {
+        Some(pokemon) => pokemon,
+        None => return vec![BattleInstructions::new(100.0, vec![])],
+    };
+
+    match user.consumed_item {
+        Some(item) if user.item.is_none() => vec![BattleInstructions::new(
+            100.0,
+            vec![BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
+                target: user_position,
+                new_item: Some(item),
+                previous_item: None,
+            })],
+        )],
+        _ => vec![BattleInstructions::new(100.0, vec![])],
+    }
+}
+
 /// Apply Knock Off - removes target's item and boosts damage if item present
 pub fn apply_knock_off(
     state: &BattleState,
@@ -6,12 +6,13 @@
 //! All moves in this module have been converted to use the new composer system.
 
 use crate::core::battle_state::BattleState;
-use crate::core::instructions::{BattleInstruction, BattleInstructions, Stat, Weather};
+use crate::core::instructions::{BattleInstruction, BattleInstructions, Stat, StatusInstruction, VolatileStatus, Weather};
 use crate::core::battle_format::BattlePosition;
 use crate::generation::GenerationMechanics;
 use std::collections::HashMap;
 use crate::data::showdown_types::MoveData;
 use crate::engine::combat::composers::status_moves::{self_stat_boost_move, enemy_stat_reduction_move};
+use crate::engine::combat::core::field_system::weather_for;
 
 // =============================================================================
 // STAT MODIFICATION MACROS
@@ -160,8 +161,12 @@ pub fn apply_growth(
 ) -> Vec<BattleInstructions> {
     let mut stat_changes = HashMap::new();
     
-    // Enhanced in sun weather
-    let boost_amount = match state.weather() {
+    // Enhanced in sun weather, unless the user holds a Utility Umbrella
+    let weather = match state.get_pokemon_at_position(user_position) {
+        Some(user) => weather_for(state, user),
+        None => state.weather(),
+    };
+    let boost_amount = match weather {
         Weather::Sun | Weather::HarshSun => 2,
         _ => 1,
     };
@@ -172,6 +177,36 @@ pub fn apply_growth(
     vec![BattleInstructions::new(100.0, self_stat_boost_move(state, user_position, &stat_changes))]
 }
 
+/// Apply Tar Shot - lowers target's Speed by 1 stage and makes it weaker to Fire
+/// The Fire weakness is read by the type chart and ends when the target switches out
+pub fn apply_tar_shot(
+    state: &BattleState,
+    user_position: BattlePosition,
+    target_positions: &[BattlePosition],
+    _generation: &GenerationMechanics,
+) -> Vec<BattleInstructions> {
+    let mut stat_changes = HashMap::new();
+    stat_changes.insert(Stat::Speed, -1);
+    let mut instructions = enemy_stat_reduction_move(state, target_positions, &stat_changes, user_position);
+
+    // Terastallized Pokemon can't be covered in tar
+    for &target_position in target_positions {
+        if let Some(target) = state.get_pokemon_at_position(target_position) {
+            if !target.is_terastallized && !target.volatile_statuses.contains(VolatileStatus::TarShot) {
+                instructions.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+                    target: target_position,
+                    status: VolatileStatus::TarShot,
+                    duration: None,
+                    previous_had_status: false,
+                    previous_duration: None,
+                }));
+            }
+        }
+    }
+
+    vec![BattleInstructions::new(100.0, instructions)]
+}
+
 /// Apply Acid - deals damage with chance to lower Defense
 /// Generation-aware: 33.2% chance in Gen 1, 10% in later generations
 pub fn apply_acid(
@@ -3,9 +3,13 @@
 //! This module implements the complete Pokemon type effectiveness chart
 //! with generation-specific variations and special cases.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 use serde::{Deserialize, Serialize};
+use crate::core::battle_format::{BattleFormat, TypeChartRule, TypeMatchup};
+use crate::core::battle_state::Pokemon;
+use crate::core::instructions::VolatileStatus;
 use crate::types::Moves;
 use crate::types::PokemonType;
 // Removed old service layer import - type chart loading now handled differently
@@ -23,6 +27,13 @@ static TYPE_CHART_CACHE: LazyLock<HashMap<u8, TypeChart>> = LazyLock::new(|| {
     cache
 });
 
+/// Global cache for the Inverse Battle version of each generation's type chart
+static INVERSE_TYPE_CHART_CACHE: LazyLock<HashMap<u8, TypeChart>> = LazyLock::new(|| {
+    TYPE_CHART_CACHE
+        .iter()
+        .map(|(&generation, chart)| (generation, chart.inverted()))
+        .collect()
+});
 
 /// Type effectiveness chart with generation support
 #[derive(Debug, Clone)]
@@ -56,6 +67,21 @@ impl TypeChart {
             .unwrap_or_else(|| &TYPE_CHART_CACHE[&9]) // Default to Gen 9 if invalid generation
     }
     
+    /// Get the type chart a format is played with
+    ///
+    /// Standard and Inverse charts come from the cache; custom charts are built
+    /// from the generation's chart with the format's matchups replaced.
+    pub fn for_format(format: &BattleFormat) -> Cow<'static, TypeChart> {
+        let generation = format.generation.number();
+        match &format.type_chart {
+            TypeChartRule::Standard => Cow::Borrowed(Self::get_cached(generation)),
+            TypeChartRule::Inverse => Cow::Borrowed(
+                INVERSE_TYPE_CHART_CACHE.get(&generation).unwrap_or_else(|| &INVERSE_TYPE_CHART_CACHE[&9]),
+            ),
+            TypeChartRule::Custom(matchups) => Cow::Owned(Self::get_cached(generation).with_matchups(matchups)),
+        }
+    }
+
     /// Create a new type chart for the specified generation (internal use only)
     /// 
     /// This method creates a new TypeChart instance and should only be used internally
@@ -83,6 +109,24 @@ impl TypeChart {
         self.effectiveness[attacking_type as usize][defending_type as usize]
     }
 
+    /// Effectiveness of a move against one of the defender's types
+    ///
+    /// Moves with their own matchups read them from this chart too, so they
+    /// follow Inverse and custom charts.
+    pub fn move_matchup(&self, move_name: Moves, move_type: PokemonType, defending_type: PokemonType) -> f32 {
+        if let Some(&multiplier) = self.special_cases.get(&(move_name, defending_type)) {
+            return multiplier;
+        }
+        match move_name {
+            // Flying Press is also Flying-type when it comes to effectiveness
+            Moves::FLYINGPRESS => {
+                self.get_effectiveness(move_type, defending_type)
+                    * self.get_effectiveness(PokemonType::Flying, defending_type)
+            }
+            _ => self.get_effectiveness(move_type, defending_type),
+        }
+    }
+
     /// Calculate damage multiplier for a move against target types
     pub fn calculate_damage_multiplier(
         &self,
@@ -91,14 +135,9 @@ impl TypeChart {
         tera_type: Option<PokemonType>,
         move_name: Option<&str>,
     ) -> f32 {
-        // Check for special case overrides first
-        if let Some(move_name) = move_name {
-            for target_type in [target_types.0, target_types.1].iter() {
-                if let Some(&override_multiplier) = self.special_cases.get(&(crate::types::FromNormalizedString::from_normalized_str(&crate::utils::normalize_name(move_name)).unwrap_or(Moves::NONE), *target_type)) {
-                    return override_multiplier;
-                }
-            }
-        }
+        let move_name = move_name
+            .and_then(|name| crate::types::FromNormalizedString::from_normalized_str(&crate::utils::normalize_name(name)))
+            .unwrap_or(Moves::NONE);
 
         // Use Tera type if Terastallized, otherwise use normal types
         let effective_types = if let Some(tera) = tera_type {
@@ -107,16 +146,37 @@ impl TypeChart {
             target_types
         };
 
-        let multiplier1 = self.get_effectiveness(move_type, effective_types.0);
+        let multiplier1 = self.move_matchup(move_name, move_type, effective_types.0);
         let multiplier2 = if effective_types.0 == effective_types.1 {
             1.0 // Single type or duplicate types
         } else {
-            self.get_effectiveness(move_type, effective_types.1)
+            self.move_matchup(move_name, move_type, effective_types.1)
         };
 
         multiplier1 * multiplier2
     }
 
+    /// Effectiveness of a move against a Pokemon, accounting for Terastallization and Tar Shot
+    pub fn move_effectiveness(&self, move_name: Moves, move_type: PokemonType, defender: &Pokemon) -> f32 {
+        let defending_types: &[PokemonType] = match defender.tera_type {
+            Some(ref tera) if defender.is_terastallized => std::slice::from_ref(tera),
+            _ => &defender.types,
+        };
+
+        let mut multiplier = 1.0;
+        for (index, &defending_type) in defending_types.iter().enumerate() {
+            if !defending_types[..index].contains(&defending_type) {
+                multiplier *= self.move_matchup(move_name, move_type, defending_type);
+            }
+        }
+
+        // Tar Shot adds a Fire weakness on top of whatever the chart says
+        if move_type == PokemonType::Fire && defender.volatile_statuses.contains(VolatileStatus::TarShot) {
+            multiplier *= 2.0;
+        }
+        multiplier
+    }
+
     /// Calculate STAB (Same Type Attack Bonus) multiplier
     pub fn calculate_stab_multiplier(
         &self,
@@ -391,11 +451,33 @@ impl TypeChart {
         self.special_cases.insert((Moves::FREEZEDRY, PokemonType::Water), 2.0);
         
         // Flying Press is Fighting-type but hits like Fighting + Flying
-        // This is handled in move-specific logic, not here
+        // This is handled in move_matchup, from this chart's Flying row
         
         // Thousand Arrows hits Flying types for neutral damage despite being Ground
         self.special_cases.insert((Moves::THOUSANDARROWS, PokemonType::Flying), 1.0);
     }
+
+    /// Inverse Battle chart: weaknesses and resistances swap, immunities become weaknesses
+    ///
+    /// Move-specific matchups such as Freeze-Dry against Water are kept as they are.
+    fn inverted(&self) -> Self {
+        let mut chart = self.clone();
+        for row in &mut chart.effectiveness {
+            for multiplier in row.iter_mut() {
+                *multiplier = if *multiplier == 0.0 { 2.0 } else { 1.0 / *multiplier };
+            }
+        }
+        chart
+    }
+
+    /// Copy of this chart with the given matchups replaced
+    fn with_matchups(&self, matchups: &[TypeMatchup]) -> Self {
+        let mut chart = self.clone();
+        for matchup in matchups {
+            chart.effectiveness[matchup.attacking as usize][matchup.defending as usize] = matchup.effectiveness.multiplier();
+        }
+        chart
+    }
 }
 
 /// Default type chart for the current generation (Gen 9)
@@ -405,3 +487,41 @@ impl Default for TypeChart {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::FormatBuilder;
+
+    #[test]
+    fn test_inverse_chart_flips_matchups() {
+        let format = FormatBuilder::gen9_ou().inverse().build().unwrap();
+        let chart = TypeChart::for_format(&format);
+
+        assert_eq!(chart.get_effectiveness(PokemonType::Fire, PokemonType::Water), 2.0);
+        assert_eq!(chart.get_effectiveness(PokemonType::Fire, PokemonType::Grass), 0.5);
+        assert_eq!(chart.get_effectiveness(PokemonType::Normal, PokemonType::Ghost), 2.0);
+
+        // Freeze-Dry stays super effective on Water, and Ice still hits Ground weakly
+        let freeze_dry = chart.calculate_damage_multiplier(PokemonType::Ice, (PokemonType::Water, PokemonType::Ground), None, Some("Freeze-Dry"));
+        assert_eq!(freeze_dry, 1.0);
+        // Flying Press reads the Flying row of the inverse chart too
+        let flying_press = chart.calculate_damage_multiplier(PokemonType::Fighting, (PokemonType::Grass, PokemonType::Grass), None, Some("Flying Press"));
+        assert_eq!(flying_press, 0.5);
+    }
+
+    #[test]
+    fn test_custom_chart_from_json() {
+        let format = FormatBuilder::gen9_ou()
+            .custom_type_chart(r#"{"Dragon": {"Fairy": 1}, "Normal": {"Ghost": 0.5}}"#)
+            .build()
+            .unwrap();
+        let chart = TypeChart::for_format(&format);
+
+        assert_eq!(chart.get_effectiveness(PokemonType::Dragon, PokemonType::Fairy), 1.0);
+        assert_eq!(chart.get_effectiveness(PokemonType::Normal, PokemonType::Ghost), 0.5);
+        assert_eq!(chart.get_effectiveness(PokemonType::Fire, PokemonType::Water), 0.5);
+
+        assert!(FormatBuilder::gen9_ou().custom_type_chart(r#"{"Fire": {"Water": 3}}"#).build().is_err());
+    }
+}
@@ -0,0 +1,681 @@
+//! # Effect Coverage
+//!
+//! Records which abilities, moves and items have behaviour in the engine.
+//! Anything the engine doesn't handle silently does nothing in battle, so
+//! teams can be checked up front and the gaps listed with the `coverage`
+//! command.
+//!
+//! Moves are classified from the move registry: a registered move is
+//! implemented, an unregistered damaging move still deals generic damage
+//! with its standard secondary effects, and an unregistered status move does
+//! nothing. Abilities and items are listed by hand from their handlers: the
+//! ability and item effect tables, the switch-in, switch-out and end-of-turn
+//! triggers, and the checks made directly in damage and move resolution.
+
+use std::fmt;
+
+use crate::core::battle_state::{BattleState, Pokemon};
+use crate::core::instructions::MoveCategory;
+use crate::data::GameDataRepository;
+use crate::engine::combat::moves::registry::get_move_registry;
+use crate::types::errors::{BattleError, BattleResult, TeamError};
+use crate::types::{Abilities, Items, Moves, PokemonName};
+
+/// How much of an effect the engine implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coverage {
+    /// The effect has dedicated behaviour
+    Implemented,
+    /// A damaging move without its own effect, handled by the generic damage path
+    Generic,
+    /// The effect does nothing
+    Unimplemented,
+}
+
+/// The kind of effect a coverage gap belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    Ability,
+    Move,
+    Item,
+}
+
+impl fmt::Display for EffectKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EffectKind::Ability => write!(f, "ability"),
+            EffectKind::Move => write!(f, "move"),
+            EffectKind::Item => write!(f, "item"),
+        }
+    }
+}
+
+/// An unimplemented effect used by a Pokemon in a battle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageGap {
+    /// The Pokemon using the effect
+    pub species: PokemonName,
+    /// Whether the effect is an ability, move or item
+    pub kind: EffectKind,
+    /// Display name of the effect
+    pub name: String,
+}
+
+impl fmt::Display for CoverageGap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} {}", self.species, self.kind, self.name)
+    }
+}
+
+/// Every ability, move and item in the game data the engine doesn't fully implement
+#[derive(Debug, Clone, Default)]
+pub struct CoverageReport {
+    /// Abilities available to some species that do nothing
+    pub abilities: Vec<Abilities>,
+    /// Status moves that do nothing
+    pub moves: Vec<Moves>,
+    /// Damaging moves that only get generic damage and secondary effects
+    pub generic_moves: Vec<Moves>,
+    /// Items with no held effect
+    pub items: Vec<Items>,
+}
+
+impl CoverageReport {
+    /// Build the report from the game data
+    pub fn from_repository(repository: &GameDataRepository) -> Self {
+        let mut report = Self::default();
+
+        for species in repository.pokemon.species_ids() {
+            let Ok(data) = repository.pokemon.find_by_id(species) else {
+                continue;
+            };
+            for ability in data.abilities.values() {
+                if ability_coverage(*ability) == Coverage::Unimplemented && !report.abilities.contains(ability) {
+                    report.abilities.push(*ability);
+                }
+            }
+        }
+
+        for move_id in repository.moves.move_ids() {
+            let Ok(data) = repository.moves.find_by_id(move_id) else {
+                continue;
+            };
+            match move_coverage(*move_id, data.category) {
+                Coverage::Implemented => {}
+                Coverage::Generic => report.generic_moves.push(*move_id),
+                Coverage::Unimplemented => report.moves.push(*move_id),
+            }
+        }
+
+        report.items = repository
+            .items
+            .item_ids()
+            .copied()
+            .filter(|item| item_coverage(*item) == Coverage::Unimplemented)
+            .collect();
+
+        report.abilities.sort_by_key(|ability| ability.as_str());
+        report.moves.sort_by_key(|move_id| move_id.as_str());
+        report.generic_moves.sort_by_key(|move_id| move_id.as_str());
+        report.items.sort_by_key(|item| item.as_str());
+        report
+    }
+}
+
+/// Coverage of an ability
+pub fn ability_coverage(ability: Abilities) -> Coverage {
+    if matches!(ability, Abilities::NONE | Abilities::NOABILITY) || IMPLEMENTED_ABILITIES.contains(&ability) {
+        Coverage::Implemented
+    } else {
+        Coverage::Unimplemented
+    }
+}
+
+/// Coverage of a move
+pub fn move_coverage(move_id: Moves, category: MoveCategory) -> Coverage {
+    if move_id == Moves::NONE || get_move_registry().is_move_registered(&move_id) {
+        Coverage::Implemented
+    } else if category == MoveCategory::Status {
+        Coverage::Unimplemented
+    } else {
+        Coverage::Generic
+    }
+}
+
+/// Coverage of a held item
+pub fn item_coverage(item: Items) -> Coverage {
+    if item == Items::NONE || IMPLEMENTED_ITEMS.contains(&item) {
+        Coverage::Implemented
+    } else {
+        Coverage::Unimplemented
+    }
+}
+
+/// Unimplemented abilities, moves and items used by a Pokemon
+pub fn pokemon_coverage_gaps(pokemon: &Pokemon) -> Vec<CoverageGap> {
+    let mut gaps = Vec::new();
+    let mut gap = |kind, name: &str| {
+        gaps.push(CoverageGap {
+            species: pokemon.species,
+            kind,
+            name: name.to_string(),
+        })
+    };
+
+    if ability_coverage(pokemon.ability) == Coverage::Unimplemented {
+        gap(EffectKind::Ability, pokemon.ability.display_name());
+    }
+    for (_, move_data) in &pokemon.moves {
+        if move_coverage(move_data.name, move_data.category) == Coverage::Unimplemented {
+            gap(EffectKind::Move, move_data.name.display_name());
+        }
+    }
+    if let Some(item) = pokemon.item.filter(|item| item_coverage(*item) == Coverage::Unimplemented) {
+        gap(EffectKind::Item, item.display_name());
+    }
+    gaps
+}
+
+/// Unimplemented abilities, moves and items used by either team
+pub fn battle_coverage_gaps(state: &BattleState) -> Vec<CoverageGap> {
+    state
+        .sides
+        .iter()
+        .flat_map(|side| side.pokemon.iter())
+        .flat_map(pokemon_coverage_gaps)
+        .collect()
+}
+
+/// Check both teams for effects the engine doesn't implement
+///
+/// With `strict` set any gap is a team validation error; otherwise the gaps
+/// are returned for the caller to warn about.
+pub fn check_battle_coverage(state: &BattleState, strict: bool) -> BattleResult<Vec<CoverageGap>> {
+    let gaps = battle_coverage_gaps(state);
+    if strict && !gaps.is_empty() {
+        let reason = format!(
+            "teams use unimplemented effects: {}",
+            gaps.iter().map(|gap| gap.to_string()).collect::<Vec<_>>().join(", ")
+        );
+        return Err(BattleError::TeamValidation(TeamError::InvalidPokemon { reason }));
+    }
+    Ok(gaps)
+}
+
+/// Abilities with a handler somewhere in the engine
+const IMPLEMENTED_ABILITIES: &[Abilities] = &[
+    // Damage, accuracy and critical hits
+    Abilities::ADAPTABILITY,
+    Abilities::BATTLEARMOR,
+    Abilities::COMPOUNDEYES,
+    Abilities::FILTER,
+    Abilities::GUTS,
+    Abilities::HEATPROOF,
+    Abilities::HUGEPOWER,
+    Abilities::MARVELSCALE,
+    Abilities::MINDSEYE,
+    Abilities::MINUS,
+    Abilities::MULTISCALE,
+    Abilities::NEUROFORCE,
+    Abilities::NOGUARD,
+    Abilities::PARENTALBOND,
+    Abilities::PLUS,
+    Abilities::PUNKROCK,
+    Abilities::PUREPOWER,
+    Abilities::SHEERFORCE,
+    Abilities::SHELLARMOR,
+    Abilities::SKILLLINK,
+    Abilities::SOLIDROCK,
+    Abilities::STEELWORKER,
+    Abilities::STRONGJAW,
+    Abilities::SUPERLUCK,
+    Abilities::TECHNICIAN,
+    Abilities::THICKFAT,
+    Abilities::TINTEDLENS,
+    Abilities::TOUGHCLAWS,
+    // Immunities
+    Abilities::DAMP,
+    Abilities::FLASHFIRE,
+    Abilities::LEVITATE,
+    Abilities::LIGHTNINGROD,
+    Abilities::MOTORDRIVE,
+    Abilities::SAPSIPPER,
+    Abilities::STORMDRAIN,
+    Abilities::VOLTABSORB,
+    Abilities::WATERABSORB,
+    Abilities::WONDERGUARD,
+    // Type changes
+    Abilities::AERILATE,
+    Abilities::GALVANIZE,
+    Abilities::NORMALIZE,
+    Abilities::PIXILATE,
+    Abilities::REFRIGERATE,
+    // Status immunities
+    Abilities::CLEARBODY,
+    Abilities::FULLMETALBODY,
+    Abilities::HYPERCUTTER,
+    Abilities::IMMUNITY,
+    Abilities::INNERFOCUS,
+    Abilities::INSOMNIA,
+    Abilities::LIMBER,
+    Abilities::MAGMAARMOR,
+    Abilities::OBLIVIOUS,
+    Abilities::OWNTEMPO,
+    Abilities::SWEETVEIL,
+    Abilities::VITALSPIRIT,
+    Abilities::WATERBUBBLE,
+    Abilities::WATERVEIL,
+    Abilities::WHITESMOKE,
+    // Speed and priority
+    Abilities::CHLOROPHYLL,
+    Abilities::GALEWINGS,
+    Abilities::MYCELIUMMIGHT,
+    Abilities::PRANKSTER,
+    Abilities::QUICKDRAW,
+    Abilities::QUICKFEET,
+    Abilities::SANDRUSH,
+    Abilities::SLUSHRUSH,
+    Abilities::STALL,
+    Abilities::SWIFTSWIM,
+    Abilities::TRIAGE,
+    Abilities::UNBURDEN,
+    // Contact
+    Abilities::AFTERMATH,
+    Abilities::CURSEDBODY,
+    Abilities::EFFECTSPORE,
+    Abilities::FLAMEBODY,
+    Abilities::GOOEY,
+    Abilities::IRONBARBS,
+    Abilities::MUMMY,
+    Abilities::POISONPOINT,
+    Abilities::ROUGHSKIN,
+    Abilities::STATIC,
+    Abilities::TANGLINGHAIR,
+    // Switching in and out
+    Abilities::DAUNTLESSSHIELD,
+    Abilities::DOWNLOAD,
+    Abilities::DRIZZLE,
+    Abilities::DROUGHT,
+    Abilities::ELECTRICSURGE,
+    Abilities::EMBODYASPECTCORNERSTONE,
+    Abilities::EMBODYASPECTHEARTHFLAME,
+    Abilities::EMBODYASPECTTEAL,
+    Abilities::EMBODYASPECTWELLSPRING,
+    Abilities::GRASSYSURGE,
+    Abilities::INTIMIDATE,
+    Abilities::INTREPIDSWORD,
+    Abilities::MISTYSURGE,
+    Abilities::NATURALCURE,
+    Abilities::PROTOSYNTHESIS,
+    Abilities::PSYCHICSURGE,
+    Abilities::QUARKDRIVE,
+    Abilities::REGENERATOR,
+    Abilities::SANDSTREAM,
+    Abilities::SCREENCLEANER,
+    Abilities::SLOWSTART,
+    Abilities::SNOWWARNING,
+    Abilities::TRACE,
+    // End of turn and weather
+    Abilities::AIRLOCK,
+    Abilities::BADDREAMS,
+    Abilities::CLOUDNINE,
+    Abilities::DRYSKIN,
+    Abilities::HYDRATION,
+    Abilities::ICEBODY,
+    Abilities::MAGICGUARD,
+    Abilities::MOODY,
+    Abilities::OVERCOAT,
+    Abilities::POISONHEAL,
+    Abilities::RAINDISH,
+    Abilities::SANDFORCE,
+    Abilities::SANDVEIL,
+    Abilities::SHEDSKIN,
+    Abilities::SNOWCLOAK,
+    Abilities::SOLARPOWER,
+    Abilities::SPEEDBOOST,
+    // Berries
+    Abilities::CHEEKPOUCH,
+    Abilities::CUDCHEW,
+    Abilities::GLUTTONY,
+    Abilities::HARVEST,
+    Abilities::RIPEN,
+    // Switching and trapping
+    Abilities::ARENATRAP,
+    Abilities::EMERGENCYEXIT,
+    Abilities::MAGNETPULL,
+    Abilities::SHADOWTAG,
+    Abilities::WIMPOUT,
+    // Formes and disguises
+    Abilities::DISGUISE,
+    Abilities::GULPMISSILE,
+    Abilities::HUNGERSWITCH,
+    Abilities::ICEFACE,
+    Abilities::ILLUSION,
+    Abilities::POWERCONSTRUCT,
+    Abilities::SCHOOLING,
+    Abilities::SHIELDSDOWN,
+    Abilities::STANCECHANGE,
+    Abilities::ZENMODE,
+    Abilities::ZEROTOHERO,
+    // Other
+    Abilities::GORILLATACTICS,
+    Abilities::INFILTRATOR,
+    Abilities::LIQUIDOOZE,
+    Abilities::LONGREACH,
+    Abilities::MAGICBOUNCE,
+    Abilities::MOLDBREAKER,
+    Abilities::NEUTRALIZINGGAS,
+    Abilities::ROCKHEAD,
+    Abilities::STICKYHOLD,
+    Abilities::TERAVOLT,
+    Abilities::TURBOBLAZE,
+    Abilities::UNSEENFIST,
+];
+
+/// Held items with a handler somewhere in the engine
+const IMPLEMENTED_ITEMS: &[Items] = &[
+    // Damage, accuracy and critical hits
+    Items::CHOICEBAND,
+    Items::CHOICESPECS,
+    Items::EVIOLITE,
+    Items::EXPERTBELT,
+    Items::FOCUSSASH,
+    Items::LEEK,
+    Items::LIFEORB,
+    Items::LOADEDDICE,
+    Items::LUCKYPUNCH,
+    Items::MUSCLEBAND,
+    Items::PUNCHINGGLOVE,
+    Items::RAZORCLAW,
+    Items::SCOPELENS,
+    Items::STICK,
+    Items::WIDELENS,
+    Items::WISEGLASSES,
+    Items::ZOOMLENS,
+    // Type boosts and gems
+    Items::BLACKBELT,
+    Items::BLACKGLASSES,
+    Items::BUGGEM,
+    Items::CHARCOAL,
+    Items::DARKGEM,
+    Items::DRACOPLATE,
+    Items::DRAGONFANG,
+    Items::DRAGONGEM,
+    Items::DRAGONSCALE,
+    Items::DREADPLATE,
+    Items::EARTHPLATE,
+    Items::ELECTRICGEM,
+    Items::FAIRYFEATHER,
+    Items::FAIRYGEM,
+    Items::FIGHTINGGEM,
+    Items::FIREGEM,
+    Items::FISTPLATE,
+    Items::FLAMEPLATE,
+    Items::FLYINGGEM,
+    Items::GHOSTGEM,
+    Items::GRASSGEM,
+    Items::GROUNDGEM,
+    Items::HARDSTONE,
+    Items::ICEGEM,
+    Items::ICICLEPLATE,
+    Items::INSECTPLATE,
+    Items::IRONPLATE,
+    Items::MAGNET,
+    Items::MEADOWPLATE,
+    Items::METALCOAT,
+    Items::MINDPLATE,
+    Items::MIRACLESEED,
+    Items::MYSTICWATER,
+    Items::NEVERMELTICE,
+    Items::NORMALGEM,
+    Items::ODDINCENSE,
+    Items::PINKBOW,
+    Items::PIXIEPLATE,
+    Items::POISONBARB,
+    Items::POISONGEM,
+    Items::POLKADOTBOW,
+    Items::PSYCHICGEM,
+    Items::ROCKGEM,
+    Items::SEAINCENSE,
+    Items::SHARPBEAK,
+    Items::SILKSCARF,
+    Items::SILVERPOWDER,
+    Items::SKYPLATE,
+    Items::SOFTSAND,
+    Items::SPELLTAG,
+    Items::SPLASHPLATE,
+    Items::SPOOKYPLATE,
+    Items::STEELGEM,
+    Items::STONEPLATE,
+    Items::TOXICPLATE,
+    Items::TWISTEDSPOON,
+    Items::WATERGEM,
+    Items::WAVEINCENSE,
+    Items::ZAPPLATE,
+    // Species items
+    Items::ADAMANTORB,
+    Items::GRISEOUSORB,
+    Items::LIGHTBALL,
+    Items::LUSTROUSORB,
+    Items::METALPOWDER,
+    Items::RUSTEDSHIELD,
+    Items::RUSTEDSWORD,
+    Items::SOULDEW,
+    Items::THICKCLUB,
+    // Speed and priority
+    Items::CHOICESCARF,
+    Items::LAGGINGTAIL,
+    Items::MACHOBRACE,
+    Items::POWERANKLET,
+    Items::POWERBAND,
+    Items::POWERBELT,
+    Items::POWERBRACER,
+    Items::POWERLENS,
+    Items::POWERWEIGHT,
+    Items::QUICKCLAW,
+    // Move restrictions
+    Items::ASSAULTVEST,
+    Items::POWERHERB,
+    Items::UTILITYUMBRELLA,
+    // Status cures
+    Items::ASPEARBERRY,
+    Items::CHERIBERRY,
+    Items::CHESTOBERRY,
+    Items::LUMBERRY,
+    Items::MENTALHERB,
+    Items::MINTBERRY,
+    Items::MIRACLEBERRY,
+    Items::PECHABERRY,
+    Items::PERSIMBERRY,
+    Items::RAWSTBERRY,
+    // Stat boosts and resets
+    Items::ABSORBBULB,
+    Items::ADRENALINEORB,
+    Items::BLUNDERPOLICY,
+    Items::BOOSTERENERGY,
+    Items::CELLBATTERY,
+    Items::ELECTRICSEED,
+    Items::GRASSYSEED,
+    Items::MISTYSEED,
+    Items::PSYCHICSEED,
+    Items::ROOMSERVICE,
+    Items::THROATSPRAY,
+    Items::WEAKNESSPOLICY,
+    Items::WHITEHERB,
+    // Berries
+    Items::APICOTBERRY,
+    Items::BABIRIBERRY,
+    Items::CHARTIBERRY,
+    Items::CHILANBERRY,
+    Items::CHOPLEBERRY,
+    Items::COBABERRY,
+    Items::COLBURBERRY,
+    Items::CUSTAPBERRY,
+    Items::ENIGMABERRY,
+    Items::GANLONBERRY,
+    Items::HABANBERRY,
+    Items::JABOCABERRY,
+    Items::KASIBBERRY,
+    Items::KEBIABERRY,
+    Items::KEEBERRY,
+    Items::LANSATBERRY,
+    Items::LEPPABERRY,
+    Items::LIECHIBERRY,
+    Items::MARANGABERRY,
+    Items::MICLEBERRY,
+    Items::OCCABERRY,
+    Items::ORANBERRY,
+    Items::PASSHOBERRY,
+    Items::PAYAPABERRY,
+    Items::PETAYABERRY,
+    Items::RINDOBERRY,
+    Items::ROSELIBERRY,
+    Items::ROWAPBERRY,
+    Items::SALACBERRY,
+    Items::SHUCABERRY,
+    Items::SITRUSBERRY,
+    Items::STARFBERRY,
+    Items::TANGABERRY,
+    Items::WACANBERRY,
+    Items::YACHEBERRY,
+    // End of turn and contact
+    Items::AIRBALLOON,
+    Items::BLACKSLUDGE,
+    Items::FLAMEORB,
+    Items::LEFTOVERS,
+    Items::ROCKYHELMET,
+    Items::STICKYBARB,
+    Items::TOXICORB,
+    // Field duration
+    Items::DAMPROCK,
+    Items::HEATROCK,
+    Items::ICYROCK,
+    Items::LIGHTCLAY,
+    Items::SMOOTHROCK,
+    Items::TERRAINEXTENDER,
+    // Switching and trapping
+    Items::BINDINGBAND,
+    Items::EJECTBUTTON,
+    Items::EJECTPACK,
+    Items::GRIPCLAW,
+    Items::IRONBALL,
+    Items::REDCARD,
+    Items::SHEDSHELL,
+    // Other
+    Items::ABILITYSHIELD,
+    Items::BIGROOT,
+    Items::CLEARAMULET,
+    Items::CORNERSTONEMASK,
+    Items::COVERTCLOAK,
+    Items::HEARTHFLAMEMASK,
+    Items::HEAVYDUTYBOOTS,
+    Items::MIRRORHERB,
+    Items::PROTECTIVEPADS,
+    Items::SHELLBELL,
+    Items::WELLSPRINGMASK,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_coverage() {
+        assert_eq!(move_coverage(Moves::SWORDSDANCE, MoveCategory::Status), Coverage::Implemented);
+        assert_eq!(move_coverage(Moves::TACKLE, MoveCategory::Physical), Coverage::Generic);
+        assert_eq!(move_coverage(Moves::MAGICROOM, MoveCategory::Status), Coverage::Unimplemented);
+    }
+
+    #[test]
+    fn test_ability_and_item_coverage() {
+        assert_eq!(ability_coverage(Abilities::NONE), Coverage::Implemented);
+        assert_eq!(ability_coverage(Abilities::LEVITATE), Coverage::Implemented);
+        assert_eq!(ability_coverage(Abilities::PICKUP), Coverage::Unimplemented);
+        assert_eq!(item_coverage(Items::LEFTOVERS), Coverage::Implemented);
+        assert_eq!(item_coverage(Items::FLOATSTONE), Coverage::Unimplemented);
+    }
+
+    #[test]
+    fn test_new_with_teams_checks_coverage() {
+        use crate::core::battle_format::BattleFormat;
+        use crate::data::generation_loader::GenerationRepository;
+        use crate::data::{GameDataRepository, RandomPokemonSet};
+        use crate::types::PokemonName;
+        use std::sync::Arc;
+
+        let team = |moves: Vec<Moves>| {
+            vec![RandomPokemonSet {
+                name: "Machamp".to_string(),
+                species: PokemonName::MACHAMP,
+                level: 100,
+                gender: None,
+                shiny: None,
+                ability: Some(Abilities::GUTS),
+                item: None,
+                moves,
+                nature: None,
+                evs: None,
+                ivs: None,
+                tera_type: None,
+                gigantamax: None,
+            }]
+        };
+        let generation_repo = Arc::new(GenerationRepository::load_from_directory("data/ps-extracted").unwrap());
+        let game_data_repo = Arc::new(GameDataRepository::from_path("data/ps-extracted").unwrap());
+        let new_state = |strict| {
+            BattleState::new_with_teams(
+                BattleFormat::gen9_ou(),
+                team(vec![Moves::TACKLE, Moves::MAGICROOM]),
+                team(vec![Moves::TACKLE]),
+                generation_repo.clone(),
+                game_data_repo.clone(),
+                strict,
+            )
+        };
+
+        let (_, gaps) = new_state(false).unwrap();
+        assert_eq!(gaps.len(), 1);
+        assert_eq!((gaps[0].kind, gaps[0].name.as_str()), (EffectKind::Move, "Magic Room"));
+        assert!(matches!(new_state(true), Err(BattleError::TeamValidation(_))));
+    }
+
+    #[test]
+    fn test_random_battle_teams_only_report_real_gaps() {
+        use crate::core::battle_format::BattleFormat;
+        use crate::data::generation_loader::GenerationRepository;
+        use crate::data::{GameDataRepository, RandomTeamLoader};
+        use std::sync::Arc;
+
+        // The teams `tapu-simu battle --team-index 0` plays with. Everything
+        // reported here really does nothing in battle.
+        let format = BattleFormat::gen9_random_battle();
+        let mut loader = RandomTeamLoader::new();
+        let (_, gaps) = BattleState::new_with_teams(
+            format.clone(),
+            loader.get_team_by_index(&format, 0).unwrap(),
+            loader.get_team_by_index(&format, 1).unwrap(),
+            Arc::new(GenerationRepository::load_from_directory("data/ps-extracted").unwrap()),
+            Arc::new(GameDataRepository::from_path("data/ps-extracted").unwrap()),
+            false,
+        )
+        .unwrap();
+
+        let mut reported: Vec<String> = gaps.iter().map(|gap| gap.to_string()).collect();
+        reported.sort();
+        assert_eq!(
+            reported,
+            [
+                "Articuno: ability Pressure",
+                "Dachsbun: ability Well-Baked Body",
+                "Dewgong: move Encore",
+                "Exeggutor-Alola: ability Frisk",
+                "Hawlucha: move Encore",
+                "Lilligant-Hisui: ability Hustle",
+                "Lucario: ability Justified",
+                "Orthworm: ability Earth Eater",
+                "Orthworm: move Coil",
+                "Shaymin-Sky: ability Serene Grace",
+            ]
+        );
+    }
+}
@@ -4,11 +4,17 @@ use crate::core::battle_state::Pokemon;
 use crate::core::battle_state::BattleState;
 use crate::core::battle_format::BattlePosition;
 use crate::engine::combat::damage_context::DamageContext;
+use crate::engine::combat::core::ability_suppression::defending_ability;
+use crate::data::showdown_types::MoveData;
 
 
 #[derive(Debug, Clone)]
 pub struct AbilityContext<'a> {
+    /// Position of the ability's holder
     pub user_position: BattlePosition,
+    /// Position of the Pokemon using the move, when a move is involved
+    pub attacker_position: Option<BattlePosition>,
+    /// Position of the move's target, when a move is involved
     pub target_position: Option<BattlePosition>,
     pub move_type: Option<PokemonType>,
     pub move_id: Option<&'a str>,
@@ -110,6 +116,10 @@ impl AbilityEffectResult {
 }
 
 pub fn apply_ability_effect(ability: &Abilities, context: AbilityContext) -> AbilityEffectResult {
+    if !ability_in_effect(ability, &context) {
+        return AbilityEffectResult::none();
+    }
+
     match *ability {
         // Type immunities
         Abilities::LEVITATE => apply_levitate(context),
@@ -178,6 +188,27 @@ pub fn apply_ability_effect(ability: &Abilities, context: AbilityContext) -> Abi
     }
 }
 
+/// Whether the holder's ability works here: it isn't suppressed by Neutralizing Gas
+/// or Gastro Acid, and, when the holder is defending, the attacker isn't ignoring it
+/// with Mold Breaker
+fn ability_in_effect(ability: &Abilities, context: &AbilityContext) -> bool {
+    let Some(holder) = context
+        .state
+        .get_pokemon_at_position(context.user_position)
+        .filter(|holder| holder.ability == *ability)
+    else {
+        return true;
+    };
+    let attacker = context
+        .attacker_position
+        .filter(|&position| position != context.user_position)
+        .and_then(|position| context.state.get_pokemon_at_position(position));
+    match attacker {
+        Some(attacker) => defending_ability(attacker, holder).is_some(),
+        None => !holder.ability_suppressed,
+    }
+}
+
 // Type immunity abilities
 fn apply_levitate(context: AbilityContext) -> AbilityEffectResult {
     if let Some(move_type) = context.move_type {
@@ -263,11 +294,20 @@ fn apply_dry_skin(context: AbilityContext) -> AbilityEffectResult {
     }
 }
 
+/// Effectiveness of the context's move against the defender on the format's type chart
+fn move_effectiveness(context: &AbilityContext, move_type: PokemonType, defender: &Pokemon) -> f32 {
+    use crate::engine::combat::type_effectiveness::TypeChart;
+    use crate::types::{FromNormalizedString, Moves};
+
+    let move_name = context
+        .move_id
+        .and_then(|id| Moves::from_normalized_str(&crate::utils::normalize_name(id)))
+        .unwrap_or(Moves::NONE);
+    TypeChart::for_format(&context.state.format).move_effectiveness(move_name, move_type, defender)
+}
+
 fn apply_wonder_guard(context: AbilityContext) -> AbilityEffectResult {
     // Wonder Guard only allows super effective moves to hit
-    use crate::engine::combat::type_effectiveness::TypeChart;
-    use crate::types::PokemonType;
-    
     // If no move type or target position, can't check effectiveness
     let move_type_id = match context.move_type {
         Some(type_id) => type_id,
@@ -285,26 +325,8 @@ fn apply_wonder_guard(context: AbilityContext) -> AbilityEffectResult {
         None => return AbilityEffectResult::none(),
     };
     
-    // Get defender types (already PokemonType)
-    let defender_type1 = defender.types[0];
-    let defender_type2 = if defender.types.len() > 1 {
-        defender.types[1]
-    } else {
-        defender_type1
-    };
-    
-    let move_type = move_type_id;
-    
-    // Check type effectiveness
-    let type_chart = TypeChart::default();
-    let effectiveness1 = type_chart.get_effectiveness(move_type, defender_type1);
-    let effectiveness2 = if defender_type1 != defender_type2 {
-        type_chart.get_effectiveness(move_type, defender_type2)
-    } else {
-        1.0
-    };
-    
-    let total_effectiveness = effectiveness1 * effectiveness2;
+    // Check type effectiveness on the format's type chart
+    let total_effectiveness = move_effectiveness(&context, move_type_id, defender);
     
     // Wonder Guard grants immunity unless the move is super effective (>1.0)
     if total_effectiveness <= 1.0 {
@@ -341,9 +363,6 @@ fn apply_thick_fat(context: AbilityContext) -> AbilityEffectResult {
 // Damage boost abilities
 fn apply_neuroforce(context: AbilityContext) -> AbilityEffectResult {
     // Boosts super effective moves by 25%
-    use crate::engine::combat::type_effectiveness::TypeChart;
-    use crate::types::PokemonType;
-    
     // If no move type or target position, can't check effectiveness
     let move_type_id = match context.move_type {
         Some(type_id) => type_id,
@@ -361,26 +380,8 @@ fn apply_neuroforce(context: AbilityContext) -> AbilityEffectResult {
         None => return AbilityEffectResult::none(),
     };
     
-    // Get defender types (already PokemonType)
-    let defender_type1 = defender.types[0];
-    let defender_type2 = if defender.types.len() > 1 {
-        defender.types[1]
-    } else {
-        defender_type1
-    };
-    
-    let move_type = move_type_id;
-    
-    // Check type effectiveness
-    let type_chart = TypeChart::default();
-    let effectiveness1 = type_chart.get_effectiveness(move_type, defender_type1);
-    let effectiveness2 = if defender_type1 != defender_type2 {
-        type_chart.get_effectiveness(move_type, defender_type2)
-    } else {
-        1.0
-    };
-    
-    let total_effectiveness = effectiveness1 * effectiveness2;
+    // Check type effectiveness on the format's type chart
+    let total_effectiveness = move_effectiveness(&context, move_type_id, defender);
     
     // Neuroforce boosts super effective moves by 25% (1.25x multiplier)
     if total_effectiveness > 1.0 {
@@ -392,9 +393,6 @@ fn apply_neuroforce(context: AbilityContext) -> AbilityEffectResult {
 
 fn apply_tinted_lens(context: AbilityContext) -> AbilityEffectResult {
     // Doubles damage of not very effective moves
-    use crate::engine::combat::type_effectiveness::TypeChart;
-    use crate::types::PokemonType;
-    
     // If no move type or target position, can't check effectiveness
     let move_type_id = match context.move_type {
         Some(type_id) => type_id,
@@ -412,26 +410,8 @@ fn apply_tinted_lens(context: AbilityContext) -> AbilityEffectResult {
         None => return AbilityEffectResult::none(),
     };
     
-    // Get defender types (already PokemonType)
-    let defender_type1 = defender.types[0];
-    let defender_type2 = if defender.types.len() > 1 {
-        defender.types[1]
-    } else {
-        defender_type1
-    };
-    
-    let move_type = move_type_id;
-    
-    // Check type effectiveness
-    let type_chart = TypeChart::default();
-    let effectiveness1 = type_chart.get_effectiveness(move_type, defender_type1);
-    let effectiveness2 = if defender_type1 != defender_type2 {
-        type_chart.get_effectiveness(move_type, defender_type2)
-    } else {
-        1.0
-    };
-    
-    let total_effectiveness = effectiveness1 * effectiveness2;
+    // Check type effectiveness on the format's type chart
+    let total_effectiveness = move_effectiveness(&context, move_type_id, defender);
     
     // Tinted Lens doubles damage of not very effective moves (<1.0)
     if total_effectiveness < 1.0 {
@@ -653,8 +633,124 @@ fn apply_type_change(context: AbilityContext, target_type: &str, power_multiplie
     AbilityEffectResult::none()
 }
 
+// Secondary-effect abilities
 
+/// Sheer Force applies when the user has the ability and the move has a secondary effect to drop.
+/// The move gains 1.3x power and its secondaries, Life Orb recoil and the target's
+/// Red Card / Eject Button / Emergency Exit triggers are all suppressed.
+pub fn sheer_force_active(pokemon: &Pokemon, move_data: &MoveData) -> bool {
+    pokemon.ability == Abilities::SHEERFORCE && move_data.secondary.is_some()
+}
 
+/// Parental Bond turns single-target, single-hit damaging moves into two hits
+pub fn parental_bond_applies(pokemon: &Pokemon, move_data: &MoveData, target_count: usize) -> bool {
+    if pokemon.ability != Abilities::PARENTALBOND || target_count != 1 {
+        return false;
+    }
+    if move_data.category == crate::core::instructions::MoveCategory::Status || move_data.base_power == 0 {
+        return false;
+    }
+    if move_data.multihit.is_some()
+        || move_data.flags.contains_key("noparentalbond")
+        || move_data.flags.contains_key("charge")
+        || move_data.flags.contains_key("futuremove")
+    {
+        return false;
+    }
+    let is_z = !matches!(move_data.is_z, crate::data::showdown_types::ZMoveData::None(false));
+    let is_max = !matches!(move_data.is_max, crate::data::showdown_types::MaxMoveData::None(false));
+    !is_z && !is_max
+}
 
+/// Power multiplier for Parental Bond's second hit (0.5x before Gen 7, 0.25x after)
+pub fn parental_bond_second_hit_multiplier(generation: u8) -> f32 {
+    if generation >= 7 {
+        0.25
+    } else {
+        0.5
+    }
+}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::battle_format::SideReference;
+    use crate::data::showdown_types::SecondaryEffect;
 
+    fn move_with_secondary() -> MoveData {
+        MoveData {
+            base_power: 90,
+            secondary: Some(SecondaryEffect {
+                chance: 10,
+                status: None,
+                volatile_status: None,
+                boosts: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sheer_force_requires_secondary() {
+        let pokemon = Pokemon {
+            ability: Abilities::SHEERFORCE,
+            ..Default::default()
+        };
+        assert!(sheer_force_active(&pokemon, &move_with_secondary()));
+        assert!(!sheer_force_active(&pokemon, &MoveData::default()));
+    }
+
+    #[test]
+    fn test_parental_bond_single_target_only() {
+        let pokemon = Pokemon {
+            ability: Abilities::PARENTALBOND,
+            ..Default::default()
+        };
+        let move_data = MoveData::default();
+        assert!(parental_bond_applies(&pokemon, &move_data, 1));
+        assert!(!parental_bond_applies(&pokemon, &move_data, 2));
+        assert_eq!(parental_bond_second_hit_multiplier(9), 0.25);
+        assert_eq!(parental_bond_second_hit_multiplier(6), 0.5);
+    }
+
+    /// Singles state with `one` facing `two`
+    fn state_with(one: Abilities, two: Abilities) -> BattleState {
+        let mut state = BattleState::default();
+        for (side, ability) in [(0, one), (1, two)] {
+            state.sides[side].add_pokemon(Pokemon {
+                ability,
+                ..Default::default()
+            });
+            state.sides[side].set_active_pokemon_at_slot(0, Some(0));
+        }
+        state
+    }
+
+    fn move_context(state: &BattleState, holder: BattlePosition) -> AbilityContext<'_> {
+        AbilityContext {
+            user_position: holder,
+            attacker_position: Some(BattlePosition::new(SideReference::SideOne, 0)),
+            target_position: Some(BattlePosition::new(SideReference::SideTwo, 0)),
+            move_type: None,
+            move_id: None,
+            base_power: None,
+            is_critical: false,
+            is_contact: false,
+            state,
+        }
+    }
+
+    #[test]
+    fn test_mold_breaker_ignores_defending_ability() {
+        let state = state_with(Abilities::MOLDBREAKER, Abilities::FILTER);
+        let context = move_context(&state, BattlePosition::new(SideReference::SideTwo, 0));
+        assert!(!ability_in_effect(&Abilities::FILTER, &context));
+    }
+
+    #[test]
+    fn test_mold_breaker_defender_leaves_attacking_ability_alone() {
+        let state = state_with(Abilities::PUNKROCK, Abilities::MOLDBREAKER);
+        let context = move_context(&state, BattlePosition::new(SideReference::SideOne, 0));
+        assert!(ability_in_effect(&Abilities::PUNKROCK, &context));
+    }
+}
@@ -10,9 +10,9 @@ use crate::engine::combat::type_effectiveness::TypeChart;
 use crate::types::{PokemonType, StatBoostArray};
 use crate::core::battle_state::{MoveCategory, Pokemon};
 use crate::core::battle_format::BattlePosition;
-use crate::core::instructions::{Stat, PokemonStatus};
-use crate::core::instructions::{BattleInstruction, BattleInstructions, StatusInstruction, PokemonInstruction, StatsInstruction};
-use crate::types::{Items, Moves};
+use crate::core::instructions::{Stat, PokemonStatus, VolatileStatus};
+use crate::core::instructions::{BattleInstruction, StatusInstruction, PokemonInstruction, StatsInstruction};
+use crate::types::{Abilities, Items, Moves};
 use std::collections::HashMap;
 
 /// Get berry item effect if the item is a berry
@@ -45,7 +45,7 @@ pub fn get_berry_item_effect(
         Items::HABANBERRY => Some(damage_reduction_berry_effect(PokemonType::Dragon, *move_type_id, context)),
         Items::COLBURBERRY => Some(damage_reduction_berry_effect(PokemonType::Dark, *move_type_id, context)),
         Items::ROSELIBERRY => Some(damage_reduction_berry_effect(PokemonType::Fairy, *move_type_id, context)),
-        Items::CHILANBERRY => Some(chilan_berry_effect(*move_type_id)), // Special case for Normal
+        Items::CHILANBERRY => Some(chilan_berry_effect(*move_type_id, context)), // Special case for Normal
         
         // Healing/Status Berries (5 items)
         Items::LUMBERRY => Some(lum_berry_effect(defender)),
@@ -68,42 +68,29 @@ pub fn get_berry_item_effect(
 // DAMAGE REDUCTION BERRIES (18 items)
 // =============================================================================
 
-/// Standard damage reduction berry that halves super effective damage
+/// Resist berry that halves super effective damage of its type, or a quarter with Ripen
 fn damage_reduction_berry_effect(
     resisted_type: PokemonType,
     move_type: PokemonType,
     context: &DamageContext,
 ) -> ItemModifier {
     // Check if this move is the resisted type
-    if move_type != resisted_type {
+    let type_effectiveness = TypeChart::for_format(&context.format.format)
+        .move_effectiveness(context.move_info.name, move_type, context.defender.pokemon);
+    if move_type != resisted_type || type_effectiveness <= 1.0 {
         return ItemModifier::default();
     }
-    
-    // Check if move is super effective
-    let type_chart = TypeChart::get_cached(9); // Gen 9 type chart
-    let attacking_type = move_type;
-    let defender_type1 = context.defender.pokemon.types.get(0).copied().unwrap_or(PokemonType::Normal);
-    let defender_type2 = context.defender.pokemon.types.get(1).copied().unwrap_or(defender_type1);
-    let type_effectiveness = type_chart.calculate_damage_multiplier(
-        attacking_type,
-        (defender_type1, defender_type2),
-        None, // No tera type
-        None, // No special move name
-    );
-    if type_effectiveness > 1.0 {
-        ItemModifier::new()
-            .with_damage_multiplier(0.5)
-            .with_consumed()
-    } else {
-        ItemModifier::default()
-    }
+
+    ItemModifier::new()
+        .with_damage_multiplier(0.5 / ripen_factor(context.defender.pokemon) as f32)
+        .with_consumed()
 }
 
 /// Chilan Berry - Special case that reduces Normal-type damage regardless of effectiveness
-fn chilan_berry_effect(move_type: PokemonType) -> ItemModifier {
+fn chilan_berry_effect(move_type: PokemonType, context: &DamageContext) -> ItemModifier {
     if move_type == PokemonType::Normal {
         ItemModifier::new()
-            .with_damage_multiplier(0.5)
+            .with_damage_multiplier(0.5 / ripen_factor(context.defender.pokemon) as f32)
             .with_consumed()
     } else {
         ItemModifier::default()
@@ -267,230 +254,321 @@ fn custap_berry_effect(pokemon: &Pokemon) -> ItemModifier {
     }
 }
 
-/// Generate berry activation instructions for reactive berries
-pub fn generate_berry_activation_instructions(
-    item_id: &Items,
+// =============================================================================
+// EATING BERRIES
+// =============================================================================
+
+/// Whether an item is a berry
+pub fn is_berry(item: Items) -> bool {
+    item.as_str().ends_with("berry")
+}
+
+/// How many times over Ripen applies a berry's effect
+fn ripen_factor(pokemon: &Pokemon) -> i8 {
+    if pokemon.ability == Abilities::RIPEN && !pokemon.ability_suppressed {
+        2
+    } else {
+        1
+    }
+}
+
+/// The type whose damage a resist berry weakens
+fn resist_berry_type(item: Items) -> Option<PokemonType> {
+    match item {
+        Items::CHOPLEBERRY => Some(PokemonType::Fighting),
+        Items::COBABERRY => Some(PokemonType::Flying),
+        Items::KEBIABERRY => Some(PokemonType::Poison),
+        Items::SHUCABERRY => Some(PokemonType::Ground),
+        Items::CHARTIBERRY => Some(PokemonType::Rock),
+        Items::TANGABERRY => Some(PokemonType::Bug),
+        Items::KASIBBERRY => Some(PokemonType::Ghost),
+        Items::BABIRIBERRY => Some(PokemonType::Steel),
+        Items::OCCABERRY => Some(PokemonType::Fire),
+        Items::PASSHOBERRY => Some(PokemonType::Water),
+        Items::RINDOBERRY => Some(PokemonType::Grass),
+        Items::WACANBERRY => Some(PokemonType::Electric),
+        Items::PAYAPABERRY => Some(PokemonType::Psychic),
+        Items::YACHEBERRY => Some(PokemonType::Ice),
+        Items::HABANBERRY => Some(PokemonType::Dragon),
+        Items::COLBURBERRY => Some(PokemonType::Dark),
+        Items::ROSELIBERRY => Some(PokemonType::Fairy),
+        Items::CHILANBERRY => Some(PokemonType::Normal),
+        _ => None,
+    }
+}
+
+/// Whether the defender's resist berry weakens a hit of this type and effectiveness
+///
+/// Chilan Berry weakens any Normal-type hit; the rest need it to be super effective.
+fn resist_berry_activates(defender: &Pokemon, move_type: PokemonType, type_effectiveness: f32) -> bool {
+    match defender.item {
+        Some(Items::CHILANBERRY) => move_type == PokemonType::Normal,
+        Some(item) => resist_berry_type(item) == Some(move_type) && type_effectiveness > 1.0,
+        None => false,
+    }
+}
+
+/// Damage multiplier from the defender's resist berry: halved, or quartered with Ripen
+pub fn resist_berry_damage_multiplier(defender: &Pokemon, move_type: PokemonType, type_effectiveness: f32) -> f32 {
+    if resist_berry_activates(defender, move_type, type_effectiveness) {
+        0.5 / ripen_factor(defender) as f32
+    } else {
+        1.0
+    }
+}
+
+/// HP fraction at or below which a pinch berry is eaten
+///
+/// Gluttony eats the quarter-HP berries at half HP instead.
+pub fn pinch_berry_threshold(pokemon: &Pokemon, berry: Items) -> Option<f32> {
+    match berry {
+        Items::SITRUSBERRY | Items::ORANBERRY => Some(0.5),
+        Items::LIECHIBERRY
+        | Items::GANLONBERRY
+        | Items::SALACBERRY
+        | Items::PETAYABERRY
+        | Items::APICOTBERRY
+        | Items::LANSATBERRY
+        | Items::STARFBERRY
+        | Items::MICLEBERRY
+        | Items::CUSTAPBERRY => {
+            if pokemon.ability == Abilities::GLUTTONY && !pokemon.ability_suppressed {
+                Some(0.5)
+            } else {
+                Some(0.25)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Instructions for a berry's effect on the Pokemon eating it at `hp`
+///
+/// Ripen doubles healing, stat raises and PP restored. Jaboca and Rowap have
+/// no effect on the holder; their damage comes from being hit.
+pub fn berry_effect_instructions(
+    berry: Items,
     pokemon: &Pokemon,
     position: BattlePosition,
+    hp: i16,
     generation: &dyn GenerationBattleMechanics,
-) -> Option<BattleInstructions> {
-    match item_id {
-        Items::LUMBERRY => {
-            if pokemon.status != PokemonStatus::None {
-                let instructions = vec![
-                    BattleInstruction::Status(StatusInstruction::Remove {
-                        target: position,
-                        status: pokemon.status,
-                        previous_duration: None,
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::LUMBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
-            }
-        },
-        
-        Items::SITRUSBERRY => {
-            let threshold = match generation.generation() {
-                Generation::Gen3 => 0.5,
-                _ => 0.25,
-            };
-            
-            let hp_percentage = pokemon.hp as f32 / pokemon.max_hp as f32;
-            if hp_percentage <= threshold {
-                // Heal 1/4 of max HP
-                let heal_amount = pokemon.max_hp / 4;
-                
-                let instructions = vec![
-                    BattleInstruction::Pokemon(PokemonInstruction::Heal {
-                        target: position,
-                        amount: heal_amount,
-                        previous_hp: Some(pokemon.hp),
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::SITRUSBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
-            }
-        },
-        
-        Items::CHESTOBERRY => {
-            if pokemon.status == PokemonStatus::Sleep {
-                let instructions = vec![
-                    BattleInstruction::Status(StatusInstruction::Remove {
-                        target: position,
-                        status: PokemonStatus::Sleep,
-                        previous_duration: None,
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::CHESTOBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
-            }
-        },
-        
-        Items::LIECHIBERRY | Items::PETAYABERRY | Items::SALACBERRY | Items::GANLONBERRY | Items::APICOTBERRY => {
-            let hp_percentage = pokemon.hp as f32 / pokemon.max_hp as f32;
-            if hp_percentage <= 0.25 {
-                let stat = match item_id {
-                    Items::LIECHIBERRY => Stat::Attack,
-                    Items::PETAYABERRY => Stat::SpecialAttack,
-                    Items::SALACBERRY => Stat::Speed,
-                    Items::GANLONBERRY => Stat::Defense,
-                    Items::APICOTBERRY => Stat::SpecialDefense,
-                    _ => return None, // Should not happen, but return None instead of panicking
-                };
-                
-                let mut stat_changes = StatBoostArray::default();
-                stat_changes.insert(stat, 1);
-                
-                let instructions = vec![
-                    BattleInstruction::Stats(StatsInstruction::BoostStats {
-                        target: position,
-                        stat_changes: stat_changes.to_hashmap(),
-                        previous_boosts: std::collections::HashMap::new(),
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(*item_id),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
-            }
-        },
-        
-        Items::LANSATBERRY => {
-            let hp_percentage = pokemon.hp as f32 / pokemon.max_hp as f32;
-            if hp_percentage <= 0.25 {
-                // Lansat Berry applies Focus Energy effect (increases critical hit ratio)
-                let instructions = vec![
-                    BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                        target: position,
-                        status: crate::core::instructions::VolatileStatus::FocusEnergy,
-                        duration: None,
-                        previous_had_status: false,
-                        previous_duration: None,
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::LANSATBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
-            }
-        },
-        
+) -> Vec<BattleInstruction> {
+    let ripen = ripen_factor(pokemon);
+    let heal = |amount: i16| {
+        vec![BattleInstruction::Pokemon(PokemonInstruction::Heal {
+            target: position,
+            amount: amount * ripen as i16,
+            previous_hp: Some(hp),
+        })]
+    };
+    let boost = |stat: Stat, amount: i8| {
+        let mut stat_changes = StatBoostArray::default();
+        stat_changes.insert(stat, amount * ripen);
+        vec![BattleInstruction::Stats(StatsInstruction::BoostStats {
+            target: position,
+            stat_changes: stat_changes.to_hashmap(),
+            previous_boosts: HashMap::new(),
+        })]
+    };
+    let volatile = |status: VolatileStatus, duration: Option<u8>| {
+        vec![BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+            target: position,
+            status,
+            duration,
+            previous_had_status: pokemon.volatile_statuses.contains(status),
+            previous_duration: None,
+        })]
+    };
+    let cure = |cures: bool| {
+        if cures {
+            vec![BattleInstruction::Status(StatusInstruction::Remove {
+                target: position,
+                status: pokemon.status,
+                previous_duration: pokemon.status_duration,
+            })]
+        } else {
+            Vec::new()
+        }
+    };
+
+    match berry {
+        // Sitrus Berry heals a flat 30 HP in Gen 3
+        Items::SITRUSBERRY if generation.generation() == Generation::Gen3 => heal(30),
+        Items::SITRUSBERRY | Items::ENIGMABERRY => heal(pokemon.max_hp / 4),
+        Items::ORANBERRY => heal(10),
+
+        Items::LUMBERRY | Items::MIRACLEBERRY => cure(pokemon.status != PokemonStatus::None),
+        Items::CHESTOBERRY | Items::MINTBERRY => cure(pokemon.status == PokemonStatus::Sleep),
+        Items::CHERIBERRY => cure(pokemon.status == PokemonStatus::Paralysis),
+        Items::RAWSTBERRY => cure(pokemon.status == PokemonStatus::Burn),
+        Items::ASPEARBERRY => cure(pokemon.status == PokemonStatus::Freeze),
+        Items::PECHABERRY => cure(matches!(pokemon.status, PokemonStatus::Poison | PokemonStatus::BadlyPoisoned)),
+
+        Items::LIECHIBERRY => boost(Stat::Attack, 1),
+        Items::GANLONBERRY | Items::KEEBERRY => boost(Stat::Defense, 1),
+        Items::SALACBERRY => boost(Stat::Speed, 1),
+        Items::PETAYABERRY => boost(Stat::SpecialAttack, 1),
+        Items::APICOTBERRY | Items::MARANGABERRY => boost(Stat::SpecialDefense, 1),
         Items::STARFBERRY => {
-            let hp_percentage = pokemon.hp as f32 / pokemon.max_hp as f32;
-            if hp_percentage <= 0.25 {
-                // Starf Berry boosts a random stat by 2 stages (excluding HP)
-                use rand::seq::SliceRandom;
-                use rand::thread_rng;
-                
-                let boostable_stats = vec![
-                    Stat::Attack, 
-                    Stat::Defense, 
-                    Stat::SpecialAttack, 
-                    Stat::SpecialDefense, 
-                    Stat::Speed, 
-                    Stat::Accuracy, 
-                    Stat::Evasion
-                ];
-                
-                let mut rng = thread_rng();
-                let random_stat = *boostable_stats.choose(&mut rng)
-                    .expect("Boostable stats vec should not be empty");
-                let mut stat_changes = StatBoostArray::default();
-                stat_changes.insert(random_stat, 2);
-                
-                let instructions = vec![
-                    BattleInstruction::Stats(StatsInstruction::BoostStats {
-                        target: position,
-                        stat_changes: stat_changes.to_hashmap(),
-                        previous_boosts: std::collections::HashMap::new(),
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::STARFBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
+            // Starf Berry boosts a random stat by 2 stages (excluding HP)
+            use rand::seq::SliceRandom;
+            use rand::thread_rng;
+
+            let boostable_stats = [
+                Stat::Attack,
+                Stat::Defense,
+                Stat::SpecialAttack,
+                Stat::SpecialDefense,
+                Stat::Speed,
+                Stat::Accuracy,
+                Stat::Evasion,
+            ];
+            let random_stat = *boostable_stats
+                .choose(&mut thread_rng())
+                .expect("Boostable stats should not be empty");
+            boost(random_stat, 2)
+        }
+
+        // Lansat Berry applies Focus Energy; Micle and Custap boost the next move
+        Items::LANSATBERRY => volatile(VolatileStatus::FocusEnergy, None),
+        Items::MICLEBERRY => volatile(VolatileStatus::MicleBoost, Some(1)),
+        Items::CUSTAPBERRY => volatile(VolatileStatus::CustapBoost, Some(1)),
+
+        // Leppa Berry restores 10 PP to the first empty move, or else the first move missing PP
+        Items::LEPPABERRY => {
+            let restored = pokemon
+                .moves
+                .iter()
+                .find(|(_, move_data)| move_data.pp == 0)
+                .or_else(|| pokemon.moves.iter().find(|(_, move_data)| move_data.pp < move_data.max_pp));
+            match restored {
+                Some((move_index, move_data)) => vec![BattleInstruction::Status(StatusInstruction::RestorePP {
+                    target: position,
+                    move_index: *move_index,
+                    amount: 10 * ripen as u8,
+                    previous_pp: move_data.pp,
+                })],
+                None => Vec::new(),
             }
-        },
-        
-        Items::MICLEBERRY => {
-            let hp_percentage = pokemon.hp as f32 / pokemon.max_hp as f32;
-            if hp_percentage <= 0.25 {
-                // Micle Berry increases accuracy of next move
-                let instructions = vec![
-                    BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                        target: position,
-                        status: crate::core::instructions::VolatileStatus::MicleBoost,
-                        duration: Some(1), // Lasts for one move
-                        previous_had_status: false,
-                        previous_duration: None,
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::MICLEBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
+        }
+
+        _ => Vec::new(),
+    }
+}
+
+/// Instructions for the Pokemon at `hp` eating its held berry
+///
+/// The berry is used up, Cheek Pouch restores a third of max HP on top, and
+/// Cud Chew eats the berry again at the end of the next turn.
+pub fn eat_berry_instructions(
+    berry: Items,
+    pokemon: &Pokemon,
+    position: BattlePosition,
+    hp: i16,
+    generation: &dyn GenerationBattleMechanics,
+) -> Vec<BattleInstruction> {
+    let mut instructions = berry_effect_instructions(berry, pokemon, position, hp, generation);
+    instructions.push(BattleInstruction::Pokemon(PokemonInstruction::ConsumeItem {
+        target: position,
+        item: berry,
+        previous_consumed_item: pokemon.consumed_item,
+    }));
+
+    if hp > 0 && !pokemon.ability_suppressed {
+        match pokemon.ability {
+            Abilities::CHEEKPOUCH => {
+                instructions.push(BattleInstruction::Pokemon(PokemonInstruction::Heal {
+                    target: position,
+                    amount: pokemon.max_hp / 3,
+                    previous_hp: Some(hp),
+                }));
             }
-        },
-        
-        Items::CUSTAPBERRY => {
-            let hp_percentage = pokemon.hp as f32 / pokemon.max_hp as f32;
-            if hp_percentage <= 0.25 {
-                // Custap Berry increases priority of next move
-                let instructions = vec![
-                    BattleInstruction::Status(StatusInstruction::ApplyVolatile {
-                        target: position,
-                        status: crate::core::instructions::VolatileStatus::CustapBoost,
-                        duration: Some(1), // Lasts for one move
-                        previous_had_status: false,
-                        previous_duration: None,
-                    }),
-                    BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
-                        target: position,
-                        new_item: None,
-                        previous_item: Some(crate::types::Items::CUSTAPBERRY),
-                    })
-                ];
-                Some(BattleInstructions::new(100.0, instructions))
-            } else {
-                None
+            Abilities::CUDCHEW if !pokemon.volatile_statuses.contains(VolatileStatus::CudChew) => {
+                instructions.push(BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+                    target: position,
+                    status: VolatileStatus::CudChew,
+                    duration: Some(2),
+                    previous_had_status: false,
+                    previous_duration: None,
+                }));
             }
-        },
-        
-        _ => None,
+            _ => {}
+        }
+    }
+    instructions
+}
+
+/// Eat the held pinch berry if the Pokemon is left at or below its threshold at `hp`
+pub fn generate_berry_activation_instructions(
+    pokemon: &Pokemon,
+    position: BattlePosition,
+    hp: i16,
+    generation: &dyn GenerationBattleMechanics,
+) -> Vec<BattleInstruction> {
+    let berry = match pokemon.item {
+        Some(item) => item,
+        None => return Vec::new(),
+    };
+    match pinch_berry_threshold(pokemon, berry) {
+        Some(threshold) if hp > 0 && hp as f32 <= pokemon.max_hp as f32 * threshold => {
+            eat_berry_instructions(berry, pokemon, position, hp, generation)
+        }
+        _ => Vec::new(),
     }
 }
 
+/// Berries the defender eats after taking `damage` from a move
+///
+/// Resist berries are used up by the hit they weaken, Jaboca and Rowap hurt
+/// a physical or special attacker for 1/8 of its max HP even if the holder
+/// faints, Kee and Maranga raise a defence, Enigma heals after a super
+/// effective hit, and pinch berries are eaten once the hit leaves the holder
+/// low enough.
+#[allow(clippy::too_many_arguments)]
+pub fn after_hit_berry_instructions(
+    defender: &Pokemon,
+    defender_position: BattlePosition,
+    attacker: &Pokemon,
+    attacker_position: BattlePosition,
+    move_type: PokemonType,
+    type_effectiveness: f32,
+    move_category: MoveCategory,
+    damage: i16,
+    generation: &dyn GenerationBattleMechanics,
+) -> Vec<BattleInstruction> {
+    let berry = match defender.item {
+        Some(item) if damage > 0 => item,
+        _ => return Vec::new(),
+    };
+    let hp = (defender.hp - damage).max(0);
+
+    if resist_berry_activates(defender, move_type, type_effectiveness) {
+        return vec![BattleInstruction::Pokemon(PokemonInstruction::ConsumeItem {
+            target: defender_position,
+            item: berry,
+            previous_consumed_item: defender.consumed_item,
+        })];
+    }
+
+    match (berry, move_category) {
+        (Items::JABOCABERRY, MoveCategory::Physical) | (Items::ROWAPBERRY, MoveCategory::Special) => {
+            let mut instructions = eat_berry_instructions(berry, defender, defender_position, hp, generation);
+            if attacker.ability != Abilities::MAGICGUARD && attacker.hp > 0 {
+                instructions.insert(0, BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: attacker_position,
+                    amount: (attacker.max_hp * ripen_factor(defender) as i16 / 8).max(1),
+                    previous_hp: Some(attacker.hp),
+                }));
+            }
+            instructions
+        }
+        _ if hp == 0 => Vec::new(),
+        (Items::KEEBERRY, MoveCategory::Physical) | (Items::MARANGABERRY, MoveCategory::Special) => {
+            eat_berry_instructions(berry, defender, defender_position, hp, generation)
+        }
+        (Items::ENIGMABERRY, _) if type_effectiveness > 1.0 => {
+            eat_berry_instructions(berry, defender, defender_position, hp, generation)
+        }
+        _ => generate_berry_activation_instructions(defender, defender_position, hp, generation),
+    }
+}
@@ -113,34 +113,12 @@ fn life_orb_effect() -> ItemModifier {
 
 /// Expert Belt - Boosts super effective moves by 1.2x
 fn expert_belt_effect(context: &DamageContext) -> ItemModifier {
-    // Get type chart for the current generation
-    let generation = match context.get_generation() {
-        Generation::Gen1 => 1,
-        Generation::Gen2 => 2,
-        Generation::Gen3 => 3,
-        Generation::Gen4 => 4,
-        Generation::Gen5 => 5,
-        Generation::Gen6 => 6,
-        Generation::Gen7 => 7,
-        Generation::Gen8 => 8,
-        Generation::Gen9 => 9,
-    };
-    
-    let type_chart = TypeChart::get_cached(generation);
-    
-    // Get move type directly
-    let move_type = context.move_info.move_type;
-    
-    let defender_type1 = context.defender.pokemon.types.get(0).copied().unwrap_or(PokemonType::Normal);
-    
-    let defender_type2 = context.defender.pokemon.types.get(1).copied().unwrap_or(defender_type1);
-    
-    // Calculate type effectiveness including Tera type if applicable
-    let type_effectiveness = type_chart.calculate_damage_multiplier(
-        move_type,
-        (defender_type1, defender_type2),
-        context.defender.pokemon.tera_type,
-        Some(context.move_info.name.as_str()),
+    // Type effectiveness on the format's type chart, including Tera type if applicable
+    let type_chart = TypeChart::for_format(&context.format.format);
+    let type_effectiveness = type_chart.move_effectiveness(
+        context.move_info.name,
+        context.move_info.move_type,
+        context.defender.pokemon,
     );
     
     if type_effectiveness > 1.0 {
@@ -235,34 +213,12 @@ fn rocky_helmet_effect(context: &DamageContext) -> ItemModifier {
 
 /// Weakness Policy - +2 Attack/Special Attack when hit by super effective moves
 fn weakness_policy_effect(context: &DamageContext) -> ItemModifier {
-    // Get type chart for the current generation
-    let generation = match context.get_generation() {
-        Generation::Gen1 => 1,
-        Generation::Gen2 => 2,
-        Generation::Gen3 => 3,
-        Generation::Gen4 => 4,
-        Generation::Gen5 => 5,
-        Generation::Gen6 => 6,
-        Generation::Gen7 => 7,
-        Generation::Gen8 => 8,
-        Generation::Gen9 => 9,
-    };
-    
-    let type_chart = TypeChart::get_cached(generation);
-    
-    // Get move type directly
-    let move_type = context.move_info.move_type;
-    
-    let defender_type1 = context.defender.pokemon.types.get(0).copied().unwrap_or(PokemonType::Normal);
-    
-    let defender_type2 = context.defender.pokemon.types.get(1).copied().unwrap_or(defender_type1);
-    
-    // Calculate type effectiveness including Tera type if applicable
-    let type_effectiveness = type_chart.calculate_damage_multiplier(
-        move_type,
-        (defender_type1, defender_type2),
-        context.defender.pokemon.tera_type,
-        Some(context.move_info.name.as_str()),
+    // Type effectiveness on the format's type chart, including Tera type if applicable
+    let type_chart = TypeChart::for_format(&context.format.format);
+    let type_effectiveness = type_chart.move_effectiveness(
+        context.move_info.name,
+        context.move_info.move_type,
+        context.defender.pokemon,
     );
     
     if type_effectiveness > 1.0 {
@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 
 use crate::core::battle_format::BattlePosition;
-use crate::core::battle_state::{MoveCategory, Pokemon};
+use crate::core::battle_state::{BattleState, MoveCategory, Pokemon};
 use crate::core::instructions::{
     BattleInstruction, BattleInstructions, PokemonInstruction, PokemonStatus, Stat,
     StatusInstruction, StatsInstruction,
@@ -199,3 +199,95 @@ fn adrenaline_orb_intimidate_effect(position: BattlePosition) -> BattleInstructi
     BattleInstructions::new(100.0, instructions)
 }
 
+
+/// Covert Cloak - the holder is unaffected by the secondary effects of moves that hit it
+pub fn blocks_secondary_effects(pokemon: &Pokemon) -> bool {
+    pokemon.item == Some(Items::COVERTCLOAK)
+}
+
+/// Clear Amulet - remove the stat drops that `source_position` inflicts on holders
+///
+/// Drops a Pokemon inflicts on itself, like Close Combat's, still go through.
+pub fn remove_clear_amulet_drops(
+    state: &BattleState,
+    source_position: BattlePosition,
+    instructions: Vec<BattleInstruction>,
+) -> Vec<BattleInstruction> {
+    instructions
+        .into_iter()
+        .filter_map(|instruction| match instruction {
+            BattleInstruction::Stats(StatsInstruction::BoostStats { target, stat_changes, previous_boosts })
+                if target != source_position
+                    && state
+                        .get_pokemon_at_position(target)
+                        .is_some_and(|pokemon| pokemon.item == Some(Items::CLEARAMULET)) =>
+            {
+                let stat_changes: HashMap<Stat, i8> =
+                    stat_changes.into_iter().filter(|&(_, change)| change > 0).collect();
+                (!stat_changes.is_empty()).then_some(BattleInstruction::Stats(StatsInstruction::BoostStats {
+                    target,
+                    stat_changes,
+                    previous_boosts,
+                }))
+            }
+            instruction => Some(instruction),
+        })
+        .collect()
+}
+
+/// Mirror Herb - copy the stat raises opposing Pokemon gain in these instructions
+///
+/// Each holder copies the first raise an opponent gets and then consumes the herb.
+pub fn mirror_herb_instructions(state: &BattleState, instructions: &[BattleInstruction]) -> Vec<BattleInstruction> {
+    let mut copies = Vec::new();
+    let mut consumed: Vec<BattlePosition> = Vec::new();
+
+    for instruction in instructions {
+        let BattleInstruction::Stats(StatsInstruction::BoostStats { target, stat_changes, .. }) = instruction else {
+            continue;
+        };
+        let Some(booster) = state.get_pokemon_at_position(*target) else {
+            continue;
+        };
+
+        for position in state.get_all_active_positions() {
+            if position.side == target.side || consumed.contains(&position) {
+                continue;
+            }
+            let Some(holder) = state.get_pokemon_at_position(position) else {
+                continue;
+            };
+            if holder.item != Some(Items::MIRRORHERB) || holder.hp == 0 {
+                continue;
+            }
+
+            // Copy what the opponent actually gained, as far as the holder has room to rise
+            let mut copied = StatBoostArray::default();
+            for (&stat, &change) in stat_changes {
+                let current = booster.stat_boosts.get_direct(stat);
+                let gained = (current + change).min(6) - current;
+                let own = holder.stat_boosts.get_direct(stat);
+                let raise = gained.min(6 - own);
+                if raise > 0 {
+                    copied.insert(stat, raise);
+                }
+            }
+            if copied.is_empty() {
+                continue;
+            }
+
+            copies.push(BattleInstruction::Stats(StatsInstruction::BoostStats {
+                target: position,
+                stat_changes: copied.to_hashmap(),
+                previous_boosts: HashMap::new(),
+            }));
+            copies.push(BattleInstruction::Pokemon(PokemonInstruction::ChangeItem {
+                target: position,
+                new_item: None,
+                previous_item: Some(Items::MIRRORHERB),
+            }));
+            consumed.push(position);
+        }
+    }
+    copies
+}
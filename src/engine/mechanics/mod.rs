@@ -1,3 +1,4 @@
 pub mod abilities;
 pub mod items;
-pub mod switch_effects;
\ No newline at end of file
+pub mod switch_effects;
+pub mod priority;
\ No newline at end of file
@@ -0,0 +1,165 @@
+//! Ability- and item-aware move priority
+//!
+//! Priority is resolved in three layers, mirroring Pokemon Showdown:
+//! the move's priority bracket (modified by Prankster, Gale Wings and Triage),
+//! a fractional bracket inside it (Quick Claw / Quick Draw / Custap Berry move
+//! first, Stall / Lagging Tail / Mycelium Might move last), and finally speed.
+
+use crate::core::battle_format::BattlePosition;
+use crate::core::battle_state::{BattleState, Move, MoveCategory, Pokemon};
+use crate::core::instructions::{Terrain, VolatileStatus};
+use crate::types::{Abilities, Items, PokemonType};
+
+/// Quick Claw activation chance
+pub const QUICK_CLAW_CHANCE: f32 = 0.2;
+
+/// Quick Draw activation chance (non-status moves only)
+pub const QUICK_DRAW_CHANCE: f32 = 0.3;
+
+/// Position of a Pokemon inside its priority bracket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FractionalPriority {
+    /// Stall, Lagging Tail, Mycelium Might (status moves)
+    Last,
+    Normal,
+    /// Quick Claw, Quick Draw or Custap Berry activated
+    First,
+}
+
+/// Get a move's priority including ability modifiers
+pub fn effective_move_priority(state: &BattleState, pokemon: &Pokemon, move_data: &Move) -> i8 {
+    let mut priority = move_data.priority;
+
+    match pokemon.ability {
+        Abilities::PRANKSTER if move_data.category == MoveCategory::Status => priority += 1,
+        // Gen 7+ Gale Wings only works at full HP
+        Abilities::GALEWINGS
+            if move_data.move_type == PokemonType::Flying
+                && (state.format.generation.number() < 7 || pokemon.hp == pokemon.max_hp) =>
+        {
+            priority += 1
+        }
+        Abilities::TRIAGE if move_has_flag(state, move_data, "heal") => priority += 3,
+        _ => {}
+    }
+
+    priority
+}
+
+/// Whether this move got its priority from Prankster
+pub fn is_prankster_boosted(pokemon: &Pokemon, move_data: &Move) -> bool {
+    pokemon.ability == Abilities::PRANKSTER && move_data.category == MoveCategory::Status
+}
+
+/// Fractional priority that doesn't depend on a random activation
+pub fn base_fractional_priority(pokemon: &Pokemon, move_data: &Move) -> FractionalPriority {
+    if pokemon.volatile_statuses.contains(VolatileStatus::CustapBoost) {
+        return FractionalPriority::First;
+    }
+    if pokemon.ability == Abilities::STALL || pokemon.item == Some(Items::LAGGINGTAIL) {
+        return FractionalPriority::Last;
+    }
+    if pokemon.ability == Abilities::MYCELIUMMIGHT && move_data.category == MoveCategory::Status {
+        return FractionalPriority::Last;
+    }
+    FractionalPriority::Normal
+}
+
+/// Chance that Quick Claw or Quick Draw moves this Pokemon to the front of its bracket
+pub fn quick_activation_chance(pokemon: &Pokemon, move_data: &Move) -> f32 {
+    if base_fractional_priority(pokemon, move_data) == FractionalPriority::First {
+        return 0.0; // Already first, nothing left to roll
+    }
+    let quick_claw = if pokemon.item == Some(Items::QUICKCLAW) { QUICK_CLAW_CHANCE } else { 0.0 };
+    let quick_draw = if pokemon.ability == Abilities::QUICKDRAW && move_data.category != MoveCategory::Status {
+        QUICK_DRAW_CHANCE
+    } else {
+        0.0
+    };
+    // Independent rolls - either one activating is enough
+    quick_claw + quick_draw - quick_claw * quick_draw
+}
+
+/// Check whether a move with the given priority is blocked from hitting a target
+///
+/// Prankster-boosted moves fail against opposing Dark types (Gen 7+), and
+/// Psychic Terrain protects grounded Pokemon from opposing priority moves.
+pub fn priority_blocks_target(
+    state: &BattleState,
+    user_position: BattlePosition,
+    target_position: BattlePosition,
+    move_data: &Move,
+    priority: i8,
+) -> bool {
+    if target_position.side == user_position.side {
+        return false;
+    }
+    let (user, target) = match (
+        state.get_pokemon_at_position(user_position),
+        state.get_pokemon_at_position(target_position),
+    ) {
+        (Some(user), Some(target)) => (user, target),
+        _ => return false,
+    };
+
+    if is_prankster_boosted(user, move_data)
+        && state.format.generation.number() >= 7
+        && target.types.contains(&PokemonType::Dark)
+    {
+        return true;
+    }
+
+    priority > 0
+        && matches!(state.terrain(), Terrain::Psychic | Terrain::PsychicTerrain)
+        && crate::engine::combat::damage::is_grounded(target)
+}
+
+/// Look up a move flag from the generation-specific move data
+fn move_has_flag(state: &BattleState, move_data: &Move, flag: &str) -> bool {
+    let name = move_data.name.as_str();
+    if let Some(data) = state
+        .generation_repo
+        .find_move_by_name_for_generation(name, state.format.generation.number())
+    {
+        return data.flags.contains_key(flag);
+    }
+    state
+        .game_data_repo
+        .moves
+        .find_by_name(name)
+        .is_some_and(|data| data.flags.contains_key(flag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Moves;
+
+    fn status_move() -> Move {
+        Move {
+            category: MoveCategory::Status,
+            base_power: 0,
+            ..Move::new(Moves::TOXIC)
+        }
+    }
+
+    #[test]
+    fn test_moves_last_in_bracket() {
+        let stall = Pokemon { ability: Abilities::STALL, ..Default::default() };
+        assert_eq!(base_fractional_priority(&stall, &Move::new(Moves::TACKLE)), FractionalPriority::Last);
+
+        let mycelium = Pokemon { ability: Abilities::MYCELIUMMIGHT, ..Default::default() };
+        assert_eq!(base_fractional_priority(&mycelium, &status_move()), FractionalPriority::Last);
+        assert_eq!(base_fractional_priority(&mycelium, &Move::new(Moves::TACKLE)), FractionalPriority::Normal);
+    }
+
+    #[test]
+    fn test_quick_activation_chance() {
+        let quick_claw = Pokemon { item: Some(Items::QUICKCLAW), ..Default::default() };
+        assert_eq!(quick_activation_chance(&quick_claw, &status_move()), QUICK_CLAW_CHANCE);
+
+        let quick_draw = Pokemon { ability: Abilities::QUICKDRAW, ..Default::default() };
+        assert_eq!(quick_activation_chance(&quick_draw, &status_move()), 0.0);
+        assert_eq!(quick_activation_chance(&quick_draw, &Move::new(Moves::TACKLE)), QUICK_DRAW_CHANCE);
+    }
+}
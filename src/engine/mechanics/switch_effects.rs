@@ -14,6 +14,7 @@ use crate::core::instructions::{BattleInstruction, BattleInstructions, FieldInst
 use crate::core::battle_state::Pokemon;
 use crate::core::battle_state::BattleState;
 use crate::generation::GenerationMechanics;
+use crate::engine::combat::core::forced_switch::eject_pack_instructions;
 use crate::engine::combat::damage::is_grounded;
 use crate::types::Abilities;
 use crate::types::PokemonType;
@@ -140,7 +141,8 @@ fn process_entry_hazards(
     
     // Sticky Web
     if let Some(&sticky_web) = side.side_conditions.get(&SideCondition::StickyWeb) {
-        if sticky_web > 0 && is_grounded(pokemon) {
+        // The web was laid by the other side, so a Clear Amulet keeps the Speed drop off
+        if sticky_web > 0 && is_grounded(pokemon) && pokemon.item != Some(crate::types::Items::CLEARAMULET) {
             let mut stat_boosts = StatBoostArray::default();
             stat_boosts.insert(Stat::Speed, -1);
             
@@ -341,13 +343,21 @@ fn apply_intimidate_effect(
                 let mut stat_boosts = StatBoostArray::default();
                 stat_boosts.insert(Stat::Attack, -1);
                 
-                instructions.push(BattleInstructions::new(100.0, vec![
+                let target = BattlePosition::new(opposing_side, slot);
+                let mut intimidate_instructions = vec![
                     BattleInstruction::Stats(StatsInstruction::BoostStats {
-                        target: BattlePosition::new(opposing_side, slot),
+                        target,
                         stat_changes: stat_boosts.to_hashmap(),
                         previous_boosts: std::collections::HashMap::new(),
                     })
-                ]));
+                ];
+                
+                // The Attack drop sets off Eject Pack
+                let mut lowered = opponent.clone();
+                lowered.stat_boosts.modify(Stat::Attack, -1);
+                intimidate_instructions.extend(eject_pack_instructions(state, target, opponent, &lowered));
+                
+                instructions.push(BattleInstructions::new(100.0, intimidate_instructions));
             }
         }
     }
@@ -908,6 +918,7 @@ fn process_switch_out_volatile_cleanup(
                 crate::core::instructions::VolatileStatus::FollowMe |
                 crate::core::instructions::VolatileStatus::Protect |
                 crate::core::instructions::VolatileStatus::Endure |
+                crate::core::instructions::VolatileStatus::Stall |
                 crate::core::instructions::VolatileStatus::FocusEnergy |
                 crate::core::instructions::VolatileStatus::LaserFocus |
                 crate::core::instructions::VolatileStatus::Rage |
@@ -1003,12 +1014,12 @@ fn calculate_spikes_damage(pokemon: &Pokemon, layers: i8) -> i16 {
 fn calculate_stealth_rock_damage(
     state: &BattleState,
     pokemon: &Pokemon,
-    generation: &GenerationMechanics,
+    _generation: &GenerationMechanics,
 ) -> i16 {
     use crate::engine::combat::type_effectiveness::TypeChart;
     use crate::types::PokemonType;
     
-    let type_chart = TypeChart::get_cached(generation.generation.number());
+    let type_chart = TypeChart::for_format(&state.format);
     let rock_type = PokemonType::Rock;
     
     let pokemon_type1 = pokemon.types[0];
@@ -1059,9 +1070,13 @@ fn get_toxic_spikes_effect(
 // HELPER FUNCTIONS
 // =============================================================================
 
-/// Check if a Pokemon is immune to Intimidate
+/// Check if a Pokemon is immune to Intimidate, through its ability or a Clear Amulet
 fn is_immune_to_intimidate(pokemon: &Pokemon, generation: &GenerationMechanics) -> bool {
-    use crate::types::Abilities;
+    use crate::types::{Abilities, Items};
+    // Intimidate doesn't reach a Pokemon behind a substitute
+    if pokemon.item == Some(Items::CLEARAMULET) || crate::engine::combat::core::substitute_protection::has_substitute(pokemon) {
+        return true;
+    }
     match pokemon.ability {
         Abilities::CLEARBODY | Abilities::HYPERCUTTER | Abilities::WHITESMOKE | Abilities::FULLMETALBODY | 
         Abilities::INNERFOCUS | Abilities::OBLIVIOUS | Abilities::OWNTEMPO | Abilities::SCRAPPY => true,
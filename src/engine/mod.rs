@@ -1,4 +1,5 @@
 pub mod combat;
+pub mod coverage;
 pub mod mechanics;
 pub mod targeting;
 pub mod turn;
\ No newline at end of file
@@ -7,12 +7,19 @@ use std::collections::HashMap;
 
 use crate::core::battle_format::{BattleFormat, BattlePosition, SideReference};
 use crate::core::battle_state::BattleState;
-use crate::core::instructions::{BattleInstruction, BattleInstructions, PokemonInstruction, Weather};
+use crate::core::instructions::{BattleInstruction, BattleInstructions, FieldInstruction, PokemonInstruction, Weather};
 use crate::core::move_choice::MoveChoice;
 use crate::core::targeting::resolve_targets;
 use crate::data::showdown_types::MoveTarget;
 use crate::engine::combat::moves::{MoveContext, OpponentMoveInfo};
-use crate::types::{BattleError, BattleResult};
+use crate::engine::combat::core::{ability_suppression, forced_switch, forme_change, generate_end_of_turn_instructions, illusion};
+use crate::engine::combat::core::field_system::weather_for;
+use crate::engine::combat::moves::special::two_turn;
+use crate::engine::mechanics::priority::{
+    base_fractional_priority, effective_move_priority, priority_blocks_target, quick_activation_chance,
+    FractionalPriority,
+};
+use crate::types::{BattleError, BattleResult, Moves};
 
 // Note: parse_move_target function removed - now using type-safe MoveTarget enum throughout
 
@@ -33,10 +40,50 @@ pub mod end_of_turn {
 }
 
 /// Generate instructions for a complete turn with two move choices
+///
+/// Every branch where the turn finishes is followed by end-of-turn residuals.
+/// A branch stopped by a mid-turn decision gets them once the turn resumes.
 pub fn generate_instructions(
     state: &BattleState,
     move_choices: (&MoveChoice, &MoveChoice),
     branch_on_damage: bool,
+) -> BattleResult<Vec<BattleInstructions>> {
+    let move_instructions = generate_move_pair_instructions(state, move_choices, branch_on_damage)?;
+
+    let mut all_instructions = Vec::new();
+    for mut move_branch in move_instructions {
+        let mut branch_state = state.clone();
+        branch_state.apply_instructions(&move_branch.instruction_list);
+        let suppression = ability_suppression::sync_ability_suppression_after_faints(state, &branch_state);
+        branch_state.apply_instructions(&suppression);
+        move_branch.instruction_list.extend(suppression);
+        if branch_state.awaiting_mid_turn_decision() {
+            all_instructions.push(move_branch);
+            continue;
+        }
+
+        for residual_branch in generate_end_of_turn_instructions(&branch_state) {
+            let mut residual_state = branch_state.clone();
+            residual_state.apply_instructions(&residual_branch.instruction_list);
+            let mut instruction_list = move_branch.instruction_list.clone();
+            instruction_list.extend(residual_branch.instruction_list);
+            instruction_list.extend(ability_suppression::sync_ability_suppression_after_faints(&branch_state, &residual_state));
+            all_instructions.push(BattleInstructions::new_with_positions(
+                move_branch.percentage * residual_branch.percentage / 100.0,
+                instruction_list,
+                move_branch.affected_positions.clone(),
+            ));
+        }
+    }
+
+    Ok(all_instructions)
+}
+
+/// Generate instructions for the moves of a turn, without end-of-turn residuals
+pub fn generate_move_pair_instructions(
+    state: &BattleState,
+    move_choices: (&MoveChoice, &MoveChoice),
+    branch_on_damage: bool,
 ) -> BattleResult<Vec<BattleInstructions>> {
     let (choice1, choice2) = move_choices;
     
@@ -61,14 +108,66 @@ pub fn generate_instructions(
         state
     )?;
     
-    // Determine move order based on priority and speed (including special switch/pursuit rules)
-    let (first_side, first_choice, second_side, second_choice) = 
-        determine_move_order_advanced(state, &side_one_choice, &side_two_choice);
+    // A side whose action was put on hold by a mid-turn forced switch resumes it now
+    let mut resumed_moves = Vec::new();
+    for (side_ref, choice) in [
+        (SideReference::SideOne, &mut side_one_choice),
+        (SideReference::SideTwo, &mut side_two_choice),
+    ] {
+        if let Some(saved) = &state.get_side_by_ref(side_ref).saved_move {
+            if matches!(choice, MoveChoice::None) {
+                *choice = saved.clone();
+            }
+            resumed_moves.push(BattleInstruction::Field(FieldInstruction::SaveMove {
+                side: side_ref,
+                choice: None,
+                previous_choice: Some(saved.clone()),
+            }));
+        }
+    }
+    
+    // Determine move order based on priority and speed (including special switch/pursuit rules).
+    // Quick Claw / Quick Draw activations make the order itself probabilistic.
+    let order_branches = determine_move_order_branches(state, &side_one_choice, &side_two_choice);
+    
+    let mut all_instructions = Vec::new();
+    for (order_percentage, first_side, first_choice, second_side, second_choice) in order_branches {
+        let ordered_instructions = generate_ordered_turn_instructions(
+            state,
+            first_side,
+            &first_choice,
+            second_side,
+            &second_choice,
+            branch_on_damage,
+        )?;
+        for mut instruction_set in ordered_instructions {
+            instruction_set.percentage = instruction_set.percentage * order_percentage / 100.0;
+            instruction_set.instruction_list.splice(0..0, resumed_moves.iter().cloned());
+            all_instructions.push(instruction_set);
+        }
+    }
+    
+    if all_instructions.is_empty() {
+        Ok(vec![BattleInstructions::new(100.0, resumed_moves)])
+    } else {
+        Ok(all_instructions)
+    }
+}
+
+/// Generate instructions for a turn once the move order is fixed
+fn generate_ordered_turn_instructions(
+    state: &BattleState,
+    first_side: SideReference,
+    first_choice: &MoveChoice,
+    second_side: SideReference,
+    second_choice: &MoveChoice,
+    branch_on_damage: bool,
+) -> BattleResult<Vec<BattleInstructions>> {
     
     // Create comprehensive move contexts with opponent information
     let first_context = create_move_context_with_opponents(
-        &first_choice,
-        &second_choice,
+        first_choice,
+        second_choice,
         first_side,
         second_side,
         state,
@@ -76,8 +175,8 @@ pub fn generate_instructions(
     );
     
     let second_context = create_move_context_with_opponents(
-        &second_choice,
-        &first_choice,
+        second_choice,
+        first_choice,
         second_side,
         first_side,
         state,
@@ -86,7 +185,7 @@ pub fn generate_instructions(
     
     // Generate instructions for first move (with context indicating it goes first)
     let first_instructions = generate_move_instructions_with_enhanced_context(
-        &first_choice, 
+        first_choice, 
         first_side, 
         0, 
         &state.format, 
@@ -95,47 +194,27 @@ pub fn generate_instructions(
         branch_on_damage,
     )?;
     
-    // Handle switch-attack interactions
-    let second_instructions = if first_choice.is_switch() && !second_choice.is_switch() {
-        // First move is a switch, second is an attack - apply the switch first
-        let mut temp_state = state.clone();
-        
-        // Apply switch instructions to get the final switched state
-        if !first_instructions.is_empty() {
-            // Use the first instruction set (switches are deterministic)
-            temp_state.apply_instructions(&first_instructions[0].instruction_list);
-        }
-        
-        // Generate second move instructions using the updated state (goes second)
-        generate_move_instructions_with_enhanced_context(
-            &second_choice, 
-            second_side, 
-            0, 
-            &state.format, 
-            &temp_state,
-            &second_context,
-            branch_on_damage,
-        )?
-    } else {
-        // Either both are switches, first is not a switch, or second is a switch
-        // In these cases, use the original state (goes second)
+    // The second move is generated from the state each first-move branch leaves behind,
+    // so switches, Protect, Magic Coat and other first-move effects are visible to it
+    let generate_second = |branch_state: &BattleState| {
         generate_move_instructions_with_enhanced_context(
-            &second_choice, 
-            second_side, 
-            0, 
-            &state.format, 
-            state,
+            second_choice,
+            second_side,
+            0,
+            &state.format,
+            branch_state,
             &second_context,
             branch_on_damage,
-        )?
+        )
     };
     
     // Combine instruction sets from both moves with move cancellation logic
     let combined_instructions = combine_move_instructions_with_cancellation(
         first_instructions, 
-        second_instructions, 
+        generate_second, 
         state,
-        &second_choice,
+        first_side,
+        second_choice,
         second_side,
     )?;
     
@@ -173,6 +252,9 @@ pub fn generate_move_instructions_with_context(
         MoveChoice::Switch(pokemon_index) => {
             generate_switch_instructions(pokemon_index.to_index(), user_pos, state)
         }
+        MoveChoice::Revive(pokemon_index) => {
+            generate_revive_instructions(pokemon_index.to_index(), user_pos, state)
+        }
         MoveChoice::Move { move_index, target_positions } => {
             generate_attack_instructions_with_context(*move_index, target_positions, user_pos, format, state, going_first)
         }
@@ -205,8 +287,12 @@ pub fn generate_move_instructions_with_context(
             
             Ok(all_instructions)
         }
+        MoveChoice::Struggle { target_positions } => {
+            let context = MoveContext { going_first, ..MoveContext::new() };
+            generate_struggle_instructions(target_positions, user_pos, format, state, &context, false)
+        }
         MoveChoice::None => {
-            Ok(vec![BattleInstructions::new(100.0, vec![])])
+            Ok(vec![BattleInstructions::new(100.0, recharge_instructions(user_pos, state))])
         }
     }
 }
@@ -224,16 +310,60 @@ fn generate_switch_instructions(
         .and_then(|&idx| idx)
         .unwrap_or(0);
     
-    // Simple switch instruction - no complex generator needed
-    let switch_instruction = BattleInstruction::Pokemon(PokemonInstruction::Switch {
+    // Zen Mode, Schooling and Stance Change formes end as the Pokemon leaves
+    let mut instruction_list: Vec<BattleInstruction> = forme_change::switch_out_forme_change(state, user_pos)
+        .into_iter()
+        .collect();
+    
+    instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::Switch {
         position: user_pos,
         new_pokemon: pokemon_index,
         previous_pokemon: Some(current_index),
+    }));
+    // Baton Pass and Shed Tail hand their effects to the incoming Pokemon
+    instruction_list.extend(forced_switch::passed_on_switch(state, user_pos, pokemon_index));
+    
+    // Neutralizing Gas starts or ends with the switch, then Illusion picks its disguise
+    // and Schooling, Shields Down and Ice Face check the incoming Pokemon's forme
+    let mut switched_state = state.clone();
+    switched_state.apply_instructions(&instruction_list);
+    let suppression = ability_suppression::sync_ability_suppression(&switched_state);
+    switched_state.apply_instructions(&suppression);
+    instruction_list.extend(suppression);
+    instruction_list.extend(illusion::illusion_on_switch_in(&switched_state, user_pos));
+    instruction_list.extend(forme_change::switch_in_forme_change(&switched_state, user_pos));
+    
+    Ok(vec![BattleInstructions::new(
+        100.0,
+        instruction_list,
+    )])
+}
+
+/// Generate instructions for a Revival Blessing revive choice
+fn generate_revive_instructions(
+    pokemon_index: usize,
+    user_pos: BattlePosition,
+    state: &BattleState,
+) -> BattleResult<Vec<BattleInstructions>> {
+    let target = state.get_side(user_pos.side.to_index())
+        .and_then(|side| side.pokemon.get(pokemon_index))
+        .filter(|pokemon| pokemon.hp == 0)
+        .ok_or_else(|| BattleError::InvalidMoveChoice {
+            reason: format!("Pokemon {} cannot be revived", pokemon_index)
+        })?;
+    
+    // Revived Pokemon come back with half of their maximum HP
+    let revive_instruction = BattleInstruction::Pokemon(PokemonInstruction::Revive {
+        side: user_pos.side,
+        pokemon_index,
+        new_hp: (target.max_hp / 2).max(1),
+        previous_hp: target.hp,
+        previous_status: target.status,
     });
     
     Ok(vec![BattleInstructions::new(
         100.0,
-        vec![switch_instruction],
+        vec![revive_instruction],
     )])
 }
 
@@ -442,18 +572,11 @@ fn determine_move_order_advanced(
 ) -> (SideReference, MoveChoice, SideReference, MoveChoice) {
     // Special handling for switches (following poke-engine logic)
     if side_one_choice.is_switch() && side_two_choice.is_switch() {
-        // Both switches - use speed to determine order
-        let side_one_speed = get_effective_speed(state, SideReference::SideOne);
-        let side_two_speed = get_effective_speed(state, SideReference::SideTwo);
-        
-        if side_one_speed > side_two_speed {
-            return (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone());
-        } else if side_one_speed == side_two_speed {
-            // Speed tie - side one wins for now (could implement random choice)
-            return (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone());
-        } else {
-            return (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone());
-        }
+        // Both switches - use speed to determine order, ties are split into branches by the caller
+        return match faster_side(state).unwrap_or(SideReference::SideOne) {
+            SideReference::SideOne => (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone()),
+            SideReference::SideTwo => (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone()),
+        };
     } else if side_one_choice.is_switch() {
         // Side one switching - switch goes first unless opponent uses Pursuit
         if is_pursuit(state, side_two_choice, SideReference::SideTwo) {
@@ -485,18 +608,123 @@ fn determine_move_order_advanced(
         return (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone());
     }
 
-    // Same priority - compare speed
+    // Same priority - Stall / Lagging Tail / Mycelium Might / Custap Berry move within the bracket
+    let side_one_fractional = get_fractional_priority(state, side_one_choice, SideReference::SideOne);
+    let side_two_fractional = get_fractional_priority(state, side_two_choice, SideReference::SideTwo);
+    
+    if side_one_fractional > side_two_fractional {
+        return (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone());
+    } else if side_two_fractional > side_one_fractional {
+        return (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone());
+    }
+    
+    // Same bracket - compare speed, ties are split into branches by the caller
+    match faster_side(state).unwrap_or(SideReference::SideOne) {
+        SideReference::SideOne => (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone()),
+        SideReference::SideTwo => (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone()),
+    }
+}
+
+/// The side whose active Pokemon acts first on speed alone, or `None` on a speed tie
+///
+/// Effective speed is inverted under Trick Room, so the slower Pokemon wins there.
+fn faster_side(state: &BattleState) -> Option<SideReference> {
     let side_one_speed = get_effective_speed(state, SideReference::SideOne);
     let side_two_speed = get_effective_speed(state, SideReference::SideTwo);
-    
-    if side_one_speed > side_two_speed {
-        (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone())
-    } else if side_one_speed == side_two_speed {
-        // Speed tie - side one wins for now (could implement random choice)
-        (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone())
+
+    match side_one_speed.cmp(&side_two_speed) {
+        std::cmp::Ordering::Greater => Some(SideReference::SideOne),
+        std::cmp::Ordering::Less => Some(SideReference::SideTwo),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+/// Whether the order of two choices comes down to a speed tie
+fn is_speed_tie(state: &BattleState, side_one_choice: &MoveChoice, side_two_choice: &MoveChoice) -> bool {
+    if faster_side(state).is_some() {
+        return false;
+    }
+    match (side_one_choice.is_switch(), side_two_choice.is_switch()) {
+        (true, true) => true,
+        (false, false) => {
+            get_move_priority(state, side_one_choice, SideReference::SideOne)
+                == get_move_priority(state, side_two_choice, SideReference::SideTwo)
+                && get_fractional_priority(state, side_one_choice, SideReference::SideOne)
+                    == get_fractional_priority(state, side_two_choice, SideReference::SideTwo)
+        }
+        _ => false,
+    }
+}
+
+/// A possible move order: (percentage, first side, first choice, second side, second choice)
+type MoveOrderBranch = (f32, SideReference, MoveChoice, SideReference, MoveChoice);
+
+/// Determine every possible move order with its probability
+///
+/// Quick Claw and Quick Draw can move a Pokemon to the front of its priority
+/// bracket; each activation that decides the order becomes its own branch.
+/// A speed tie is an even split between the two orders.
+///
+/// From Gen 8 the remaining actions are re-sorted by current speed after each
+/// action. Each side acts once per turn here, so once the first action is
+/// settled the order is fixed in every generation.
+fn determine_move_order_branches(
+    state: &BattleState,
+    side_one_choice: &MoveChoice,
+    side_two_choice: &MoveChoice,
+) -> Vec<MoveOrderBranch> {
+    let default_order = determine_move_order_advanced(state, side_one_choice, side_two_choice);
+
+    // Without Quick Claw, the order is the default one, or either one on a speed tie
+    let mut unchanged = vec![(100.0, default_order.0)];
+    if is_speed_tie(state, side_one_choice, side_two_choice) {
+        unchanged = vec![(50.0, SideReference::SideOne), (50.0, SideReference::SideTwo)];
+    }
+
+    let mut branches: Vec<MoveOrderBranch> = Vec::new();
+    let mut push_branch = |percentage: f32, first_side: SideReference| {
+        if percentage <= 0.0 {
+            return;
+        }
+        let order = if first_side == SideReference::SideOne {
+            (SideReference::SideOne, side_one_choice.clone(), SideReference::SideTwo, side_two_choice.clone())
+        } else {
+            (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone())
+        };
+        // Merge with an existing branch that has the same order
+        if let Some(existing) = branches.iter_mut().find(|branch| branch.1 == first_side) {
+            existing.0 += percentage;
+        } else {
+            branches.push((percentage, order.0, order.1, order.2, order.3));
+        }
+    };
+
+    // Switches, and moves in different priority brackets, aren't affected by Quick Claw
+    let quick_claw_applies = !side_one_choice.is_switch()
+        && !side_two_choice.is_switch()
+        && get_move_priority(state, side_one_choice, SideReference::SideOne)
+            == get_move_priority(state, side_two_choice, SideReference::SideTwo);
+    let (side_one_quick, side_two_quick) = if quick_claw_applies {
+        (
+            get_quick_activation_chance(state, side_one_choice, SideReference::SideOne),
+            get_quick_activation_chance(state, side_two_choice, SideReference::SideTwo),
+        )
     } else {
-        (SideReference::SideTwo, side_two_choice.clone(), SideReference::SideOne, side_one_choice.clone())
+        (0.0, 0.0)
+    };
+
+    // Only one side activating changes the order; both or neither fall back to the normal order
+    let side_one_first = side_one_quick * (1.0 - side_two_quick) * 100.0;
+    let side_two_first = side_two_quick * (1.0 - side_one_quick) * 100.0;
+    let unchanged_share = 100.0 - side_one_first - side_two_first;
+
+    for (percentage, first_side) in unchanged {
+        push_branch(unchanged_share * percentage / 100.0, first_side);
     }
+    push_branch(side_one_first, SideReference::SideOne);
+    push_branch(side_two_first, SideReference::SideTwo);
+
+    branches
 }
 
 /// Check if a move choice is Pursuit
@@ -549,27 +777,33 @@ fn combine_move_instructions(
 /// Combine instruction sets with move cancellation logic
 fn combine_move_instructions_with_cancellation(
     first_instructions: Vec<BattleInstructions>,
-    second_instructions: Vec<BattleInstructions>,
+    generate_second: impl Fn(&BattleState) -> BattleResult<Vec<BattleInstructions>>,
     initial_state: &BattleState,
+    first_side: SideReference,
     second_choice: &MoveChoice,
     second_side: SideReference,
 ) -> BattleResult<Vec<BattleInstructions>> {
-    if first_instructions.is_empty() && second_instructions.is_empty() {
-        return Ok(vec![BattleInstructions::new_with_positions(100.0, vec![], vec![])]);
-    } else if first_instructions.is_empty() {
+    if first_instructions.is_empty() {
+        let second_instructions = generate_second(initial_state)?;
+        if second_instructions.is_empty() {
+            return Ok(vec![BattleInstructions::new_with_positions(100.0, vec![], vec![])]);
+        }
         return Ok(second_instructions);
-    } else if second_instructions.is_empty() {
-        return Ok(first_instructions);
     }
 
     let mut combined = Vec::new();
     
     // For each first instruction, check if second move should be cancelled
-    for first_instr in &first_instructions {
+    for mut first_instr in first_instructions {
         // Apply first move to a temporary state
         let mut temp_state = initial_state.clone();
         temp_state.apply_instructions(&first_instr.instruction_list);
         
+        // A fainted Neutralizing Gas user gives the others their abilities back before the second move
+        let suppression = ability_suppression::sync_ability_suppression_after_faints(initial_state, &temp_state);
+        temp_state.apply_instructions(&suppression);
+        first_instr.instruction_list.extend(suppression);
+        
         // Check if second move should be cancelled
         if should_cancel_move(&temp_state, second_choice, second_side) {
             // Second move is cancelled - only include first move's instructions
@@ -578,7 +812,27 @@ fn combine_move_instructions_with_cancellation(
                 first_instr.instruction_list.clone(),
                 first_instr.affected_positions.clone(),
             ));
+        } else if must_switch_mid_turn(&temp_state, first_side) && !second_choice.is_switch() {
+            // The first side picks a replacement before the second move happens,
+            // so save that move and resume it once the switch is chosen
+            let mut instruction_list = first_instr.instruction_list.clone();
+            instruction_list.push(BattleInstruction::Field(FieldInstruction::SaveMove {
+                side: second_side,
+                choice: Some(second_choice.clone()),
+                previous_choice: temp_state.get_side_by_ref(second_side).saved_move.clone(),
+            }));
+            combined.push(BattleInstructions::new_with_positions(
+                first_instr.percentage,
+                instruction_list,
+                first_instr.affected_positions.clone(),
+            ));
         } else {
+            let second_instructions = generate_second(&temp_state)?;
+            if second_instructions.is_empty() {
+                combined.push(first_instr.clone());
+                continue;
+            }
+            
             // Second move can proceed - combine both instruction sets
             for second_instr in &second_instructions {
                 let mut combined_instruction_list = first_instr.instruction_list.clone();
@@ -624,6 +878,11 @@ fn should_cancel_move(
         if user_pokemon.volatile_statuses.contains(VolatileStatus::Flinch) {
             return true; // Cancel move if attacker is flinched
         }
+        
+        // A Pokemon forced out mid-turn (Eject Button, Emergency Exit, ...) loses its action
+        if user_pokemon.must_switch && !choice.is_switch() {
+            return true;
+        }
     }
     
     // Only check for attack moves, not switches or status moves
@@ -631,6 +890,11 @@ fn should_cancel_move(
         // Get the move data to check if it's a status move
         if let Some(user_pokemon) = state.get_pokemon_at_position(user_pos) {
             if let Some(move_data) = user_pokemon.get_move(*move_index) {
+                // A target that flew up or dug under this turn can't be hit
+                if is_target_out_of_reach(state, move_data.name, user_pos, target_positions) {
+                    return true;
+                }
+                
                 // Status moves can still be used even if target has fainted
                 if move_data.base_power == 0 {
                     return false;
@@ -653,6 +917,12 @@ fn should_cancel_move(
     false
 }
 
+/// Check if a side's active Pokemon has to be replaced before the turn goes on
+fn must_switch_mid_turn(state: &BattleState, side: SideReference) -> bool {
+    state.get_pokemon_at_position(BattlePosition::new(side, 0))
+        .is_some_and(|pokemon| pokemon.hp > 0 && pokemon.must_switch)
+}
+
 /// Determine which move goes first based on priority and speed (simple version)
 fn determine_move_order<'a>(
     state: &BattleState,
@@ -687,17 +957,37 @@ fn determine_move_order<'a>(
     }
 }
 
-/// Get move priority for a choice
+/// Get the active Pokemon and the move it chose
+fn get_chosen_move<'a>(
+    state: &'a BattleState,
+    choice: &MoveChoice,
+    side: SideReference,
+) -> Option<(&'a crate::core::battle_state::Pokemon, &'a crate::core::battle_state::Move)> {
+    let move_index = choice.move_index()?;
+    let pokemon = state.get_side(side.to_index()).and_then(|s| s.get_active_pokemon_at_slot(0))?;
+    let move_data = pokemon.get_move(move_index)?;
+    Some((pokemon, move_data))
+}
+
+/// Get move priority for a choice, including Prankster / Gale Wings / Triage
 fn get_move_priority(state: &BattleState, choice: &MoveChoice, side: SideReference) -> i8 {
-    if let Some(move_index) = choice.move_index() {
-        let pokemon = state.get_side(side.to_index()).and_then(|s| s.get_active_pokemon_at_slot(0));
-        if let Some(pokemon) = pokemon {
-            if let Some(move_data) = pokemon.get_move(move_index) {
-                return move_data.priority;
-            }
-        }
-    }
-    0 // Default priority
+    get_chosen_move(state, choice, side)
+        .map(|(pokemon, move_data)| effective_move_priority(state, pokemon, move_data))
+        .unwrap_or(0) // Default priority
+}
+
+/// Get the deterministic position of a choice inside its priority bracket
+fn get_fractional_priority(state: &BattleState, choice: &MoveChoice, side: SideReference) -> FractionalPriority {
+    get_chosen_move(state, choice, side)
+        .map(|(pokemon, move_data)| base_fractional_priority(pokemon, move_data))
+        .unwrap_or(FractionalPriority::Normal)
+}
+
+/// Get the chance that Quick Claw / Quick Draw activates for a choice
+fn get_quick_activation_chance(state: &BattleState, choice: &MoveChoice, side: SideReference) -> f32 {
+    get_chosen_move(state, choice, side)
+        .map(|(pokemon, move_data)| quick_activation_chance(pokemon, move_data))
+        .unwrap_or(0.0)
 }
 
 /// Get effective speed for a side
@@ -730,12 +1020,29 @@ fn calculate_move_accuracy(
     
     let mut final_accuracy = base_accuracy;
     
-    // Apply weather-based accuracy modifications
+    // Apply weather-based accuracy modifications, using the weather as the target feels it
+    let weather = match _targets.first().and_then(|&target| state.get_pokemon_at_position(target)) {
+        Some(target) => weather_for(state, target),
+        None => state.weather(),
+    };
     final_accuracy = apply_weather_accuracy_modifiers(
         final_accuracy,
         move_data.name.as_str(),
-        state.weather(),
+        weather,
     );
+
+    // Sand Veil and Snow Cloak make the target harder to hit in their weather
+    let weather_evasion = _targets.first().and_then(|&target| state.get_pokemon_at_position(target)).is_some_and(|target| {
+        !target.ability_suppressed
+            && match weather {
+                Weather::Sand | Weather::Sandstorm => target.ability == crate::types::Abilities::SANDVEIL,
+                Weather::Hail | Weather::Snow => target.ability == crate::types::Abilities::SNOWCLOAK,
+                _ => false,
+            }
+    });
+    if weather_evasion && move_data.accuracy != 0 {
+        final_accuracy *= 0.8;
+    }
     
     // Apply ability modifiers (e.g., Compound Eyes, No Guard)
     final_accuracy = apply_ability_accuracy_modifiers(
@@ -762,10 +1069,47 @@ fn calculate_move_accuracy(
         state,
     );
     
+    // Moves cannot miss a target left exposed by Glaive Rush
+    let target_exposed = _targets.iter().any(|&target| {
+        state.get_pokemon_at_position(target)
+            .map(|pokemon| pokemon.volatile_statuses.contains(crate::core::instructions::VolatileStatus::GlaiveRush))
+            .unwrap_or(false)
+    });
+    if target_exposed {
+        final_accuracy = 100.0;
+    }
+    
+    // Fly, Dig and the like dodge everything but a few moves
+    if is_target_out_of_reach(state, move_data.name, user_pos, _targets) {
+        final_accuracy = 0.0;
+    }
+    
+    // The charging turn of a two-turn move never misses
+    if two_turn::charges_this_turn(state, user_pos, move_data.name) {
+        final_accuracy = 100.0;
+    }
+    
     // Clamp to valid range
     final_accuracy.max(0.0).min(100.0)
 }
 
+/// Check if a semi-invulnerable target can't be reached by the move, unless No Guard is in play
+fn is_target_out_of_reach(
+    state: &BattleState,
+    move_name: Moves,
+    user_pos: BattlePosition,
+    targets: &[BattlePosition],
+) -> bool {
+    let no_guard = std::iter::once(user_pos).chain(targets.iter().copied()).any(|position| {
+        state.get_pokemon_at_position(position)
+            .is_some_and(|pokemon| pokemon.ability == crate::types::Abilities::NOGUARD)
+    });
+    !no_guard && targets.iter().filter(|&&target| target != user_pos).any(|&target| {
+        state.get_pokemon_at_position(target)
+            .is_some_and(|pokemon| !two_turn::can_hit_semi_invulnerable(move_name, pokemon))
+    })
+}
+
 /// Apply weather-specific accuracy modifications for certain moves
 fn apply_weather_accuracy_modifiers(
     base_accuracy: f32,
@@ -1024,6 +1368,9 @@ fn generate_move_instructions_with_enhanced_context(
         MoveChoice::Switch(pokemon_index) => {
             generate_switch_instructions(pokemon_index.to_index(), user_pos, state)
         }
+        MoveChoice::Revive(pokemon_index) => {
+            generate_revive_instructions(pokemon_index.to_index(), user_pos, state)
+        }
         MoveChoice::Move { move_index, target_positions } => {
             generate_attack_instructions_with_enhanced_context(*move_index, target_positions, user_pos, format, state, context, branch_on_damage)
         }
@@ -1031,8 +1378,11 @@ fn generate_move_instructions_with_enhanced_context(
             // For now, treat Tera moves the same as regular moves (simplified)
             generate_attack_instructions_with_enhanced_context(*move_index, target_positions, user_pos, format, state, context, branch_on_damage)
         }
+        MoveChoice::Struggle { target_positions } => {
+            generate_struggle_instructions(target_positions, user_pos, format, state, context, branch_on_damage)
+        }
         MoveChoice::None => {
-            Ok(vec![BattleInstructions::new(100.0, vec![])])
+            Ok(vec![BattleInstructions::new(100.0, recharge_instructions(user_pos, state))])
         }
     }
 }
@@ -1047,9 +1397,10 @@ fn generate_attack_instructions_with_enhanced_context(
     context: &MoveContext,
     branch_on_damage: bool,
 ) -> BattleResult<Vec<BattleInstructions>> {
-    use crate::engine::combat::moves::apply_move_effects;
     use crate::engine::combat::core::move_prevention::{cannot_use_move, generate_prevention_instructions};
-    use crate::generation::GenerationMechanics;
+    use crate::engine::combat::core::move_reflection::{find_reflectors, reflected_targets};
+    use crate::engine::combat::core::move_protection::{find_shield, shield_punishment, Shield};
+    use crate::engine::combat::core::forced_switch::apply_forced_switch_triggers;
     
     // Get user Pokemon and move data
     let user_pokemon = state.get_pokemon_at_position(user_pos)
@@ -1069,22 +1420,8 @@ fn generate_attack_instructions_with_enhanced_context(
     } else {
         // Fallback to standard repository for moves not found in generation-specific data
         if let Some(repo_move_data) = state.game_data_repo.moves.find_by_name(&move_data_raw.name.as_str()) {
-            // Convert repository::MoveData to showdown_types::MoveData
-            crate::data::showdown_types::MoveData {
-                name: repo_move_data.name,
-                base_power: repo_move_data.base_power as u16,
-                accuracy: repo_move_data.accuracy as u16,
-                pp: repo_move_data.pp,
-                max_pp: repo_move_data.max_pp,
-                move_type: repo_move_data.move_type,
-                category: repo_move_data.category.clone(),
-                priority: repo_move_data.priority,
-                target: repo_move_data.target.clone(),
-                flags: repo_move_data.flags.clone(),
-                drain: repo_move_data.drain,
-                recoil: repo_move_data.recoil,
-                ..crate::data::showdown_types::MoveData::default()
-            }
+            // Same showdown_types::MoveData, so secondaries and self effects carry over
+            repo_move_data.clone()
         } else {
         // Fallback: create a basic MoveData from the Move
         crate::data::showdown_types::MoveData {
@@ -1109,7 +1446,24 @@ fn generate_attack_instructions_with_enhanced_context(
     };
     
     if let Some(prevention) = cannot_use_move(user_pokemon, &move_choice, Some(&move_data), state, user_pos) {
-        return Ok(generate_prevention_instructions(prevention, user_pos, user_pokemon));
+        let mut prevention_instructions = generate_prevention_instructions(prevention, user_pos, user_pokemon);
+        // A charge that gets interrupted is lost
+        release_charge(user_pos, user_pokemon, &mut prevention_instructions);
+        clear_glaive_rush_on_move(user_pos, state, &mut prevention_instructions);
+        return Ok(prevention_instructions);
+    }
+    
+    // Stance Change swaps Aegislash's forme first, so the move runs from the new forme
+    if let Some(stance_change) = forme_change::stance_change(state, user_pos, &move_data) {
+        let mut changed_state = state.clone();
+        changed_state.apply_instructions(std::slice::from_ref(&stance_change));
+        let mut instruction_sets = generate_attack_instructions_with_enhanced_context(
+            move_index, explicit_targets, user_pos, format, &changed_state, context, branch_on_damage,
+        )?;
+        for instruction_set in &mut instruction_sets {
+            instruction_set.instruction_list.insert(0, stance_change.clone());
+        }
+        return Ok(instruction_sets);
     }
     
     // Determine targets using the same logic as before
@@ -1119,12 +1473,205 @@ fn generate_attack_instructions_with_enhanced_context(
         explicit_targets.to_vec()
     };
     
-    // 2. Check move accuracy (CRITICAL: this was missing!)
-    let accuracy_percentage = calculate_move_accuracy(move_data_raw, user_pos, &targets, state, context.going_first);
+    // Prankster-boosted moves fail against Dark types, and Psychic Terrain blocks priority moves
+    let priority = effective_move_priority(state, user_pokemon, move_data_raw);
+    let had_targets = !targets.is_empty();
+    let targets: Vec<BattlePosition> = targets.into_iter()
+        .filter(|&target| !priority_blocks_target(state, user_pos, target, move_data_raw, priority))
+        .collect();
+    
+    // Protect and its relatives shield targets from the attack, though not from a charging turn
+    let shields: Vec<(BattlePosition, Shield)> = if two_turn::charges_this_turn(state, user_pos, move_data_raw.name) {
+        Vec::new()
+    } else {
+        targets.iter()
+            .filter_map(|&target| find_shield(state, &move_data, user_pos, target, priority).map(|shield| (target, shield)))
+            .collect()
+    };
+    let punishment: Vec<BattleInstruction> = shields.iter()
+        .flat_map(|&(target, shield)| shield_punishment(state, &move_data, user_pos, target, shield))
+        .collect();
+    let targets: Vec<BattlePosition> = targets.into_iter()
+        .filter(|target| !shields.iter().any(|(shielded, _)| shielded == target))
+        .collect();
+    
+    if had_targets && targets.is_empty() {
+        let mut failed = vec![BattleInstructions::new(100.0, punishment)];
+        release_charge(user_pos, user_pokemon, &mut failed);
+        record_last_used_move(user_pos, state, move_data_raw.name, &mut failed);
+        clear_glaive_rush_on_move(user_pos, state, &mut failed);
+        return Ok(failed);
+    }
+    
+    // 2. Magic Coat / Magic Bounce send reflectable moves back at the user
+    let reflectors = find_reflectors(state, &move_data, user_pos, &targets);
+    
+    let mut instruction_sets = if reflectors.is_empty() {
+        generate_hit_or_miss_instructions(move_data_raw, &move_data, user_pos, &targets, state, context, branch_on_damage)?
+    } else {
+        // Targets that don't reflect still take the move normally (side moves are bounced whole)
+        let remaining_targets: Vec<BattlePosition> = targets.iter()
+            .copied()
+            .filter(|target| !reflectors.contains(target))
+            .collect();
+        let mut sets = if remaining_targets.is_empty() {
+            Vec::new()
+        } else {
+            generate_hit_or_miss_instructions(move_data_raw, &move_data, user_pos, &remaining_targets, state, context, branch_on_damage)?
+        };
+        
+        for reflector in reflectors {
+            let bounced_targets = reflected_targets(&move_data, user_pos);
+            let bounced = generate_hit_or_miss_instructions(move_data_raw, &move_data, reflector, &bounced_targets, state, context, branch_on_damage)?;
+            sets = combine_move_instructions(sets, bounced);
+        }
+        sets
+    };
+    
+    // Blocked contact moves punish the user before the move hits anyone else
+    if !punishment.is_empty() {
+        for instruction_set in &mut instruction_sets {
+            instruction_set.instruction_list.splice(0..0, punishment.iter().cloned());
+        }
+    }
+    
+    // Disguise and Ice Face take the hit in the Pokemon's place
+    let instruction_sets = forme_change::apply_disguise_triggers(state, &move_data, user_pos, instruction_sets);
+    let instruction_sets = illusion::apply_illusion_breaks(state, user_pos, instruction_sets);
+    
+    // 3. Eject Button, Red Card, Emergency Exit and friends can pull Pokemon out mid-turn
+    let mut instruction_sets = apply_forced_switch_triggers(state, &move_data, user_pos, instruction_sets);
+    
+    // A released charge ends whether the attack lands or misses
+    release_charge(user_pos, user_pokemon, &mut instruction_sets);
+    record_last_used_move(user_pos, state, move_data_raw.name, &mut instruction_sets);
+    clear_glaive_rush_on_move(user_pos, state, &mut instruction_sets);
+    
+    Ok(instruction_sets)
+}
+
+/// End a two-turn move's charge at the start of every branch
+fn release_charge(
+    user_pos: BattlePosition,
+    user_pokemon: &crate::core::battle_state::Pokemon,
+    instruction_sets: &mut [BattleInstructions],
+) {
+    let release = two_turn::release_charge_instructions(user_pokemon, user_pos);
+    if release.is_empty() {
+        return;
+    }
+    for instruction_set in instruction_sets {
+        instruction_set.instruction_list.splice(0..0, release.iter().cloned());
+    }
+}
+
+/// Generate instructions for Struggle
+///
+/// Struggle hits a random opponent for typeless damage without ever missing, and
+/// costs the user 1/4 of its max HP (rounded half up) whenever it connects.
+fn generate_struggle_instructions(
+    explicit_targets: &[BattlePosition],
+    user_pos: BattlePosition,
+    format: &BattleFormat,
+    state: &BattleState,
+    context: &MoveContext,
+    branch_on_damage: bool,
+) -> BattleResult<Vec<BattleInstructions>> {
+    use crate::core::battle_state::{Move, MoveCategory};
+    use crate::engine::combat::core::forced_switch::apply_forced_switch_triggers;
+    use crate::engine::combat::core::move_prevention::{cannot_use_move, generate_prevention_instructions};
+    use crate::types::{Moves, PokemonType};
+    
+    let user_pokemon = state.get_pokemon_at_position(user_pos)
+        .ok_or_else(|| BattleError::InvalidState { 
+            reason: "No Pokemon at user position".to_string() 
+        })?;
+    
+    let struggle = Move {
+        name: Moves::STRUGGLE,
+        base_power: 50,
+        accuracy: 0, // Never misses
+        move_type: PokemonType::Typeless,
+        pp: 1,
+        max_pp: 1,
+        target: MoveTarget::RandomNormal,
+        category: MoveCategory::Physical,
+        priority: 0,
+    };
+    let move_data = crate::data::showdown_types::MoveData {
+        name: struggle.name,
+        base_power: struggle.base_power as u16,
+        accuracy: struggle.accuracy as u16,
+        pp: struggle.pp,
+        max_pp: struggle.max_pp,
+        move_type: struggle.move_type,
+        category: struggle.category,
+        priority: struggle.priority,
+        target: struggle.target,
+        flags: [("contact".to_string(), 1), ("protect".to_string(), 1)].into_iter().collect(),
+        ..crate::data::showdown_types::MoveData::default()
+    };
+    
+    let choice = MoveChoice::Struggle { target_positions: explicit_targets.to_vec() };
+    if let Some(prevention) = cannot_use_move(user_pokemon, &choice, Some(&move_data), state, user_pos) {
+        let mut prevention_instructions = generate_prevention_instructions(prevention, user_pos, user_pokemon);
+        clear_glaive_rush_on_move(user_pos, state, &mut prevention_instructions);
+        return Ok(prevention_instructions);
+    }
+    
+    let targets = if explicit_targets.is_empty() {
+        resolve_targets(struggle.target, user_pos, format, state)
+    } else {
+        explicit_targets.to_vec()
+    };
+    
+    let mut instruction_sets = generate_hit_or_miss_instructions(&struggle, &move_data, user_pos, &targets, state, context, branch_on_damage)?;
+    
+    // The recoil is based on max HP, so Rock Head doesn't stop it
+    let recoil = ((user_pokemon.max_hp + 2) / 4).max(1);
+    for instruction_set in &mut instruction_sets {
+        let landed = instruction_set.instruction_list.iter().any(|instruction| {
+            matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. }) if targets.contains(target))
+        });
+        if landed {
+            instruction_set.instruction_list.push(BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: user_pos,
+                amount: recoil,
+                previous_hp: None,
+            }));
+            if !instruction_set.affected_positions.contains(&user_pos) {
+                instruction_set.affected_positions.push(user_pos);
+            }
+        }
+    }
+    
+    let instruction_sets = forme_change::apply_disguise_triggers(state, &move_data, user_pos, instruction_sets);
+    let instruction_sets = illusion::apply_illusion_breaks(state, user_pos, instruction_sets);
+    let mut instruction_sets = apply_forced_switch_triggers(state, &move_data, user_pos, instruction_sets);
+    clear_glaive_rush_on_move(user_pos, state, &mut instruction_sets);
+    
+    Ok(instruction_sets)
+}
+
+/// Generate the miss branch (if any) and the accuracy-scaled hit branches of a move
+fn generate_hit_or_miss_instructions(
+    move_data_raw: &crate::core::battle_state::Move,
+    move_data: &crate::data::showdown_types::MoveData,
+    user_pos: BattlePosition,
+    targets: &[BattlePosition],
+    state: &BattleState,
+    context: &MoveContext,
+    branch_on_damage: bool,
+) -> BattleResult<Vec<BattleInstructions>> {
+    use crate::engine::combat::moves::apply_move_effects;
+    use crate::engine::combat::core::move_protection::break_protection_instructions;
+    
+    // Check move accuracy
+    let accuracy_percentage = calculate_move_accuracy(move_data_raw, user_pos, targets, state, context.going_first);
     
     let mut instruction_sets = Vec::new();
     
-    // 3. If move can miss, create miss instruction set
+    // If move can miss, create miss instruction set
     if accuracy_percentage < 100.0 {
         let miss_percentage = 100.0 - accuracy_percentage;
         instruction_sets.push(BattleInstructions::new(
@@ -1133,7 +1680,7 @@ fn generate_attack_instructions_with_enhanced_context(
         ));
     }
     
-    // 4. Only generate hit instructions if move can hit
+    // Only generate hit instructions if move can hit
     if accuracy_percentage > 0.0 {
         // Get generation mechanics
         let generation = state.get_generation_mechanics();
@@ -1142,18 +1689,22 @@ fn generate_attack_instructions_with_enhanced_context(
         let repository = create_generation_repository(&generation)?;
         let hit_instructions = apply_move_effects(
             state,
-            &move_data,
+            move_data,
             user_pos,
-            &targets,
+            targets,
             &generation,
             context,
             &repository,
             branch_on_damage,
         )?;
         
+        // Feint and other protection-breaking moves lift the targets' protection as they hit
+        let broken = break_protection_instructions(state, move_data, user_pos, targets);
+        
         // Scale hit instruction probabilities by accuracy
         for mut hit_instruction in hit_instructions {
             hit_instruction.percentage = (hit_instruction.percentage * accuracy_percentage) / 100.0;
+            hit_instruction.instruction_list.splice(0..0, broken.iter().cloned());
             instruction_sets.push(hit_instruction);
         }
     }
@@ -1161,6 +1712,84 @@ fn generate_attack_instructions_with_enhanced_context(
     Ok(instruction_sets)
 }
 
+/// Remember the move as the user's last used move
+///
+/// This is recorded for every move, not only in battles where something reads it:
+/// choice lock, Encore, Torment, Gigaton Hammer and Blood Moon restrict the next
+/// choice with it, two-turn moves and Mat Block check it, and determinized states
+/// carry it over from the observed state. Whether a reader exists can change
+/// mid-battle (an Encore from a Pokemon that switches in later), so a skipped
+/// record can't be recovered.
+fn record_last_used_move(
+    user_pos: BattlePosition,
+    state: &BattleState,
+    move_name: crate::types::Moves,
+    instruction_sets: &mut Vec<BattleInstructions>,
+) {
+    use crate::core::instructions::StatusInstruction;
+    
+    let previous_move = state.get_pokemon_at_position(user_pos)
+        .and_then(|pokemon| pokemon.last_used_move);
+    if previous_move == Some(move_name) {
+        return;
+    }
+    
+    if instruction_sets.is_empty() {
+        instruction_sets.push(BattleInstructions::new(100.0, vec![]));
+    }
+    for instruction_set in instruction_sets.iter_mut() {
+        instruction_set.instruction_list.insert(0, BattleInstruction::Status(StatusInstruction::SetLastUsedMove {
+            target: user_pos,
+            move_name,
+            previous_move,
+        }));
+    }
+}
+
+/// A recharging Pokemon spends its turn doing nothing, which ends the recharge
+fn recharge_instructions(user_pos: BattlePosition, state: &BattleState) -> Vec<BattleInstruction> {
+    use crate::core::instructions::{StatusInstruction, VolatileStatus};
+    
+    let recharging = state.get_pokemon_at_position(user_pos)
+        .is_some_and(|pokemon| pokemon.hp > 0 && pokemon.volatile_statuses.contains(VolatileStatus::MustRecharge));
+    if !recharging {
+        return Vec::new();
+    }
+    
+    vec![BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+        target: user_pos,
+        status: VolatileStatus::MustRecharge,
+        previous_duration: None,
+    })]
+}
+
+/// Glaive Rush's drawback lasts until the user's next move, so clear it first in every branch
+fn clear_glaive_rush_on_move(
+    user_pos: BattlePosition,
+    state: &BattleState,
+    instruction_sets: &mut Vec<BattleInstructions>,
+) {
+    use crate::core::instructions::{StatusInstruction, VolatileStatus};
+    
+    let exposed = state.get_pokemon_at_position(user_pos)
+        .map(|pokemon| pokemon.volatile_statuses.contains(VolatileStatus::GlaiveRush))
+        .unwrap_or(false);
+    if !exposed {
+        return;
+    }
+    
+    if instruction_sets.is_empty() {
+        instruction_sets.push(BattleInstructions::new(100.0, vec![]));
+    }
+    for instruction_set in instruction_sets.iter_mut() {
+        instruction_set.instruction_list.insert(0, BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+            target: user_pos,
+            status: VolatileStatus::GlaiveRush,
+            previous_duration: None,
+        }));
+    }
+}
+
 /// Create a generation-specific repository for move effects
 fn create_generation_repository(generation: &crate::generation::GenerationMechanics) -> crate::types::BattleResult<crate::data::GameDataRepository> {
     use crate::types::BattleError;
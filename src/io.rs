@@ -3,6 +3,7 @@
 //! This module provides CLI interface and subcommands for Tapu Simu.
 
 use crate::core::battle_format::BattleFormat;
+use crate::engine::coverage::CoverageReport;
 use clap::{Parser, Subcommand};
 
 /// Tapu Simu CLI
@@ -59,6 +60,14 @@ pub enum Commands {
         /// Random seed for reproducible battles
         #[arg(long)]
         seed: Option<u64>,
+
+        /// Only show players what the opponent has revealed
+        #[arg(long)]
+        partial_observability: bool,
+
+        /// Reject teams using unimplemented abilities, moves or items
+        #[arg(long)]
+        strict: bool,
     },
 
     /// Validate battle format configuration
@@ -69,6 +78,13 @@ pub enum Commands {
 
     /// Show engine information
     Info,
+
+    /// List abilities, moves and items without implemented behaviour
+    Coverage {
+        /// Also list damaging moves that only use generic damage
+        #[arg(long)]
+        generic_moves: bool,
+    },
 }
 
 /// Parse battle format from string
@@ -95,6 +111,7 @@ pub fn parse_battle_format(format_str: &str) -> Result<BattleFormat, String> {
         )),
         "gen9ou" | "gen9-ou" => Ok(BattleFormat::gen9_ou()),
         "gen4ou" | "gen4-ou" => Ok(BattleFormat::gen4_ou()),
+        "gen9lc" | "gen9-lc" | "lc" => Ok(BattleFormat::gen9_little_cup()),
         "gen9randombattle" | "gen9random" | "gen 9 random battle" => {
             Ok(BattleFormat::gen9_random_battle())
         }
@@ -114,7 +131,7 @@ pub fn parse_battle_format(format_str: &str) -> Result<BattleFormat, String> {
             // Provide helpful suggestions for invalid formats
             let suggestions = vec![
                 "singles", "doubles", "triples", "vgc",
-                "gen9ou", "gen4ou", 
+                "gen9ou", "gen4ou", "gen9lc",
                 "gen9randombattle", "gen9randomdoubles",
                 "gen8randombattle", "gen8randomdoubles",
                 "gen7randombattle"
@@ -156,3 +173,28 @@ pub fn print_engine_info() {
     println!();
 }
 
+
+/// Print the abilities, moves and items the engine doesn't implement
+pub fn print_coverage_report(report: &CoverageReport, generic_moves: bool) {
+    println!("Unimplemented abilities ({}):", report.abilities.len());
+    for ability in &report.abilities {
+        println!("  - {}", ability.display_name());
+    }
+    println!();
+    println!("Unimplemented status moves ({}):", report.moves.len());
+    for move_id in &report.moves {
+        println!("  - {}", move_id.display_name());
+    }
+    println!();
+    if generic_moves {
+        println!("Damaging moves using generic damage ({}):", report.generic_moves.len());
+        for move_id in &report.generic_moves {
+            println!("  - {}", move_id.display_name());
+        }
+        println!();
+    }
+    println!("Unimplemented items ({}):", report.items.len());
+    for item in &report.items {
+        println!("  - {}", item.display_name());
+    }
+}
@@ -42,7 +42,7 @@ pub use core::battle_environment::{
     run_battle_from_state, run_parallel_battles_with_states, BattleEnvironment, BattleResult,
     DamageMaximizer, FirstMovePlayer, ParallelBattleResults, Player, RandomPlayer, TurnInfo,
 };
-pub use core::battle_format::{BattleFormat, BattlePosition, FormatType, SideReference};
+pub use core::battle_format::{BattleFormat, BattlePosition, FormatType, LevelRule, SideReference, TypeChartRule};
 pub use core::battle_state::BattleState;
 pub use core::instructions::{
     BattleInstruction, BattleInstructions, FieldInstruction, PokemonInstruction, StatsInstruction,
@@ -7,7 +7,8 @@ use rand::rngs::StdRng;
 use rand::SeedableRng;
 
 use tapu_simu::data::RandomTeamLoader;
-use tapu_simu::io::{parse_battle_format, print_engine_info, Cli, Commands};
+use tapu_simu::engine::coverage::CoverageReport;
+use tapu_simu::io::{parse_battle_format, print_coverage_report, print_engine_info, Cli, Commands};
 use tapu_simu::types::errors::{BattleError, BattleResult};
 use tapu_simu::{BattleEnvironment, BattleFormat, BattleState, DamageMaximizer, FirstMovePlayer, RandomPlayer};
 
@@ -26,6 +27,8 @@ fn main() -> BattleResult<()> {
             team_index,
             config,
             seed,
+            partial_observability,
+            strict,
         } => {
             let battle_format = parse_battle_format(&format)
                 .map_err(|e| BattleError::InvalidState { reason: e })?;
@@ -40,6 +43,8 @@ fn main() -> BattleResult<()> {
                 team_index,
                 config,
                 seed,
+                partial_observability,
+                strict,
             )?;
         }
 
@@ -52,6 +57,11 @@ fn main() -> BattleResult<()> {
         Commands::Info => {
             print_engine_info();
         }
+
+        Commands::Coverage { generic_moves } => {
+            let repository = tapu_simu::data::GameDataRepository::from_path("data/ps-extracted")?;
+            print_coverage_report(&CoverageReport::from_repository(&repository), generic_moves);
+        }
     }
 
     Ok(())
@@ -69,20 +79,36 @@ fn run_battle(
     team_index: Option<usize>,
     config_file: Option<String>,
     seed: Option<u64>,
+    partial_observability: bool,
+    strict: bool,
 ) -> BattleResult<()> {
-    setup_battle_config(seed, config_file, verbose)?;
+    // `--strict` turns on rejection even when the configuration leaves it off
+    let strict_validation = setup_battle_config(seed, config_file, verbose)? || strict;
     let players = create_players(player_one, player_two)?;
-    let results = execute_battles(format, players, runs, max_turns, team_index, verbose, log_file)?;
+    let results = execute_battles(
+        format,
+        players,
+        runs,
+        max_turns,
+        team_index,
+        verbose,
+        log_file,
+        partial_observability,
+        strict_validation,
+    )?;
     print_battle_summary(results, runs, player_one, player_two);
     Ok(())
 }
 
 /// Setup battle configuration including random seed and config loading
+///
+/// Returns whether the configuration rejects teams using unimplemented effects.
+/// Without a config file they only produce warnings.
 fn setup_battle_config(
     seed: Option<u64>,
     config_file: Option<String>,
     verbose: bool,
-) -> BattleResult<()> {
+) -> BattleResult<bool> {
     if let Some(seed_value) = seed {
         let _rng = StdRng::seed_from_u64(seed_value);
         if verbose {
@@ -94,16 +120,17 @@ fn setup_battle_config(
         if verbose {
             println!("Loading configuration from: {}", config_path);
         }
-        let _config = tapu_simu::Config::load(&config_path)
+        let config = tapu_simu::Config::load(&config_path)
             .map_err(|e| BattleError::InvalidState { 
                 reason: format!("Failed to load config: {}", e) 
             })?;
         if verbose {
             println!("Configuration loaded successfully");
         }
+        return Ok(config.battle.strict_format_validation);
     }
 
-    Ok(())
+    Ok(tapu_simu::Config::default().battle.strict_format_validation)
 }
 
 /// Create player instances based on player type strings
@@ -123,6 +150,8 @@ fn execute_battles(
     team_index: Option<usize>,
     verbose: bool,
     log_file: Option<String>,
+    partial_observability: bool,
+    strict_validation: bool,
 ) -> BattleResult<(usize, usize, usize)> {
 
     println!("Running {} battle(s) in {} format", runs, format);
@@ -238,7 +267,21 @@ fn execute_battles(
                 .expect("Failed to load game data repository")
         );
         
-        let mut state = BattleState::new_with_teams(format.clone(), team_one, team_two, generation_repo, game_data_repo);
+        let (mut state, gaps) = BattleState::new_with_teams(
+            format.clone(),
+            team_one,
+            team_two,
+            generation_repo,
+            game_data_repo,
+            strict_validation,
+        )?;
+        if verbose || runs == 1 {
+            for gap in &gaps {
+                eprintln!("Warning: {} is not implemented", gap);
+            }
+        } else if !gaps.is_empty() {
+            eprintln!("Warning: battle {} uses {} unimplemented effect(s)", run, gaps.len());
+        }
 
         if verbose && run == 1 {
             println!("Initialized battle state with format: {}", state.format);
@@ -262,6 +305,9 @@ fn execute_battles(
             };
             env = env.with_log_file(battle_log_path);
         }
+        if partial_observability {
+            env = env.with_partial_observability();
+        }
 
         let result = env.run_battle(state);
 
@@ -53,6 +53,7 @@ impl Simulator {
     /// Get a battle builder for creating custom battles
     pub fn battle(&self) -> BattleBuilder<'_> {
         BattleBuilder::new(&self.data, self.generation_repo.clone())
+            .strict_format_validation(self.config.battle.strict_format_validation)
     }
 
     /// Quick API for common random battle
@@ -104,6 +104,9 @@ pub enum FormatError {
     
     #[error("Format rule violation: {rule}")]
     RuleViolation { rule: String },
+
+    #[error("Invalid type chart: {reason}")]
+    InvalidTypeChart { reason: String },
 }
 
 /// Errors related to team validation and generation
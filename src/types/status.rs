@@ -130,6 +130,18 @@ pub enum VolatileStatus {
     MustSwitch,
     MicleBoost,
     CustapBoost,
+    SaltCure,
+    SyrupBomb,
+    Trapped,
+    PhantomForce,
+    CudChew,
+    SpikyShield,
+    SilkTrap,
+    Obstruct,
+    Stall,
+    BatonPass,
+    ShedTail,
+    TarShot,
 }
 
 impl From<u8> for VolatileStatus {
@@ -0,0 +1,188 @@
+//! # Ability Suppression Tests
+//!
+//! This module contains tests for the ways an ability gets switched off:
+//! Neutralizing Gas while its user is out, Gastro Acid and Core Enforcer on a
+//! single target, Mold Breaker ignoring the target's ability for one move, and
+//! the Ability Shield that protects against all of them.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattlePosition, SideReference};
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::VolatileStatus;
+use tapu_simu::core::move_choice::{MoveChoice, PokemonIndex};
+use tapu_simu::engine::combat::core::sync_ability_suppression;
+use tapu_simu::engine::turn;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Whether the Pokemon at `position` has its ability switched off
+fn suppressed(state: &BattleState, position: BattlePosition) -> bool {
+    state.get_pokemon_at_position(position).unwrap().ability_suppressed
+}
+
+/// HP the Pokemon at `position` has lost
+fn hp_lost(state: &BattleState, position: BattlePosition) -> i16 {
+    let pokemon = state.get_pokemon_at_position(position).unwrap();
+    pokemon.max_hp - pokemon.hp
+}
+
+/// Start the battle from `builder`'s state, with Neutralizing Gas taking hold
+/// of the leads as it would when they're sent out
+fn state_after_leads(builder: &TestBuilder) -> BattleState {
+    let mut state = builder.build_state();
+    let suppression = sync_ability_suppression(&state);
+    state.apply_instructions(&suppression);
+    state
+}
+
+/// Play one turn, applying every branch
+fn play_turn(state: &mut BattleState, move_one: &MoveChoice, move_two: &MoveChoice) {
+    for branch in turn::generate_instructions(state, (move_one, move_two), false).unwrap() {
+        state.apply_instructions(&branch.instruction_list);
+    }
+}
+
+/// Damage `attacker`'s Earthquake deals to a Levitate Bronzong
+fn earthquake_into_levitate(attacker: PokemonSpec, bronzong_item: Option<&'static str>) -> i16 {
+    let mut bronzong = PokemonSpec::new("Bronzong").ability("Levitate").moves(vec!["Splash"]);
+    if let Some(item) = bronzong_item {
+        bronzong = bronzong.item(item);
+    }
+    let builder = TestBuilder::new("earthquake into levitate")
+        .unwrap()
+        .team_one(attacker.moves(vec!["Earthquake"]))
+        .team_two(bronzong);
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let state = builder.turn(earthquake, splash).final_state();
+    hp_lost(&state, Positions::SIDE_TWO_0)
+}
+
+// ============================================================================
+// Neutralizing Gas
+// ============================================================================
+
+/// Test Neutralizing Gas suppresses the opponent's ability on entry and hands it back on exit
+#[test]
+fn test_neutralizing_gas_entry_and_exit() {
+    let builder = TestBuilder::new("neutralizing gas entry and exit")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Garchomp").moves(vec!["Splash"]),
+            PokemonSpec::new("Weezing").ability("Neutralizing Gas").moves(vec!["Splash"]),
+        ])
+        .team_two(PokemonSpec::new("Bronzong").ability("Levitate").moves(vec!["Splash"]));
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state = state_after_leads(&builder);
+    assert!(!suppressed(&state, Positions::SIDE_TWO_0));
+
+    play_turn(&mut state, &MoveChoice::Switch(PokemonIndex::P1), &splash);
+    assert!(suppressed(&state, Positions::SIDE_TWO_0));
+    assert!(!suppressed(&state, Positions::SIDE_ONE_0));
+
+    play_turn(&mut state, &MoveChoice::Switch(PokemonIndex::P0), &splash);
+    assert!(!suppressed(&state, Positions::SIDE_TWO_0));
+}
+
+/// Test the opponent gets its ability back as soon as the Neutralizing Gas user faints
+#[test]
+fn test_neutralizing_gas_ends_when_its_user_faints() {
+    let builder = TestBuilder::new("neutralizing gas user faints")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Weezing").ability("Neutralizing Gas").hp(1).moves(vec!["Splash"]),
+            PokemonSpec::new("Garchomp").moves(vec!["Splash"]),
+        ])
+        .team_two(PokemonSpec::new("Bronzong").ability("Levitate").moves(vec!["Tackle"]));
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let tackle = builder.create_move_choice("Tackle", SideReference::SideTwo);
+    let mut state = state_after_leads(&builder);
+    assert!(suppressed(&state, Positions::SIDE_TWO_0));
+
+    play_turn(&mut state, &splash, &tackle);
+    assert_eq!(state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().hp, 0);
+    assert!(!suppressed(&state, Positions::SIDE_TWO_0));
+}
+
+// ============================================================================
+// Gastro Acid and Core Enforcer
+// ============================================================================
+
+/// Test Gastro Acid suppresses Levitate, letting a later Earthquake hit
+#[test]
+fn test_gastro_acid_suppresses_ability() {
+    let builder = TestBuilder::new("gastro acid")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Gastro Acid", "Earthquake"]))
+        .team_two(PokemonSpec::new("Bronzong").ability("Levitate").moves(vec!["Splash"]));
+    let gastro_acid = builder.create_move_choice("Gastro Acid", SideReference::SideOne);
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state = builder.build_state();
+
+    play_turn(&mut state, &gastro_acid, &splash);
+    let bronzong = state.get_pokemon_at_position(Positions::SIDE_TWO_0).unwrap();
+    assert!(bronzong.ability_suppressed);
+    assert!(bronzong.volatile_statuses.contains(VolatileStatus::GastroAcid));
+
+    play_turn(&mut state, &earthquake, &splash);
+    assert!(hp_lost(&state, Positions::SIDE_TWO_0) > 0);
+}
+
+/// Whether Zygarde's Core Enforcer leaves `opponent` with its ability suppressed
+fn core_enforcer_suppresses(opponent: PokemonSpec) -> bool {
+    let builder = TestBuilder::new("core enforcer")
+        .unwrap()
+        .team_one(PokemonSpec::new("Zygarde").moves(vec!["Core Enforcer"]))
+        .team_two(opponent.moves(vec!["Splash"]));
+    let core_enforcer = builder.create_move_choice("Core Enforcer", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let state = builder.turn(core_enforcer, splash).final_state();
+    suppressed(&state, Positions::SIDE_TWO_0)
+}
+
+/// Test Core Enforcer only suppresses a target that has already moved
+#[test]
+fn test_core_enforcer_needs_target_to_move_first() {
+    // Zygarde outspeeds Bronzong, so Core Enforcer lands before Bronzong moves
+    assert!(!core_enforcer_suppresses(PokemonSpec::new("Bronzong").ability("Levitate")));
+    // Regieleki moves before Zygarde
+    assert!(core_enforcer_suppresses(PokemonSpec::new("Regieleki").ability("Transistor")));
+}
+
+// ============================================================================
+// Mold Breaker and Ability Shield
+// ============================================================================
+
+/// Test Mold Breaker's Earthquake ignores Levitate
+#[test]
+fn test_mold_breaker_ignores_levitate() {
+    assert_eq!(earthquake_into_levitate(PokemonSpec::new("Excadrill").ability("Sand Rush"), None), 0);
+    assert!(earthquake_into_levitate(PokemonSpec::new("Excadrill").ability("Mold Breaker"), None) > 0);
+}
+
+/// Test an Ability Shield keeps Levitate working against Mold Breaker
+#[test]
+fn test_ability_shield_blocks_mold_breaker() {
+    let attacker = PokemonSpec::new("Excadrill").ability("Mold Breaker");
+    assert_eq!(earthquake_into_levitate(attacker, Some("Ability Shield")), 0);
+}
+
+/// Test an Ability Shield protects against Neutralizing Gas and Core Enforcer
+#[test]
+fn test_ability_shield_blocks_suppression() {
+    let builder = TestBuilder::new("ability shield against neutralizing gas")
+        .unwrap()
+        .team_one(PokemonSpec::new("Weezing").ability("Neutralizing Gas").moves(vec!["Splash"]))
+        .team_two(
+            PokemonSpec::new("Bronzong")
+                .ability("Levitate")
+                .item("Ability Shield")
+                .moves(vec!["Splash"]),
+        );
+    assert!(!suppressed(&state_after_leads(&builder), Positions::SIDE_TWO_0));
+
+    let shielded = PokemonSpec::new("Regieleki").ability("Transistor").item("Ability Shield");
+    assert!(!core_enforcer_suppresses(shielded));
+}
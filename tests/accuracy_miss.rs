@@ -21,7 +21,9 @@ use tapu_simu::core::instructions::{
     BattleInstruction, BattleInstructions, PokemonInstruction, PokemonStatus, StatusInstruction,
     VolatileStatus, Weather,
 };
+use tapu_simu::types::Moves;
 
+use utils::convenience::last_used_move;
 use utils::{PokemonSpec, Positions, TestBuilder};
 
 // ============================================================================
@@ -34,11 +36,14 @@ use utils::{PokemonSpec, Positions, TestBuilder};
 fn test_compound_eyes_does_not_cause_instructions_with_more_than_100_percent() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0, // Should cap at 100%
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 22,
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::TACKLE),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 22,
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -67,21 +72,27 @@ fn test_flinching_on_move_that_can_miss() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 5.0, // Miss chance
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: Positions::SIDE_ONE_0,
-                amount: 87,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::AIRSLASH),
+                last_used_move(Positions::SIDE_TWO_0, Moves::IRONHEAD),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: Positions::SIDE_ONE_0,
+                    amount: 87,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![Positions::SIDE_ONE_0],
         },
         BattleInstructions {
             percentage: 66.5, // Hit, no flinch (95% * 70%)
             instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::AIRSLASH),
                 BattleInstruction::Pokemon(PokemonInstruction::Damage {
                     target: Positions::SIDE_TWO_0,
                     amount: 42,
                     previous_hp: None,
                 }),
+                last_used_move(Positions::SIDE_TWO_0, Moves::IRONHEAD),
                 BattleInstruction::Pokemon(PokemonInstruction::Damage {
                     target: Positions::SIDE_ONE_0,
                     amount: 87,
@@ -93,6 +104,7 @@ fn test_flinching_on_move_that_can_miss() {
         BattleInstructions {
             percentage: 28.5, // Hit with flinch (95% * 30%)
             instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::AIRSLASH),
                 BattleInstruction::Pokemon(PokemonInstruction::Damage {
                     target: Positions::SIDE_TWO_0,
                     amount: 42,
@@ -105,6 +117,12 @@ fn test_flinching_on_move_that_can_miss() {
                     previous_had_status: false,
                     previous_duration: None,
                 }),
+                // The flinch wears off at the end of the turn
+                BattleInstruction::Status(StatusInstruction::RemoveVolatile {
+                    target: Positions::SIDE_TWO_0,
+                    status: VolatileStatus::Flinch,
+                    previous_duration: None,
+                }),
             ],
             affected_positions: vec![Positions::SIDE_TWO_0],
         },
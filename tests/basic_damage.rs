@@ -21,10 +21,13 @@ use tapu_simu::constants::moves::{
 };
 use tapu_simu::core::battle_format::{BattlePosition, SideReference};
 use tapu_simu::core::instructions::{
-    BattleInstruction, BattleInstructions, PokemonInstruction, SideCondition, Stat,
+    BattleInstruction, BattleInstructions, FieldInstruction, PokemonInstruction, SideCondition,
+    Stat,
 };
 use tapu_simu::generation::Generation;
+use tapu_simu::types::Moves;
 
+use utils::convenience::{damage_in, last_used_move};
 use utils::{PokemonSpec, Positions, TestBuilder};
 
 /// Test basic move pair instruction generation
@@ -35,11 +38,13 @@ fn test_basic_move_pair_instruction_generation() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::TACKLE),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: BattlePosition::new(SideReference::SideTwo, 0),
                 amount: 38,        // Actual damage for Pikachu Tackle vs Charmander
                 previous_hp: None, // Framework normalizes this
             }),
+            last_used_move(Positions::SIDE_TWO_0, Moves::TACKLE),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: BattlePosition::new(SideReference::SideOne, 0),
                 amount: 38,        // Actual damage for Charmander Tackle vs Pikachu
@@ -73,20 +78,26 @@ fn test_branch_on_crit() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 100.0 * (1.0 - GEN9_BASE_CRIT_CHANCE),
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: base_damage,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::WATERGUN),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: base_damage,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 100.0 * GEN9_BASE_CRIT_CHANCE,
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: (GEN4_CRIT_MULTIPLIER * base_damage as f32).floor() as i16,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::WATERGUN),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: (GEN4_CRIT_MULTIPLIER * base_damage as f32).floor() as i16,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -110,20 +121,26 @@ fn test_branch_when_a_roll_can_kill() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: (1.0 - GEN9_BASE_CRIT_CHANCE) * 6.0 / 16.0 * 100.0, // Calculated probability for non-KO damage
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 36,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::TACKLE),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 36,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 100.0 - ((1.0 - GEN9_BASE_CRIT_CHANCE) * 6.0 / 16.0 * 100.0), // Calculated probability for KO damage
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 38, // Exact damage to reach 0 HP
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::TACKLE),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 38, // Exact damage to reach 0 HP
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -149,20 +166,26 @@ fn test_crit_does_not_overkill() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 100.0 * (1.0 - crit_chance),
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: base_damage,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::WATERGUN),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: base_damage,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 100.0 * crit_chance,
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 80, // Crit damage also capped at remaining HP
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::WATERGUN),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 80, // Crit damage also capped at remaining HP
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -186,25 +209,31 @@ fn test_highcrit_move() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 5.0,          // Miss chance
-            instruction_list: vec![], // No effect on miss
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::RAZORLEAF)], // No effect on miss
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 83.125, // Hit, no crit
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 35, // Base damage for high crit move
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::RAZORLEAF),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 35, // Base damage for high crit move
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 11.875, // Hit with high crit rate
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 53,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::RAZORLEAF),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 53,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -225,11 +254,14 @@ fn test_highcrit_move() {
 fn test_min_damage_killing_does_not_branch() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0, // Single outcome
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 1, // Remaining HP
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::TACKLE),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 1, // Remaining HP
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -249,11 +281,14 @@ fn test_min_damage_killing_does_not_branch() {
 fn test_stormthrow_crits_without_branch() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0, // Single outcome, guaranteed crit
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 69, // Estimated crit damage for Surging Strikes
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::STORMTHROW),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 69, // Estimated crit damage for Surging Strikes
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -272,11 +307,14 @@ fn test_stormthrow_crits_without_branch() {
 fn test_wickedblow_always_crits_without_a_branch() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0, // Single outcome, guaranteed crit
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 107, // Estimated crit damage for Wicked Blow
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::WICKEDBLOW),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 107, // Estimated crit damage for Wicked Blow
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -297,11 +335,14 @@ fn test_wickedblow_always_ignores_defensive_boost_on_opponent_because_of_crit()
     defense_boosts.insert(Stat::Defense, 2); // +2 Defense boost
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0, // Single outcome
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 107, // Same damage as without boost (crit ignores it)
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::WICKEDBLOW),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 107, // Same damage as without boost (crit ignores it)
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -324,11 +365,14 @@ fn test_wickedblow_always_ignores_defensive_boost_on_opponent_because_of_crit()
 fn test_wickedblow_cannot_crit_on_shellarmor() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0, // Single outcome, no crit due to Shell Armor
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 133, // Non-crit damage
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::WICKEDBLOW),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 133, // Non-crit damage
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -375,20 +419,26 @@ fn test_crit_roll_ignores_other_boost() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 0.390625, // Non-crit (1/256)
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 36, // Non-crit damage
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 36, // Non-crit damage
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 99.609375, // Persian + Slash = 255/256 crit rate in Gen 1
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 133, // Gen 1 crit damage ignoring defense boost
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 133, // Gen 1 crit damage ignoring defense boost
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -415,20 +465,26 @@ fn test_crit_roll_ignores_other_boost_negative_boost() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 0.390625, // Non-crit (1/256)
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 136, // Non-crit damage with -2 Defense
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 136, // Non-crit damage with -2 Defense
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 99.609375, // Persian + Slash = 255/256 crit rate in Gen 1
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 133, // Gen 1 crit damage ignoring defense boost
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 133, // Gen 1 crit damage ignoring defense boost
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -455,20 +511,26 @@ fn test_crit_roll_ignores_own_boost() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 0.390625, // Non-crit (1/256)
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 136, // Non-crit damage with +2 Attack
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 136, // Non-crit damage with +2 Attack
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 99.609375, // Persian + Slash = 255/256 crit rate in Gen 1
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 133, // Gen 1 crit damage ignoring attack boost
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 133, // Gen 1 crit damage ignoring attack boost
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -493,20 +555,38 @@ fn test_crit_roll_ignores_reflect() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 0.390625, // Non-crit (1/256)
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 69, // Non-crit damage with Reflect
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 69, // Non-crit damage with Reflect
+                    previous_hp: None,
+                }),
+                // Reflect runs out at the end of the turn
+                BattleInstruction::Field(FieldInstruction::RemoveSideCondition {
+                    side: SideReference::SideOne,
+                    condition: SideCondition::Reflect,
+                    previous_duration: 1,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 99.609375, // Persian + Slash = 255/256 crit rate in Gen 1
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 133, // Gen 1 crit damage ignoring Reflect
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SLASH),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 133, // Gen 1 crit damage ignoring Reflect
+                    previous_hp: None,
+                }),
+                // Reflect runs out at the end of the turn
+                BattleInstruction::Field(FieldInstruction::RemoveSideCondition {
+                    side: SideReference::SideOne,
+                    condition: SideCondition::Reflect,
+                    previous_duration: 1,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -566,20 +646,26 @@ fn test_gen2_branch_on_crit() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 100.0 * (1.0 - crit_chance),
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 36,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::POUND),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 36,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 100.0 * crit_chance,
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 70, // Gen 2 still uses 2x
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::POUND),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 70, // Gen 2 still uses 2x
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -601,20 +687,26 @@ fn test_gen2_crit_does_not_overkill() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 100.0 * (1.0 - crit_chance),
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 35,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::POUND),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 35,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 100.0 * crit_chance,
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 50,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::POUND),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 50,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -673,11 +765,14 @@ fn test_gen2_highcrit_move() {
 fn test_gen2_min_damage_killing_does_not_branch() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 1,
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::POUND),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 1,
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -699,20 +794,26 @@ fn test_gen3_branch_when_a_roll_can_kill() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 70.3125,
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 46,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::THUNDERSHOCK),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 46,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
         BattleInstructions {
             percentage: 100.0 - 70.3125,
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: BattlePosition::new(SideReference::SideTwo, 0),
-                amount: 50,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::THUNDERSHOCK),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: BattlePosition::new(SideReference::SideTwo, 0),
+                    amount: 50,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
         },
     ];
@@ -732,11 +833,14 @@ fn test_gen3_branch_when_a_roll_can_kill() {
 fn test_super_effective_damage() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 96, // Super effective damage (2x effectiveness)
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::WATERGUN),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 96, // Super effective damage (2x effectiveness)
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -754,11 +858,14 @@ fn test_super_effective_damage() {
 fn test_not_very_effective_damage() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: BattlePosition::new(SideReference::SideTwo, 0),
-            amount: 10, // Not very effective damage (0.5x effectiveness)
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::POUND),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: BattlePosition::new(SideReference::SideTwo, 0),
+                amount: 10, // Not very effective damage (0.5x effectiveness)
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![BattlePosition::new(SideReference::SideTwo, 0)],
     }];
 
@@ -770,3 +877,45 @@ fn test_not_very_effective_damage() {
         .expect_instructions(expected_instructions)
         .assert_success();
 }
+
+// ============================================================================
+// Life Orb Tests
+// ============================================================================
+
+/// Test Life Orb boosts damage in every generation that has it
+/// Gen 4 multiplies base damage by 1.3x, Gen 5+ applies a 5324/4096 final modifier
+#[test]
+fn test_life_orb_boosts_damage_gen4_onwards() {
+    for generation in [
+        Generation::Gen4,
+        Generation::Gen5,
+        Generation::Gen6,
+        Generation::Gen7,
+        Generation::Gen9,
+    ] {
+        let damage = |item: Option<&'static str>| {
+            let mut attacker = PokemonSpec::new("Garchomp").moves(vec!["Earthquake"]);
+            if let Some(item) = item {
+                attacker = attacker.item(item);
+            }
+            let builder = TestBuilder::new_with_generation("life orb boost", generation)
+                .unwrap()
+                .team_one(attacker)
+                .team_two(PokemonSpec::new("Snorlax"));
+            let choice = builder.create_move_choice("Earthquake", SideReference::SideOne);
+            let branches = builder.generate(&choice, &tapu_simu::core::move_choice::MoveChoice::None);
+            damage_in(&branches[0], Positions::SIDE_TWO_0)
+        };
+
+        let plain = damage(None) as f32;
+        let boosted = damage(Some("Life Orb")) as f32;
+        let ratio = boosted / plain;
+        assert!(
+            (1.25..=1.35).contains(&ratio),
+            "{:?}: Life Orb should boost damage by about 1.3x, got {} -> {}",
+            generation,
+            plain,
+            boosted
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! # Berry Tests
+//!
+//! This module contains tests for berries eaten when their holder is hit:
+//! Jaboca and Rowap strike back at the attacker, type-resist berries weaken
+//! the hit, and Ripen doubles both.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::BattleInstructions;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Play Garchomp's `attack` into Blissey holding `berry` with `ability`
+fn retaliation_branches(attack: &'static str, berry: &'static str, ability: &'static str) -> (BattleState, Vec<BattleInstructions>) {
+    let builder = TestBuilder::new("retaliation berry")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").ability("Rough Skin").moves(vec![attack]))
+        .team_two(PokemonSpec::new("Blissey").ability(ability).item(berry).moves(vec!["Splash"]));
+    let attack = builder.create_move_choice(attack, SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    (builder.build_state(), builder.generate(&attack, &splash))
+}
+
+/// The damage Garchomp takes back in each branch where its attack hit
+fn recoil_taken(attack: &'static str, berry: &'static str, ability: &'static str) -> Vec<i16> {
+    let (_, branches) = retaliation_branches(attack, berry, ability);
+    branches
+        .iter()
+        .filter(|branch| damage_in(branch, Positions::SIDE_TWO_0) > 0)
+        .map(|branch| damage_in(branch, Positions::SIDE_ONE_0))
+        .collect()
+}
+
+/// Test Jaboca hurts a physical attacker and Rowap a special one, by 1/8 of its max HP
+#[test]
+fn test_jaboca_and_rowap_hurt_the_attacker() {
+    let (state, _) = retaliation_branches("Tackle", "Jaboca Berry", "Natural Cure");
+    let eighth = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().max_hp / 8;
+
+    let jaboca = recoil_taken("Tackle", "Jaboca Berry", "Natural Cure");
+    assert!(!jaboca.is_empty() && jaboca.iter().all(|&damage| damage == eighth));
+    let rowap = recoil_taken("Dragon Pulse", "Rowap Berry", "Natural Cure");
+    assert!(!rowap.is_empty() && rowap.iter().all(|&damage| damage == eighth));
+
+    // Each only answers its own category of hit
+    assert!(recoil_taken("Dragon Pulse", "Jaboca Berry", "Natural Cure").iter().all(|&damage| damage == 0));
+}
+
+/// Test Ripen doubles the damage Jaboca deals back
+#[test]
+fn test_ripen_doubles_jaboca() {
+    let (state, _) = retaliation_branches("Tackle", "Jaboca Berry", "Ripen");
+    let quarter = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().max_hp * 2 / 8;
+
+    let ripened = recoil_taken("Tackle", "Jaboca Berry", "Ripen");
+    assert!(!ripened.is_empty() && ripened.iter().all(|&damage| damage == quarter));
+}
+
+/// The smallest damage Earthquake deals to Tyranitar holding `item` with `ability`
+fn earthquake_damage(item: &'static str, ability: &'static str) -> i16 {
+    let builder = TestBuilder::new("resist berry")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake"]))
+        .team_two(PokemonSpec::new("Tyranitar").ability(ability).item(item).moves(vec!["Splash"]));
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    builder
+        .generate(&earthquake, &splash)
+        .iter()
+        .map(|branch| damage_in(branch, Positions::SIDE_TWO_0))
+        .filter(|&damage| damage > 0)
+        .min()
+        .unwrap()
+}
+
+/// Test a resist berry halves a super effective hit, and Ripen quarters it
+#[test]
+fn test_resist_berry_with_ripen() {
+    let unheld = earthquake_damage("Leftovers", "Unnerve") as f32;
+    let halved = earthquake_damage("Shuca Berry", "Unnerve") as f32 / unheld;
+    let quartered = earthquake_damage("Shuca Berry", "Ripen") as f32 / unheld;
+    assert!((halved - 0.5).abs() < 0.02, "{}", halved);
+    assert!((quartered - 0.25).abs() < 0.02, "{}", quartered);
+}
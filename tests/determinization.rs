@@ -0,0 +1,160 @@
+//! # Determinization Tests
+//!
+//! This module contains tests for `DeterminizationSampler`: the sets it fills in
+//! for a partially revealed opponent have to agree with the moves, item and
+//! damage that have been seen, and are weighted by how common they are.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::BattleObservation;
+use tapu_simu::data::determinization::DeterminizationSampler;
+use tapu_simu::data::random_team_loader::{RandomPokemonSet, RandomTeam};
+use tapu_simu::data::types::Stats;
+use tapu_simu::types::{Abilities, Items, Moves, PokemonName};
+
+use utils::{PokemonSpec, TestBuilder};
+
+/// A level 100 random battle set
+fn set(species: PokemonName, ability: Abilities, item: Items, moves: &[Moves]) -> RandomPokemonSet {
+    RandomPokemonSet {
+        name: species.as_str().to_string(),
+        species,
+        level: 100,
+        gender: None,
+        shiny: None,
+        ability: Some(ability),
+        item: Some(item),
+        moves: moves.to_vec(),
+        nature: None,
+        evs: None,
+        ivs: None,
+        tera_type: None,
+        gigantamax: None,
+    }
+}
+
+fn scarf_garchomp() -> RandomPokemonSet {
+    set(
+        PokemonName::GARCHOMP,
+        Abilities::ROUGHSKIN,
+        Items::CHOICESCARF,
+        &[Moves::EARTHQUAKE, Moves::OUTRAGE, Moves::STONEEDGE, Moves::FIREFANG],
+    )
+}
+
+fn life_orb_garchomp() -> RandomPokemonSet {
+    set(
+        PokemonName::GARCHOMP,
+        Abilities::ROUGHSKIN,
+        Items::LIFEORB,
+        &[Moves::SWORDSDANCE, Moves::EARTHQUAKE, Moves::SCALESHOT, Moves::FIREFANG],
+    )
+}
+
+fn leftovers_garchomp() -> RandomPokemonSet {
+    set(
+        PokemonName::GARCHOMP,
+        Abilities::ROUGHSKIN,
+        Items::LEFTOVERS,
+        &[Moves::SWORDSDANCE, Moves::EARTHQUAKE, Moves::STEALTHROCK, Moves::DRAGONTAIL],
+    )
+}
+
+fn gengar() -> RandomPokemonSet {
+    set(
+        PokemonName::GENGAR,
+        Abilities::CURSEDBODY,
+        Items::LIFEORB,
+        &[Moves::SHADOWBALL, Moves::SLUDGEBOMB, Moves::FOCUSBLAST, Moves::NASTYPLOT],
+    )
+}
+
+/// Teams with the Leftovers Garchomp three times as common as the Life Orb one
+fn teams() -> Vec<RandomTeam> {
+    vec![
+        vec![scarf_garchomp(), gengar()],
+        vec![life_orb_garchomp(), gengar()],
+        vec![leftovers_garchomp(), gengar()],
+        vec![leftovers_garchomp(), gengar()],
+        vec![leftovers_garchomp(), gengar()],
+    ]
+}
+
+/// Side one's observation after Garchomp, holding `item`, uses `used_move` on Hippowdon
+fn observe_garchomp(item: &'static str, used_move: &'static str) -> BattleObservation {
+    let evs = Stats { hp: 85, attack: 85, defense: 85, special_attack: 85, special_defense: 85, speed: 85 };
+    let builder = TestBuilder::new("determinization")
+        .unwrap()
+        .team_one(PokemonSpec::new("Hippowdon").moves(vec!["Splash"]))
+        .team_two_multi(vec![
+            PokemonSpec::new("Garchomp")
+                .ability("Rough Skin")
+                .item(item)
+                .moves(vec!["Swords Dance", "Earthquake", "Scale Shot", "Fire Fang"])
+                .evs(evs),
+            PokemonSpec::new("Gengar"),
+        ]);
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let chosen = builder.create_move_choice(used_move, SideReference::SideTwo);
+    let mut state = builder.build_state();
+    let branches = builder.generate(&splash, &chosen);
+    state.record_revealed((&splash, &chosen), &branches[0].instruction_list);
+    state.apply_instructions(&branches[0].instruction_list);
+    state.view_for(SideReference::SideOne)
+}
+
+/// Test only sets with every revealed move are candidates, weighted by how common they are
+#[test]
+fn test_consistent_sets_follow_revealed_moves() {
+    let observation = observe_garchomp("Leftovers", "Swords Dance");
+    let sampler = DeterminizationSampler::from_teams(&teams());
+
+    let candidates = sampler.consistent_sets(&observation, 0);
+    let mut items: Vec<(Option<Items>, f64)> = candidates.iter().map(|(set, likelihood)| (set.item, *likelihood)).collect();
+    items.sort_by(|a, b| a.1.total_cmp(&b.1));
+    assert_eq!(items, vec![(Some(Items::LIFEORB), 0.25), (Some(Items::LEFTOVERS), 0.75)]);
+}
+
+/// Test a revealed item rules out sets holding anything else
+#[test]
+fn test_consistent_sets_follow_revealed_item() {
+    let mut observation = observe_garchomp("Leftovers", "Swords Dance");
+    observation.state.sides[1].revealed[0].item = Some(Some(Items::LEFTOVERS));
+    let sampler = DeterminizationSampler::from_teams(&teams());
+
+    let candidates = sampler.consistent_sets(&observation, 0);
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].0.item, Some(Items::LEFTOVERS));
+    assert_eq!(candidates[0].1, 1.0);
+}
+
+/// Test the damage a hit dealt rules out sets whose weakest roll is too strong
+#[test]
+fn test_consistent_sets_follow_observed_damage() {
+    let observation = observe_garchomp("Leftovers", "Earthquake");
+    let sampler = DeterminizationSampler::from_teams(&teams());
+
+    let candidates = sampler.consistent_sets(&observation, 0);
+    let items: Vec<Option<Items>> = candidates.iter().map(|(set, _)| set.item).collect();
+    assert_eq!(items, vec![Some(Items::CHOICESCARF), Some(Items::LEFTOVERS)]);
+}
+
+/// Test samples keep what was revealed and fill the unseen bench with other species
+#[test]
+fn test_samples_agree_with_observation() {
+    let observation = observe_garchomp("Leftovers", "Earthquake");
+    let mut sampler = DeterminizationSampler::from_teams(&teams()).with_seed(7);
+
+    for sample in sampler.sample(&observation, 8) {
+        let opponent = &sample.state.sides[1];
+        let garchomp = &opponent.pokemon[0];
+        assert_eq!(garchomp.species, PokemonName::GARCHOMP);
+        assert_ne!(garchomp.item, Some(Items::LIFEORB));
+        assert!(garchomp.moves.iter().any(|(_, known)| known.name == Moves::EARTHQUAKE));
+        assert!(garchomp.max_hp > 100);
+
+        assert_eq!(opponent.pokemon[1].species, PokemonName::GENGAR);
+        assert!(sample.weight > 0.0 && sample.weight <= 1.0);
+    }
+}
@@ -0,0 +1,110 @@
+//! # End of Turn Tests
+//!
+//! This module contains tests for the residuals that run after every turn
+//! branch: their chances multiply with the branches the moves produced, tied
+//! Pokemon resolve in every order, and weather-dependent abilities see the
+//! weather as it affects them.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{
+    BattleInstruction, BattleInstructions, PokemonInstruction, PokemonStatus, Weather,
+};
+use tapu_simu::engine::turn;
+use tapu_simu::types::Items;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Branch percentages, sorted
+fn percentages(branches: &[BattleInstructions]) -> Vec<f32> {
+    let mut percentages: Vec<f32> = branches.iter().map(|branch| branch.percentage).collect();
+    percentages.sort_by(f32::total_cmp);
+    percentages
+}
+
+/// Test a residual's chance multiplies with each branch of the turn's moves
+#[test]
+fn test_residual_chance_multiplies_move_branches() {
+    let builder = TestBuilder::new("residual product")
+        .unwrap()
+        .team_one(PokemonSpec::new("Arbok").ability("Shed Skin").moves(vec!["Will-O-Wisp"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_status(Positions::SIDE_ONE_0, PokemonStatus::Burn);
+    let will_o_wisp = builder.create_move_choice("Will-O-Wisp", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+
+    let branches = builder.generate(&will_o_wisp, &splash);
+    // Will-O-Wisp hits 85% of the time and Shed Skin cures 30% of the time
+    let expected = [15.0 * 0.3, 15.0 * 0.7, 85.0 * 0.3, 85.0 * 0.7];
+    let actual = percentages(&branches);
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-3, "{} != {}", actual, expected);
+    }
+}
+
+/// Test tied Pokemon take their residuals in either order, each half the time
+///
+/// Whichever Blissey takes the sandstorm first faints and ends the battle
+/// before the other is hit.
+#[test]
+fn test_speed_tied_residuals_branch_both_orders() {
+    let builder = TestBuilder::new("residual speed tie")
+        .unwrap()
+        .team_one(PokemonSpec::new("Blissey").moves(vec!["Splash"]).hp(1))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]).hp(1))
+        .with_weather(Weather::Sand);
+    let splash_one = builder.create_move_choice("Splash", SideReference::SideOne);
+    let splash_two = builder.create_move_choice("Splash", SideReference::SideTwo);
+
+    let branches = builder.generate(&splash_one, &splash_two);
+    let chance_survives = |survivor| {
+        branches
+            .iter()
+            .filter(|branch| {
+                !branch.instruction_list.iter().any(|instruction| {
+                    matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. }) if *target == survivor)
+                })
+            })
+            .map(|branch| branch.percentage)
+            .sum::<f32>()
+    };
+    assert!((chance_survives(Positions::SIDE_ONE_0) - 50.0).abs() < 1e-3);
+    assert!((chance_survives(Positions::SIDE_TWO_0) - 50.0).abs() < 1e-3);
+}
+
+/// Play a turn of Splash with Harvest Exeggutor's Sitrus Berry already eaten
+fn harvest_branches(opponent: PokemonSpec) -> Vec<BattleInstructions> {
+    let builder = TestBuilder::new("harvest")
+        .unwrap()
+        .team_one(PokemonSpec::new("Exeggutor").ability("Harvest").moves(vec!["Splash"]))
+        .team_two(opponent.moves(vec!["Splash"]))
+        .with_weather(Weather::Sun);
+    let splash_one = builder.create_move_choice("Splash", SideReference::SideOne);
+    let splash_two = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state: BattleState = builder.build_state();
+    let exeggutor = &mut state.sides[0].pokemon[0];
+    exeggutor.item = None;
+    exeggutor.consumed_item = Some(Items::SITRUSBERRY);
+    turn::generate_instructions(&state, (&splash_one, &splash_two), false).unwrap()
+}
+
+/// Whether a branch gives Exeggutor its berry back
+fn harvests(branch: &BattleInstructions) -> bool {
+    branch.instruction_list.iter().any(|instruction| {
+        matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::ChangeItem { target, new_item: Some(Items::SITRUSBERRY), .. }) if *target == Positions::SIDE_ONE_0)
+    })
+}
+
+/// Test Harvest always restores the berry in sun, but only half the time under Cloud Nine
+#[test]
+fn test_harvest_follows_effective_weather() {
+    let sunny = harvest_branches(PokemonSpec::new("Blissey"));
+    assert!(sunny.iter().all(harvests));
+
+    let suppressed = harvest_branches(PokemonSpec::new("Golduck").ability("Cloud Nine"));
+    let chance: f32 = suppressed.iter().filter(|branch| harvests(branch)).map(|branch| branch.percentage).sum();
+    assert!((chance - 50.0).abs() < 1e-3);
+}
@@ -0,0 +1,162 @@
+//! # Mid-turn Forced Switch Tests
+//!
+//! This module contains tests for the effects that pull a Pokemon out in the
+//! middle of a turn: Eject Button, Red Card, Emergency Exit, Wimp Out and
+//! Eject Pack, and for resuming the move that was put on hold meanwhile.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattlePosition, SideReference};
+use tapu_simu::core::instructions::{
+    BattleInstruction, BattleInstructions, FieldInstruction, PokemonInstruction,
+};
+use tapu_simu::core::move_choice::{MoveChoice, PokemonIndex};
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Whether a branch forces the Pokemon at `position` to switch out
+fn forces_switch(branch: &BattleInstructions, position: BattlePosition) -> bool {
+    branch.instruction_list.iter().any(|instruction| {
+        matches!(
+            instruction,
+            BattleInstruction::Pokemon(PokemonInstruction::ForceSwitch { target, .. }) if *target == position
+        )
+    })
+}
+
+/// Test Eject Button switches its holder out after being hit
+#[test]
+fn test_eject_button_switches_holder_out() {
+    let builder = TestBuilder::new("eject button")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake"]))
+        .team_two_multi(vec![
+            PokemonSpec::new("Blissey").item("Eject Button"),
+            PokemonSpec::new("Chansey"),
+        ])
+        .turn_one_move("Earthquake");
+
+    let state = builder.final_state();
+    let blissey = state.get_pokemon_at_position(Positions::SIDE_TWO_0).unwrap();
+    assert!(blissey.must_switch);
+    assert_eq!(blissey.item, None);
+    assert!(state.awaiting_mid_turn_decision());
+}
+
+/// Test Red Card drags in each benched Pokemon with an equal share of the branch
+#[test]
+fn test_red_card_splits_evenly_over_bench() {
+    let builder = TestBuilder::new("red card")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Garchomp").moves(vec!["Earthquake"]),
+            PokemonSpec::new("Pikachu"),
+            PokemonSpec::new("Eevee"),
+        ])
+        .team_two(PokemonSpec::new("Blissey").item("Red Card"));
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideOne);
+    let branches = builder.generate(&earthquake, &MoveChoice::None);
+
+    assert_eq!(branches.len(), 2);
+    for (branch, expected_index) in branches.iter().zip([1, 2]) {
+        assert_eq!(branch.percentage, 50.0);
+        assert!(branch.instruction_list.contains(&BattleInstruction::Pokemon(
+            PokemonInstruction::Switch {
+                position: Positions::SIDE_ONE_0,
+                new_pokemon: expected_index,
+                previous_pokemon: Some(0),
+            }
+        )));
+    }
+}
+
+/// Test Emergency Exit switches out once a hit drops the Pokemon to half HP
+#[test]
+fn test_emergency_exit_at_half_hp() {
+    // Golisopod: 291 max HP, so 150 HP is just above half
+    let builder = TestBuilder::new("emergency exit")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake"]))
+        .team_two_multi(vec![
+            PokemonSpec::new("Golisopod").ability("Emergency Exit").hp(150),
+            PokemonSpec::new("Chansey"),
+        ]);
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideOne);
+    let branches = builder.generate(&earthquake, &MoveChoice::None);
+
+    assert!(branches.iter().all(|branch| forces_switch(branch, Positions::SIDE_TWO_0)));
+}
+
+/// Test Wimp Out does not activate when the Pokemon was already at half HP or less
+#[test]
+fn test_wimp_out_needs_to_cross_half_hp() {
+    // Wimpod: 191 max HP
+    let wimp_out = |hp: u16| {
+        let builder = TestBuilder::new("wimp out")
+            .unwrap()
+            .team_one(PokemonSpec::new("Blissey").moves(vec!["Seismic Toss"]))
+            .team_two_multi(vec![
+                PokemonSpec::new("Wimpod").ability("Wimp Out").hp(hp),
+                PokemonSpec::new("Chansey"),
+            ]);
+        let toss = builder.create_move_choice("Seismic Toss", SideReference::SideOne);
+        builder.generate(&toss, &MoveChoice::None)
+    };
+
+    assert!(wimp_out(120).iter().all(|branch| forces_switch(branch, Positions::SIDE_TWO_0)));
+    assert!(!wimp_out(90).iter().any(|branch| forces_switch(branch, Positions::SIDE_TWO_0)));
+}
+
+/// Test Eject Pack switches its holder out after its own stat drop and holds the foe's move
+#[test]
+fn test_eject_pack_saves_slower_move() {
+    let builder = TestBuilder::new("eject pack")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Lucario").item("Eject Pack").moves(vec!["Close Combat"]),
+            PokemonSpec::new("Pikachu"),
+        ])
+        .team_two(PokemonSpec::new("Cresselia").moves(vec!["Seismic Toss"]));
+    let close_combat = builder.create_move_choice("Close Combat", SideReference::SideOne);
+    let toss = builder.create_move_choice("Seismic Toss", SideReference::SideTwo);
+    let branches = builder.generate(&close_combat, &toss);
+
+    for branch in &branches {
+        assert!(forces_switch(branch, Positions::SIDE_ONE_0));
+        assert!(branch.instruction_list.iter().any(|instruction| matches!(
+            instruction,
+            BattleInstruction::Field(FieldInstruction::SaveMove {
+                side: SideReference::SideTwo,
+                choice: Some(_),
+                ..
+            })
+        )));
+        assert_eq!(damage_in(branch, Positions::SIDE_ONE_0), 0);
+    }
+}
+
+/// Test the held move resumes against the replacement once the switch is chosen
+#[test]
+fn test_saved_move_resumes_after_forced_switch() {
+    let builder = TestBuilder::new("saved move resumes")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Lucario").item("Eject Pack").moves(vec!["Close Combat"]),
+            PokemonSpec::new("Pikachu"),
+        ])
+        .team_two(PokemonSpec::new("Cresselia").moves(vec!["Seismic Toss"]));
+    let close_combat = builder.create_move_choice("Close Combat", SideReference::SideOne);
+    let toss = builder.create_move_choice("Seismic Toss", SideReference::SideTwo);
+    let builder = builder
+        .turn(close_combat, toss)
+        .turn(MoveChoice::Switch(PokemonIndex::P1), MoveChoice::None);
+
+    let state = builder.final_state();
+    let pikachu = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap();
+    assert_eq!(pikachu.species.as_str(), "pikachu");
+    // Seismic Toss deals damage equal to the user's level
+    assert_eq!(pikachu.max_hp - pikachu.hp, 100);
+    assert!(state.get_side_by_ref(SideReference::SideTwo).saved_move.is_none());
+    assert!(!state.awaiting_mid_turn_decision());
+}
@@ -0,0 +1,193 @@
+//! # Forme Change Tests
+//!
+//! This module contains tests for the abilities that change a Pokemon's forme
+//! mid-battle: Zen Mode and Schooling follow the Pokemon's HP, while Disguise
+//! and Ice Face absorb a hit before breaking.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattleFormat, BattlePosition};
+use tapu_simu::core::battle_state::{BattleState, Pokemon};
+use tapu_simu::core::instructions::{BattleInstruction, PokemonInstruction, Weather};
+use tapu_simu::types::PokemonName;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// The Pokemon at `position` in `state`
+fn pokemon_at(state: &BattleState, position: BattlePosition) -> &Pokemon {
+    state.get_pokemon_at_position(position).unwrap()
+}
+
+/// Test Zen Mode changes Darmanitan at half HP or less, recalculating its stats
+#[test]
+fn test_zen_mode_at_half_hp() {
+    let state = TestBuilder::new("zen mode")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Darmanitan")
+                .ability("Zen Mode")
+                .moves(vec!["Splash"])
+                .hp(150),
+        )
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_with_moves("Splash", "Splash")
+        .final_state();
+
+    let darmanitan = pokemon_at(&state, Positions::SIDE_ONE_0);
+    assert_eq!(darmanitan.species, PokemonName::DARMANITANZEN);
+    // 140 base Special Attack with 31 IVs and no EVs at level 100
+    assert_eq!(darmanitan.stats.special_attack, 316);
+    assert_eq!(darmanitan.hp, 150);
+}
+
+/// Test Zen Mode leaves Darmanitan alone above half HP
+#[test]
+fn test_zen_mode_above_half_hp() {
+    let state = TestBuilder::new("zen mode above half")
+        .unwrap()
+        .team_one(PokemonSpec::new("Darmanitan").ability("Zen Mode").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_with_moves("Splash", "Splash")
+        .final_state();
+
+    assert_eq!(pokemon_at(&state, Positions::SIDE_ONE_0).species, PokemonName::DARMANITAN);
+}
+
+/// Test Schooling forms a school above 1/4 HP and breaks it up below
+#[test]
+fn test_schooling_follows_hp() {
+    let schooled = TestBuilder::new("schooling")
+        .unwrap()
+        .team_one(PokemonSpec::new("Wishiwashi").ability("Schooling").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_with_moves("Splash", "Splash")
+        .final_state();
+    let wishiwashi = pokemon_at(&schooled, Positions::SIDE_ONE_0);
+    assert_eq!(wishiwashi.species, PokemonName::WISHIWASHISCHOOL);
+    // 140 base Attack with 31 IVs and no EVs at level 100
+    assert_eq!(wishiwashi.stats.attack, 316);
+
+    let weakened = TestBuilder::new("schooling at low hp")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Wishiwashi")
+                .ability("Schooling")
+                .moves(vec!["Splash"])
+                .hp(40),
+        )
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_with_moves("Splash", "Splash")
+        .final_state();
+    assert_eq!(pokemon_at(&weakened, Positions::SIDE_ONE_0).species, PokemonName::WISHIWASHI);
+}
+
+/// Test Disguise takes the hit and costs 1/8 of max HP from Gen 8
+#[test]
+fn test_disguise_busts_with_chip_damage() {
+    let builder = TestBuilder::new("disguise")
+        .unwrap()
+        .team_one(PokemonSpec::new("Mimikyu").ability("Disguise").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Tackle"]))
+        .turn_with_moves("Splash", "Tackle");
+    let splash = builder.create_move_choice("Splash", Positions::SIDE_ONE_0.side);
+    let tackle = builder.create_move_choice("Tackle", Positions::SIDE_TWO_0.side);
+    let max_hp = pokemon_at(&builder.build_state(), Positions::SIDE_ONE_0).max_hp;
+
+    for branch in builder.generate(&splash, &tackle) {
+        let damage: Vec<i16> = branch
+            .instruction_list
+            .iter()
+            .filter_map(|instruction| match instruction {
+                BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. })
+                    if *target == Positions::SIDE_ONE_0 =>
+                {
+                    Some(*amount)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(damage, vec![max_hp / 8]);
+        assert!(branch.instruction_list.iter().any(|instruction| matches!(
+            instruction,
+            BattleInstruction::Pokemon(PokemonInstruction::FormeChange { new_forme, .. }) if new_forme == "mimikyubusted"
+        )));
+    }
+
+    let state = builder.final_state();
+    let mimikyu = pokemon_at(&state, Positions::SIDE_ONE_0);
+    assert_eq!(mimikyu.species, PokemonName::MIMIKYUBUSTED);
+    assert_eq!(mimikyu.hp, max_hp - max_hp / 8);
+}
+
+/// Test Disguise takes the hit for free before Gen 8
+#[test]
+fn test_disguise_without_chip_damage_in_gen7() {
+    let state = TestBuilder::new_with_format("disguise gen 7", BattleFormat::gen7_ou())
+        .unwrap()
+        .team_one(PokemonSpec::new("Mimikyu").ability("Disguise").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Tackle"]))
+        .turn_with_moves("Splash", "Tackle")
+        .final_state();
+
+    let mimikyu = pokemon_at(&state, Positions::SIDE_ONE_0);
+    assert_eq!(mimikyu.species, PokemonName::MIMIKYUBUSTED);
+    assert_eq!(mimikyu.hp, mimikyu.max_hp);
+}
+
+/// Test Ice Face blocks a physical hit and stays broken without snow
+#[test]
+fn test_ice_face_blocks_physical_hit() {
+    let state = TestBuilder::new("ice face")
+        .unwrap()
+        .team_one(PokemonSpec::new("Eiscue").ability("Ice Face").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Tackle"]))
+        .turn_with_moves("Splash", "Tackle")
+        .final_state();
+
+    let eiscue = pokemon_at(&state, Positions::SIDE_ONE_0);
+    assert_eq!(eiscue.species, PokemonName::EISCUENOICE);
+    assert_eq!(eiscue.hp, eiscue.max_hp);
+}
+
+/// Test Ice Face lets special hits through
+#[test]
+fn test_ice_face_ignores_special_hit() {
+    let state = TestBuilder::new("ice face special")
+        .unwrap()
+        .team_one(PokemonSpec::new("Eiscue").ability("Ice Face").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Dragon Pulse"]))
+        .turn_with_moves("Splash", "Dragon Pulse")
+        .final_state();
+
+    let eiscue = pokemon_at(&state, Positions::SIDE_ONE_0);
+    assert_eq!(eiscue.species, PokemonName::EISCUE);
+    assert!(eiscue.hp < eiscue.max_hp);
+}
+
+/// Test snow rebuilds Eiscue's ice at the end of the turn
+#[test]
+fn test_ice_face_rebuilt_in_snow() {
+    let state = TestBuilder::new("ice face snow")
+        .unwrap()
+        .team_one(PokemonSpec::new("Eiscue").ability("Ice Face").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Tackle"]))
+        .with_weather(Weather::Snow)
+        .turn_with_moves("Splash", "Tackle")
+        .final_state();
+
+    assert_eq!(pokemon_at(&state, Positions::SIDE_ONE_0).species, PokemonName::EISCUE);
+}
+
+/// Test Cloud Nine on the field keeps snow from rebuilding the ice
+#[test]
+fn test_ice_face_not_rebuilt_under_cloud_nine() {
+    let state = TestBuilder::new("ice face cloud nine")
+        .unwrap()
+        .team_one(PokemonSpec::new("Eiscue").ability("Ice Face").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Golduck").ability("Cloud Nine").moves(vec!["Tackle"]))
+        .with_weather(Weather::Snow)
+        .turn_with_moves("Splash", "Tackle")
+        .final_state();
+
+    assert_eq!(pokemon_at(&state, Positions::SIDE_ONE_0).species, PokemonName::EISCUENOICE);
+}
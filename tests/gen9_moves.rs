@@ -0,0 +1,260 @@
+//! # Generation 9 Move Tests
+//!
+//! This module contains behaviour tests for the Generation 9 moves with
+//! lingering or party-wide effects: Salt Cure, Revival Blessing, Tidy Up
+//! and Glaive Rush.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{
+    BattleInstruction, BattleInstructions, PokemonInstruction, PokemonStatus, SideCondition,
+    Stat, VolatileStatus,
+};
+use tapu_simu::core::move_choice::{MoveChoice, MoveIndex, PokemonIndex};
+use tapu_simu::engine::turn;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+// ============================================================================
+// Salt Cure
+// ============================================================================
+
+/// Salt Cure deals 1/4 max HP per turn to Water types
+#[test]
+fn test_salt_cure_residual_against_water_type() {
+    // Vaporeon: 401 max HP at level 100
+    TestBuilder::new("salt cure vs water")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garganacl").moves(vec!["Salt Cure"]))
+        .team_two(PokemonSpec::new("Vaporeon"))
+        .with_volatile_status(Positions::SIDE_TWO_0, VolatileStatus::SaltCure)
+        .turn(MoveChoice::None, MoveChoice::None)
+        .expect_damage(Positions::SIDE_TWO_0, 100)
+        .assert_success();
+}
+
+/// Salt Cure deals 1/4 max HP per turn to Steel types
+#[test]
+fn test_salt_cure_residual_against_steel_type() {
+    // Skarmory: 271 max HP at level 100
+    TestBuilder::new("salt cure vs steel")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garganacl").moves(vec!["Salt Cure"]))
+        .team_two(PokemonSpec::new("Skarmory"))
+        .with_volatile_status(Positions::SIDE_TWO_0, VolatileStatus::SaltCure)
+        .turn(MoveChoice::None, MoveChoice::None)
+        .expect_damage(Positions::SIDE_TWO_0, 67)
+        .assert_success();
+}
+
+/// Salt Cure deals 1/8 max HP per turn to everything else
+#[test]
+fn test_salt_cure_residual_against_neutral_type() {
+    // Garchomp: 357 max HP at level 100
+    TestBuilder::new("salt cure vs neutral")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garganacl").moves(vec!["Salt Cure"]))
+        .team_two(PokemonSpec::new("Garchomp"))
+        .with_volatile_status(Positions::SIDE_TWO_0, VolatileStatus::SaltCure)
+        .turn(MoveChoice::None, MoveChoice::None)
+        .expect_damage(Positions::SIDE_TWO_0, 44)
+        .assert_success();
+}
+
+/// Magic Guard keeps Salt Cure from dealing damage unless the ability is suppressed
+#[test]
+fn test_salt_cure_respects_magic_guard() {
+    let builder = TestBuilder::new("salt cure vs magic guard")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garganacl").moves(vec!["Splash"]))
+        .team_two(PokemonSpec::new("Clefable").ability("Magic Guard").moves(vec!["Splash"]))
+        .with_volatile_status(Positions::SIDE_TWO_0, VolatileStatus::SaltCure);
+    let mut state = builder.build_state();
+    let salt_cure_damage = |state: &BattleState| {
+        turn::generate_instructions(state, (&MoveChoice::None, &MoveChoice::None), false)
+            .unwrap()
+            .iter()
+            .map(|branch| damage_in(branch, Positions::SIDE_TWO_0))
+            .max()
+            .unwrap()
+    };
+    assert_eq!(salt_cure_damage(&state), 0);
+
+    state.sides[1].pokemon[0].ability_suppressed = true;
+    assert!(salt_cure_damage(&state) > 0);
+}
+
+// ============================================================================
+// Revival Blessing
+// ============================================================================
+
+/// Revival Blessing brings a fainted party member back at half HP
+#[test]
+fn test_revival_blessing_revives_at_half_hp() {
+    // Pikachu: 211 max HP at level 100, revived with 105
+    let expected_revive = vec![BattleInstructions::new(
+        100.0,
+        vec![BattleInstruction::Pokemon(PokemonInstruction::Revive {
+            side: SideReference::SideOne,
+            pokemon_index: 1,
+            new_hp: 105,
+            previous_hp: 0,
+            previous_status: PokemonStatus::None,
+        })],
+    )];
+
+    TestBuilder::new("revival blessing")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Pawmot").moves(vec!["Revival Blessing"]),
+            PokemonSpec::new("Pikachu").hp(0),
+        ])
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_side_condition(SideReference::SideOne, SideCondition::RevivalBlessing)
+        .turn(MoveChoice::Revive(PokemonIndex::P1), MoveChoice::None)
+        .expect_instructions(expected_revive)
+        .expect_no_side_condition(SideReference::SideOne, SideCondition::RevivalBlessing)
+        .assert_success();
+}
+
+/// A revived Pokemon comes back without the status it fainted with
+#[test]
+fn test_revival_blessing_clears_status() {
+    let builder = TestBuilder::new("revival blessing status")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Pawmot").moves(vec!["Revival Blessing"]),
+            PokemonSpec::new("Pikachu").hp(0),
+        ])
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_side_condition(SideReference::SideOne, SideCondition::RevivalBlessing);
+    let mut state = builder.build_state();
+    state.sides[0].pokemon[1].status = PokemonStatus::Burn;
+
+    let branches = turn::generate_instructions(&state, (&MoveChoice::Revive(PokemonIndex::P1), &MoveChoice::None), false).unwrap();
+    assert!(branches[0].instruction_list.iter().any(|instruction| matches!(
+        instruction,
+        BattleInstruction::Pokemon(PokemonInstruction::Revive { previous_status: PokemonStatus::Burn, .. })
+    )));
+    state.apply_instructions(&branches[0].instruction_list);
+    let pikachu = &state.sides[0].pokemon[1];
+    assert!(pikachu.hp > 0);
+    assert_eq!(pikachu.status, PokemonStatus::None);
+}
+
+/// Using Revival Blessing with a fainted party member sets up the revive choice
+#[test]
+fn test_revival_blessing_offers_revive_choice() {
+    TestBuilder::new("revival blessing choice")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Pawmot").moves(vec!["Revival Blessing"]),
+            PokemonSpec::new("Pikachu").hp(0),
+        ])
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_one_move("Revival Blessing")
+        .expect_side_condition(SideReference::SideOne, SideCondition::RevivalBlessing)
+        .assert_success();
+}
+
+/// Revival Blessing fails when nobody in the party has fainted
+#[test]
+fn test_revival_blessing_fails_without_fainted_member() {
+    TestBuilder::new("revival blessing without fainted member")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Pawmot").moves(vec!["Revival Blessing"]),
+            PokemonSpec::new("Pikachu"),
+        ])
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_one_move("Revival Blessing")
+        .expect_no_side_condition(SideReference::SideOne, SideCondition::RevivalBlessing)
+        .assert_success();
+}
+
+// ============================================================================
+// Tidy Up
+// ============================================================================
+
+/// Tidy Up clears hazards on both sides and every Substitute
+#[test]
+fn test_tidy_up_removes_hazards_and_substitutes() {
+    TestBuilder::new("tidy up")
+        .unwrap()
+        .team_one(PokemonSpec::new("Maushold").moves(vec!["Tidy Up"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Splash"]))
+        .with_side_condition(SideReference::SideOne, SideCondition::StealthRock)
+        .with_side_condition(SideReference::SideTwo, SideCondition::Spikes)
+        .with_substitute(Positions::SIDE_TWO_0, 89)
+        .turn_one_move("Tidy Up")
+        .expect_no_side_condition(SideReference::SideOne, SideCondition::StealthRock)
+        .expect_no_side_condition(SideReference::SideTwo, SideCondition::Spikes)
+        .expect_no_volatile_status(Positions::SIDE_TWO_0, VolatileStatus::Substitute)
+        .expect_substitute_health(Positions::SIDE_TWO_0, 0)
+        .expect_stat_change(Positions::SIDE_ONE_0, Stat::Attack, 1)
+        .expect_stat_change(Positions::SIDE_ONE_0, Stat::Speed, 1)
+        .assert_success();
+}
+
+// ============================================================================
+// Glaive Rush
+// ============================================================================
+
+/// A Pokemon exposed by Glaive Rush takes double damage from moves that cannot miss it
+#[test]
+fn test_glaive_rush_exposes_user() {
+    let builder = TestBuilder::new("glaive rush exposure")
+        .unwrap()
+        .team_one(PokemonSpec::new("Baxcalibur").moves(vec!["Glaive Rush", "Splash"]))
+        .team_two(PokemonSpec::new("Conkeldurr").moves(vec!["Focus Blast"]));
+    let attack = MoveChoice::Move {
+        move_index: MoveIndex::M0,
+        target_positions: vec![Positions::SIDE_ONE_0],
+    };
+
+    let normal = builder.generate(&MoveChoice::None, &attack);
+    assert_eq!(normal.len(), 2, "Focus Blast should branch on its 70% accuracy");
+
+    let builder = builder.with_volatile_status(Positions::SIDE_ONE_0, VolatileStatus::GlaiveRush);
+    let exposed = builder.generate(&MoveChoice::None, &attack);
+    assert_eq!(exposed.len(), 1, "Focus Blast cannot miss an exposed target");
+    assert_eq!(exposed[0].percentage, 100.0);
+
+    let hit = normal
+        .iter()
+        .map(|branch| damage_in(branch, Positions::SIDE_ONE_0))
+        .max()
+        .unwrap();
+    let exposed_hit = damage_in(&exposed[0], Positions::SIDE_ONE_0);
+    assert!(
+        (exposed_hit - 2 * hit).abs() <= 1,
+        "expected about double damage, got {} vs {}",
+        exposed_hit,
+        hit
+    );
+}
+
+/// Glaive Rush's drawback persists through the opponent's moves and ends when the user moves again
+#[test]
+fn test_glaive_rush_lasts_until_user_moves_again() {
+    TestBuilder::new("glaive rush persists")
+        .unwrap()
+        .team_one(PokemonSpec::new("Baxcalibur").moves(vec!["Glaive Rush", "Splash"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_one_move("Glaive Rush")
+        .turn_two_move("Splash")
+        .expect_volatile_status(Positions::SIDE_ONE_0, VolatileStatus::GlaiveRush)
+        .assert_success();
+
+    TestBuilder::new("glaive rush cleared")
+        .unwrap()
+        .team_one(PokemonSpec::new("Baxcalibur").moves(vec!["Glaive Rush", "Splash"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_one_move("Glaive Rush")
+        .turn_one_move("Splash")
+        .expect_no_volatile_status(Positions::SIDE_ONE_0, VolatileStatus::GlaiveRush)
+        .assert_success();
+}
@@ -0,0 +1,73 @@
+//! # Held Item Tests
+//!
+//! This module contains tests for held items that shield their holder from
+//! other Pokemon's effects: Clear Amulet against stat drops and Covert Cloak
+//! against secondary effects.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::instructions::{
+    BattleInstruction, BattleInstructions, StatsInstruction, StatusInstruction, VolatileStatus,
+};
+use tapu_simu::core::move_choice::{MoveChoice, PokemonIndex};
+use tapu_simu::engine::mechanics::switch_effects::process_switch_in_effects;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Whether any branch changes side one's active Pokemon's stat boosts
+fn boosts_side_one(branches: &[BattleInstructions]) -> bool {
+    branches.iter().flat_map(|branch| &branch.instruction_list).any(|instruction| {
+        matches!(instruction, BattleInstruction::Stats(StatsInstruction::BoostStats { target, .. }) if *target == Positions::SIDE_ONE_0)
+    })
+}
+
+/// Intimidate's effects as Gyarados switches in against Garchomp holding `item`
+fn intimidate_branches(item: &'static str) -> Vec<BattleInstructions> {
+    let builder = TestBuilder::new("intimidate")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").item(item).moves(vec!["Splash"]))
+        .team_two_multi(vec![
+            PokemonSpec::new("Blissey").moves(vec!["Splash"]),
+            PokemonSpec::new("Gyarados").ability("Intimidate"),
+        ]);
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let mut state = builder.build_state();
+    let switched = builder.generate(&splash, &MoveChoice::Switch(PokemonIndex::P1));
+    state.apply_instructions(&switched[0].instruction_list);
+    process_switch_in_effects(&state, Positions::SIDE_TWO_0, &state.get_generation_mechanics())
+}
+
+/// Test Clear Amulet keeps Intimidate from lowering the holder's Attack
+#[test]
+fn test_clear_amulet_blocks_intimidate() {
+    assert!(boosts_side_one(&intimidate_branches("Leftovers")));
+    assert!(!boosts_side_one(&intimidate_branches("Clear Amulet")));
+}
+
+/// Whether Blissey, holding `item`, can flinch from Air Slash
+fn flinches_from_air_slash(item: &'static str) -> bool {
+    let builder = TestBuilder::new("covert cloak")
+        .unwrap()
+        .team_one(PokemonSpec::new("Pikachu").moves(vec!["Air Slash"]))
+        .team_two(PokemonSpec::new("Blissey").item(item).moves(vec!["Splash"]));
+    let air_slash = builder.create_move_choice("Air Slash", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let branches = builder.generate(&air_slash, &splash);
+    assert!(branches.iter().any(|branch| damage_in(branch, Positions::SIDE_TWO_0) > 0));
+    branches.iter().flat_map(|branch| &branch.instruction_list).any(|instruction| {
+        matches!(
+            instruction,
+            BattleInstruction::Status(StatusInstruction::ApplyVolatile { target, status: VolatileStatus::Flinch, .. })
+                if *target == Positions::SIDE_TWO_0
+        )
+    })
+}
+
+/// Test Covert Cloak blocks a secondary effect but not the hit itself
+#[test]
+fn test_covert_cloak_blocks_secondary_effect() {
+    assert!(flinches_from_air_slash("Leftovers"));
+    assert!(!flinches_from_air_slash("Covert Cloak"));
+}
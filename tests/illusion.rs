@@ -0,0 +1,122 @@
+//! # Illusion Tests
+//!
+//! This module contains tests for Zoroark's Illusion: the disguise taken on
+//! switching in, how the opponent sees it, and the damaging hits that break it.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{BattleInstruction, PokemonInstruction};
+use tapu_simu::core::move_choice::{MoveChoice, PokemonIndex};
+use tapu_simu::engine::turn;
+use tapu_simu::types::PokemonName;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// A builder with Zoroark disguised as Blissey, the last member of its party
+fn disguised_zoroark(opponent_move: &'static str) -> TestBuilder {
+    TestBuilder::new("illusion")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Zoroark").ability("Illusion").moves(vec!["Splash"]),
+            PokemonSpec::new("Garchomp"),
+            PokemonSpec::new("Blissey"),
+        ])
+        .team_two(PokemonSpec::new("Garchomp").moves(vec![opponent_move]))
+}
+
+/// Put Zoroark's disguise in place, as its switch-in would
+fn apply_disguise(state: &mut BattleState) {
+    state.apply_instructions(&[BattleInstruction::Pokemon(PokemonInstruction::ChangeIllusion {
+        target: Positions::SIDE_ONE_0,
+        new_illusion: Some(2),
+        previous_illusion: None,
+    })]);
+}
+
+/// Test Zoroark switches in disguised as the last healthy member of its party
+#[test]
+fn test_illusion_copies_last_party_member_on_switch_in() {
+    let builder = TestBuilder::new("illusion switch in")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Garchomp"),
+            PokemonSpec::new("Zoroark").ability("Illusion"),
+            PokemonSpec::new("Blissey"),
+        ])
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Splash"]));
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    for branch in builder.generate(&MoveChoice::Switch(PokemonIndex::P1), &splash) {
+        state.apply_instructions(&branch.instruction_list);
+    }
+
+    let zoroark = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap();
+    assert_eq!(zoroark.species, PokemonName::ZOROARK);
+    assert_eq!(zoroark.illusion, Some(2));
+
+    // The opponent sees Blissey, while Zoroark's own side still sees Zoroark
+    let opponent_view = state.view_for_side(SideReference::SideTwo);
+    let apparent = opponent_view.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap();
+    assert_eq!(apparent.species, PokemonName::BLISSEY);
+    assert_eq!(apparent.hp, apparent.max_hp);
+    let own_view = state.view_for_side(SideReference::SideOne);
+    assert_eq!(own_view.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().species, PokemonName::ZOROARK);
+}
+
+/// Test a damaging hit breaks the disguise
+#[test]
+fn test_damaging_hit_breaks_illusion() {
+    let builder = disguised_zoroark("Tackle");
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let tackle = builder.create_move_choice("Tackle", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    apply_disguise(&mut state);
+
+    let branches = turn::generate_instructions(&state, (&splash, &tackle), false).unwrap();
+    for branch in &branches {
+        assert!(branch.instruction_list.iter().any(|instruction| matches!(
+            instruction,
+            BattleInstruction::Pokemon(PokemonInstruction::ChangeIllusion { target, new_illusion: None, previous_illusion: Some(2) })
+                if *target == Positions::SIDE_ONE_0
+        )));
+    }
+    state.apply_instructions(&branches[0].instruction_list);
+
+    assert_eq!(state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().illusion, None);
+    let opponent_view = state.view_for_side(SideReference::SideTwo);
+    assert_eq!(opponent_view.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().species, PokemonName::ZOROARK);
+}
+
+/// Test a status move leaves the disguise in place
+#[test]
+fn test_status_move_keeps_illusion() {
+    let builder = disguised_zoroark("Growl");
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let growl = builder.create_move_choice("Growl", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    apply_disguise(&mut state);
+
+    for branch in turn::generate_instructions(&state, (&splash, &growl), false).unwrap() {
+        state.apply_instructions(&branch.instruction_list);
+    }
+
+    assert_eq!(state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().illusion, Some(2));
+}
+
+/// Test a hit taken by a substitute leaves the disguise in place
+#[test]
+fn test_hit_on_substitute_keeps_illusion() {
+    let builder = disguised_zoroark("Tackle").with_substitute(Positions::SIDE_ONE_0, 100);
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let tackle = builder.create_move_choice("Tackle", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    apply_disguise(&mut state);
+
+    for branch in turn::generate_instructions(&state, (&splash, &tackle), false).unwrap() {
+        state.apply_instructions(&branch.instruction_list);
+    }
+
+    assert_eq!(state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().illusion, Some(2));
+}
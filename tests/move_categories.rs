@@ -16,11 +16,15 @@ mod utils;
 
 use std::collections::HashMap;
 
+use tapu_simu::core::battle_format::SideReference;
 use tapu_simu::core::instructions::{
-    BattleInstruction, BattleInstructions, PokemonInstruction, Stat, StatsInstruction,
-    StatusInstruction, VolatileStatus,
+    BattleInstruction, BattleInstructions, FieldInstruction, PokemonInstruction, Stat,
+    StatsInstruction, StatusInstruction, Terrain, VolatileStatus, Weather,
 };
+use tapu_simu::core::move_choice::MoveChoice;
+use tapu_simu::types::Moves;
 
+use utils::convenience::{damage_in, last_used_move};
 use utils::{PokemonSpec, Positions, TestBuilder};
 
 // ============================================================================
@@ -36,6 +40,7 @@ fn test_basic_multi_hit_move() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::DRAGONDARTS),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
                 amount: 27, // Single hit damage
@@ -67,12 +72,13 @@ fn test_skilllink_always_has_5_hits() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 15.0, // Miss chance
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::TAILSLAP)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 85.0, // Hit chance
             instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::TAILSLAP),
                 BattleInstruction::Pokemon(PokemonInstruction::Damage {
                     target: Positions::SIDE_TWO_0,
                     amount: 12, // Single hit damage from Tail Slap
@@ -128,11 +134,13 @@ fn test_boltbeak() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::BOLTBEAK),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
                 amount: 81, // Double power Bolt Beak damage
                 previous_hp: None,
             }),
+            last_used_move(Positions::SIDE_TWO_0, Moves::TACKLE),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_ONE_0,
                 amount: 34,
@@ -162,11 +170,14 @@ fn test_grassknot_basepower_changing_based_on_weight() {
     // Test against a lightweight Pokemon (should have lower power)
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 30, // Lower power against lightweight Pokemon
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::GRASSKNOT),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 30, // Lower power against lightweight Pokemon
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -185,11 +196,14 @@ fn test_grassknot_basepower_changing_to_max_damage() {
     // Test against a very heavy Pokemon (should have maximum power)
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 91, // Maximum power against heavy Pokemon
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::GRASSKNOT),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 91, // Maximum power against heavy Pokemon
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -208,11 +222,14 @@ fn test_hardpress() {
     // Hard Press deals more damage when target has high HP
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 125, // High damage when target at full HP
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::HARDPRESS),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 125, // High damage when target at full HP
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -231,11 +248,14 @@ fn test_heatcrash_highest_base_power() {
     // Heat Crash power based on weight difference - attacker much heavier
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 51, // High power due to large weight difference
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::HEATCRASH),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 51, // High power due to large weight difference
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -253,11 +273,14 @@ fn test_heatcrash_highest_base_power() {
 fn test_heavyslam_highest_base_power() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 51, // Maximum power Heavy Slam
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::HEAVYSLAM),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 51, // Maximum power Heavy Slam
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -275,11 +298,14 @@ fn test_heavyslam_highest_base_power() {
 fn test_heavyslam_lowest_base_power() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 45, // Minimum power Heavy Slam
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::HEAVYSLAM),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 45, // Minimum power Heavy Slam
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -297,11 +323,14 @@ fn test_heavyslam_lowest_base_power() {
 fn test_lowkick_basepower_highest_damage() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 332, // Maximum power Low Kick
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::LOWKICK),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 332, // Maximum power Low Kick
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -319,11 +348,14 @@ fn test_lowkick_basepower_highest_damage() {
 fn test_lowkick_basepower_lowest_damage() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 40, // Minimum power Low Kick
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::LOWKICK),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 40, // Minimum power Low Kick
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -346,11 +378,14 @@ fn test_bodypress() {
     // Body Press uses Defense instead of Attack for damage calculation
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 52, // Damage calculated using Defense stat
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::BODYPRESS),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 52, // Damage calculated using Defense stat
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -368,11 +403,14 @@ fn test_bodypress() {
 fn test_endeavor() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 179, // Damage to bring target HP down to user's HP (40)
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::ENDEAVOR),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 179, // Damage to bring target HP down to user's HP (40)
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -390,7 +428,7 @@ fn test_endeavor() {
 fn test_endeavor_versus_ghost() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![], // No effect against Ghost type
+        instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::ENDEAVOR)], // No effect against Ghost type
         affected_positions: vec![],
     }];
 
@@ -408,7 +446,7 @@ fn test_endeavor_versus_ghost() {
 fn test_endeavor_when_higher_hp_than_opponent() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![], // No effect when user HP > target HP
+        instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::ENDEAVOR)], // No effect when user HP > target HP
         affected_positions: vec![],
     }];
 
@@ -427,6 +465,7 @@ fn test_finalgambit() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::FINALGAMBIT),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
                 amount: 126, // Damage equal to user's current HP
@@ -460,6 +499,7 @@ fn test_finalgambit_versus_ghost() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::FINALGAMBIT),
             // User still faints even though move failed
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_ONE_0,
@@ -488,11 +528,14 @@ fn test_finalgambit_versus_ghost() {
 fn test_foulplay() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 84, // Damage calculated using target's Attack stat
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::FOULPLAY),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 84, // Damage calculated using target's Attack stat
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -510,11 +553,22 @@ fn test_foulplay() {
 fn test_hydrosteam() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 76, // Increased power in sun instead of reduced
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::HYDROSTEAM),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 76, // Increased power in sun instead of reduced
+                previous_hp: None,
+            }),
+            // The sun counts down at the end of the turn
+            BattleInstruction::Field(FieldInstruction::Weather {
+                new_weather: Weather::Sun,
+                turns: Some(4),
+                source: None,
+                previous_weather: Weather::Sun,
+                previous_turns: Some(5),
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -534,6 +588,7 @@ fn test_mindblown() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::MINDBLOWN),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
                 amount: 125, // High power explosion damage
@@ -563,6 +618,7 @@ fn test_mindblown_does_not_overkill() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::MINDBLOWN),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
                 amount: 125, // Normal target damage
@@ -595,7 +651,7 @@ fn test_mindblown_does_not_overkill() {
 fn test_mindblown_into_damp() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![], // No effect due to Damp
+        instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::MINDBLOWN)], // No effect due to Damp
         affected_positions: vec![],
     }];
 
@@ -613,11 +669,14 @@ fn test_mindblown_into_damp() {
 fn test_mindseye_versus_ghost_type() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 46, // Normal damage despite Ghost type immunity
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::TACKLE),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 46, // Normal damage despite Ghost type immunity
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -639,11 +698,14 @@ fn test_mindseye_versus_ghost_type() {
 fn test_moongeistbeam_into_ice_scales() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 168, // Full damage, ignoring Ice Scales
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::MOONGEISTBEAM),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 168, // Full damage, ignoring Ice Scales
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -666,6 +728,7 @@ fn test_painsplit() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::PAINSPLIT),
             BattleInstruction::Pokemon(PokemonInstruction::Heal {
                 target: Positions::SIDE_ONE_0,
                 amount: 13, // User gains HP to reach average
@@ -698,11 +761,14 @@ fn test_painsplit() {
 fn test_seismictoss() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 100, // Damage equal to user's level
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::SEISMICTOSS),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 100, // Damage equal to user's level
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -724,11 +790,14 @@ fn test_seismictoss() {
 fn test_seismictoss_does_not_overkill() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 50, // Capped at remaining HP
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::SEISMICTOSS),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 50, // Capped at remaining HP
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -750,7 +819,7 @@ fn test_seismictoss_does_not_overkill() {
 fn test_seismictoss_versus_ghost_type() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![], // No effect against Ghost type
+        instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::SEISMICTOSS)], // No effect against Ghost type
         affected_positions: vec![],
     }];
 
@@ -770,16 +839,19 @@ fn test_superfang() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 10.0, // Miss chance
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::SUPERFANG)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 90.0, // Hit chance  
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: Positions::SIDE_TWO_0,
-                amount: 109, // 50% of target's current HP
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SUPERFANG),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: Positions::SIDE_TWO_0,
+                    amount: 109, // 50% of target's current HP
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![],
         }
     ];
@@ -800,16 +872,19 @@ fn test_superfang_at_1hp() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 10.0, // Miss chance
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::SUPERFANG)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 90.0, // Hit chance
-            instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-                target: Positions::SIDE_TWO_0,
-                amount: 1,
-                previous_hp: None,
-            })],
+            instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SUPERFANG),
+                BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                    target: Positions::SIDE_TWO_0,
+                    amount: 1,
+                    previous_hp: None,
+                }),
+            ],
             affected_positions: vec![],
         }
     ];
@@ -830,12 +905,12 @@ fn test_superfang_versus_ghost_type() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 10.0, // Miss chance
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::SUPERFANG)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 90.0, // Hit chance, but no effect due to immunity
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::SUPERFANG)],
             affected_positions: vec![],
         }
     ];
@@ -861,12 +936,13 @@ fn test_contact_multi_hit_move_versus_rockyhelmet() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 15.0, // Miss chance
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::TAILSLAP)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 85.0, // Hit chance
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::TAILSLAP),
             // First hit
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
@@ -951,6 +1027,7 @@ fn test_multi_hit_move_where_first_hit_breaks_substitute() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::DRAGONDARTS),
             // First hit: Substitute takes damage and breaks
             BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth {
                 target: Positions::SIDE_TWO_0,
@@ -993,6 +1070,7 @@ fn test_triple_multihit_move_versus_substitute_and_rockyhelmet() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::SURGINGSTRIKES),
             // First hit: Substitute takes damage
             BattleInstruction::Pokemon(PokemonInstruction::ChangeSubstituteHealth {
                 target: Positions::SIDE_TWO_0,
@@ -1046,7 +1124,7 @@ fn test_triple_multihit_move_versus_substitute_and_rockyhelmet() {
 fn test_explosion_into_damp() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![], // No effect due to Damp
+        instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::EXPLOSION)], // No effect due to Damp
         affected_positions: vec![],
     }];
 
@@ -1065,6 +1143,7 @@ fn test_explosion_into_ghost_type() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::EXPLOSION),
             // User still faints even though explosion didn't hit
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_ONE_0,
@@ -1090,6 +1169,7 @@ fn test_fast_explosion_makes_other_side_unable_to_move() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::EXPLOSION),
             // Explosion damage
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
@@ -1126,12 +1206,13 @@ fn test_scaleshot_only_boosts_once() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 10.0,
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::SCALESHOT)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 90.0,
             instruction_list: vec![
+                last_used_move(Positions::SIDE_ONE_0, Moves::SCALESHOT),
                 // Multiple hits
                 BattleInstruction::Pokemon(PokemonInstruction::Damage {
                     target: Positions::SIDE_TWO_0,
@@ -1180,12 +1261,13 @@ fn test_population_bomb_with_widelens() {
     let expected_instructions = vec![
         BattleInstructions {
             percentage: 1.0, // Miss chance  
-            instruction_list: vec![],
+            instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::POPULATIONBOMB)],
             affected_positions: vec![],
         },
         BattleInstructions {
             percentage: 99.0, // Hit chance
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::POPULATIONBOMB),
             // All 10 hits land due to improved accuracy
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
@@ -1265,7 +1347,7 @@ fn test_population_bomb_with_widelens() {
 fn test_basic_levitate() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![], // Ground move has no effect
+        instruction_list: vec![last_used_move(Positions::SIDE_ONE_0, Moves::EARTHQUAKE)], // Ground move has no effect
         affected_positions: vec![],
     }];
 
@@ -1287,11 +1369,14 @@ fn test_basic_levitate() {
 fn test_acrobatics_no_item() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 47, // Double power when no item
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::ACROBATICS),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 47, // Double power when no item
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1309,11 +1394,14 @@ fn test_acrobatics_no_item() {
 fn test_acrobatics_with_item() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 24, // Normal power when holding item
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::ACROBATICS),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 24, // Normal power when holding item
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1335,11 +1423,22 @@ fn test_acrobatics_with_item() {
 fn test_weatherball_in_rain() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 130, // Double power in weather, becomes Water-type
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::WEATHERBALL),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 130, // Double power in weather, becomes Water-type
+                previous_hp: None,
+            }),
+            // The rain counts down at the end of the turn
+            BattleInstruction::Field(FieldInstruction::Weather {
+                new_weather: Weather::Rain,
+                turns: Some(4),
+                source: None,
+                previous_weather: Weather::Rain,
+                previous_turns: Some(5),
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1358,11 +1457,22 @@ fn test_weatherball_in_rain() {
 fn test_terrainpulse_in_electric_terrain() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 129, // Double power in terrain, becomes Electric-type
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::TERRAINPULSE),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 129, // Double power in terrain, becomes Electric-type
+                previous_hp: None,
+            }),
+            // The terrain counts down at the end of the turn
+            BattleInstruction::Field(FieldInstruction::Terrain {
+                new_terrain: Terrain::Electric,
+                turns: Some(4),
+                source: None,
+                previous_terrain: Terrain::Electric,
+                previous_turns: Some(5),
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1381,11 +1491,20 @@ fn test_terrainpulse_in_electric_terrain() {
 fn test_facade_with_burn() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 85, // Double power when burned
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::FACADE),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 85, // Double power when burned
+                previous_hp: None,
+            }),
+            // Burn damage at the end of the turn
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_ONE_0,
+                amount: 16,
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1408,11 +1527,14 @@ fn test_facade_with_burn() {
 fn test_hex_with_status() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 172, // Double power against statused Pokemon
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::HEX),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 172, // Double power against statused Pokemon
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1434,6 +1556,7 @@ fn test_wakeupslap_on_sleeping() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
         instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::WAKEUPSLAP),
             BattleInstruction::Pokemon(PokemonInstruction::Damage {
                 target: Positions::SIDE_TWO_0,
                 amount: 153, // Double power against sleeping Pokemon
@@ -1461,11 +1584,14 @@ fn test_wakeupslap_on_sleeping() {
 fn test_punishment_against_boosted() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 76, // Increased power based on stat boosts
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::PUNISHMENT),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 76, // Increased power based on stat boosts
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1489,11 +1615,14 @@ fn test_punishment_against_boosted() {
 fn test_stored_power_with_boosts() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 74, // Increased power based on user's stat boosts
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::STOREDPOWER),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 74, // Increased power based on user's stat boosts
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1517,11 +1646,14 @@ fn test_stored_power_with_boosts() {
 fn test_gyro_ball_high_power() {
     let expected_instructions = vec![BattleInstructions {
         percentage: 100.0,
-        instruction_list: vec![BattleInstruction::Pokemon(PokemonInstruction::Damage {
-            target: Positions::SIDE_TWO_0,
-            amount: 153, // High power against much faster Pokemon
-            previous_hp: None,
-        })],
+        instruction_list: vec![
+            last_used_move(Positions::SIDE_ONE_0, Moves::GYROBALL),
+            BattleInstruction::Pokemon(PokemonInstruction::Damage {
+                target: Positions::SIDE_TWO_0,
+                amount: 153, // High power against much faster Pokemon
+                previous_hp: None,
+            }),
+        ],
         affected_positions: vec![Positions::SIDE_TWO_0],
     }];
 
@@ -1533,3 +1665,31 @@ fn test_gyro_ball_high_power() {
         .expect_instructions(expected_instructions)
         .assert_success();
 }
+
+/// Test Solar Beam keeps its full power in rain that Cloud Nine suppresses
+#[test]
+fn test_solarbeam_in_rain_negated_by_cloud_nine() {
+    let solar_beam_damage = |opponent: PokemonSpec| {
+        let builder = TestBuilder::new("solar beam power")
+            .unwrap()
+            .team_one(
+                PokemonSpec::new("Venusaur")
+                    .item("Power Herb")
+                    .moves(vec!["Solar Beam"]),
+            )
+            .team_two(opponent)
+            .with_weather(Weather::Rain);
+        let solar_beam = builder.create_move_choice("Solar Beam", SideReference::SideOne);
+        let branches = builder.generate(&solar_beam, &MoveChoice::None);
+        damage_in(&branches[0], Positions::SIDE_TWO_0)
+    };
+
+    let weakened = solar_beam_damage(PokemonSpec::new("Golduck").ability("Damp"));
+    let full_power = solar_beam_damage(PokemonSpec::new("Golduck").ability("Cloud Nine"));
+    assert!(
+        full_power > weakened * 3 / 2,
+        "expected full power under Cloud Nine, got {} vs {}",
+        full_power,
+        weakened
+    );
+}
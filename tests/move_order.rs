@@ -0,0 +1,225 @@
+//! # Move Order Tests
+//!
+//! This module contains tests for the order actions resolve in within a turn:
+//! speed ties split into even branches, Trick Room reverses speed order
+//! until it is used again, abilities raise a move's priority bracket, and
+//! Quick Claw and Quick Draw move a Pokemon to the front of its bracket.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattlePosition, SideReference};
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{
+    BattleInstruction, BattleInstructions, PokemonInstruction, StatsInstruction, Terrain,
+};
+use tapu_simu::engine::turn;
+use tapu_simu::generation::Generation;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Whether `first` boosts its stats before `second` does in a branch
+fn boosts_first(branch: &BattleInstructions, first: BattlePosition, second: BattlePosition) -> bool {
+    let boost_index = |position| {
+        branch.instruction_list.iter().position(|instruction| {
+            matches!(instruction, BattleInstruction::Stats(StatsInstruction::BoostStats { target, .. }) if *target == position)
+        })
+    };
+    boost_index(first).unwrap() < boost_index(second).unwrap()
+}
+
+/// Play a turn on `state`, applying every branch, and return the branches
+fn play_turn(builder: &TestBuilder, state: &mut BattleState, move_one: &str, move_two: &str) -> Vec<BattleInstructions> {
+    let choice_one = builder.create_move_choice(move_one, SideReference::SideOne);
+    let choice_two = builder.create_move_choice(move_two, SideReference::SideTwo);
+    let branches = turn::generate_instructions(state, (&choice_one, &choice_two), false).unwrap();
+    for branch in &branches {
+        state.apply_instructions(&branch.instruction_list);
+    }
+    branches
+}
+
+/// Test a speed tie splits the turn into two even branches, one for each order
+#[test]
+fn test_speed_tie_branches_both_orders() {
+    let builder = TestBuilder::new("speed tie")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Swords Dance"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Agility"]));
+    let swords_dance = builder.create_move_choice("Swords Dance", SideReference::SideOne);
+    let agility = builder.create_move_choice("Agility", SideReference::SideTwo);
+
+    let branches = builder.generate(&swords_dance, &agility);
+    assert_eq!(branches.len(), 2);
+    for branch in &branches {
+        assert!((branch.percentage - 50.0).abs() < 1e-3);
+    }
+    let side_one_first = branches
+        .iter()
+        .filter(|branch| boosts_first(branch, Positions::SIDE_ONE_0, Positions::SIDE_TWO_0))
+        .count();
+    assert_eq!(side_one_first, 1);
+}
+
+/// Test Trick Room lets the slower Pokemon move first, and ends when used again
+#[test]
+fn test_trick_room_reverses_order_until_used_again() {
+    let builder = TestBuilder::new("trick room")
+        .unwrap()
+        .team_one(PokemonSpec::new("Snorlax").moves(vec!["Trick Room", "Swords Dance"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Agility", "Splash"]));
+    let mut state = builder.build_state();
+
+    let before = builder.generate(
+        &builder.create_move_choice("Swords Dance", SideReference::SideOne),
+        &builder.create_move_choice("Agility", SideReference::SideTwo),
+    );
+    assert!(before.iter().all(|branch| boosts_first(branch, Positions::SIDE_TWO_0, Positions::SIDE_ONE_0)));
+
+    play_turn(&builder, &mut state, "Trick Room", "Splash");
+    assert!(state.is_trick_room_active());
+    let reversed = play_turn(&builder, &mut state, "Swords Dance", "Agility");
+    assert!(reversed.iter().all(|branch| boosts_first(branch, Positions::SIDE_ONE_0, Positions::SIDE_TWO_0)));
+
+    play_turn(&builder, &mut state, "Trick Room", "Splash");
+    assert!(!state.is_trick_room_active());
+}
+
+/// Play `move_one` from `attacker` against the faster Regieleki's Agility
+fn against_agility(attacker: PokemonSpec, move_one: &'static str) -> Vec<BattleInstructions> {
+    let builder = TestBuilder::new("priority")
+        .unwrap()
+        .team_one(attacker.moves(vec![move_one]))
+        .team_two(PokemonSpec::new("Regieleki").moves(vec!["Agility"]));
+    builder.generate(
+        &builder.create_move_choice(move_one, SideReference::SideOne),
+        &builder.create_move_choice("Agility", SideReference::SideTwo),
+    )
+}
+
+/// Whether side one's action lands before Regieleki's Agility in every branch
+fn side_one_moves_first(branches: &[BattleInstructions]) -> bool {
+    branches.iter().all(|branch| {
+        let agility = branch.instruction_list.iter().position(|instruction| {
+            matches!(instruction, BattleInstruction::Stats(StatsInstruction::BoostStats { target, .. }) if *target == Positions::SIDE_TWO_0)
+        });
+        let side_one = branch.instruction_list.iter().position(|instruction| {
+            matches!(
+                instruction,
+                BattleInstruction::Stats(StatsInstruction::BoostStats { target, .. }) if *target == Positions::SIDE_ONE_0
+            ) || matches!(
+                instruction,
+                BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. }) if *target == Positions::SIDE_TWO_0
+            )
+        });
+        side_one.unwrap() < agility.unwrap()
+    })
+}
+
+/// Test Prankster moves a slower Pokemon's status moves ahead, but not its attacks
+#[test]
+fn test_prankster_raises_status_move_priority() {
+    assert!(side_one_moves_first(&against_agility(PokemonSpec::new("Sableye").ability("Prankster"), "Swords Dance")));
+    assert!(!side_one_moves_first(&against_agility(PokemonSpec::new("Sableye").ability("Keen Eye"), "Swords Dance")));
+    assert!(!side_one_moves_first(&against_agility(PokemonSpec::new("Sableye").ability("Prankster"), "Shadow Claw")));
+}
+
+/// Whether Sableye's Prankster Growl lowers `target`'s Attack in `generation`
+fn prankster_growl_lands(target: &'static str, generation: Generation) -> bool {
+    let builder = TestBuilder::new_with_generation("prankster against dark", generation)
+        .unwrap()
+        .team_one(PokemonSpec::new("Sableye").ability("Prankster").moves(vec!["Growl"]))
+        .team_two(PokemonSpec::new(target).moves(vec!["Splash"]));
+    let branches = builder.generate(
+        &builder.create_move_choice("Growl", SideReference::SideOne),
+        &builder.create_move_choice("Splash", SideReference::SideTwo),
+    );
+    branches.iter().flat_map(|branch| &branch.instruction_list).any(|instruction| {
+        matches!(instruction, BattleInstruction::Stats(StatsInstruction::BoostStats { target, .. }) if *target == Positions::SIDE_TWO_0)
+    })
+}
+
+/// Test Prankster-boosted moves fail against Dark types from Gen 7
+#[test]
+fn test_prankster_fails_against_dark_types() {
+    assert!(prankster_growl_lands("Garchomp", Generation::Gen9));
+    assert!(!prankster_growl_lands("Umbreon", Generation::Gen9));
+    assert!(prankster_growl_lands("Umbreon", Generation::Gen6));
+}
+
+/// Test Gale Wings raises Flying moves' priority only while the user is at full HP
+#[test]
+fn test_gale_wings_needs_full_hp() {
+    let talonflame = || PokemonSpec::new("Talonflame").ability("Gale Wings");
+    let talonflame_max_hp = TestBuilder::new("talonflame")
+        .unwrap()
+        .team_one(talonflame())
+        .team_two(PokemonSpec::new("Regieleki"))
+        .build_state()
+        .get_pokemon_at_position(Positions::SIDE_ONE_0)
+        .unwrap()
+        .max_hp as u16;
+
+    assert!(side_one_moves_first(&against_agility(talonflame(), "Brave Bird")));
+    assert!(!side_one_moves_first(&against_agility(talonflame().hp(talonflame_max_hp - 1), "Brave Bird")));
+    assert!(!side_one_moves_first(&against_agility(talonflame(), "Flare Blitz")));
+}
+
+/// Test Triage moves a slower Pokemon's healing moves ahead
+#[test]
+fn test_triage_raises_healing_move_priority() {
+    assert!(side_one_moves_first(&against_agility(PokemonSpec::new("Comfey").ability("Triage"), "Draining Kiss")));
+    assert!(!side_one_moves_first(&against_agility(PokemonSpec::new("Comfey").ability("Flower Veil"), "Draining Kiss")));
+}
+
+/// Quick Attack into `target` with `terrain` set, as the damage of each branch
+fn quick_attack_damage(target: &'static str, terrain: Terrain) -> Vec<i16> {
+    let builder = TestBuilder::new("psychic terrain")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Quick Attack"]))
+        .team_two(PokemonSpec::new(target).moves(vec!["Splash"]))
+        .with_terrain(terrain);
+    builder
+        .generate(
+            &builder.create_move_choice("Quick Attack", SideReference::SideOne),
+            &builder.create_move_choice("Splash", SideReference::SideTwo),
+        )
+        .iter()
+        .map(|branch| damage_in(branch, Positions::SIDE_TWO_0))
+        .collect()
+}
+
+/// Test Psychic Terrain blocks priority moves against grounded targets only
+#[test]
+fn test_psychic_terrain_blocks_priority_against_grounded_targets() {
+    assert!(quick_attack_damage("Blissey", Terrain::None).iter().any(|&damage| damage > 0));
+    assert!(quick_attack_damage("Blissey", Terrain::PsychicTerrain).iter().all(|&damage| damage == 0));
+    assert!(quick_attack_damage("Corviknight", Terrain::PsychicTerrain).iter().any(|&damage| damage > 0));
+}
+
+/// The chance a slow Pokemon with `item` and `ability` acts before Regieleki's Agility using `move_one`
+fn chance_to_move_first(item: &'static str, ability: &'static str, move_one: &'static str) -> f32 {
+    against_agility(PokemonSpec::new("Slowbro-Galar").item(item).ability(ability), move_one)
+        .iter()
+        .filter(|branch| side_one_moves_first(std::slice::from_ref(branch)))
+        .map(|branch| branch.percentage)
+        .sum()
+}
+
+/// Test Quick Claw and Quick Draw branch on their activation chances
+#[test]
+fn test_quick_claw_and_quick_draw_chances() {
+    let expectations = [
+        ("Leftovers", "Own Tempo", "Swords Dance", 0.0),
+        ("Quick Claw", "Own Tempo", "Swords Dance", 20.0),
+        ("Leftovers", "Quick Draw", "Shell Side Arm", 30.0),
+        // Quick Draw only works with attacking moves
+        ("Leftovers", "Quick Draw", "Swords Dance", 0.0),
+        // Either activating is enough
+        ("Quick Claw", "Quick Draw", "Shell Side Arm", 100.0 - 80.0 * 0.7),
+    ];
+    for (item, ability, move_one, expected) in expectations {
+        let chance = chance_to_move_first(item, ability, move_one);
+        assert!((chance - expected).abs() < 1e-3, "{} {} {}: {} != {}", item, ability, move_one, chance, expected);
+    }
+}
@@ -0,0 +1,84 @@
+//! # Move Reflection Tests
+//!
+//! This module contains tests for Magic Coat and Magic Bounce, which send
+//! reflectable status moves back at their user.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::instructions::{PokemonStatus, SideCondition};
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Test Magic Coat sends a status move back at its user
+#[test]
+fn test_magic_coat_reflects_status_move() {
+    TestBuilder::new("magic coat reflects spore")
+        .unwrap()
+        .team_one(PokemonSpec::new("Breloom").moves(vec!["Spore"]))
+        .team_two(PokemonSpec::new("Xatu").moves(vec!["Magic Coat"]))
+        .turn_with_moves("Spore", "Magic Coat")
+        .expect_status(Positions::SIDE_ONE_0, PokemonStatus::Sleep)
+        .expect_status(Positions::SIDE_TWO_0, PokemonStatus::None)
+        .assert_success();
+}
+
+/// Test Magic Bounce sends a status move back at its user
+#[test]
+fn test_magic_bounce_reflects_status_move() {
+    TestBuilder::new("magic bounce reflects glare")
+        .unwrap()
+        .team_one(PokemonSpec::new("Arbok").moves(vec!["Glare"]))
+        .team_two(PokemonSpec::new("Espeon").ability("Magic Bounce"))
+        .turn_one_move("Glare")
+        .expect_status(Positions::SIDE_ONE_0, PokemonStatus::Paralysis)
+        .expect_status(Positions::SIDE_TWO_0, PokemonStatus::None)
+        .assert_success();
+}
+
+/// Test bounced hazards land on the original user's side
+#[test]
+fn test_magic_bounce_sends_hazards_to_user_side() {
+    TestBuilder::new("magic bounce reflects spikes")
+        .unwrap()
+        .team_one(PokemonSpec::new("Skarmory").moves(vec!["Spikes"]))
+        .team_two(PokemonSpec::new("Espeon").ability("Magic Bounce"))
+        .turn_one_move("Spikes")
+        .expect_side_condition(SideReference::SideOne, SideCondition::Spikes)
+        .expect_no_side_condition(SideReference::SideTwo, SideCondition::Spikes)
+        .assert_success();
+}
+
+/// Test a reflected move is not reflected a second time
+#[test]
+fn test_reflected_move_is_not_reflected_again() {
+    TestBuilder::new("bounced move does not bounce back")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Hatterene")
+                .ability("Magic Bounce")
+                .moves(vec!["Glare"]),
+        )
+        .team_two(PokemonSpec::new("Espeon").ability("Magic Bounce"))
+        .turn_one_move("Glare")
+        .expect_status(Positions::SIDE_ONE_0, PokemonStatus::Paralysis)
+        .expect_status(Positions::SIDE_TWO_0, PokemonStatus::None)
+        .assert_success();
+}
+
+/// Test Mold Breaker ignores Magic Bounce
+#[test]
+fn test_mold_breaker_ignores_magic_bounce() {
+    TestBuilder::new("mold breaker vs magic bounce")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Excadrill")
+                .ability("Mold Breaker")
+                .moves(vec!["Glare"]),
+        )
+        .team_two(PokemonSpec::new("Espeon").ability("Magic Bounce"))
+        .turn_one_move("Glare")
+        .expect_status(Positions::SIDE_ONE_0, PokemonStatus::None)
+        .expect_status(Positions::SIDE_TWO_0, PokemonStatus::Paralysis)
+        .assert_success();
+}
@@ -0,0 +1,143 @@
+//! # Move Restriction Tests
+//!
+//! This module contains tests for the effects that take moves out of a
+//! Pokemon's options: Disable, Encore, Torment, choice lock, Assault Vest
+//! and Imprison, and for the Struggle fallback when nothing is left.
+
+mod utils;
+
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{BattleInstruction, StatusInstruction, VolatileStatus};
+use tapu_simu::core::move_choice::{MoveChoice, MoveIndex};
+use tapu_simu::types::Moves;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// The moves side one's active Pokemon can select, in move slot order
+fn selectable_moves(state: &BattleState) -> Vec<Moves> {
+    let pokemon = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap();
+    state
+        .get_all_options()
+        .0
+        .iter()
+        .filter_map(|choice| match choice {
+            MoveChoice::Move { move_index, .. } => pokemon.get_move(*move_index).map(|known| known.name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether side one is left with Struggle
+fn can_only_struggle(state: &BattleState) -> bool {
+    let (options, _) = state.get_all_options();
+    options.iter().any(|choice| matches!(choice, MoveChoice::Struggle { .. }))
+        && selectable_moves(state).is_empty()
+}
+
+/// Test a disabled move is dropped from the options
+#[test]
+fn test_disable_removes_disabled_move() {
+    let mut state = TestBuilder::new("disable")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake", "Dragon Claw"]))
+        .team_two(PokemonSpec::new("Blissey"))
+        .build_state();
+    state.apply_instructions(&[BattleInstruction::Status(StatusInstruction::DisableMove {
+        target: Positions::SIDE_ONE_0,
+        move_index: MoveIndex::M0,
+        duration: 4,
+        previous_disabled: false,
+    })]);
+
+    assert_eq!(selectable_moves(&state), vec![Moves::DRAGONCLAW]);
+}
+
+/// Test Encore locks the Pokemon into its last used move
+#[test]
+fn test_encore_locks_last_used_move() {
+    let state = TestBuilder::new("encore")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake", "Dragon Claw"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_volatile_status(Positions::SIDE_ONE_0, VolatileStatus::Encore)
+        .turn_one_move("Earthquake")
+        .final_state();
+
+    assert_eq!(selectable_moves(&state), vec![Moves::EARTHQUAKE]);
+}
+
+/// Test Torment stops the same move being selected twice in a row
+#[test]
+fn test_torment_blocks_repeated_move() {
+    let state = TestBuilder::new("torment")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake", "Dragon Claw"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_volatile_status(Positions::SIDE_ONE_0, VolatileStatus::Torment)
+        .turn_one_move("Earthquake")
+        .final_state();
+
+    assert_eq!(selectable_moves(&state), vec![Moves::DRAGONCLAW]);
+}
+
+/// Test a choice item locks the holder into the first move it uses
+#[test]
+fn test_choice_item_locks_first_move() {
+    let state = TestBuilder::new("choice lock")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Garchomp")
+                .item("Choice Scarf")
+                .moves(vec!["Earthquake", "Dragon Claw"]),
+        )
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .turn_one_move("Dragon Claw")
+        .final_state();
+
+    assert_eq!(selectable_moves(&state), vec![Moves::DRAGONCLAW]);
+}
+
+/// Test Assault Vest keeps status moves out of the options
+#[test]
+fn test_assault_vest_blocks_status_moves() {
+    let state = TestBuilder::new("assault vest")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Garchomp")
+                .item("Assault Vest")
+                .moves(vec!["Earthquake", "Swords Dance"]),
+        )
+        .team_two(PokemonSpec::new("Blissey"))
+        .build_state();
+
+    assert_eq!(selectable_moves(&state), vec![Moves::EARTHQUAKE]);
+}
+
+/// Test Imprison keeps moves the imprisoning Pokemon knows out of the opponent's options
+#[test]
+fn test_imprison_blocks_shared_moves() {
+    let state = TestBuilder::new("imprison")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake", "Dragon Claw"]))
+        .team_two(PokemonSpec::new("Mew").moves(vec!["Imprison", "Earthquake"]))
+        .with_volatile_status(Positions::SIDE_TWO_0, VolatileStatus::Imprison)
+        .build_state();
+
+    assert_eq!(selectable_moves(&state), vec![Moves::DRAGONCLAW]);
+}
+
+/// Test Struggle is offered once every move is restricted
+#[test]
+fn test_struggle_when_every_move_is_restricted() {
+    let state = TestBuilder::new("struggle fallback")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Garchomp")
+                .item("Assault Vest")
+                .moves(vec!["Swords Dance", "Protect"]),
+        )
+        .team_two(PokemonSpec::new("Blissey"))
+        .build_state();
+
+    assert!(can_only_struggle(&state));
+}
@@ -0,0 +1,106 @@
+//! # Observation Tests
+//!
+//! This module contains tests for `BattleState::view_for`: each side sees its
+//! own team as it is and the opposing team only as far as it has been revealed.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::{BattleState, Pokemon};
+use tapu_simu::core::move_choice::MoveChoice;
+use tapu_simu::types::{Abilities, Items, Moves, PokemonName, PokemonType};
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// A builder with a fully specified Garchomp leading for side two
+fn hidden_garchomp(item: &'static str) -> TestBuilder {
+    TestBuilder::new("observation")
+        .unwrap()
+        .team_one(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .team_two_multi(vec![
+            PokemonSpec::new("Garchomp")
+                .ability("Rough Skin")
+                .item(item)
+                .moves(vec!["Earthquake", "Dragon Claw", "Swords Dance", "Fire Fang"])
+                .ev_spread(0, 252, 0, 0, 4, 252),
+            PokemonSpec::new("Gengar"),
+        ])
+}
+
+/// Play one turn, recording what it reveals before applying its first branch
+fn play_turn(builder: &TestBuilder, state: &mut BattleState, move_one: &MoveChoice, move_two: &MoveChoice) {
+    let branches = builder.generate(move_one, move_two);
+    state.record_revealed((move_one, move_two), &branches[0].instruction_list);
+    state.apply_instructions(&branches[0].instruction_list);
+}
+
+/// The opposing active Pokemon as side one observes it
+fn observed_opponent(state: &BattleState) -> Pokemon {
+    let observation = state.view_for(SideReference::SideOne);
+    observation.state.get_pokemon_at_position(Positions::SIDE_TWO_0).unwrap().clone()
+}
+
+/// Test the view hides moves, items, abilities, EVs and Tera types that weren't revealed
+#[test]
+fn test_view_hides_unrevealed_details() {
+    let builder = hidden_garchomp("Choice Scarf");
+    let splash = builder.create_move_choice("Splash", SideReference::SideOne);
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    state.sides[1].pokemon[0].tera_type = Some(PokemonType::Fire);
+    play_turn(&builder, &mut state, &splash, &earthquake);
+
+    let garchomp = observed_opponent(&state);
+    assert_eq!(garchomp.species, PokemonName::GARCHOMP);
+    let moves: Vec<Moves> = garchomp.moves.iter().map(|(_, known)| known.name).collect();
+    assert_eq!(moves, vec![Moves::EARTHQUAKE]);
+    assert_eq!(garchomp.item, None);
+    assert_eq!(garchomp.ability, Abilities::NONE);
+    assert_eq!(garchomp.tera_type, None);
+
+    // HP is out of 100 and stats come from 85 EVs in every stat
+    assert_eq!((garchomp.hp, garchomp.max_hp), (100, 100));
+    assert_eq!((garchomp.evs.attack, garchomp.evs.speed), (85, 85));
+    let real = state.get_pokemon_at_position(Positions::SIDE_TWO_0).unwrap();
+    assert_ne!(garchomp.stats.speed, real.stats.speed);
+
+    // The bench hasn't been sent out
+    let observation = state.view_for(SideReference::SideOne);
+    assert_eq!(observation.opponent().pokemon[1].species, PokemonName::NONE);
+}
+
+/// Test the observing side's own team is left as it is
+#[test]
+fn test_view_keeps_own_team() {
+    let builder = hidden_garchomp("Choice Scarf");
+    let state = builder.build_state();
+
+    let observation = state.view_for(SideReference::SideTwo);
+    let own = &observation.state.sides[1];
+    assert_eq!(own.pokemon[0].item, Some(Items::CHOICESCARF));
+    assert_eq!(own.pokemon[0].moves.len(), 4);
+    assert_eq!(own.pokemon[1].species, PokemonName::GENGAR);
+}
+
+/// Test a consumed item is revealed
+#[test]
+fn test_view_reveals_consumed_item() {
+    let builder = TestBuilder::new("observation consumed item")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp").moves(vec!["Earthquake"]))
+        .team_two(
+            PokemonSpec::new("Tyranitar")
+                .item("Shuca Berry")
+                .moves(vec!["Splash", "Crunch"]),
+        );
+    let earthquake = builder.create_move_choice("Earthquake", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    play_turn(&builder, &mut state, &earthquake, &splash);
+
+    let tyranitar = observed_opponent(&state);
+    assert_eq!(tyranitar.item, None);
+    let revealed = state.sides[1].revealed_pokemon(0);
+    assert_eq!(revealed.item, Some(None));
+    assert_eq!(revealed.moves, vec![Moves::SPLASH]);
+}
@@ -0,0 +1,132 @@
+//! # Protection Tests
+//!
+//! This module contains tests for the protection move family: consecutive
+//! stalling moves grow less likely to work, the shields punish contact, and
+//! Mat Block only works on the user's first turn out.
+
+mod utils;
+
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{
+    BattleInstruction, BattleInstructions, StatsInstruction, StatusInstruction, VolatileStatus,
+};
+use tapu_simu::core::move_choice::{MoveChoice, PokemonIndex};
+use tapu_simu::engine::turn;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Generate a turn's branches from `state`
+fn branches_for(state: &BattleState, move_one: &MoveChoice, move_two: &MoveChoice) -> Vec<BattleInstructions> {
+    turn::generate_instructions(state, (move_one, move_two), false).unwrap()
+}
+
+/// Whether a branch gives side one's active Pokemon its Protect
+fn protects(branch: &BattleInstructions) -> bool {
+    branch.instruction_list.iter().any(|instruction| {
+        matches!(
+            instruction,
+            BattleInstruction::Status(StatusInstruction::ApplyVolatile { target, status: VolatileStatus::Protect, .. })
+                if *target == Positions::SIDE_ONE_0
+        )
+    })
+}
+
+/// Test each consecutive Protect is a third as likely to work as the last
+#[test]
+fn test_consecutive_protects_decay() {
+    let builder = TestBuilder::new("protect decay")
+        .unwrap()
+        .team_one(PokemonSpec::new("Blissey").moves(vec!["Protect"]))
+        .team_two(PokemonSpec::new("Garchomp").moves(vec!["Splash"]));
+    let protect = builder.create_move_choice("Protect", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state = builder.build_state();
+
+    for expected in [100.0, 100.0 / 3.0, 100.0 / 9.0] {
+        let branches = branches_for(&state, &protect, &splash);
+        let chance: f32 = branches.iter().filter(|branch| protects(branch)).map(|branch| branch.percentage).sum();
+        assert!((chance - expected).abs() < 1e-3, "{} != {}", chance, expected);
+        let success = branches.iter().find(|branch| protects(branch)).unwrap();
+        state.apply_instructions(&success.instruction_list);
+    }
+
+    // A failed Protect resets the run
+    let branches = branches_for(&state, &protect, &splash);
+    let failure = branches.iter().find(|branch| !protects(branch)).unwrap();
+    state.apply_instructions(&failure.instruction_list);
+    let branches = branches_for(&state, &protect, &splash);
+    assert!(branches.iter().all(protects));
+}
+
+/// Garchomp's Tackle into a Blissey using `shield`
+fn tackle_into(shield: &'static str) -> Vec<BattleInstructions> {
+    let builder = TestBuilder::new("contact punishment")
+        .unwrap()
+        .team_one(PokemonSpec::new("Blissey").moves(vec![shield]))
+        .team_two(PokemonSpec::new("Garchomp").ability("Rough Skin").moves(vec!["Tackle"]));
+    let shield = builder.create_move_choice(shield, SideReference::SideOne);
+    let tackle = builder.create_move_choice("Tackle", SideReference::SideTwo);
+    builder.generate(&shield, &tackle)
+}
+
+/// Test Spiky Shield blocks a contact move and hurts the attacker for 1/8 of its max HP
+#[test]
+fn test_spiky_shield_hurts_contact_attacker() {
+    let garchomp_max_hp = TestBuilder::new("garchomp")
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp"))
+        .team_two(PokemonSpec::new("Garchomp"))
+        .build_state()
+        .get_pokemon_at_position(Positions::SIDE_ONE_0)
+        .unwrap()
+        .max_hp;
+
+    for branch in tackle_into("Spiky Shield") {
+        assert_eq!(damage_in(&branch, Positions::SIDE_ONE_0), 0);
+        assert_eq!(damage_in(&branch, Positions::SIDE_TWO_0), garchomp_max_hp / 8);
+    }
+}
+
+/// Test King's Shield blocks a contact move and lowers the attacker's Attack
+#[test]
+fn test_kings_shield_lowers_contact_attacker_attack() {
+    for branch in tackle_into("King's Shield") {
+        assert_eq!(damage_in(&branch, Positions::SIDE_ONE_0), 0);
+        assert!(branch.instruction_list.iter().any(|instruction| matches!(
+            instruction,
+            BattleInstruction::Stats(StatsInstruction::BoostStats { target, stat_changes, .. })
+                if *target == Positions::SIDE_TWO_0 && stat_changes.values().any(|&change| change < 0)
+        )));
+    }
+}
+
+/// Test Mat Block only works on the user's first turn out, and works again after switching back in
+#[test]
+fn test_mat_block_only_on_first_turn_out() {
+    let builder = TestBuilder::new("mat block")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Greninja").ability("Torrent").moves(vec!["Mat Block"]),
+            PokemonSpec::new("Blissey"),
+        ])
+        .team_two(PokemonSpec::new("Snorlax").moves(vec!["Tackle"]));
+    let mat_block = builder.create_move_choice("Mat Block", SideReference::SideOne);
+    let tackle = builder.create_move_choice("Tackle", SideReference::SideTwo);
+    let mut state = builder.build_state();
+
+    let blocks = |state: &mut BattleState| {
+        let branches = branches_for(state, &mat_block, &tackle);
+        state.apply_instructions(&branches[0].instruction_list);
+        branches.iter().all(|branch| damage_in(branch, Positions::SIDE_ONE_0) == 0)
+    };
+    assert!(blocks(&mut state));
+    assert!(!blocks(&mut state));
+
+    for switch in [PokemonIndex::P1, PokemonIndex::P0] {
+        let branches = branches_for(&state, &MoveChoice::Switch(switch), &tackle);
+        state.apply_instructions(&branches[0].instruction_list);
+    }
+    assert!(blocks(&mut state));
+}
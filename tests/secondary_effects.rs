@@ -19,6 +19,7 @@ use tapu_simu::core::instructions::{
 use tapu_simu::core::move_choice::MoveChoice;
 use tapu_simu::generation::Generation;
 
+use utils::convenience::damage_in;
 use utils::{PokemonSpec, Positions, StatChanges, TestBuilder};
 
 // ============================================================================
@@ -495,4 +496,135 @@ fn test_substitute_does_not_let_secondary_status_effect_happen() {
         .turn_with_moves("Thunder Punch", "Soft-Boiled")
         .expect_substitute_health(Positions::SIDE_TWO_0, 100) // Substitute takes damage but no paralysis
         .assert_success();
-}
\ No newline at end of file
+}
+// ============================================================================
+// Sheer Force Tests
+// ============================================================================
+
+/// Test Sheer Force removes both the secondary effect and Life Orb recoil
+#[test]
+fn test_sheer_force_removes_secondary_and_life_orb_recoil() {
+    let iron_head = |ability: &'static str| {
+        TestBuilder::new("sheer force life orb")
+            .unwrap()
+            .team_one(
+                PokemonSpec::new("Nidoking")
+                    .ability(ability)
+                    .item("Life Orb")
+                    .moves(vec!["Iron Head"]),
+            )
+            .team_two(PokemonSpec::new("Blissey"))
+    };
+    let flinches = |branch: &BattleInstructions| {
+        branch.instruction_list.iter().any(|instruction| {
+            matches!(
+                instruction,
+                BattleInstruction::Status(StatusInstruction::ApplyVolatile {
+                    status: VolatileStatus::Flinch,
+                    ..
+                })
+            )
+        })
+    };
+
+    // Without Sheer Force the flinch chance branches and Life Orb costs 1/10 max HP
+    let builder = iron_head("Poison Point");
+    let choice = builder.create_move_choice("Iron Head", SideReference::SideOne);
+    let branches = builder.generate(&choice, &MoveChoice::None);
+    assert_eq!(branches.len(), 2);
+    assert!(branches.iter().any(flinches));
+    assert!(branches
+        .iter()
+        .all(|branch| damage_in(branch, Positions::SIDE_ONE_0) == 30));
+
+    // With Sheer Force there is a single branch with neither
+    let builder = iron_head("Sheer Force");
+    let branches = builder.generate(&choice, &MoveChoice::None);
+    assert_eq!(branches.len(), 1);
+    assert!(!flinches(&branches[0]));
+    assert_eq!(damage_in(&branches[0], Positions::SIDE_ONE_0), 0);
+    assert!(damage_in(&branches[0], Positions::SIDE_TWO_0) > 0);
+}
+
+// ============================================================================
+// Parental Bond Tests
+// ============================================================================
+
+/// Kangaskhan's Iron Head into Blissey, with `ability`, in `generation`
+fn parental_bond_iron_head(ability: &'static str, generation: Generation) -> Vec<BattleInstructions> {
+    let builder = TestBuilder::new_with_generation("parental bond", generation)
+        .unwrap()
+        .team_one(PokemonSpec::new("Kangaskhan").ability(ability).moves(vec!["Iron Head"]))
+        .team_two(PokemonSpec::new("Blissey"));
+    let iron_head = builder.create_move_choice("Iron Head", SideReference::SideOne);
+    builder.generate(&iron_head, &MoveChoice::None)
+}
+
+/// The damage of each hit a branch deals to side two's active Pokemon, in order
+fn hits_on_side_two(branch: &BattleInstructions) -> Vec<i16> {
+    branch
+        .instruction_list
+        .iter()
+        .filter_map(|instruction| match instruction {
+            BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. })
+                if *target == Positions::SIDE_TWO_0 =>
+            {
+                Some(*amount)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Test Parental Bond hits twice, the second hit dealing a quarter of the damage
+#[test]
+fn test_parental_bond_hits_twice() {
+    let single_hit = hits_on_side_two(&parental_bond_iron_head("Scrappy", Generation::Gen9)[0]);
+    assert_eq!(single_hit.len(), 1);
+
+    for branch in parental_bond_iron_head("Parental Bond", Generation::Gen9) {
+        let hits = hits_on_side_two(&branch);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0], single_hit[0]);
+        assert!((hits[1] - single_hit[0] / 4).abs() <= 1, "{:?}", hits);
+    }
+}
+
+/// Test Parental Bond's second hit deals half the damage before Gen 7
+#[test]
+fn test_parental_bond_second_hit_halved_in_gen6() {
+    let single_hit = hits_on_side_two(&parental_bond_iron_head("Scrappy", Generation::Gen6)[0]);
+    for branch in parental_bond_iron_head("Parental Bond", Generation::Gen6) {
+        let hits = hits_on_side_two(&branch);
+        assert_eq!(hits.len(), 2);
+        assert!((hits[1] - single_hit[0] / 2).abs() <= 1, "{:?}", hits);
+    }
+}
+
+/// Test each Parental Bond hit rolls its own secondary effect
+#[test]
+fn test_parental_bond_rolls_secondary_on_both_hits() {
+    let flinch_after_hit = |branch: &BattleInstructions| {
+        let instructions = &branch.instruction_list;
+        let flinch = instructions.iter().position(|instruction| {
+            matches!(
+                instruction,
+                BattleInstruction::Status(StatusInstruction::ApplyVolatile { status: VolatileStatus::Flinch, .. })
+            )
+        })?;
+        Some(instructions[..flinch].iter().filter(|instruction| {
+            matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. }) if *target == Positions::SIDE_TWO_0)
+        }).count())
+    };
+    let chance_of_flinch_after = |hit: usize| -> f32 {
+        parental_bond_iron_head("Parental Bond", Generation::Gen9)
+            .iter()
+            .filter(|branch| flinch_after_hit(branch) == Some(hit))
+            .map(|branch| branch.percentage)
+            .sum()
+    };
+
+    // Iron Head flinches 30% of the time, and the second hit rolls again when the first did not
+    assert!((chance_of_flinch_after(1) - 30.0).abs() < 1e-3);
+    assert!((chance_of_flinch_after(2) - 70.0 * 0.3).abs() < 1e-3);
+}
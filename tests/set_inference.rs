@@ -0,0 +1,140 @@
+//! # Set Inference Tests
+//!
+//! This module contains tests for `infer_opponent_sets`: turn histories are
+//! played out the way `BattleEnvironment` records them, and the posterior over
+//! the opponent's hidden set has to follow the speed and damage they showed.
+
+mod utils;
+
+use tapu_simu::core::battle_environment::TurnInfo;
+use tapu_simu::core::battle_format::SideReference;
+use tapu_simu::core::instructions::{BattleInstruction, BattleInstructions, PokemonInstruction, Weather};
+use tapu_simu::core::move_choice::MoveChoice;
+use tapu_simu::core::set_inference::{infer_opponent_sets, SetPosterior};
+use tapu_simu::data::types::Stats;
+use tapu_simu::engine::turn;
+use tapu_simu::types::Items;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// The same value in every stat
+fn evs(value: i16) -> Stats {
+    Stats { hp: value, attack: value, defense: value, special_attack: value, special_defense: value, speed: value }
+}
+
+/// Play turns from a builder's state, recording each like the battle environment does
+///
+/// `pick` chooses which of a turn's generated branches happens.
+fn play(builder: &TestBuilder, turns: &[(&str, &str)], pick: fn(&[BattleInstructions]) -> usize) -> Vec<TurnInfo> {
+    let mut state = builder.build_state();
+    state.record_revealed((&MoveChoice::None, &MoveChoice::None), &[]);
+
+    let mut history = Vec::new();
+    for (turn_number, (move_one, move_two)) in turns.iter().enumerate() {
+        let side_one_choice = builder.create_move_choice(move_one, SideReference::SideOne);
+        let side_two_choice = builder.create_move_choice(move_two, SideReference::SideTwo);
+        let state_before = state.clone();
+        let instructions = turn::generate_instructions(&state, (&side_one_choice, &side_two_choice), false).unwrap();
+        let chosen = pick(&instructions);
+        state.record_revealed((&side_one_choice, &side_two_choice), &instructions[chosen].instruction_list);
+        state.apply_instructions(&instructions[chosen].instruction_list);
+        history.push(TurnInfo {
+            turn_number: turn_number + 1,
+            state_before,
+            side_one_choice,
+            side_two_choice,
+            instructions_generated: instructions,
+            chosen_sequence: Some(chosen),
+            state_after: state.clone(),
+        });
+    }
+    history
+}
+
+/// The most likely item in a posterior
+fn top_item(posterior: &SetPosterior) -> Option<Items> {
+    posterior.items[0].0
+}
+
+/// Test Choice Scarf tops the item marginal once the opponent outspeeds at +0
+#[test]
+fn test_choice_scarf_inferred_from_outspeeding() {
+    let builder = TestBuilder::new("set inference scarf")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Garchomp")
+                .moves(vec!["Earthquake"])
+                .ev_spread(0, 0, 0, 0, 0, 252),
+        )
+        .team_two(
+            PokemonSpec::new("Tyranitar")
+                .ability("Unnerve")
+                .item("Choice Scarf")
+                .moves(vec!["Crunch"])
+                .ev_spread(0, 0, 0, 0, 0, 252),
+        );
+    let history = play(&builder, &[("Earthquake", "Crunch")], |_| 0);
+
+    let posteriors = infer_opponent_sets(&history, SideReference::SideOne);
+    assert_eq!(posteriors.len(), 1);
+    assert_eq!(top_item(&posteriors[0]), Some(Items::CHOICESCARF));
+    assert!(posteriors[0].notes.iter().any(|note| note == "Choice Scarf likely: outsped at +0"));
+}
+
+/// Test the opponent's damage is read from its own move, not from our Life Orb recoil before it
+#[test]
+fn test_life_orb_inferred_past_own_life_orb_recoil() {
+    let builder = TestBuilder::new("set inference life orb")
+        .unwrap()
+        .team_one(
+            PokemonSpec::new("Garchomp")
+                .item("Life Orb")
+                .moves(vec!["Earthquake"]),
+        )
+        .team_two(
+            PokemonSpec::new("Tyranitar")
+                .ability("Unnerve")
+                .item("Life Orb")
+                .moves(vec!["Crunch"])
+                .evs(evs(85)),
+        );
+    let history = play(&builder, &[("Earthquake", "Crunch")], |_| 0);
+
+    let posteriors = infer_opponent_sets(&history, SideReference::SideOne);
+    assert_eq!(top_item(&posteriors[0]), Some(Items::LIFEORB));
+}
+
+/// Test residual damage after a missed move isn't taken for its hit
+///
+/// Read as a hit, the sandstorm's chip would make Tyranitar look slower than
+/// Dragonite and count against Choice Scarf.
+#[test]
+fn test_residual_damage_not_taken_for_a_missed_hit() {
+    let builder = TestBuilder::new("set inference residual")
+        .unwrap()
+        .team_one(PokemonSpec::new("Dragonite").ability("Inner Focus").moves(vec!["Dragon Claw"]))
+        .team_two(
+            PokemonSpec::new("Tyranitar")
+                .ability("Unnerve")
+                .item("Choice Scarf")
+                .moves(vec!["Stone Edge"])
+                .ev_spread(0, 0, 0, 0, 0, 252),
+        )
+        .with_weather(Weather::Sand);
+    // The branch where Stone Edge misses, leaving the sandstorm as Dragonite's only damage
+    let history = play(&builder, &[("Dragon Claw", "Stone Edge")], |branches| {
+        branches
+            .iter()
+            .position(|branch| {
+                let hits = branch.instruction_list.iter().filter(|instruction| {
+                    matches!(instruction, BattleInstruction::Pokemon(PokemonInstruction::Damage { target, .. }) if *target == Positions::SIDE_ONE_0)
+                });
+                hits.count() == 1
+            })
+            .unwrap()
+    });
+
+    let posteriors = infer_opponent_sets(&history, SideReference::SideOne);
+    let probability = |item| posteriors[0].items.iter().find(|(known, _)| *known == item).unwrap().1;
+    assert!((probability(Some(Items::CHOICESCARF)) - probability(None)).abs() < 1e-9);
+}
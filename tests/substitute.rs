@@ -0,0 +1,129 @@
+//! # Substitute Tests
+//!
+//! This module contains tests for how Substitute takes hits: sound moves pass
+//! through it from Gen 6, draining and recoil follow the damage it absorbed,
+//! and Shed Tail hands it to the Pokemon switching in.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattlePosition, SideReference};
+use tapu_simu::core::instructions::{BattleInstruction, BattleInstructions, PokemonInstruction, VolatileStatus};
+use tapu_simu::core::move_choice::{MoveChoice, PokemonIndex};
+use tapu_simu::engine::turn;
+use tapu_simu::generation::Generation;
+use tapu_simu::types::PokemonName;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// The damage a branch deals to the substitute at `position`
+fn substitute_damage_in(branch: &BattleInstructions, position: BattlePosition) -> i16 {
+    branch
+        .instruction_list
+        .iter()
+        .filter_map(|instruction| match instruction {
+            BattleInstruction::Pokemon(PokemonInstruction::DamageSubstitute { target, amount, .. }) if *target == position => {
+                Some(*amount)
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+/// The HP a branch restores to the Pokemon at `position`
+fn heal_in(branch: &BattleInstructions, position: BattlePosition) -> i16 {
+    branch
+        .instruction_list
+        .iter()
+        .filter_map(|instruction| match instruction {
+            BattleInstruction::Pokemon(PokemonInstruction::Heal { target, amount, .. }) if *target == position => Some(*amount),
+            _ => None,
+        })
+        .sum()
+}
+
+/// Hyper Voice into a Blissey behind a substitute, in `generation`
+fn hyper_voice_branches(generation: Generation) -> Vec<BattleInstructions> {
+    let builder = TestBuilder::new_with_generation("sound through substitute", generation)
+        .unwrap()
+        .team_one(PokemonSpec::new("Sylveon").moves(vec!["Hyper Voice"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_substitute(Positions::SIDE_TWO_0, 100);
+    let hyper_voice = builder.create_move_choice("Hyper Voice", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    builder.generate(&hyper_voice, &splash)
+}
+
+/// Test sound moves go through a substitute from Gen 6, but hit it before
+#[test]
+fn test_sound_moves_bypass_substitute_from_gen6() {
+    let hits = |branches: &[BattleInstructions]| {
+        branches
+            .iter()
+            .filter(|branch| damage_in(branch, Positions::SIDE_TWO_0) > 0 || substitute_damage_in(branch, Positions::SIDE_TWO_0) > 0)
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let gen6 = hits(&hyper_voice_branches(Generation::Gen6));
+    assert!(!gen6.is_empty());
+    assert!(gen6.iter().all(|branch| substitute_damage_in(branch, Positions::SIDE_TWO_0) == 0));
+
+    let gen5 = hits(&hyper_voice_branches(Generation::Gen5));
+    assert!(!gen5.is_empty());
+    assert!(gen5.iter().all(|branch| damage_in(branch, Positions::SIDE_TWO_0) == 0));
+}
+
+/// Test draining and recoil are worked out from the damage the substitute took
+#[test]
+fn test_drain_and_recoil_follow_substitute_damage() {
+    let builder = TestBuilder::new("drain into substitute")
+        .unwrap()
+        .team_one(PokemonSpec::new("Venusaur").moves(vec!["Giga Drain"]).hp(100))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_substitute(Positions::SIDE_TWO_0, 10);
+    let giga_drain = builder.create_move_choice("Giga Drain", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    for branch in builder.generate(&giga_drain, &splash) {
+        assert_eq!(substitute_damage_in(&branch, Positions::SIDE_TWO_0), 10);
+        assert_eq!(heal_in(&branch, Positions::SIDE_ONE_0), 5);
+    }
+
+    let builder = TestBuilder::new("recoil into substitute")
+        .unwrap()
+        .team_one(PokemonSpec::new("Snorlax").moves(vec!["Double-Edge"]))
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]))
+        .with_substitute(Positions::SIDE_TWO_0, 30);
+    let double_edge = builder.create_move_choice("Double-Edge", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    for branch in builder.generate(&double_edge, &splash) {
+        assert_eq!(substitute_damage_in(&branch, Positions::SIDE_TWO_0), 30);
+        assert_eq!(damage_in(&branch, Positions::SIDE_ONE_0), 10);
+    }
+}
+
+/// Test Shed Tail's substitute passes to the Pokemon switching in
+#[test]
+fn test_shed_tail_passes_substitute() {
+    let builder = TestBuilder::new("shed tail")
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Cyclizar").moves(vec!["Shed Tail"]),
+            PokemonSpec::new("Garchomp"),
+        ])
+        .team_two(PokemonSpec::new("Blissey").moves(vec!["Splash"]));
+    let shed_tail = builder.create_move_choice("Shed Tail", SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let mut state = builder.build_state();
+    let cyclizar_max_hp = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap().max_hp;
+
+    for (move_one, move_two) in [(shed_tail, splash.clone()), (MoveChoice::Switch(PokemonIndex::P1), splash)] {
+        let branches = turn::generate_instructions(&state, (&move_one, &move_two), false).unwrap();
+        state.apply_instructions(&branches[0].instruction_list);
+    }
+
+    let garchomp = state.get_pokemon_at_position(Positions::SIDE_ONE_0).unwrap();
+    assert_eq!(garchomp.species, PokemonName::GARCHOMP);
+    assert!(garchomp.volatile_statuses.contains(VolatileStatus::Substitute));
+    assert_eq!(garchomp.substitute_health, cyclizar_max_hp / 4);
+}
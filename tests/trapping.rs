@@ -0,0 +1,58 @@
+//! # Trapping Tests
+//!
+//! This module contains tests for traps that only last while the Pokemon
+//! that set them stays on the field.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattleFormat, BattlePosition, SideReference};
+use tapu_simu::core::battle_state::BattleState;
+use tapu_simu::core::instructions::{BattleInstruction, PokemonInstruction, VolatileStatus};
+use tapu_simu::core::move_choice::MoveChoice;
+use tapu_simu::engine::combat::core::trapping::is_trapped;
+
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Switch the Pokemon at `position` out for the party member at `new_pokemon`
+fn switch(state: &mut BattleState, position: BattlePosition, new_pokemon: usize) {
+    let previous_pokemon = state.sides[position.side.to_index()].active_pokemon_indices[position.slot];
+    state.apply_instructions(&[BattleInstruction::Pokemon(PokemonInstruction::Switch {
+        position,
+        new_pokemon,
+        previous_pokemon,
+    })]);
+}
+
+/// Test a trap ends when its source switches out, but not when its partner does
+#[test]
+fn test_trap_ends_only_when_its_source_switches() {
+    let builder = TestBuilder::new_with_format("mean look in doubles", BattleFormat::gen9_vgc())
+        .unwrap()
+        .team_one_multi(vec![
+            PokemonSpec::new("Umbreon").moves(vec!["Mean Look"]),
+            PokemonSpec::new("Garchomp"),
+            PokemonSpec::new("Blissey"),
+            PokemonSpec::new("Chansey"),
+        ])
+        .team_two_multi(vec![
+            PokemonSpec::new("Dragonite"),
+            PokemonSpec::new("Snorlax"),
+            PokemonSpec::new("Gengar"),
+        ]);
+    let mean_look = builder.create_move_choice("Mean Look", SideReference::SideOne);
+    let mut state = builder.build_state();
+    for branch in builder.generate(&mean_look, &MoveChoice::None) {
+        state.apply_instructions(&branch.instruction_list);
+    }
+    assert!(is_trapped(&state, Positions::SIDE_TWO_0));
+
+    // The partner that didn't use Mean Look leaves, so the trap stays
+    switch(&mut state, Positions::SIDE_ONE_1, 2);
+    assert!(is_trapped(&state, Positions::SIDE_TWO_0));
+
+    // Umbreon leaves, so the trap ends
+    switch(&mut state, Positions::SIDE_ONE_0, 3);
+    assert!(!is_trapped(&state, Positions::SIDE_TWO_0));
+    let dragonite = state.get_pokemon_at_position(Positions::SIDE_TWO_0).unwrap();
+    assert!(!dragonite.volatile_statuses.contains(VolatileStatus::Trapped));
+}
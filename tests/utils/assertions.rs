@@ -330,6 +330,33 @@ impl BattleAssertions {
 /// Convenience functions for common assertion patterns
 pub mod convenience {
     use super::*;
+    use tapu_simu::core::instructions::{PokemonInstruction, StatusInstruction};
+    use tapu_simu::types::Moves;
+
+    /// The instruction recording a Pokemon's first move of the battle
+    pub fn last_used_move(position: BattlePosition, move_name: Moves) -> BattleInstruction {
+        BattleInstruction::Status(StatusInstruction::SetLastUsedMove {
+            target: position,
+            move_name,
+            previous_move: None,
+        })
+    }
+
+    /// Total direct damage dealt to a position within one instruction branch
+    pub fn damage_in(instructions: &BattleInstructions, position: BattlePosition) -> i16 {
+        instructions
+            .instruction_list
+            .iter()
+            .filter_map(|instruction| match instruction {
+                BattleInstruction::Pokemon(PokemonInstruction::Damage { target, amount, .. })
+                    if *target == position =>
+                {
+                    Some(*amount)
+                }
+                _ => None,
+            })
+            .sum()
+    }
     
     /// Assert that an attack deals the expected damage
     pub fn assert_attack_damage(
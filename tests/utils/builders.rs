@@ -8,11 +8,13 @@ use super::framework::{
 };
 use std::collections::HashMap;
 use tapu_simu::core::battle_format::{BattleFormat, BattlePosition, SideReference};
+use tapu_simu::core::battle_state::BattleState;
 use tapu_simu::core::instructions::{
     BattleInstructions, PokemonStatus, SideCondition, Stat, Terrain, VolatileStatus, Weather,
 };
 use tapu_simu::core::move_choice::{MoveChoice, MoveIndex};
 use tapu_simu::data::types::Stats;
+use tapu_simu::engine::turn;
 use tapu_simu::generation::Generation;
 use tapu_simu::types::DataResult;
 
@@ -112,6 +114,11 @@ impl TestBuilder {
         self.setup(SetupAction::AddSubstitute(position, health))
     }
 
+    /// Apply a volatile status to a Pokemon
+    pub fn with_volatile_status(self, position: BattlePosition, status: VolatileStatus) -> Self {
+        self.setup(SetupAction::ApplyVolatile(position, status))
+    }
+
     /// Add a turn with move choices
     pub fn turn(mut self, move_one: MoveChoice, move_two: MoveChoice) -> Self {
         self.test.moves.push((move_one, move_two));
@@ -180,6 +187,11 @@ impl TestBuilder {
         self.expect(ExpectedOutcome::SideCondition(side, condition))
     }
 
+    /// Expect a side condition to be absent
+    pub fn expect_no_side_condition(self, side: SideReference, condition: SideCondition) -> Self {
+        self.expect(ExpectedOutcome::NoSideCondition(side, condition))
+    }
+
     /// Expect a Pokemon to faint
     pub fn expect_faint(self, position: BattlePosition) -> Self {
         self.expect(ExpectedOutcome::Faint(position))
@@ -200,6 +212,11 @@ impl TestBuilder {
         self.expect(ExpectedOutcome::VolatileStatus(position, status))
     }
 
+    /// Expect a volatile status to be absent at a position
+    pub fn expect_no_volatile_status(self, position: BattlePosition, status: VolatileStatus) -> Self {
+        self.expect(ExpectedOutcome::NoVolatileStatus(position, status))
+    }
+
     /// Expect specific instructions
     pub fn expect_instructions(mut self, instructions: Vec<BattleInstructions>) -> Self {
         self.test.expected_instructions.push(instructions);
@@ -212,6 +229,36 @@ impl TestBuilder {
         self
     }
 
+    /// Build the battle state this test starts from, with setup applied
+    pub fn build_state(&self) -> BattleState {
+        self.framework
+            .prepare_state(&self.test)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Generate one turn's instruction branches from the prepared state
+    pub fn generate(&self, move_one: &MoveChoice, move_two: &MoveChoice) -> Vec<BattleInstructions> {
+        let state = self.build_state();
+        turn::generate_instructions(&state, (move_one, move_two), self.test.branch_on_damage)
+            .unwrap_or_else(|e| panic!("Battle engine error: {:?}", e))
+    }
+
+    /// Play every turn on the prepared state and return where the battle ends up
+    ///
+    /// Like `run`, every branch of a turn is applied, so this is meant for turns
+    /// that resolve to a single branch.
+    pub fn final_state(&self) -> BattleState {
+        let mut state = self.build_state();
+        for (move_one, move_two) in &self.test.moves {
+            let branches = turn::generate_instructions(&state, (move_one, move_two), self.test.branch_on_damage)
+                .unwrap_or_else(|e| panic!("Battle engine error: {:?}", e));
+            for branch in &branches {
+                state.apply_instructions(&branch.instruction_list);
+            }
+        }
+        state
+    }
+
     /// Execute the test and return the result
     pub fn run(self) -> TestResult {
         self.framework.execute_test(self.test)
@@ -224,7 +271,7 @@ impl TestBuilder {
     }
 
     /// Create a move choice from move name and side
-    fn create_move_choice(&self, move_name: &str, side: SideReference) -> MoveChoice {
+    pub fn create_move_choice(&self, move_name: &str, side: SideReference) -> MoveChoice {
         // For test builder, we need to determine the move index based on the team spec
         let move_index = self.find_move_index_in_team(move_name, side);
 
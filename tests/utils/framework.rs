@@ -108,12 +108,60 @@ impl TapuTestFramework {
 
     /// Execute a complete battle test
     pub fn execute_test(&self, test: BattleTest) -> TestResult {
-        // Create battle state with teams
-        let mut state = match self.create_battle_state(&test.team_one, &test.team_two) {
+        let mut state = match self.prepare_state(&test) {
             Ok(state) => state,
-            Err(e) => return TestResult::Failed(format!("Failed to create battle state: {}", e)),
+            Err(e) => return TestResult::Failed(e),
         };
 
+        // Execute moves and validate outcomes
+        for (turn_idx, (move_one, move_two)) in test.moves.iter().enumerate() {
+            // Execute the turn
+            match self.execute_turn(
+                &mut state,
+                move_one.clone(),
+                move_two.clone(),
+                test.branch_on_damage,
+            ) {
+                Ok(instructions) => {
+                    // Validate expected outcomes for this turn
+                    if let Some(expected_instructions) = test.expected_instructions.get(turn_idx) {
+                        if !self.validate_instructions(&instructions, expected_instructions) {
+                            // Show normalized instructions for debugging
+                            let normalized_actual = self.normalize_instructions(&instructions);
+                            let normalized_expected =
+                                self.normalize_instructions(expected_instructions);
+                            return TestResult::Failed(format!(
+                                "Instructions mismatch on turn {}: expected {:?}, got {:?}",
+                                turn_idx + 1,
+                                normalized_expected,
+                                normalized_actual
+                            ));
+                        }
+                    }
+                }
+                Err(e) => {
+                    return TestResult::Failed(format!("Turn {} failed: {}", turn_idx + 1, e))
+                }
+            }
+        }
+
+        // Validate final expected outcomes
+        for expected_outcome in &test.expected_outcomes {
+            if let Err(e) = self.validate_outcome(&state, expected_outcome) {
+                return TestResult::Failed(format!("Final outcome validation failed: {}", e));
+            }
+        }
+
+        TestResult::Success
+    }
+
+    /// Build the battle state for a test with overrides and setup actions applied
+    pub fn prepare_state(&self, test: &BattleTest) -> Result<BattleState, String> {
+        // Create battle state with teams
+        let mut state = self
+            .create_battle_state(&test.team_one, &test.team_two)
+            .map_err(|e| format!("Failed to create battle state: {}", e))?;
+
         // Apply Pokemon-specific overrides
         match &test.team_one {
             TeamSpec::Pokemon(spec) => {
@@ -155,51 +203,11 @@ impl TapuTestFramework {
 
         // Apply setup actions
         for setup_action in &test.setup {
-            if let Err(e) = self.apply_setup_action(&mut state, setup_action) {
-                return TestResult::Failed(format!("Failed to apply setup action: {}", e));
-            }
-        }
-
-        // Execute moves and validate outcomes
-        for (turn_idx, (move_one, move_two)) in test.moves.iter().enumerate() {
-            // Execute the turn
-            match self.execute_turn(
-                &mut state,
-                move_one.clone(),
-                move_two.clone(),
-                test.branch_on_damage,
-            ) {
-                Ok(instructions) => {
-                    // Validate expected outcomes for this turn
-                    if let Some(expected_instructions) = test.expected_instructions.get(turn_idx) {
-                        if !self.validate_instructions(&instructions, expected_instructions) {
-                            // Show normalized instructions for debugging
-                            let normalized_actual = self.normalize_instructions(&instructions);
-                            let normalized_expected =
-                                self.normalize_instructions(expected_instructions);
-                            return TestResult::Failed(format!(
-                                "Instructions mismatch on turn {}: expected {:?}, got {:?}",
-                                turn_idx + 1,
-                                normalized_expected,
-                                normalized_actual
-                            ));
-                        }
-                    }
-                }
-                Err(e) => {
-                    return TestResult::Failed(format!("Turn {} failed: {}", turn_idx + 1, e))
-                }
-            }
+            self.apply_setup_action(&mut state, setup_action)
+                .map_err(|e| format!("Failed to apply setup action: {}", e))?;
         }
 
-        // Validate final expected outcomes
-        for expected_outcome in &test.expected_outcomes {
-            if let Err(e) = self.validate_outcome(&state, expected_outcome) {
-                return TestResult::Failed(format!("Final outcome validation failed: {}", e));
-            }
-        }
-
-        TestResult::Success
+        Ok(state)
     }
 
     /// Create a battle state with the specified teams
@@ -266,6 +274,7 @@ impl TapuTestFramework {
         let pokemon_data = self
             .generation_repository
             .find_pokemon_by_name_for_generation(spec.species, self.format.generation.number())
+            .or_else(|| self.repository.pokemon.find_by_name(spec.species))
             .ok_or_else(|| {
                 use tapu_simu::types::DataError;
                 DataError::SpeciesNotFound {
@@ -426,6 +435,8 @@ impl TapuTestFramework {
 
         pokemon.max_hp = pokemon.stats.hp;
         pokemon.hp = pokemon.max_hp;
+        pokemon.ivs = ivs;
+        pokemon.evs = evs;
 
         // Set types from Pokemon data
         pokemon.types = pokemon_data.types.clone();
@@ -456,6 +467,7 @@ impl TapuTestFramework {
                 let move_data = self
                     .generation_repository
                     .find_move_by_name_for_generation(move_name, self.format.generation.number())
+                    .or_else(|| self.repository.moves.find_by_name(move_name))
                     .ok_or_else(|| {
                         use tapu_simu::types::DataError;
                         DataError::MoveNotFound {
@@ -543,6 +555,13 @@ impl TapuTestFramework {
                 let side_state = state.get_side_by_ref_mut(*side);
                 side_state.side_conditions.insert(*condition, 1);
             }
+            SetupAction::ApplyVolatile(position, status) => {
+                if let Some(pokemon) = state.get_pokemon_at_position_mut(*position) {
+                    pokemon.volatile_statuses.insert(*status);
+                } else {
+                    return Err(format!("No Pokemon at position {:?}", position));
+                }
+            }
             SetupAction::AddSubstitute(position, health) => {
                 if let Some(pokemon) = state.get_pokemon_at_position_mut(*position) {
                     pokemon.volatile_statuses.insert(VolatileStatus::Substitute);
@@ -723,6 +742,14 @@ impl TapuTestFramework {
                     ));
                 }
             }
+            ExpectedOutcome::NoSideCondition(side, condition) => {
+                if state.get_side_by_ref(*side).side_conditions.contains_key(condition) {
+                    return Err(format!(
+                        "Side condition {:?} should not be present for {:?}",
+                        condition, side
+                    ));
+                }
+            }
             ExpectedOutcome::Faint(position) => {
                 if let Some(pokemon) = state.get_pokemon_at_position(*position) {
                     if pokemon.hp > 0 {
@@ -795,6 +822,18 @@ impl TapuTestFramework {
                     return Err(format!("No Pokemon at position {:?}", position));
                 }
             }
+            ExpectedOutcome::NoVolatileStatus(position, status) => {
+                if let Some(pokemon) = state.get_pokemon_at_position(*position) {
+                    if pokemon.volatile_statuses.contains(*status) {
+                        return Err(format!(
+                            "Volatile status {:?} should not be present at {:?}",
+                            status, position
+                        ));
+                    }
+                } else {
+                    return Err(format!("No Pokemon at position {:?}", position));
+                }
+            }
         }
 
         Ok(())
@@ -850,6 +889,7 @@ pub enum SetupAction {
     SetHP(BattlePosition, u16),
     AddSideCondition(SideReference, SideCondition),
     AddSubstitute(BattlePosition, i16),
+    ApplyVolatile(BattlePosition, VolatileStatus),
 }
 
 /// Expected outcomes for test validation
@@ -861,12 +901,14 @@ pub enum ExpectedOutcome {
     WeatherSet(Weather),
     TerrainSet(Terrain),
     SideCondition(SideReference, SideCondition),
+    NoSideCondition(SideReference, SideCondition),
     Faint(BattlePosition),
     Switch(BattlePosition, usize),
     NoEffect(BattlePosition),
     Instructions(Vec<BattleInstructions>),
     SubstituteHealth(BattlePosition, i16),
     VolatileStatus(BattlePosition, VolatileStatus),
+    NoVolatileStatus(BattlePosition, VolatileStatus),
 }
 
 /// Complete battle test specification
@@ -0,0 +1,194 @@
+//! # Weather Tests
+//!
+//! This module contains tests for how weather plays out by generation: Snow
+//! replacing Hail's chip damage with an Ice-type Defense boost, sand's boost to
+//! Rock types, the abilities that read the weather, and the Cloud Nine, Air
+//! Lock and Utility Umbrella effects that hide it.
+
+mod utils;
+
+use tapu_simu::core::battle_format::{BattlePosition, SideReference};
+use tapu_simu::core::instructions::{BattleInstructions, Weather};
+use tapu_simu::generation::Generation;
+
+use utils::convenience::damage_in;
+use utils::{PokemonSpec, Positions, TestBuilder};
+
+/// Damage in the most likely branch, the one without a critical hit
+fn hit_damage(branches: &[BattleInstructions], position: BattlePosition) -> i16 {
+    let likeliest = branches
+        .iter()
+        .max_by(|a, b| a.percentage.total_cmp(&b.percentage))
+        .expect("no branches");
+    damage_in(likeliest, position)
+}
+
+/// Chance the attack on side two lands
+fn chance_to_hit(branches: &[BattleInstructions]) -> f32 {
+    branches
+        .iter()
+        .filter(|branch| damage_in(branch, Positions::SIDE_TWO_0) > 0)
+        .map(|branch| branch.percentage)
+        .sum()
+}
+
+/// Damage side one's `attack` deals to `defender`, in `weather` when given
+fn attack_damage(
+    attacker: PokemonSpec,
+    attack: &'static str,
+    defender: PokemonSpec,
+    weather: Option<Weather>,
+) -> i16 {
+    let mut builder = TestBuilder::new("weather damage")
+        .unwrap()
+        .team_one(attacker.moves(vec![attack]))
+        .team_two(defender.moves(vec!["Splash"]));
+    if let Some(weather) = weather {
+        builder = builder.with_weather(weather);
+    }
+    let attack = builder.create_move_choice(attack, SideReference::SideOne);
+    let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+    hit_damage(&builder.generate(&attack, &splash), Positions::SIDE_TWO_0)
+}
+
+/// End-of-turn weather damage to `target` in `weather`, with `partner` on the other side
+fn chip_damage(generation: Generation, weather: Weather, partner: PokemonSpec, target: PokemonSpec) -> i16 {
+    let builder = TestBuilder::new_with_generation("weather chip", generation)
+        .unwrap()
+        .team_one(partner.moves(vec!["Splash"]))
+        .team_two(target.moves(vec!["Splash"]))
+        .with_weather(weather);
+    let splash_one = builder.create_move_choice("Splash", SideReference::SideOne);
+    let splash_two = builder.create_move_choice("Splash", SideReference::SideTwo);
+    let branches = builder.generate(&splash_one, &splash_two);
+    assert_eq!(branches.len(), 1);
+    damage_in(&branches[0], Positions::SIDE_TWO_0)
+}
+
+// ============================================================================
+// Snow and Hail
+// ============================================================================
+
+/// Test Gen 8 Hail chips 1/16 while Gen 9 Snow deals no damage
+#[test]
+fn test_snow_deals_no_chip_damage_unlike_hail() {
+    let blissey = || PokemonSpec::new("Blissey");
+    let chip = chip_damage(Generation::Gen8, Weather::Hail, PokemonSpec::new("Garchomp"), blissey());
+    let state = TestBuilder::new_with_generation("blissey", Generation::Gen8)
+        .unwrap()
+        .team_one(PokemonSpec::new("Garchomp"))
+        .team_two(blissey())
+        .build_state();
+    let max_hp = state.get_pokemon_at_position(Positions::SIDE_TWO_0).unwrap().max_hp;
+    assert_eq!(chip, max_hp / 16);
+
+    assert_eq!(chip_damage(Generation::Gen9, Weather::Snow, PokemonSpec::new("Garchomp"), blissey()), 0);
+    // Hail set in Gen 9 is read as Snow
+    assert_eq!(chip_damage(Generation::Gen9, Weather::Hail, PokemonSpec::new("Garchomp"), blissey()), 0);
+}
+
+/// Test Snow raises the Defense of Ice types by half
+#[test]
+fn test_snow_boosts_ice_type_defense() {
+    let physical = |weather| {
+        attack_damage(PokemonSpec::new("Garchomp"), "Earthquake", PokemonSpec::new("Glaceon"), weather)
+    };
+    let clear = physical(None) as f32;
+    let snow = physical(Some(Weather::Snow)) as f32;
+    assert!(snow < clear);
+    assert!((snow - clear / 1.5).abs() <= 2.0, "clear {} snow {}", clear, snow);
+
+    // Special Defense is untouched
+    let special = |weather| {
+        attack_damage(PokemonSpec::new("Heatran"), "Flamethrower", PokemonSpec::new("Glaceon"), weather)
+    };
+    assert_eq!(special(None), special(Some(Weather::Snow)));
+}
+
+// ============================================================================
+// Sand
+// ============================================================================
+
+/// Test sand raises the Special Defense of Rock types by half
+#[test]
+fn test_sand_boosts_rock_type_special_defense() {
+    let special = |weather| {
+        attack_damage(PokemonSpec::new("Vaporeon"), "Surf", PokemonSpec::new("Golem"), weather)
+    };
+    let clear = special(None) as f32;
+    let sand = special(Some(Weather::Sand)) as f32;
+    assert!(sand < clear);
+    assert!((sand - clear / 1.5).abs() <= 2.0, "clear {} sand {}", clear, sand);
+}
+
+/// Test Sand Force powers up Ground moves in sand
+#[test]
+fn test_sand_force_boosts_ground_moves_in_sand() {
+    let earthquake = |ability, weather| {
+        attack_damage(PokemonSpec::new("Excadrill").ability(ability), "Earthquake", PokemonSpec::new("Metagross"), weather)
+    };
+    let without = earthquake("Sand Rush", Some(Weather::Sand)) as f32;
+    let with = earthquake("Sand Force", Some(Weather::Sand)) as f32;
+    assert!(with > without);
+    assert!((with - without * 1.3).abs() <= 2.0, "without {} with {}", without, with);
+    // No boost outside sand
+    assert_eq!(earthquake("Sand Force", None), earthquake("Sand Rush", None));
+}
+
+/// Test Sand Veil in sand and Snow Cloak in Snow make the holder harder to hit
+#[test]
+fn test_sand_veil_and_snow_cloak_evasion() {
+    let tackle_lands = |defender: PokemonSpec, weather: Option<Weather>| {
+        let mut builder = TestBuilder::new("weather evasion")
+            .unwrap()
+            .team_one(PokemonSpec::new("Blissey").moves(vec!["Tackle"]))
+            .team_two(defender.moves(vec!["Splash"]));
+        if let Some(weather) = weather {
+            builder = builder.with_weather(weather);
+        }
+        let tackle = builder.create_move_choice("Tackle", SideReference::SideOne);
+        let splash = builder.create_move_choice("Splash", SideReference::SideTwo);
+        chance_to_hit(&builder.generate(&tackle, &splash))
+    };
+
+    let garchomp = || PokemonSpec::new("Garchomp").ability("Sand Veil");
+    assert_eq!(tackle_lands(garchomp(), None), 100.0);
+    assert!((tackle_lands(garchomp(), Some(Weather::Sand)) - 80.0).abs() < 0.01);
+
+    let glaceon = || PokemonSpec::new("Glaceon").ability("Snow Cloak");
+    assert_eq!(tackle_lands(glaceon(), None), 100.0);
+    assert!((tackle_lands(glaceon(), Some(Weather::Snow)) - 80.0).abs() < 0.01);
+}
+
+// ============================================================================
+// Hiding the weather
+// ============================================================================
+
+/// Test Cloud Nine and Air Lock on the field stop Hail's chip damage
+#[test]
+fn test_cloud_nine_and_air_lock_cancel_weather() {
+    for (species, ability) in [("Golduck", "Cloud Nine"), ("Rayquaza", "Air Lock")] {
+        let partner = PokemonSpec::new(species).ability(ability);
+        let chip = chip_damage(Generation::Gen8, Weather::Hail, partner, PokemonSpec::new("Blissey"));
+        assert_eq!(chip, 0, "{} should cancel Hail", ability);
+    }
+
+    // Rain's boost to Water moves is gone too
+    let surf = |ability, weather| {
+        attack_damage(PokemonSpec::new("Golduck").ability(ability), "Surf", PokemonSpec::new("Blissey"), weather)
+    };
+    assert!(surf("Damp", Some(Weather::Rain)) > surf("Damp", None));
+    assert_eq!(surf("Cloud Nine", Some(Weather::Rain)), surf("Cloud Nine", None));
+}
+
+/// Test a Utility Umbrella holder takes Water moves in rain as if the weather were clear
+#[test]
+fn test_utility_umbrella_ignores_rain() {
+    let surf = |defender: PokemonSpec, weather| {
+        attack_damage(PokemonSpec::new("Vaporeon"), "Surf", defender, weather)
+    };
+    let blissey = || PokemonSpec::new("Blissey");
+    assert!(surf(blissey(), Some(Weather::Rain)) > surf(blissey(), None));
+    let umbrella = || PokemonSpec::new("Blissey").item("Utility Umbrella");
+    assert_eq!(surf(umbrella(), Some(Weather::Rain)), surf(umbrella(), None));
+}